@@ -0,0 +1,82 @@
+#!/usr/bin/env python3
+"""Generates validator/src/validation/comparison/tables.rs.
+
+The full case folding of Unicode (the C and F mappings of `CaseFolding.txt`) is read from ICU,
+whose version is pinned below so that the table only changes with a deliberate upgrade. Run it
+from the root of the repository:
+
+    python3 scripts/case_folding_table.py > validator/src/validation/comparison/tables.rs
+"""
+
+import ctypes
+
+ICU_VERSION = 72
+UNICODE_VERSION = "15.0.0"
+
+U_FOLD_CASE_DEFAULT = 0
+U_SURROGATE = 18
+
+uc = ctypes.CDLL("libicuuc.so.%d" % ICU_VERSION)
+
+
+def icu(name, restype, *argtypes):
+    function = getattr(uc, "%s_%d" % (name, ICU_VERSION))
+    function.restype = restype
+    function.argtypes = argtypes
+    return function
+
+
+u_getUnicodeVersion = icu("u_getUnicodeVersion", None, ctypes.c_void_p)
+u_charType = icu("u_charType", ctypes.c_int8, ctypes.c_int32)
+u_strFoldCase = icu(
+    "u_strFoldCase", ctypes.c_int32,
+    ctypes.c_void_p, ctypes.c_int32, ctypes.c_void_p, ctypes.c_int32, ctypes.c_uint32, ctypes.c_void_p,
+)
+
+
+def unicode_version():
+    version = (ctypes.c_uint8 * 4)()
+    u_getUnicodeVersion(version)
+    return "%d.%d.%d" % tuple(version[:3])
+
+
+def fold(c):
+    source = chr(c).encode("utf-16-le")
+    error = ctypes.c_int(0)
+    out = (ctypes.c_uint16 * 16)()
+    n = u_strFoldCase(out, 16, source, len(source) // 2, U_FOLD_CASE_DEFAULT, ctypes.byref(error))
+    if error.value > 0:
+        raise RuntimeError("ICU error %d" % error.value)
+    return bytes(out)[: n * 2].decode("utf-16-le")
+
+
+def rust_char(c):
+    return "\\u{%X}" % ord(c) if ord(c) >= 0x7F or c in "\\'\"" else c
+
+
+def main():
+    assert unicode_version() == UNICODE_VERSION, unicode_version()
+
+    folds = []
+    for c in range(0x110000):
+        if u_charType(c) == U_SURROGATE:
+            continue
+        folded = fold(c)
+        if folded != chr(c):
+            folds.append((c, folded))
+
+    print("// Generated by scripts/case_folding_table.py from the Unicode %s data of ICU %d,"
+          % (UNICODE_VERSION, ICU_VERSION))
+    print("// do not edit by hand")
+    print()
+    print("/// The full case folding of the characters that are changed by it, the C and F mappings of")
+    print("/// `CaseFolding.txt`, sorted by character")
+    print("#[rustfmt::skip]")
+    print("pub(super) static CASE_FOLDING: [(char, &str); %d] = [" % len(folds))
+    for c, folded in folds:
+        print("    ('\\u{%X}', \"%s\")," % (c, "".join(rust_char(f) for f in folded)))
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,173 @@
+#!/usr/bin/env python3
+"""Generates validator/src/validation/unicode_security/tables.rs.
+
+The Script and Script_Extensions properties of UAX #24 and the skeletons of the confusables data
+of UTS #39 are read from ICU, which embeds the Unicode character database and `confusables.txt`.
+The ICU version is pinned below so that the tables only change with a deliberate upgrade. Run it
+from the root of the repository:
+
+    python3 scripts/unicode_security_tables.py > validator/src/validation/unicode_security/tables.rs
+"""
+
+import ctypes
+
+ICU_VERSION = 72
+UNICODE_VERSION = "15.0.0"
+
+# Hangul syllables are decomposed algorithmically instead of being listed in the table
+HANGUL_SYLLABLES = range(0xAC00, 0xD7A4)
+
+U_UNASSIGNED = 0
+U_SURROGATE = 18
+
+uc = ctypes.CDLL("libicuuc.so.%d" % ICU_VERSION)
+i18n = ctypes.CDLL("libicui18n.so.%d" % ICU_VERSION)
+
+
+def icu(lib, name, restype, *argtypes):
+    function = getattr(lib, "%s_%d" % (name, ICU_VERSION))
+    function.restype = restype
+    function.argtypes = argtypes
+    return function
+
+
+u_getUnicodeVersion = icu(uc, "u_getUnicodeVersion", None, ctypes.c_void_p)
+u_charType = icu(uc, "u_charType", ctypes.c_int8, ctypes.c_int32)
+uscript_getScript = icu(uc, "uscript_getScript", ctypes.c_int, ctypes.c_int32, ctypes.c_void_p)
+uscript_getScriptExtensions = icu(
+    uc, "uscript_getScriptExtensions", ctypes.c_int32,
+    ctypes.c_int32, ctypes.c_void_p, ctypes.c_int32, ctypes.c_void_p,
+)
+uscript_getName = icu(uc, "uscript_getName", ctypes.c_char_p, ctypes.c_int)
+uspoof_open = icu(i18n, "uspoof_open", ctypes.c_void_p, ctypes.c_void_p)
+uspoof_getSkeleton = icu(
+    i18n, "uspoof_getSkeleton", ctypes.c_int32,
+    ctypes.c_void_p, ctypes.c_uint32, ctypes.c_void_p, ctypes.c_int32,
+    ctypes.c_void_p, ctypes.c_int32, ctypes.c_void_p,
+)
+
+
+def check(error):
+    if error.value > 0:
+        raise RuntimeError("ICU error %d" % error.value)
+
+
+def unicode_version():
+    version = (ctypes.c_uint8 * 4)()
+    u_getUnicodeVersion(version)
+    return "%d.%d.%d" % tuple(version[:3])
+
+
+def script_name(code):
+    return uscript_getName(code).decode().replace("_", "")
+
+
+def script(c):
+    error = ctypes.c_int(0)
+    code = uscript_getScript(c, ctypes.byref(error))
+    check(error)
+    return script_name(code)
+
+
+def script_extensions(c):
+    error = ctypes.c_int(0)
+    codes = (ctypes.c_int * 64)()
+    n = uscript_getScriptExtensions(c, codes, 64, ctypes.byref(error))
+    check(error)
+    return tuple(sorted(script_name(code) for code in codes[:n]))
+
+
+def skeleton(checker, c):
+    source = chr(c).encode("utf-16-le")
+    error = ctypes.c_int(0)
+    out = (ctypes.c_uint16 * 64)()
+    n = uspoof_getSkeleton(checker, 0, source, len(source) // 2, out, 64, ctypes.byref(error))
+    check(error)
+    return bytes(out)[: n * 2].decode("utf-16-le")
+
+
+def is_assigned(c):
+    return u_charType(c) not in (U_UNASSIGNED, U_SURROGATE)
+
+
+def ranges(values):
+    """Merges the consecutive code points having the same value into inclusive ranges"""
+    merged = []
+    for c, value in values:
+        if merged and merged[-1][1] == c - 1 and merged[-1][2] == value:
+            merged[-1][1] = c
+        else:
+            merged.append([c, c, value])
+    return merged
+
+
+def rust_char(c):
+    return "\\u{%X}" % ord(c) if ord(c) >= 0x7F or ord(c) < 0x20 or c in "\\'\"" else c
+
+
+def main():
+    assert unicode_version() == UNICODE_VERSION, unicode_version()
+
+    assigned = [c for c in range(0x110000) if is_assigned(c)]
+    scripts = [(c, script(c)) for c in assigned]
+    script_ranges = ranges((c, s) for c, s in scripts if s != "Unknown")
+    extension_ranges = ranges(
+        (c, extensions)
+        for (c, s), extensions in zip(scripts, map(script_extensions, assigned))
+        if extensions != (s,)
+    )
+    used = {s for _, s in scripts} | {s for _, _, e in extension_ranges for s in e}
+    names = ["Common", "Inherited"]
+    names += sorted(used - {"Common", "Inherited", "Unknown"}) + ["Unknown"]
+
+    error = ctypes.c_int(0)
+    checker = uspoof_open(ctypes.byref(error))
+    check(error)
+    confusables = []
+    for c in assigned:
+        if c in HANGUL_SYLLABLES:
+            continue
+        prototype = skeleton(checker, c)
+        if prototype != chr(c):
+            confusables.append((c, prototype))
+
+    print("// Generated by scripts/unicode_security_tables.py from the Unicode %s data of ICU %d,"
+          % (UNICODE_VERSION, ICU_VERSION))
+    print("// do not edit by hand")
+    print()
+    print("/// The scripts of UAX #24.")
+    print("#[allow(clippy::enum_variant_names)]")
+    print("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]")
+    print("pub(super) enum Script {")
+    for name in names:
+        print("    %s," % name)
+    print("}")
+    print()
+    print("/// Inclusive ranges of code points and their Script property, sorted and without overlaps.")
+    print("/// The code points that are not listed are of the `Unknown` script.")
+    print("#[rustfmt::skip]")
+    print("pub(super) static SCRIPT_RANGES: [(u32, u32, Script); %d] = [" % len(script_ranges))
+    for start, end, s in script_ranges:
+        print("    (0x%04X, 0x%04X, Script::%s)," % (start, end, s))
+    print("];")
+    print()
+    print("/// Inclusive ranges of code points and their Script_Extensions property, for the code points")
+    print("/// used by other scripts than their own, like the Arabic comma `،` used by Syriac and Thaana")
+    print("#[rustfmt::skip]")
+    print("pub(super) static SCRIPT_EXTENSIONS: [(u32, u32, &[Script]); %d] = [" % len(extension_ranges))
+    for start, end, extensions in extension_ranges:
+        print("    (0x%04X, 0x%04X, &[%s])," % (start, end, ", ".join("Script::" + e for e in extensions)))
+    print("];")
+    print()
+    print("/// The skeleton of the characters that are not their own skeleton: the prototype of")
+    print("/// `confusables.txt` of each character of their NFD form, like `𝐚` being `a` and `é` being")
+    print("/// `e\\u{301}`. Hangul syllables are not listed.")
+    print("#[rustfmt::skip]")
+    print("pub(super) static CONFUSABLES: [(char, &str); %d] = [" % len(confusables))
+    for c, prototype in confusables:
+        print("    ('\\u{%X}', \"%s\")," % (c, "".join(rust_char(p) for p in prototype)))
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,390 @@
+use std::fmt;
+
+use crate::{ErrorPath, PathSegment, ValidationError, ValidationErrors};
+
+/// The position of a value in a JSON document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceSpan {
+    /// The byte offset of the start of the value
+    pub start: usize,
+    /// The byte offset of the end of the value, exclusive
+    pub end: usize,
+    /// The line of the start of the value, starting at 1
+    pub line: usize,
+    /// The column of the start of the value in characters, starting at 1
+    pub column: usize,
+}
+
+/// A validation error with the path of the field it is about and its position in the document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocatedError<'e> {
+    pub path: ErrorPath,
+    pub error: &'e ValidationError,
+    /// The span of the value of the field or, if it isn't in the document like for a missing
+    /// field, of its closest parent. `None` if the document isn't valid JSON or is nested more than
+    /// 128 levels deep, like serde_json refuses to.
+    pub span: Option<SourceSpan>,
+}
+
+impl ValidationErrors {
+    /// Finds the values the errors are about in `source`, the JSON document that was deserialized
+    /// into the validated value. The errors are sorted by position in the document.
+    ///
+    /// `__all__` errors point to the object they are about.
+    pub fn locate_in_json<'e>(&'e self, source: &str) -> Vec<LocatedError<'e>> {
+        let root = Parser { source: source.as_bytes(), pos: 0, depth: 0 }.parse_document();
+        let mut located = self
+            .iter_flat()
+            .map(|(path, error)| {
+                let span = root.as_ref().map(|root| root.find(path.segments()).span(source));
+                LocatedError { path, error, span }
+            })
+            .collect::<Vec<_>>();
+        located.sort_by_key(|e| e.span.map_or(usize::MAX, |s| s.start));
+        located
+    }
+
+    /// Returns a report displaying each error with the snippet of `source` it is about.
+    pub fn json_report<'a>(&'a self, source: &'a str) -> JsonReport<'a> {
+        JsonReport { source, errors: self.locate_in_json(source) }
+    }
+}
+
+/// Displays the validation errors with the lines of the JSON document they are about, the value
+/// being underlined with carets:
+///
+/// ```text
+/// error: items[3].price: must be at least 0
+///  --> 12:14
+///    |
+/// 12 |     "price": -1,
+///    |              ^^
+/// ```
+pub struct JsonReport<'a> {
+    source: &'a str,
+    errors: Vec<LocatedError<'a>>,
+}
+
+impl JsonReport<'_> {
+    pub fn errors(&self) -> &[LocatedError<'_>] {
+        &self.errors
+    }
+}
+
+impl fmt::Display for JsonReport<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, located) in self.errors.iter().enumerate() {
+            if idx > 0 {
+                writeln!(fmt)?;
+            }
+            writeln!(fmt, "error: {}: {}", located.path, located.error)?;
+            let Some(span) = located.span else {
+                continue;
+            };
+
+            let line_start = self.source[..span.start].rfind('\n').map_or(0, |i| i + 1);
+            let line_end =
+                self.source[span.start..].find('\n').map_or(self.source.len(), |i| span.start + i);
+            let line = self.source[line_start..line_end].trim_end_matches('\r');
+            let width = span.line.to_string().len();
+            // Values spanning several lines are only underlined on their first line
+            let carets = self.source[span.start..span.end.min(line_end)].chars().count().max(1);
+
+            writeln!(fmt, "{:width$}--> {}:{}", "", span.line, span.column, width = width)?;
+            writeln!(fmt, "{:width$} |", "", width = width)?;
+            writeln!(fmt, "{} | {}", span.line, line)?;
+            writeln!(
+                fmt,
+                "{:width$} | {:indent$}{}",
+                "",
+                "",
+                "^".repeat(carets),
+                width = width,
+                indent = span.column - 1
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// The values of a JSON document with their byte offsets
+#[derive(Debug)]
+struct Node {
+    start: usize,
+    end: usize,
+    kind: NodeKind,
+}
+
+#[derive(Debug)]
+enum NodeKind {
+    Object(Vec<(String, Node)>),
+    Array(Vec<Node>),
+    Scalar,
+}
+
+impl Node {
+    // Returns the value at `path` or its closest parent in the document
+    fn find(&self, path: &[PathSegment]) -> &Node {
+        let mut node = self;
+        for segment in path {
+            let child = match segment {
+                PathSegment::Field(key) => node.field(key),
+                PathSegment::Key(key) => node.field(key),
+                PathSegment::Index(index) => node.item(*index),
+            };
+            match child {
+                Some(child) => node = child,
+                None => break,
+            }
+        }
+        node
+    }
+
+    fn field(&self, key: &str) -> Option<&Node> {
+        match &self.kind {
+            // The last value wins for duplicate keys, like with serde_json
+            NodeKind::Object(fields) => fields.iter().rev().find(|(k, _)| k == key).map(|(_, n)| n),
+            _ => None,
+        }
+    }
+
+    fn item(&self, index: usize) -> Option<&Node> {
+        match &self.kind {
+            NodeKind::Array(items) => items.get(index),
+            _ => None,
+        }
+    }
+
+    fn span(&self, source: &str) -> SourceSpan {
+        let before = &source[..self.start];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        SourceSpan {
+            start: self.start,
+            end: self.end,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+// A JSON parser keeping only the structure and offsets of the values, returning `None` for invalid
+// documents
+struct Parser<'a> {
+    source: &'a [u8],
+    pos: usize,
+    // The number of objects and arrays the parser is in, bounded by `MAX_DEPTH` so that deeply
+    // nested documents don't overflow the stack
+    depth: usize,
+}
+
+// The recursion limit of serde_json
+const MAX_DEPTH: usize = 128;
+
+impl Parser<'_> {
+    fn parse_document(mut self) -> Option<Node> {
+        let node = self.parse_value()?;
+        self.skip_whitespace();
+        (self.pos == self.source.len()).then_some(node)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.source.get(self.pos), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        self.skip_whitespace();
+        let found = self.source.get(self.pos) == Some(&byte);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn parse_value(&mut self) -> Option<Node> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let kind = match self.source.get(self.pos)? {
+            b'{' | b'[' if self.depth == MAX_DEPTH => return None,
+            b'{' => {
+                self.pos += 1;
+                self.depth += 1;
+                let mut fields = Vec::new();
+                if !self.eat(b'}') {
+                    loop {
+                        self.skip_whitespace();
+                        let key = self.parse_string()?;
+                        if !self.eat(b':') {
+                            return None;
+                        }
+                        fields.push((key, self.parse_value()?));
+                        if self.eat(b'}') {
+                            break;
+                        }
+                        if !self.eat(b',') {
+                            return None;
+                        }
+                    }
+                }
+                self.depth -= 1;
+                NodeKind::Object(fields)
+            }
+            b'[' => {
+                self.pos += 1;
+                self.depth += 1;
+                let mut items = Vec::new();
+                if !self.eat(b']') {
+                    loop {
+                        items.push(self.parse_value()?);
+                        if self.eat(b']') {
+                            break;
+                        }
+                        if !self.eat(b',') {
+                            return None;
+                        }
+                    }
+                }
+                self.depth -= 1;
+                NodeKind::Array(items)
+            }
+            b'"' => {
+                self.parse_string()?;
+                NodeKind::Scalar
+            }
+            _ => {
+                let len = self.source[self.pos..]
+                    .iter()
+                    .take_while(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'+' | b'.'))
+                    .count();
+                if len == 0 {
+                    return None;
+                }
+                self.pos += len;
+                NodeKind::Scalar
+            }
+        };
+        Some(Node { start, end: self.pos, kind })
+    }
+
+    fn parse_string(&mut self) -> Option<String> {
+        if self.source.get(self.pos) != Some(&b'"') {
+            return None;
+        }
+        self.pos += 1;
+        let start = self.pos;
+        let mut escaped = false;
+        loop {
+            match self.source.get(self.pos)? {
+                b'\\' => {
+                    escaped = true;
+                    self.pos += 2;
+                }
+                b'"' => break,
+                _ => self.pos += 1,
+            }
+        }
+        let raw = std::str::from_utf8(self.source.get(start..self.pos)?).ok()?;
+        self.pos += 1;
+        if escaped {
+            serde_json::from_str(&format!("\"{}\"", raw)).ok()
+        } else {
+            Some(raw.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use crate::{ValidationError, ValidationErrors};
+
+    const SOURCE: &str = r#"{
+  "name": "",
+  "items": [
+    {"price": 1},
+    {"price": -1, "name": "pen"}
+  ]
+}"#;
+
+    fn errors() -> ValidationErrors {
+        let mut errors = ValidationErrors::new();
+        errors.add("name", ValidationError::new("length"));
+        errors.add_item("items", 1, "price", ValidationError::new("range"));
+        errors.add_item("items", 1, "name", ValidationError::new("length"));
+        errors.add_item("items", 0, "name", ValidationError::new("missing"));
+        errors
+    }
+
+    #[test]
+    fn test_locate_in_json() {
+        let errors = errors();
+        let located = errors.locate_in_json(SOURCE);
+        let found = located
+            .iter()
+            .map(|e| {
+                let span = e.span.unwrap();
+                (
+                    e.path.to_string(),
+                    e.error.code.as_ref(),
+                    span.line,
+                    span.column,
+                    span.end - span.start,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            found,
+            vec![
+                ("name".to_string(), "length", 2, 11, 2),
+                ("items[0].name".to_string(), "missing", 4, 5, 12),
+                ("items[1].price".to_string(), "range", 5, 15, 2),
+                ("items[1].name".to_string(), "length", 5, 27, 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_json_has_no_spans() {
+        let errors = errors();
+        let located = errors.locate_in_json("{\"name\": ");
+        assert_eq!(located.len(), 4);
+        assert!(located.iter().all(|e| e.span.is_none()));
+    }
+
+    #[test]
+    fn test_deeply_nested_json_has_no_spans() {
+        let errors = errors();
+        let nested = |depth| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(errors.locate_in_json(&nested(128)).iter().all(|e| e.span.is_some()));
+        assert!(errors.locate_in_json(&nested(129)).iter().all(|e| e.span.is_none()));
+        assert!(errors.locate_in_json(&nested(100_000)).iter().all(|e| e.span.is_none()));
+    }
+
+    #[test]
+    fn test_json_report() {
+        let mut errors = ValidationErrors::new();
+        errors.add(
+            "name",
+            ValidationError::new("length").with_message(Cow::from("The name can't be empty")),
+        );
+        errors.add_item("items", 1, "price", ValidationError::new("range"));
+
+        let report = errors.json_report(SOURCE).to_string();
+        let expected = r#"error: name: The name can't be empty
+ --> 2:11
+  |
+2 |   "name": "",
+  |           ^^
+
+error: items[1].price: out of range
+ --> 5:15
+  |
+5 |     {"price": -1, "name": "pen"}
+  |               ^^
+"#;
+        assert_eq!(report, expected);
+    }
+}
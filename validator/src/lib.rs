@@ -39,16 +39,36 @@
 //! | ----------------------- | ----------------------------------------------------- |
 //! | `email`                 |                                                       |
 //! | `url`                   |                                                       |
+//! | `public_url`            |                                                       |
+//! | `ip`                    |                                                       |
+//! | `cidr`                  |                                                       |
+//! | `socket_addr`           |                                                       |
 //! | `length`                |                                                       |
 //! | `range`                 |                                                       |
 //! | `must_match`            |                                                       |
 //! | `contains`              |                                                       |
 //! | `does_not_contain`      |                                                       |
+//! | `one_of`                |                                                       |
+//! | `none_of`               |                                                       |
+//! | `unique`                |                                                       |
+//! | `unique_by`             |                                                       |
+//! | `sorted`                |                                                       |
 //! | `custom`                |                                                       |
 //! | `regex`                 |                                                       |
 //! | `credit_card`           | (Requires the feature `card` to be enabled)           |
+//! | `card_expiry`           | (Requires the feature `card` to be enabled)           |
+//! | `card_cvv`              | (Requires the feature `card` to be enabled)           |
 //! | `non_control_character` |                                                       |
+//! | `normalized`            | (Requires the feature `unicode_normalization`)        |
+//! | `single_script`         |                                                       |
+//! | `no_confusable_with`    |                                                       |
+//! | `no_bidi_controls`      |                                                       |
+//! | `no_invisible`          |                                                       |
+//! | `password`              |                                                       |
 //! | `required`              |                                                       |
+//! | `not_blank`             |                                                       |
+//! | `non_empty`             |                                                       |
+//! | `trimmed`               |                                                       |
 //!
 //! [Checkout the project README of an in-depth usage description with examples.](https://github.com/Keats/validator/blob/master/README.md)
 //!
@@ -61,26 +81,65 @@
 //! ```
 
 mod display_impl;
+mod json_source;
+mod messages;
+mod path;
+mod redact;
+mod sanitize;
+pub mod serde;
 mod traits;
 mod types;
+mod valid;
 mod validation;
 
+pub use validation::blank::{ValidateNonEmpty, ValidateNotBlank, ValidateTrimmed};
 #[cfg(feature = "card")]
-pub use validation::cards::ValidateCreditCard;
-pub use validation::contains::ValidateContains;
-pub use validation::does_not_contain::ValidateDoesNotContain;
+pub use validation::cards::{
+    CardBrand, Clock, CreditCardError, SystemClock, ValidateCardCvv, ValidateCardExpiry,
+    ValidateCreditCard, YearMonth,
+};
+pub use validation::cidr::{IpCidr, IpCidrParseError, ValidateCidr};
+pub use validation::comparison::{AsComparisonStr, TextComparison};
+pub use validation::contains::{validate_contains_with, ValidateContains};
+pub use validation::does_not_contain::{validate_does_not_contain_with, ValidateDoesNotContain};
 pub use validation::email::ValidateEmail;
-pub use validation::ip::ValidateIp;
+pub use validation::ip::{IpRules, IpVersion, ValidateIp};
 pub use validation::length::ValidateLength;
-pub use validation::must_match::validate_must_match;
+pub use validation::must_match::{validate_must_match, validate_must_match_with};
 pub use validation::non_control_character::ValidateNonControlCharacter;
+#[cfg(feature = "unicode_normalization")]
+pub use validation::normalized::{NormalizationForm, ValidateNormalized};
+pub use validation::one_of::{validate_none_of_with, validate_one_of_with, ValidateOneOf};
+pub use validation::password::{
+    estimate_password_strength, PasswordBlocklist, PasswordError, PasswordErrorKind, PasswordRules,
+    PasswordStrength, PasswordSuggestion, PasswordUserInput, ValidatePassword,
+};
+pub use validation::pattern_set::{
+    validate_contains_any, validate_does_not_contain_any, PatternSet,
+};
 pub use validation::range::ValidateRange;
 pub use validation::regex::{AsRegex, ValidateRegex};
 pub use validation::required::ValidateRequired;
-pub use validation::urls::ValidateUrl;
+pub use validation::socket_addr::ValidateSocketAddr;
+pub use validation::sorted::{validate_sorted, SortOrder};
+pub use validation::unicode_security::{skeleton, ValidateUnicodeSecurity};
+pub use validation::unique::validate_unique;
+pub use validation::urls::{
+    HostResolver, PublicUrlViolation, StaticResolver, SystemResolver, UrlPolicy,
+    UrlPolicyViolation, ValidateUrl,
+};
 
-pub use traits::{Validate, ValidateArgs};
+pub use json_source::{JsonReport, LocatedError, SourceSpan};
+pub use messages::{reset_message_catalog, set_message_catalog, DefaultMessages, MessageCatalog};
+pub use path::{AsErrorPath, ErrorPath, ErrorPathParseError, IntoIterFlat, IterFlat, PathSegment};
+pub use redact::REDACTED;
+pub use sanitize::{Sanitize, SanitizeStrings, Sanitizer};
+pub use traits::{Validate, ValidateArgs, ValidateNested};
 pub use types::{ValidationError, ValidationErrors, ValidationErrorsKind};
+pub use valid::Valid;
+
+/// The `indexmap` crate used by `ValidationErrors`, to build or match errors without depending on it
+pub use indexmap;
 
 #[cfg(feature = "derive")]
-pub use validator_derive::Validate;
+pub use validator_derive::{Sanitize, Validate};
@@ -0,0 +1,246 @@
+use std::borrow::Cow;
+use std::sync::{Arc, RwLock};
+
+use serde_json::Value;
+
+use crate::ValidationError;
+
+/// Gives the message template of the errors that don't have a custom message, used when
+/// displaying them. The `{param}` placeholders of the template are replaced by the params of the
+/// error.
+///
+/// Closures taking a `&ValidationError` and returning an `Option<Cow<'static, str>>` implement
+/// it, so a catalog can be set with `set_message_catalog` to translate the messages or change a
+/// few of them.
+pub trait MessageCatalog: Send + Sync {
+    /// Returns the template of the message of `error`, `None` falling back to `DefaultMessages`.
+    fn template(&self, error: &ValidationError) -> Option<Cow<'static, str>>;
+}
+
+impl<F> MessageCatalog for F
+where
+    F: Fn(&ValidationError) -> Option<Cow<'static, str>> + Send + Sync,
+{
+    fn template(&self, error: &ValidationError) -> Option<Cow<'static, str>> {
+        self(error)
+    }
+}
+
+static CATALOG: RwLock<Option<Arc<dyn MessageCatalog>>> = RwLock::new(None);
+
+/// Sets the catalog used for the messages of all the errors without a custom message, replacing
+/// the previous one. The codes it has no template for keep their default message.
+pub fn set_message_catalog(catalog: impl MessageCatalog + 'static) {
+    *CATALOG.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(catalog));
+}
+
+/// Goes back to the default messages.
+pub fn reset_message_catalog() {
+    *CATALOG.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// The default messages of the codes of the built-in validators, in English. The errors of
+/// the `custom` validators and the codes set with `code = "..."` have none, unless they use one
+/// of the built-in codes.
+pub struct DefaultMessages;
+
+impl MessageCatalog for DefaultMessages {
+    fn template(&self, error: &ValidationError) -> Option<Cow<'static, str>> {
+        let has = |name: &str| error.params.contains_key(name);
+        let template = match error.code.as_ref() {
+            "length" if has("equal") => "length must be {equal}",
+            "length" => return bounds(error, "length must be", "invalid length"),
+            "range" => return bounds(error, "must be", "out of range"),
+            "email" => "must be a valid email address",
+            "url" => "must be a valid URL",
+            "url_scheme" => "must use one of the schemes {schemes}",
+            "url_host" => "must have an allowed host",
+            "url_credentials" => "must not contain credentials",
+            "url_length" => "must be at most {max_length} characters long",
+            "url_fragment" => "must not have a fragment",
+            "url_private_address" => "must not point to the private address {address}",
+            "url_unresolvable" => "must point to a host that can be resolved",
+            "ip" if has("in_cidr") => "must be an IP address in {in_cidr}",
+            "ip" if has("not_in_cidr") => "must be an IP address outside of {not_in_cidr}",
+            "ip" => "must be a valid IP address",
+            "cidr" => "must be a valid CIDR block",
+            "socket_addr" => "must be a valid socket address",
+            "credit_card" => "must be a valid credit card number",
+            "credit_card_brand" => "must be a card of one of the brands {brands}",
+            "card_expiry" => "must be a valid expiry date that has not passed",
+            "card_cvv" => "must be a valid card security code",
+            "contains" if has("patterns") => "must contain one of {patterns}",
+            "contains" => "must contain {needle}",
+            "does_not_contain" if has("patterns") => "must not contain any of {patterns}",
+            "does_not_contain" => "must not contain {needle}",
+            "must_match" => "must match the other field",
+            "non_control_character" => "must not contain control characters",
+            "not_blank" => "must not be blank",
+            "non_empty" => "must not be empty",
+            "trimmed" => "must not start or end with whitespace",
+            "normalized" => "must be in the Unicode normalization form {form}",
+            "one_of" => "must be one of {values}",
+            "none_of" => "must not be one of {values}",
+            "password_length" => "password is too short",
+            "password_classes" => "password must use more kinds of characters",
+            "password_user_input" => "password must not contain personal information",
+            "password_blocklisted" => "password is too common",
+            "password_strength" => "password is too weak",
+            "regex" => "must match the expected format",
+            "required" => "is required",
+            "single_script" => "must not mix characters of several scripts",
+            "no_confusable_with" => "must not be confusable with another value",
+            "no_bidi_controls" => "must not contain bidirectional control characters",
+            "no_invisible" => "must not contain invisible characters",
+            "sorted" => "must be in {order} order",
+            "unique" | "unique_by" => "must not be a duplicate of the item {first_index}",
+            _ => return None,
+        };
+        Some(Cow::Borrowed(template))
+    }
+}
+
+// The template of `length` and `range`, describing the bounds that are set
+fn bounds(
+    error: &ValidationError,
+    prefix: &str,
+    without_bounds: &'static str,
+) -> Option<Cow<'static, str>> {
+    let bounds = [
+        ("min", "at least {min}"),
+        ("exclusive_min", "greater than {exclusive_min}"),
+        ("max", "at most {max}"),
+        ("exclusive_max", "less than {exclusive_max}"),
+    ]
+    .into_iter()
+    .filter(|(param, _)| error.params.contains_key(*param))
+    .map(|(_, bound)| bound)
+    .collect::<Vec<_>>();
+
+    if bounds.is_empty() {
+        Some(Cow::Borrowed(without_bounds))
+    } else {
+        Some(Cow::Owned(format!("{} {}", prefix, bounds.join(" and "))))
+    }
+}
+
+impl ValidationError {
+    /// Returns the message of the error with its params interpolated: its custom message if it has
+    /// one, otherwise the one of the catalog set with `set_message_catalog` or of
+    /// `DefaultMessages`. `None` if there is no message for its code.
+    pub fn render_message(&self) -> Option<String> {
+        if let Some(message) = &self.message {
+            return Some(self.interpolate(message));
+        }
+        let catalog = CATALOG.read().unwrap_or_else(|e| e.into_inner()).clone();
+        let template =
+            catalog.and_then(|c| c.template(self)).or_else(|| DefaultMessages.template(self))?;
+        Some(self.interpolate(&template))
+    }
+
+    /// Replaces the `{param}` placeholders of `template` by the value of the params, strings being
+    /// written without quotes and arrays as comma-separated lists. Placeholders of params the
+    /// error doesn't have are kept as they are and `{{` and `}}` are written as `{` and `}`.
+    pub fn interpolate(&self, template: &str) -> String {
+        let mut message = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find(['{', '}']) {
+            message.push_str(&rest[..start]);
+            rest = &rest[start..];
+            if rest.starts_with("{{") || rest.starts_with("}}") {
+                message.push_str(&rest[..1]);
+                rest = &rest[2..];
+                continue;
+            }
+            let param = Some(rest)
+                .filter(|rest| rest.starts_with('{'))
+                .and_then(|rest| rest[1..].find('}'))
+                .map(|end| &rest[1..end + 1])
+                .filter(|name| !name.contains('{'))
+                .and_then(|name| self.params.get(name).map(|value| (name, value)));
+            match param {
+                Some((name, value)) => {
+                    write_value(&mut message, value);
+                    rest = &rest[name.len() + 2..];
+                }
+                None => {
+                    message.push_str(&rest[..1]);
+                    rest = &rest[1..];
+                }
+            }
+        }
+        message.push_str(rest);
+        message
+    }
+}
+
+fn write_value(message: &mut String, value: &Value) {
+    match value {
+        Value::String(s) => message.push_str(s),
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    message.push_str(", ");
+                }
+                write_value(message, item);
+            }
+        }
+        value => message.push_str(&value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{DefaultMessages, MessageCatalog};
+    use crate::ValidationError;
+
+    #[test]
+    fn test_interpolate() {
+        let error = ValidationError::new("length")
+            .with_param("min", 3)
+            .with_param("value", "ab")
+            .with_param("schemes", ["http", "https"]);
+
+        assert_eq!(
+            error.interpolate("must be at least {min} characters, got {value}"),
+            "must be at least 3 characters, got ab"
+        );
+        assert_eq!(error.interpolate("one of {schemes}"), "one of http, https");
+        assert_eq!(error.interpolate("{unknown} {min"), "{unknown} {min");
+        assert_eq!(error.interpolate("{{min}} is {min}}}"), "{min} is 3}");
+        assert_eq!(error.interpolate("} {min}"), "} 3");
+        assert_eq!(error.interpolate("é{value}ü"), "éabü");
+    }
+
+    #[test]
+    fn test_default_messages() {
+        let template = |error: ValidationError| DefaultMessages.template(&error);
+
+        let length = ValidationError::new("length").with_param("min", 1).with_param("max", 5);
+        assert_eq!(template(length).unwrap(), "length must be at least {min} and at most {max}");
+        let length = ValidationError::new("length").with_param("equal", 2);
+        assert_eq!(template(length).unwrap(), "length must be {equal}");
+        let range = ValidationError::new("range").with_param("exclusive_min", 0);
+        assert_eq!(template(range).unwrap(), "must be greater than {exclusive_min}");
+        assert_eq!(
+            template(ValidationError::new("email")).unwrap(),
+            "must be a valid email address"
+        );
+        assert_eq!(template(ValidationError::new("my_code")), None);
+    }
+
+    #[test]
+    fn test_render_message() {
+        let error = ValidationError::new("range").with_param("max", 10).with_param("value", 11);
+        assert_eq!(error.render_message().unwrap(), "must be at most 10");
+
+        let error = error.with_message("{value} is more than {max}");
+        assert_eq!(error.render_message().unwrap(), "11 is more than 10");
+
+        let error = ValidationError::new("my_code").with_message(Cow::Borrowed("Static"));
+        assert_eq!(error.render_message().unwrap(), "Static");
+        assert_eq!(ValidationError::new("my_code").render_message(), None);
+    }
+}
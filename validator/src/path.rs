@@ -0,0 +1,745 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+use crate::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+/// A step of an `ErrorPath`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PathSegment {
+    /// A field of a struct
+    Field(Cow<'static, str>),
+    /// An item of a list
+    Index(usize),
+    /// An entry of a map
+    Key(String),
+}
+
+/// The path of a value inside the validated value, like `address.lines[2]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ErrorPath(Vec<PathSegment>);
+
+impl ErrorPath {
+    pub fn new() -> ErrorPath {
+        ErrorPath(Vec::new())
+    }
+
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+
+    pub fn push(&mut self, segment: PathSegment) {
+        self.0.push(segment);
+    }
+
+    pub fn pop(&mut self) -> Option<PathSegment> {
+        self.0.pop()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Whether `prefix` is this path or one of its parents.
+    pub fn starts_with(&self, prefix: &ErrorPath) -> bool {
+        self.0.starts_with(&prefix.0)
+    }
+
+    /// Returns the path with the struct field `name` added.
+    pub fn field(mut self, name: impl Into<Cow<'static, str>>) -> ErrorPath {
+        self.0.push(PathSegment::Field(name.into()));
+        self
+    }
+
+    /// Returns the path with the list item `index` added.
+    pub fn index(mut self, index: usize) -> ErrorPath {
+        self.0.push(PathSegment::Index(index));
+        self
+    }
+
+    /// Returns the path with the map entry `key` added.
+    pub fn key(mut self, key: impl Into<String>) -> ErrorPath {
+        self.0.push(PathSegment::Key(key.into()));
+        self
+    }
+
+    /// Renders the path as `address.lines[2]`, map keys being quoted like `labels["en"]`. This
+    /// is also the `Display` format.
+    pub fn to_dotted(&self) -> String {
+        let mut s = String::new();
+        for segment in &self.0 {
+            match segment {
+                PathSegment::Field(name) => {
+                    if !s.is_empty() {
+                        s.push('.');
+                    }
+                    s.push_str(name);
+                }
+                PathSegment::Index(index) => write!(s, "[{}]", index).unwrap(),
+                PathSegment::Key(key) => {
+                    write!(s, "[{}]", serde_json::Value::from(key.as_str())).unwrap()
+                }
+            }
+        }
+        s
+    }
+
+    /// Renders the path as a JSON Pointer (RFC 6901) like `/address/lines/2`.
+    pub fn to_json_pointer(&self) -> String {
+        let mut s = String::new();
+        for segment in &self.0 {
+            s.push('/');
+            match segment {
+                PathSegment::Field(name) => s.push_str(&name.replace('~', "~0").replace('/', "~1")),
+                PathSegment::Key(key) => s.push_str(&key.replace('~', "~0").replace('/', "~1")),
+                PathSegment::Index(index) => write!(s, "{}", index).unwrap(),
+            }
+        }
+        s
+    }
+
+    /// Renders the path in the bracket notation of HTML forms like `address[lines][2]`.
+    pub fn to_bracket(&self) -> String {
+        let mut s = String::new();
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => s.push_str(name),
+                PathSegment::Field(name) => write!(s, "[{}]", name).unwrap(),
+                PathSegment::Key(key) => write!(s, "[{}]", key).unwrap(),
+                PathSegment::Index(index) => write!(s, "[{}]", index).unwrap(),
+            }
+        }
+        s
+    }
+}
+
+impl fmt::Display for ErrorPath {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}", self.to_dotted())
+    }
+}
+
+impl From<Vec<PathSegment>> for ErrorPath {
+    fn from(segments: Vec<PathSegment>) -> ErrorPath {
+        ErrorPath(segments)
+    }
+}
+
+impl FromIterator<PathSegment> for ErrorPath {
+    fn from_iter<I: IntoIterator<Item = PathSegment>>(iter: I) -> ErrorPath {
+        ErrorPath(iter.into_iter().collect())
+    }
+}
+
+/// The error returned when parsing a string that is not a path in the dotted notation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorPathParseError;
+
+impl fmt::Display for ErrorPathParseError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "invalid error path")
+    }
+}
+
+impl std::error::Error for ErrorPathParseError {}
+
+/// Parses the dotted notation of `to_dotted`, like `items[2].sku` or `labels["en"]`.
+impl FromStr for ErrorPath {
+    type Err = ErrorPathParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut path = ErrorPath::new();
+        let mut rest = s;
+        while !rest.is_empty() {
+            if let Some(bracketed) = rest.strip_prefix('[') {
+                if bracketed.starts_with('"') {
+                    // Map keys are JSON strings, which can contain `]`
+                    let mut stream =
+                        serde_json::Deserializer::from_str(bracketed).into_iter::<String>();
+                    let key = stream.next().and_then(Result::ok).ok_or(ErrorPathParseError)?;
+                    rest = bracketed[stream.byte_offset()..]
+                        .strip_prefix(']')
+                        .ok_or(ErrorPathParseError)?;
+                    path.push(PathSegment::Key(key));
+                } else {
+                    let (index, after) = bracketed.split_once(']').ok_or(ErrorPathParseError)?;
+                    if index.is_empty() || !index.bytes().all(|b| b.is_ascii_digit()) {
+                        return Err(ErrorPathParseError);
+                    }
+                    path.push(PathSegment::Index(index.parse().map_err(|_| ErrorPathParseError)?));
+                    rest = after;
+                }
+            } else {
+                let field = match rest.strip_prefix('.') {
+                    Some(field) if !path.is_empty() => field,
+                    None if path.is_empty() => rest,
+                    _ => return Err(ErrorPathParseError),
+                };
+                let end = field.find(['.', '[']).unwrap_or(field.len());
+                if end == 0 {
+                    return Err(ErrorPathParseError);
+                }
+                path.push(PathSegment::Field(Cow::Owned(field[..end].to_string())));
+                rest = &field[end..];
+            }
+        }
+        Ok(path)
+    }
+}
+
+/// The paths taken by the `ValidationErrors` methods looking up errors: an `ErrorPath` or a
+/// string in the dotted notation like `items[2].sku`. An invalid string matches no errors.
+pub trait AsErrorPath {
+    fn as_error_path(&self) -> Option<Cow<'_, ErrorPath>>;
+}
+
+impl AsErrorPath for ErrorPath {
+    fn as_error_path(&self) -> Option<Cow<'_, ErrorPath>> {
+        Some(Cow::Borrowed(self))
+    }
+}
+
+impl AsErrorPath for str {
+    fn as_error_path(&self) -> Option<Cow<'_, ErrorPath>> {
+        self.parse().ok().map(Cow::Owned)
+    }
+}
+
+impl AsErrorPath for String {
+    fn as_error_path(&self) -> Option<Cow<'_, ErrorPath>> {
+        self.as_str().as_error_path()
+    }
+}
+
+impl<T: AsErrorPath + ?Sized> AsErrorPath for &T {
+    fn as_error_path(&self) -> Option<Cow<'_, ErrorPath>> {
+        T::as_error_path(self)
+    }
+}
+
+/// Iterator over the errors of a `ValidationErrors` with their path, returned by
+/// `ValidationErrors::iter_flat`.
+pub struct IterFlat<'a>(std::vec::IntoIter<(ErrorPath, &'a ValidationError)>);
+
+impl<'a> Iterator for IterFlat<'a> {
+    type Item = (ErrorPath, &'a ValidationError);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// Owning iterator over the errors of a `ValidationErrors` with their path.
+pub struct IntoIterFlat(std::vec::IntoIter<(ErrorPath, ValidationError)>);
+
+impl Iterator for IntoIterFlat {
+    type Item = (ErrorPath, ValidationError);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+// The `_item` errors of a list are about the item itself, their path stops at the index. The
+// keys of maps that can't be written as a field, like `a.b`, are map key segments.
+fn key_segment(path: &ErrorPath, key: Cow<'static, str>) -> Option<PathSegment> {
+    if key == "_item" && matches!(path.0.last(), Some(PathSegment::Index(_))) {
+        None
+    } else if key.is_empty() || key.contains(['.', '[']) {
+        Some(PathSegment::Key(key.into_owned()))
+    } else {
+        Some(PathSegment::Field(key))
+    }
+}
+
+fn flatten<'a>(
+    errors: &'a ValidationErrors,
+    path: &mut ErrorPath,
+    flat: &mut Vec<(ErrorPath, &'a ValidationError)>,
+) {
+    for (key, kind) in errors.errors() {
+        let segment = key_segment(path, key.clone());
+        let pushed = segment.is_some();
+        path.0.extend(segment);
+        flatten_kind(kind, path, flat);
+        if pushed {
+            path.pop();
+        }
+    }
+}
+
+fn flatten_kind<'a>(
+    kind: &'a ValidationErrorsKind,
+    path: &mut ErrorPath,
+    flat: &mut Vec<(ErrorPath, &'a ValidationError)>,
+) {
+    match kind {
+        ValidationErrorsKind::Field(field_errors) => {
+            flat.extend(field_errors.iter().map(|error| (path.clone(), error)));
+        }
+        ValidationErrorsKind::Struct(nested) => flatten(nested, path, flat),
+        ValidationErrorsKind::List(items) => {
+            for (index, item) in items {
+                path.push(PathSegment::Index(*index));
+                flatten(item, path, flat);
+                path.pop();
+            }
+        }
+    }
+}
+
+fn flatten_owned(
+    errors: ValidationErrors,
+    path: &mut ErrorPath,
+    flat: &mut Vec<(ErrorPath, ValidationError)>,
+) {
+    for (key, kind) in errors.into_errors() {
+        let segment = key_segment(path, key);
+        let pushed = segment.is_some();
+        path.0.extend(segment);
+        match kind {
+            ValidationErrorsKind::Field(field_errors) => {
+                flat.extend(field_errors.into_iter().map(|error| (path.clone(), error)));
+            }
+            ValidationErrorsKind::Struct(nested) => flatten_owned(*nested, path, flat),
+            ValidationErrorsKind::List(items) => {
+                for (index, item) in items {
+                    path.push(PathSegment::Index(index));
+                    flatten_owned(*item, path, flat);
+                    path.pop();
+                }
+            }
+        }
+        if pushed {
+            path.pop();
+        }
+    }
+}
+
+// Keeps the errors for which `f` returns `true`, removing the structs and lists left empty
+fn retain_errors<F>(errors: &mut ValidationErrors, path: &mut ErrorPath, f: &mut F)
+where
+    F: FnMut(&ErrorPath, &ValidationError) -> bool,
+{
+    errors.0.retain(|key, kind| {
+        let segment = key_segment(path, key.clone());
+        let pushed = segment.is_some();
+        path.0.extend(segment);
+        let keep = match kind {
+            ValidationErrorsKind::Field(field_errors) => {
+                field_errors.retain(|error| f(path, error));
+                !field_errors.is_empty()
+            }
+            ValidationErrorsKind::Struct(nested) => {
+                retain_errors(nested, path, f);
+                !nested.is_empty()
+            }
+            ValidationErrorsKind::List(items) => {
+                items.retain(|index, item| {
+                    path.push(PathSegment::Index(*index));
+                    retain_errors(item, path, f);
+                    path.pop();
+                    !item.is_empty()
+                });
+                !items.is_empty()
+            }
+        };
+        if pushed {
+            path.pop();
+        }
+        keep
+    });
+}
+
+fn segment_key(segment: &PathSegment) -> Option<&str> {
+    match segment {
+        PathSegment::Field(name) => Some(name),
+        PathSegment::Key(key) => Some(key),
+        PathSegment::Index(_) => None,
+    }
+}
+
+// Splits a path into the path of the struct or list item holding its errors and their key
+fn split_entry(path: &[PathSegment]) -> (&[PathSegment], &str) {
+    match path.split_last() {
+        Some((PathSegment::Field(name), parent)) => (parent, name),
+        Some((PathSegment::Key(key), parent)) => (parent, key),
+        Some((PathSegment::Index(_), _)) => (path, "_item"),
+        None => (path, "__all__"),
+    }
+}
+
+impl ValidationErrors {
+    /// Returns every error with the path of the value it is about, going through nested structs
+    /// and lists.
+    pub fn iter_flat(&self) -> IterFlat<'_> {
+        let mut flat = Vec::new();
+        flatten(self, &mut ErrorPath::new(), &mut flat);
+        IterFlat(flat.into_iter())
+    }
+
+    /// Returns the errors at `path`. Like with `iter_flat`, the path of a list item gives its
+    /// `_item` errors and the empty path the `__all__` errors.
+    pub fn get(&self, path: impl AsErrorPath) -> Option<&ValidationErrorsKind> {
+        let path = path.as_error_path()?;
+        let (parent, key) = split_entry(path.segments());
+        self.nested(parent)?.0.get(key)
+    }
+
+    /// Returns the field-level errors at `path`, the slice being empty if there are none.
+    pub fn errors_at(&self, path: impl AsErrorPath) -> &[ValidationError] {
+        match self.get(path) {
+            Some(ValidationErrorsKind::Field(errors)) => errors,
+            _ => &[],
+        }
+    }
+
+    /// Whether there are errors at `path` or in the values it contains, `items[2]` matching the
+    /// errors of the fields of that item.
+    pub fn contains(&self, path: impl AsErrorPath) -> bool {
+        let Some(path) = path.as_error_path() else {
+            return false;
+        };
+        self.nested(path.segments()).is_some_and(|nested| !nested.is_empty())
+            || self.get(path.as_ref()).is_some()
+    }
+
+    /// Removes and returns the errors at `path`, the ones `get` returns. The structs and lists
+    /// holding them that are left without errors are removed as well.
+    pub fn remove(&mut self, path: impl AsErrorPath) -> Option<ValidationErrorsKind> {
+        let path = path.as_error_path()?;
+        let (parent, key) = split_entry(path.segments());
+        let removed = self.nested_mut(parent)?.0.shift_remove(key);
+        if removed.is_some() {
+            self.remove_empty_at(parent);
+        }
+        removed
+    }
+
+    /// Keeps only the errors for which `f` returns `true`.
+    pub fn retain(&mut self, mut f: impl FnMut(&ErrorPath, &ValidationError) -> bool) {
+        retain_errors(self, &mut ErrorPath::new(), &mut f);
+    }
+
+    // Returns the errors of the struct or list item at `path`
+    fn nested(&self, path: &[PathSegment]) -> Option<&ValidationErrors> {
+        let mut errors = self;
+        let mut segments = path.iter().peekable();
+        while let Some(segment) = segments.next() {
+            errors = match (errors.0.get(segment_key(segment)?)?, segments.peek()) {
+                (ValidationErrorsKind::List(items), Some(PathSegment::Index(index))) => {
+                    segments.next();
+                    items.get(index)?
+                }
+                (ValidationErrorsKind::Struct(nested), _) => nested,
+                _ => return None,
+            };
+        }
+        Some(errors)
+    }
+
+    fn nested_mut(&mut self, path: &[PathSegment]) -> Option<&mut ValidationErrors> {
+        let mut errors = self;
+        let mut segments = path.iter().peekable();
+        while let Some(segment) = segments.next() {
+            errors = match (errors.0.get_mut(segment_key(segment)?)?, segments.peek()) {
+                (ValidationErrorsKind::List(items), Some(PathSegment::Index(index))) => {
+                    segments.next();
+                    items.get_mut(index)?
+                }
+                (ValidationErrorsKind::Struct(nested), _) => nested,
+                _ => return None,
+            };
+        }
+        Some(errors)
+    }
+
+    // Removes the structs and lists along `path` left without errors, from the deepest one
+    fn remove_empty_at(&mut self, path: &[PathSegment]) {
+        let Some((segment, rest)) = path.split_first() else {
+            return;
+        };
+        let Some(key) = segment_key(segment) else {
+            return;
+        };
+        let is_empty = match (self.0.get_mut(key), rest) {
+            (Some(ValidationErrorsKind::List(items)), [PathSegment::Index(index), rest @ ..]) => {
+                if let Some(item) = items.get_mut(index) {
+                    item.remove_empty_at(rest);
+                    if item.is_empty() {
+                        items.remove(index);
+                    }
+                }
+                items.is_empty()
+            }
+            (Some(ValidationErrorsKind::Struct(nested)), rest) => {
+                nested.remove_empty_at(rest);
+                nested.is_empty()
+            }
+            _ => return,
+        };
+        if is_empty {
+            self.0.shift_remove(key);
+        }
+    }
+
+    // Adds the error at `path`, merging it with the existing errors like `merge_field`. A path
+    // ending with a list index adds the error to the `_item` errors of that item and the empty
+    // path to `__all__`.
+    fn add_at_path(&mut self, path: &[PathSegment], error: ValidationError) {
+        let (key, rest) = match path {
+            [PathSegment::Field(name), rest @ ..] => (name.clone(), rest),
+            [PathSegment::Key(key), rest @ ..] => (Cow::Owned(key.clone()), rest),
+            _ => (Cow::Borrowed("__all__"), &[][..]),
+        };
+
+        let kind = match rest {
+            [] => ValidationErrorsKind::Field(vec![error]),
+            [PathSegment::Index(index), rest @ ..] => {
+                let mut item = ValidationErrors::new();
+                if rest.is_empty() {
+                    item.add("_item", error);
+                } else {
+                    item.add_at_path(rest, error);
+                }
+                ValidationErrorsKind::List(BTreeMap::from([(*index, Box::new(item))]))
+            }
+            _ => {
+                let mut nested = ValidationErrors::new();
+                nested.add_at_path(rest, error);
+                ValidationErrorsKind::Struct(Box::new(nested))
+            }
+        };
+        self.merge_field(key, kind);
+    }
+}
+
+impl ValidationErrorsKind {
+    /// Returns every error with its path from the value the errors are about, like
+    /// `ValidationErrors::iter_flat`. The paths of the errors of a list start with the index of
+    /// the item, such as `[2].name`, and the errors of a field have an empty path.
+    pub fn iter_flat(&self) -> IterFlat<'_> {
+        let mut flat = Vec::new();
+        flatten_kind(self, &mut ErrorPath::new(), &mut flat);
+        IterFlat(flat.into_iter())
+    }
+}
+
+impl<'a> IntoIterator for &'a ValidationErrors {
+    type Item = (ErrorPath, &'a ValidationError);
+    type IntoIter = IterFlat<'a>;
+
+    fn into_iter(self) -> IterFlat<'a> {
+        self.iter_flat()
+    }
+}
+
+impl IntoIterator for ValidationErrors {
+    type Item = (ErrorPath, ValidationError);
+    type IntoIter = IntoIterFlat;
+
+    fn into_iter(self) -> IntoIterFlat {
+        let mut flat = Vec::new();
+        flatten_owned(self, &mut ErrorPath::new(), &mut flat);
+        IntoIterFlat(flat.into_iter())
+    }
+}
+
+/// Adds the errors at their path, with the layout of the derived validation: struct fields and
+/// map keys are `ValidationErrorsKind::Struct` entries, list items `ValidationErrorsKind::List`
+/// ones.
+impl Extend<(ErrorPath, ValidationError)> for ValidationErrors {
+    fn extend<I: IntoIterator<Item = (ErrorPath, ValidationError)>>(&mut self, iter: I) {
+        for (path, error) in iter {
+            self.add_at_path(path.segments(), error);
+        }
+    }
+}
+
+impl FromIterator<(ErrorPath, ValidationError)> for ValidationErrors {
+    fn from_iter<I: IntoIterator<Item = (ErrorPath, ValidationError)>>(iter: I) -> Self {
+        let mut errors = ValidationErrors::new();
+        errors.extend(iter);
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ErrorPath, PathSegment};
+    use crate::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+    fn errors() -> ValidationErrors {
+        let mut address = ValidationErrors::new();
+        address.add_item("lines", 2, "_item", ValidationError::new("length"));
+        address.add_item("lines", 3, "number", ValidationError::new("range"));
+        let mut errors = ValidationErrors::new();
+        errors.add("mail", ValidationError::new("email"));
+        errors.0.insert("address".into(), ValidationErrorsKind::Struct(Box::new(address)));
+        errors
+    }
+
+    #[test]
+    fn test_renderers() {
+        let path = ErrorPath::new().field("address").field("lines").index(2);
+        assert_eq!(path.to_dotted(), "address.lines[2]");
+        assert_eq!(path.to_string(), "address.lines[2]");
+        assert_eq!(path.to_json_pointer(), "/address/lines/2");
+        assert_eq!(path.to_bracket(), "address[lines][2]");
+
+        let path = ErrorPath::new().field("labels").key("a/b~\"c\"");
+        assert_eq!(path.to_dotted(), r#"labels["a/b~\"c\""]"#);
+        assert_eq!(path.to_json_pointer(), "/labels/a~1b~0\"c\"");
+        assert_eq!(path.to_bracket(), "labels[a/b~\"c\"]");
+
+        let path: ErrorPath = vec![PathSegment::Index(0), PathSegment::Field("name".into())].into();
+        assert_eq!(path.to_dotted(), "[0].name");
+        assert_eq!(path.to_json_pointer(), "/0/name");
+        assert_eq!(ErrorPath::new().to_json_pointer(), "");
+    }
+
+    #[test]
+    fn test_iter_flat() {
+        let errors = errors();
+        let mut flat = errors
+            .iter_flat()
+            .map(|(path, err)| (path.to_string(), err.code.to_string()))
+            .collect::<Vec<_>>();
+        flat.sort();
+        assert_eq!(
+            flat,
+            vec![
+                ("address.lines[2]".to_string(), "length".to_string()),
+                ("address.lines[3].number".to_string(), "range".to_string()),
+                ("mail".to_string(), "email".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_collect_round_trip() {
+        let errors = errors();
+        let collected: ValidationErrors = errors.clone().into_iter().collect();
+        assert_eq!(collected, errors);
+
+        let from_refs: ValidationErrors =
+            (&errors).into_iter().map(|(path, err)| (path, err.clone())).collect();
+        assert_eq!(from_refs, errors);
+
+        let errors: ValidationErrors =
+            [(ErrorPath::new(), ValidationError::new("syntax"))].into_iter().collect();
+        assert_eq!(errors.field_errors()["__all__"][0].code, "syntax");
+    }
+
+    #[test]
+    fn test_extend_merges_errors_of_another_kind() {
+        let mut errors = ValidationErrors::new();
+        errors.add("tags", ValidationError::new("length"));
+        errors.extend([("tags[1]".parse().unwrap(), ValidationError::new("ascii"))]);
+        assert_eq!(errors.iter_flat().count(), 2);
+        assert_eq!(errors.errors_at("tags")[1].params["index"], 1);
+
+        let mut errors = ValidationErrors::new();
+        errors.add("address", ValidationError::new("required"));
+        errors.extend([("address.city".parse().unwrap(), ValidationError::new("length"))]);
+        assert_eq!(errors.errors_at("address.__all__")[0].code, "required");
+        assert_eq!(errors.errors_at("address.city")[0].code, "length");
+    }
+
+    #[test]
+    fn test_parse() {
+        let path: ErrorPath = "address.lines[2]".parse().unwrap();
+        assert_eq!(path, ErrorPath::new().field("address").field("lines").index(2));
+
+        let path: ErrorPath = r#"[0].labels["a]\"b"].name"#.parse().unwrap();
+        assert_eq!(path, ErrorPath::new().index(0).field("labels").key("a]\"b").field("name"));
+        assert_eq!(path.to_dotted().parse::<ErrorPath>().unwrap(), path);
+
+        assert_eq!("".parse::<ErrorPath>().unwrap(), ErrorPath::new());
+        for invalid in ["a..b", ".a", "a.", "a[", "a[x]", "a[-1]", "a[1]b", "a[\"b]"] {
+            assert!(invalid.parse::<ErrorPath>().is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_query_by_path() {
+        let errors = errors();
+        assert_eq!(errors.errors_at("mail")[0].code, "email");
+        assert_eq!(errors.errors_at("address.lines[2]")[0].code, "length");
+        assert_eq!(errors.errors_at("address.lines[3].number")[0].code, "range");
+        assert!(errors.errors_at("address.lines[4].number").is_empty());
+        assert!(errors.errors_at("address.lines").is_empty());
+        assert!(errors.errors_at("mail[").is_empty());
+
+        let path = ErrorPath::new().field("address").field("lines");
+        assert!(matches!(errors.get(&path), Some(ValidationErrorsKind::List(_))));
+        assert!(errors.contains(&path));
+        assert!(errors.contains("address.lines[3]"));
+        assert!(errors.contains("address"));
+        assert!(errors.contains(""));
+        assert!(!errors.contains("address.lines[1]"));
+        assert!(!errors.contains("name"));
+    }
+
+    #[test]
+    fn test_remove_by_path() {
+        let mut errors = errors();
+        assert!(errors.remove("address.lines[1].number").is_none());
+        assert!(matches!(
+            errors.remove("address.lines[3].number"),
+            Some(ValidationErrorsKind::Field(_))
+        ));
+        assert!(!errors.contains("address.lines[3]"));
+        assert!(errors.contains("address.lines[2]"));
+
+        errors.remove("address.lines[2]");
+        assert!(!errors.contains("address"));
+        assert_eq!(errors.errors().len(), 1);
+    }
+
+    #[test]
+    fn test_remove_only_prunes_the_parents_of_the_path() {
+        let mut errors = errors();
+        errors.0.insert("empty".into(), ValidationErrorsKind::Struct(Box::default()));
+        errors.remove("mail");
+        assert!(errors.0.contains_key("empty"));
+        assert!(errors.contains("address.lines[3].number"));
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut errors = errors();
+        let address = ErrorPath::new().field("address");
+        errors.retain(|path, _| !path.starts_with(&address));
+        assert_eq!(errors.errors().len(), 1);
+        assert!(errors.contains("mail"));
+
+        errors.retain(|_, error| error.code != "email");
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_retain_keeps_the_order_of_the_errors() {
+        let mut errors = errors();
+        errors.retain(|path, _| path.to_string() != "address.lines[2]");
+        assert!(!errors.contains("address.lines[2]"));
+        assert_eq!(
+            errors.iter_flat().map(|(path, _)| path.to_string()).collect::<Vec<_>>(),
+            ["mail", "address.lines[3].number"]
+        );
+    }
+}
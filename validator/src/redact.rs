@@ -0,0 +1,82 @@
+use serde_json::Value;
+
+use crate::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+/// What the params holding a value of the validated struct are replaced with when redacted.
+pub const REDACTED: &str = "[redacted]";
+
+// `value` holds the value of the field and `other` the value of the field it must match for
+// `must_match`
+static VALUE_PARAMS: [&str; 2] = ["value", "other"];
+
+impl ValidationError {
+    /// Replaces the params holding values of the validated struct, `value` and the `other` param
+    /// of `must_match`, with `REDACTED`. The other params, like `min` or `max`, are kept.
+    pub fn redact_values(&mut self) {
+        for name in VALUE_PARAMS {
+            if let Some(value) = self.params.get_mut(name) {
+                *value = Value::String(REDACTED.to_string());
+            }
+        }
+    }
+}
+
+impl ValidationErrorsKind {
+    /// Redacts the values of all the errors, including the ones of nested structs and list items.
+    pub fn redact_values(&mut self) {
+        match self {
+            ValidationErrorsKind::Field(errors) => {
+                errors.iter_mut().for_each(ValidationError::redact_values)
+            }
+            ValidationErrorsKind::Struct(nested) => nested.redact_values(),
+            ValidationErrorsKind::List(items) => {
+                items.values_mut().for_each(|item| item.redact_values())
+            }
+        }
+    }
+}
+
+impl ValidationErrors {
+    /// Redacts the values of all the errors, see `ValidationError::redact_values`, for example
+    /// before logging them.
+    ///
+    /// Fields marked with `#[validate(sensitive)]` or listed in `#[validate(redact = [...])]` are
+    /// always redacted by the derived validation.
+    pub fn redact_values(&mut self) {
+        self.0.values_mut().for_each(ValidationErrorsKind::redact_values);
+    }
+
+    /// Redacts the values of the errors of `field` only.
+    pub fn redact_field_values(&mut self, field: &str) {
+        if let Some(kind) = self.0.get_mut(field) {
+            kind.redact_values();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ValidationError, ValidationErrors, REDACTED};
+
+    #[test]
+    fn test_redact_values() {
+        let mut errors = ValidationErrors::new();
+        errors.add("token", ValidationError::new("length").with_param("value", "s3cr3t"));
+        errors.add_item(
+            "codes",
+            1,
+            "_item",
+            ValidationError::new("unique").with_param("value", "1234").with_param("first_index", 0),
+        );
+        errors.add("name", ValidationError::new("length").with_param("value", ""));
+
+        errors.redact_field_values("token");
+        assert_eq!(errors.errors_at("token")[0].params["value"], REDACTED);
+        assert_eq!(errors.errors_at("codes[1]")[0].params["value"], "1234");
+
+        errors.redact_values();
+        assert_eq!(errors.errors_at("codes[1]")[0].params["value"], REDACTED);
+        assert_eq!(errors.errors_at("codes[1]")[0].params["first_index"], 0);
+        assert_eq!(errors.errors_at("name")[0].params["value"], REDACTED);
+    }
+}
@@ -0,0 +1,262 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, LinkedList, VecDeque};
+
+use indexmap::IndexMap;
+
+use crate::{Validate, ValidationErrors};
+
+/// Normalizes the values of a struct in place before it is validated. It is implemented by
+/// deriving `Sanitize`, and for the collections of sanitizable values so that the derive can
+/// recurse into them.
+pub trait Sanitize {
+    fn sanitize(&mut self);
+
+    /// Sanitizes the value and validates it afterwards, so that the validation runs on the
+    /// normalized values.
+    fn sanitize_and_validate(&mut self) -> Result<(), ValidationErrors>
+    where
+        Self: Validate,
+    {
+        self.sanitize();
+        self.validate()
+    }
+}
+
+impl<T: Sanitize + ?Sized> Sanitize for &mut T {
+    fn sanitize(&mut self) {
+        T::sanitize(self)
+    }
+}
+
+impl<T: Sanitize + ?Sized> Sanitize for Box<T> {
+    fn sanitize(&mut self) {
+        T::sanitize(self)
+    }
+}
+
+impl<T: Sanitize> Sanitize for Option<T> {
+    fn sanitize(&mut self) {
+        if let Some(value) = self {
+            value.sanitize();
+        }
+    }
+}
+
+macro_rules! impl_sanitize_list {
+    ($container:ty) => {
+        impl<T: Sanitize> Sanitize for $container {
+            fn sanitize(&mut self) {
+                self.iter_mut().for_each(T::sanitize);
+            }
+        }
+    };
+}
+
+impl_sanitize_list!(Vec<T>);
+impl_sanitize_list!(VecDeque<T>);
+impl_sanitize_list!(LinkedList<T>);
+impl_sanitize_list!([T]);
+
+impl<T: Sanitize, const N: usize> Sanitize for [T; N] {
+    fn sanitize(&mut self) {
+        self.iter_mut().for_each(T::sanitize);
+    }
+}
+
+// Only the values of maps are sanitized, changing the keys could merge entries
+impl<K, V: Sanitize, S> Sanitize for HashMap<K, V, S> {
+    fn sanitize(&mut self) {
+        self.values_mut().for_each(V::sanitize);
+    }
+}
+
+impl<K, V: Sanitize> Sanitize for BTreeMap<K, V> {
+    fn sanitize(&mut self) {
+        self.values_mut().for_each(V::sanitize);
+    }
+}
+
+impl<K, V: Sanitize, S> Sanitize for IndexMap<K, V, S> {
+    fn sanitize(&mut self) {
+        self.values_mut().for_each(V::sanitize);
+    }
+}
+
+/// The string transformations available as `#[sanitizer(...)]` field attributes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Sanitizer {
+    /// Removes the leading and trailing whitespace
+    Trim,
+    Lowercase,
+    Uppercase,
+    /// Replaces every run of whitespace by a single space
+    CollapseWhitespace,
+    /// Removes the control characters, including newlines and tabs
+    StripControl,
+    /// Puts the string in the Unicode normalization form NFC
+    #[cfg(feature = "unicode_normalization")]
+    Nfc,
+}
+
+impl Sanitizer {
+    pub fn apply(&self, s: &mut String) {
+        match self {
+            Sanitizer::Trim => {
+                s.truncate(s.trim_end().len());
+                let start = s.len() - s.trim_start().len();
+                s.drain(..start);
+            }
+            Sanitizer::Lowercase => {
+                if s.chars().any(|c| !c.is_lowercase()) {
+                    *s = s.to_lowercase();
+                }
+            }
+            Sanitizer::Uppercase => {
+                if s.chars().any(|c| !c.is_uppercase()) {
+                    *s = s.to_uppercase();
+                }
+            }
+            Sanitizer::CollapseWhitespace => {
+                let mut collapsed = String::with_capacity(s.len());
+                let mut previous_whitespace = false;
+                for c in s.chars() {
+                    if c.is_whitespace() {
+                        if !previous_whitespace {
+                            collapsed.push(' ');
+                        }
+                        previous_whitespace = true;
+                    } else {
+                        collapsed.push(c);
+                        previous_whitespace = false;
+                    }
+                }
+                *s = collapsed;
+            }
+            Sanitizer::StripControl => s.retain(|c| !c.is_control()),
+            #[cfg(feature = "unicode_normalization")]
+            Sanitizer::Nfc => {
+                if let Cow::Owned(normalized) = crate::NormalizationForm::Nfc.normalize(s) {
+                    *s = normalized;
+                }
+            }
+        }
+    }
+}
+
+/// Gives mutable access to all the strings of a value, so that the string sanitizers can be used
+/// on `Option<String>` or `Vec<String>` fields as well as on `String`.
+pub trait SanitizeStrings {
+    fn sanitize_strings(&mut self, f: &mut dyn FnMut(&mut String));
+}
+
+impl SanitizeStrings for String {
+    fn sanitize_strings(&mut self, f: &mut dyn FnMut(&mut String)) {
+        f(self)
+    }
+}
+
+impl SanitizeStrings for Cow<'_, str> {
+    fn sanitize_strings(&mut self, f: &mut dyn FnMut(&mut String)) {
+        f(self.to_mut())
+    }
+}
+
+impl<T: SanitizeStrings + ?Sized> SanitizeStrings for &mut T {
+    fn sanitize_strings(&mut self, f: &mut dyn FnMut(&mut String)) {
+        T::sanitize_strings(self, f)
+    }
+}
+
+impl<T: SanitizeStrings + ?Sized> SanitizeStrings for Box<T> {
+    fn sanitize_strings(&mut self, f: &mut dyn FnMut(&mut String)) {
+        T::sanitize_strings(self, f)
+    }
+}
+
+impl<T: SanitizeStrings> SanitizeStrings for Option<T> {
+    fn sanitize_strings(&mut self, f: &mut dyn FnMut(&mut String)) {
+        if let Some(value) = self {
+            value.sanitize_strings(f);
+        }
+    }
+}
+
+macro_rules! impl_sanitize_strings_list {
+    ($container:ty) => {
+        impl<T: SanitizeStrings> SanitizeStrings for $container {
+            fn sanitize_strings(&mut self, f: &mut dyn FnMut(&mut String)) {
+                self.iter_mut().for_each(|value| value.sanitize_strings(f));
+            }
+        }
+    };
+}
+
+impl_sanitize_strings_list!(Vec<T>);
+impl_sanitize_strings_list!(VecDeque<T>);
+impl_sanitize_strings_list!(LinkedList<T>);
+impl_sanitize_strings_list!([T]);
+
+impl<T: SanitizeStrings, const N: usize> SanitizeStrings for [T; N] {
+    fn sanitize_strings(&mut self, f: &mut dyn FnMut(&mut String)) {
+        self.iter_mut().for_each(|value| value.sanitize_strings(f));
+    }
+}
+
+impl<K, V: SanitizeStrings, S> SanitizeStrings for HashMap<K, V, S> {
+    fn sanitize_strings(&mut self, f: &mut dyn FnMut(&mut String)) {
+        self.values_mut().for_each(|value| value.sanitize_strings(f));
+    }
+}
+
+impl<K, V: SanitizeStrings> SanitizeStrings for BTreeMap<K, V> {
+    fn sanitize_strings(&mut self, f: &mut dyn FnMut(&mut String)) {
+        self.values_mut().for_each(|value| value.sanitize_strings(f));
+    }
+}
+
+impl<K, V: SanitizeStrings, S> SanitizeStrings for IndexMap<K, V, S> {
+    fn sanitize_strings(&mut self, f: &mut dyn FnMut(&mut String)) {
+        self.values_mut().for_each(|value| value.sanitize_strings(f));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{SanitizeStrings, Sanitizer};
+
+    fn apply(sanitizer: Sanitizer, s: &str) -> String {
+        let mut s = s.to_string();
+        sanitizer.apply(&mut s);
+        s
+    }
+
+    #[test]
+    fn test_sanitizers() {
+        assert_eq!(apply(Sanitizer::Trim, "  hello world\n"), "hello world");
+        assert_eq!(apply(Sanitizer::Trim, "   "), "");
+        assert_eq!(apply(Sanitizer::Lowercase, "Bob@Example.COM"), "bob@example.com");
+        assert_eq!(apply(Sanitizer::Uppercase, "fr-be"), "FR-BE");
+        assert_eq!(apply(Sanitizer::CollapseWhitespace, " a \t\n b  c"), " a b c");
+        assert_eq!(apply(Sanitizer::StripControl, "a\u{0}b\nc\u{9F}"), "abc");
+    }
+
+    #[cfg(feature = "unicode_normalization")]
+    #[test]
+    fn test_nfc_sanitizer() {
+        assert_eq!(apply(Sanitizer::Nfc, "cafe\u{0301}"), "caf\u{00E9}");
+    }
+
+    #[test]
+    fn test_sanitize_strings_in_collections() {
+        let mut values = vec![Some(" a ".to_string()), None];
+        values.sanitize_strings(&mut |s| Sanitizer::Trim.apply(s));
+        assert_eq!(values, vec![Some("a".to_string()), None]);
+
+        let mut labels = HashMap::from([(" key ", " value ".to_string())]);
+        labels.sanitize_strings(&mut |s| Sanitizer::Trim.apply(s));
+        assert_eq!(labels[" key "], "value");
+    }
+}
@@ -0,0 +1,426 @@
+//! Deserializes and validates a value in one go, reporting the deserialization errors as
+//! `ValidationError`s so that clients get a single error shape.
+//!
+//! A value with the wrong type gets a `type` error and a missing field a `missing` error, at the
+//! same place in the `ValidationErrors` tree as the errors of the validators of that field. Fields
+//! that aren't accepted because of `#[serde(deny_unknown_fields)]` get an `unknown` error.
+//!
+//! To find as many errors as possible, the faulty values are replaced by placeholders and the
+//! deserialization is retried. The placeholders are never returned: if the input can be
+//! deserialized with them, the result is validated and the validation errors of the other fields
+//! are merged with the deserialization errors. When no placeholder fits, only the errors found so
+//! far are returned.
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use ::serde::de::value::BorrowedStrDeserializer;
+use ::serde::de::{
+    DeserializeOwned, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess,
+    Visitor,
+};
+use ::serde::forward_to_deserialize_any;
+use serde_json::{Map, Value};
+
+use crate::{
+    ErrorPath, PathSegment, Validate, ValidationError, ValidationErrors, ValidationErrorsKind,
+};
+
+// The values tried in place of a faulty one, in order
+fn placeholder(attempt: usize) -> Option<Value> {
+    Some(match attempt {
+        0 => Value::Null,
+        1 => Value::Bool(false),
+        2 => Value::from(0),
+        3 => Value::from(""),
+        4 => Value::Array(Vec::new()),
+        5 => Value::Object(Map::new()),
+        _ => return None,
+    })
+}
+
+/// Deserializes a `T` from a JSON value and validates it.
+pub fn from_value<T>(mut value: Value) -> Result<T, ValidationErrors>
+where
+    T: DeserializeOwned + Validate,
+{
+    let mut errors = ValidationErrors::new();
+    // The paths replaced by a placeholder and the index of that placeholder
+    let mut patched: HashMap<Vec<PathSegment>, usize> = HashMap::new();
+
+    loop {
+        let error_path = RefCell::new(None);
+        let deserializer =
+            PathDeserializer { value: &value, path: Vec::new(), error_path: &error_path };
+
+        let error = match T::deserialize(deserializer) {
+            Ok(res) => {
+                if errors.is_empty() {
+                    return res.validate().map(|_| res);
+                }
+                if let Err(validation_errors) = res.validate() {
+                    merge_validation_errors(&mut errors, validation_errors);
+                }
+                return Err(errors);
+            }
+            Err(e) => e,
+        };
+        let mut path = error_path.into_inner().unwrap_or_default();
+        let message = error.to_string();
+
+        // Errors inside a placeholder mean that it wasn't the right one
+        if let Some(patched_path) =
+            (0..=path.len()).rev().map(|i| &path[..i]).find(|p| patched.contains_key(*p))
+        {
+            let patched_path = patched_path.to_vec();
+            let attempt = patched[&patched_path] + 1;
+            match placeholder(attempt) {
+                Some(v) => {
+                    set_value(&mut value, &patched_path, Some(v));
+                    patched.insert(patched_path, attempt);
+                    continue;
+                }
+                None => return Err(errors),
+            }
+        }
+
+        let (code, replacement) = if let Some(field) = backquoted(&message, "missing field `") {
+            path.push(PathSegment::Field(Cow::Owned(field.to_string())));
+            ("missing", placeholder(0))
+        } else if let Some(field) = backquoted(&message, "unknown field `") {
+            path.push(PathSegment::Field(Cow::Owned(field.to_string())));
+            ("unknown", None)
+        } else {
+            ("type", placeholder(0))
+        };
+
+        let mut err = ValidationError::new(code).with_message(Cow::Owned(message));
+        if code == "type" {
+            if let Some(v) = get_value(&value, &path) {
+                err.add_param(Cow::from("value"), v);
+            }
+        }
+        errors.extend([(ErrorPath::from(path.clone()), err)]);
+
+        // The value itself can't be replaced
+        if path.is_empty() {
+            return Err(errors);
+        }
+        if replacement.is_some() {
+            patched.insert(path.clone(), 0);
+        }
+        set_value(&mut value, &path, replacement);
+    }
+}
+
+/// Deserializes a `T` from a JSON string and validates it. If the string isn't valid JSON, a
+/// `syntax` error is returned in `__all__`.
+pub fn from_str<T>(s: &str) -> Result<T, ValidationErrors>
+where
+    T: DeserializeOwned + Validate,
+{
+    from_deserializer(&mut serde_json::Deserializer::from_str(s))
+}
+
+/// Deserializes a `T` from any deserializer and validates it. If the input can't be read, a
+/// `syntax` error is returned in `__all__`.
+pub fn from_deserializer<'de, D, T>(deserializer: D) -> Result<T, ValidationErrors>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned + Validate,
+{
+    match <Value as ::serde::Deserialize>::deserialize(deserializer) {
+        Ok(value) => from_value(value),
+        Err(e) => {
+            let err = ValidationError::new("syntax").with_message(Cow::Owned(e.to_string()));
+            Err([(ErrorPath::new(), err)].into_iter().collect())
+        }
+    }
+}
+
+fn backquoted<'a>(message: &'a str, prefix: &str) -> Option<&'a str> {
+    let rest = message.strip_prefix(prefix)?;
+    rest.find('`').map(|end| &rest[..end])
+}
+
+fn get_value<'a>(value: &'a Value, path: &[PathSegment]) -> Option<&'a Value> {
+    path.iter().try_fold(value, |v, segment| match segment {
+        PathSegment::Field(key) => v.get(key.as_ref()),
+        PathSegment::Key(key) => v.get(key),
+        PathSegment::Index(index) => v.get(index),
+    })
+}
+
+// Sets the value at `path`, removing it if `None`
+fn set_value(value: &mut Value, path: &[PathSegment], new_value: Option<Value>) {
+    let Some((last, parent_path)) = path.split_last() else {
+        return;
+    };
+    let parent = parent_path.iter().try_fold(value, |v, segment| match segment {
+        PathSegment::Field(key) => v.get_mut(key.as_ref()),
+        PathSegment::Key(key) => v.get_mut(key),
+        PathSegment::Index(index) => v.get_mut(index),
+    });
+    match (parent, last, new_value) {
+        (Some(Value::Object(map)), PathSegment::Field(key), Some(v)) => {
+            map.insert(key.to_string(), v);
+        }
+        (Some(Value::Object(map)), PathSegment::Field(key), None) => {
+            map.remove(key.as_ref());
+        }
+        (Some(Value::Array(items)), PathSegment::Index(index), Some(v)) if *index < items.len() => {
+            items[*index] = v;
+        }
+        _ => (),
+    }
+}
+
+// Adds the validation errors that aren't about a value that failed to deserialize, since those
+// were run on a placeholder
+fn merge_validation_errors(errors: &mut ValidationErrors, validation_errors: ValidationErrors) {
+    for (key, kind) in validation_errors.0 {
+        match (errors.0.get_mut(&key), kind) {
+            (None, kind) => {
+                errors.0.insert(key, kind);
+            }
+            (Some(ValidationErrorsKind::Struct(existing)), ValidationErrorsKind::Struct(new)) => {
+                merge_validation_errors(existing, *new);
+            }
+            (Some(ValidationErrorsKind::List(existing)), ValidationErrorsKind::List(new)) => {
+                for (index, new_item) in new {
+                    match existing.get_mut(&index) {
+                        Some(existing_item) => merge_validation_errors(existing_item, *new_item),
+                        None => {
+                            existing.insert(index, new_item);
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+// Deserializes a `Value` while keeping track of the path of the innermost value that failed
+struct PathDeserializer<'a> {
+    value: &'a Value,
+    path: Vec<PathSegment>,
+    error_path: &'a RefCell<Option<Vec<PathSegment>>>,
+}
+
+impl<'a> PathDeserializer<'a> {
+    fn track<T>(
+        path: &[PathSegment],
+        error_path: &RefCell<Option<Vec<PathSegment>>>,
+        res: Result<T, serde_json::Error>,
+    ) -> Result<T, serde_json::Error> {
+        if res.is_err() {
+            error_path.borrow_mut().get_or_insert_with(|| path.to_vec());
+        }
+        res
+    }
+
+    fn child(&self, value: &'a Value, segment: PathSegment) -> PathDeserializer<'a> {
+        let mut path = self.path.clone();
+        path.push(segment);
+        PathDeserializer { value, path, error_path: self.error_path }
+    }
+}
+
+impl<'a> Deserializer<'a> for PathDeserializer<'a> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let res = match self.value {
+            Value::Array(items) => {
+                visitor.visit_seq(SeqDeserializer { parent: &self, iter: items.iter().enumerate() })
+            }
+            Value::Object(map) => {
+                visitor.visit_map(MapDeserializer { parent: &self, iter: map.iter(), value: None })
+            }
+            value => value.deserialize_any(visitor),
+        };
+        Self::track(&self.path, self.error_path, res)
+    }
+
+    fn deserialize_option<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.value.is_null() {
+            return visitor.visit_none();
+        }
+        let (path, error_path) = (self.path.clone(), self.error_path);
+        Self::track(&path, error_path, visitor.visit_some(self))
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'a>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let (path, error_path) = (self.path.clone(), self.error_path);
+        Self::track(&path, error_path, visitor.visit_newtype_struct(self))
+    }
+
+    fn deserialize_enum<V: Visitor<'a>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let res = self.value.deserialize_enum(name, variants, visitor);
+        Self::track(&self.path, self.error_path, res)
+    }
+
+    forward_to_deserialize_any! {
+        <W: Visitor<'a>>
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+struct SeqDeserializer<'p, 'a> {
+    parent: &'p PathDeserializer<'a>,
+    iter: std::iter::Enumerate<std::slice::Iter<'a, Value>>,
+}
+
+impl<'a> SeqAccess<'a> for SeqDeserializer<'_, 'a> {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<S: DeserializeSeed<'a>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, Self::Error> {
+        match self.iter.next() {
+            Some((index, value)) => {
+                seed.deserialize(self.parent.child(value, PathSegment::Index(index))).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+struct MapDeserializer<'p, 'a> {
+    parent: &'p PathDeserializer<'a>,
+    iter: serde_json::map::Iter<'a>,
+    value: Option<(&'a String, &'a Value)>,
+}
+
+impl<'a> MapAccess<'a> for MapDeserializer<'_, 'a> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<S: DeserializeSeed<'a>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, Self::Error> {
+        match self.iter.next() {
+            Some((key, value)) => {
+                self.value = Some((key, value));
+                seed.deserialize(KeyDeserializer(key)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'a>>(
+        &mut self,
+        seed: S,
+    ) -> Result<S::Value, Self::Error> {
+        let (key, value) = self.value.take().expect("next_value_seed called before next_key_seed");
+        seed.deserialize(self.parent.child(value, PathSegment::Field(Cow::Owned(key.clone()))))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+// Deserializes the keys of objects, parsing them for maps with numeric or boolean keys like
+// `serde_json` does
+struct KeyDeserializer<'a>(&'a str);
+
+macro_rules! deserialize_parsed_key {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                match self.0.parse() {
+                    Ok(v) => visitor.$visit(v),
+                    Err(_) => self.deserialize_any(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'a> Deserializer<'a> for KeyDeserializer<'a> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        BorrowedStrDeserializer::new(self.0).deserialize_any(visitor)
+    }
+
+    deserialize_parsed_key! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    fn deserialize_option<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'a>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'a>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_enum(self.0.into_deserializer())
+    }
+
+    forward_to_deserialize_any! {
+        <W: Visitor<'a>>
+        char str string bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::set_value;
+    use crate::PathSegment;
+
+    #[test]
+    fn test_set_value() {
+        let mut value = json!({"a": [1, {"b": 2}]});
+        let a0 = [PathSegment::Field("a".into()), PathSegment::Index(0)];
+        set_value(&mut value, &a0, Some(json!(null)));
+        let b =
+            [PathSegment::Field("a".into()), PathSegment::Index(1), PathSegment::Field("b".into())];
+        set_value(&mut value, &b, None);
+        assert_eq!(value, json!({"a": [null, {}]}));
+    }
+}
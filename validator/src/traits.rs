@@ -2,6 +2,9 @@ use crate::types::{ValidationErrors, ValidationErrorsKind};
 use std::borrow::Cow;
 use std::collections::btree_map::BTreeMap;
 use std::collections::HashMap;
+use std::fmt::Display;
+
+use indexmap::IndexMap;
 
 /// This is the original trait that was implemented by deriving `Validate`. It will still be
 /// implemented for struct validations that don't take custom arguments. The call is being
@@ -16,28 +19,55 @@ impl<T: Validate> Validate for &T {
     }
 }
 
+/// The validation of the value of a `#[validate(nested)]` field, returning the errors to put
+/// under the field: `ValidationErrorsKind::Struct` for a struct and `ValidationErrorsKind::List`
+/// for a collection, with the errors of its items by position.
+///
+/// It is implemented for every type implementing `Validate` and for the standard collections of
+/// such types, which don't implement `Validate` since their errors are not the ones of a struct.
+pub trait ValidateNested {
+    fn validate_nested(&self) -> Result<(), ValidationErrorsKind>;
+}
+
+impl<T: Validate + ?Sized> ValidateNested for T {
+    fn validate_nested(&self) -> Result<(), ValidationErrorsKind> {
+        self.validate().map_err(|e| ValidationErrorsKind::Struct(Box::new(e)))
+    }
+}
+
+fn validate_items<'a, T, I>(items: I) -> Result<(), ValidationErrorsKind>
+where
+    T: ValidateNested + ?Sized + 'a,
+    I: IntoIterator<Item = &'a T>,
+{
+    let mut vec_err: BTreeMap<usize, Box<ValidationErrors>> = BTreeMap::new();
+
+    for (index, item) in items.into_iter().enumerate() {
+        if let Err(e) = item.validate_nested() {
+            vec_err.insert(index, Box::new(item_errors(e)));
+        }
+    }
+
+    if vec_err.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationErrorsKind::List(vec_err))
+    }
+}
+
+// The errors of an item which is itself a collection are put under its `_item` key
+fn item_errors(kind: ValidationErrorsKind) -> ValidationErrors {
+    match kind {
+        ValidationErrorsKind::Struct(errors) => *errors,
+        kind => ValidationErrors(IndexMap::from([(Cow::Borrowed("_item"), kind)])),
+    }
+}
+
 macro_rules! impl_validate_list {
     ($container:ty) => {
-        impl<T: Validate> Validate for $container {
-            fn validate(&self) -> Result<(), ValidationErrors> {
-                let mut vec_err: BTreeMap<usize, Box<ValidationErrors>> = BTreeMap::new();
-
-                for (index, item) in self.iter().enumerate() {
-                    if let Err(e) = item.validate() {
-                        vec_err.insert(index, Box::new(e));
-                    }
-                }
-
-                if vec_err.is_empty() {
-                    Ok(())
-                } else {
-                    let err_kind = ValidationErrorsKind::List(vec_err);
-                    let errors = ValidationErrors(std::collections::HashMap::from([(
-                        Cow::Borrowed("_tmp_validator"),
-                        err_kind,
-                    )]));
-                    Err(errors)
-                }
+        impl<T: ValidateNested> ValidateNested for $container {
+            fn validate_nested(&self) -> Result<(), ValidationErrorsKind> {
+                validate_items(self.iter())
             }
         }
     };
@@ -51,68 +81,48 @@ impl_validate_list!(std::collections::VecDeque<T>);
 impl_validate_list!(std::vec::Vec<T>);
 impl_validate_list!([T]);
 
-impl<T: Validate, const N: usize> Validate for [T; N] {
-    fn validate(&self) -> Result<(), ValidationErrors> {
-        let mut vec_err: BTreeMap<usize, Box<ValidationErrors>> = BTreeMap::new();
-
-        for (index, item) in self.iter().enumerate() {
-            if let Err(e) = item.validate() {
-                vec_err.insert(index, Box::new(e));
-            }
-        }
-
-        if vec_err.is_empty() {
-            Ok(())
-        } else {
-            let err_kind = ValidationErrorsKind::List(vec_err);
-            let errors = ValidationErrors(std::collections::HashMap::from([(
-                Cow::Borrowed("_tmp_validator"),
-                err_kind,
-            )]));
-            Err(errors)
-        }
+impl<T: ValidateNested, const N: usize> ValidateNested for [T; N] {
+    fn validate_nested(&self) -> Result<(), ValidationErrorsKind> {
+        validate_items(self.iter())
     }
 }
 
-impl<K, V: Validate, S> Validate for &HashMap<K, V, S> {
-    fn validate(&self) -> Result<(), ValidationErrors> {
-        let mut vec_err: BTreeMap<usize, Box<ValidationErrors>> = BTreeMap::new();
-
-        for (index, (_key, value)) in self.iter().enumerate() {
-            if let Err(e) = value.validate() {
-                vec_err.insert(index, Box::new(e));
-            }
-        }
-
-        if vec_err.is_empty() {
-            Ok(())
-        } else {
-            let err_kind = ValidationErrorsKind::List(vec_err);
-            let errors =
-                ValidationErrors(HashMap::from([(Cow::Borrowed("_tmp_validator"), err_kind)]));
-            Err(errors)
+// The errors of the values of a map are reported under their key, like the fields of a struct
+fn validate_entries<'a, K, V, I>(entries: I) -> ValidationErrors
+where
+    K: Display + 'a,
+    V: ValidateNested + 'a,
+    I: IntoIterator<Item = (&'a K, &'a V)>,
+{
+    let mut errors = ValidationErrors::new();
+    for (key, value) in entries {
+        if let Err(e) = value.validate_nested() {
+            errors.merge_field(key.to_string(), e);
         }
     }
+    errors
 }
 
-impl<K, V: Validate> Validate for &BTreeMap<K, V> {
-    fn validate(&self) -> Result<(), ValidationErrors> {
-        let mut vec_err: BTreeMap<usize, Box<ValidationErrors>> = BTreeMap::new();
+fn entries_result(errors: ValidationErrors) -> Result<(), ValidationErrorsKind> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationErrorsKind::Struct(Box::new(errors)))
+    }
+}
 
-        for (index, (_key, value)) in self.iter().enumerate() {
-            if let Err(e) = value.validate() {
-                vec_err.insert(index, Box::new(e));
-            }
-        }
+impl<K: Display, V: ValidateNested, S> ValidateNested for HashMap<K, V, S> {
+    fn validate_nested(&self) -> Result<(), ValidationErrorsKind> {
+        let mut errors = validate_entries(self.iter());
+        // Sorted so that the errors don't depend on the iteration order of the map
+        errors.0.sort_keys();
+        entries_result(errors)
+    }
+}
 
-        if vec_err.is_empty() {
-            Ok(())
-        } else {
-            let err_kind = ValidationErrorsKind::List(vec_err);
-            let errors =
-                ValidationErrors(HashMap::from([(Cow::Borrowed("_tmp_validator"), err_kind)]));
-            Err(errors)
-        }
+impl<K: Display, V: ValidateNested> ValidateNested for BTreeMap<K, V> {
+    fn validate_nested(&self) -> Result<(), ValidationErrorsKind> {
+        entries_result(validate_entries(self.iter()))
     }
 }
 
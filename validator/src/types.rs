@@ -1,30 +1,60 @@
 use std::borrow::Cow;
-use std::collections::{hash_map::Entry::Vacant, BTreeMap, HashMap};
+use std::collections::{btree_map, BTreeMap};
 
+use indexmap::map::Entry::{Occupied, Vacant};
+use indexmap::IndexMap;
 use serde::ser::Serialize;
 use serde_derive::{Deserialize, Serialize};
 use serde_json::{to_value, Value};
 
+use crate::{ErrorPath, PathSegment};
+
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct ValidationError {
     pub code: Cow<'static, str>,
     pub message: Option<Cow<'static, str>>,
-    pub params: HashMap<Cow<'static, str>, Value>,
+    pub params: IndexMap<Cow<'static, str>, Value>,
 }
 
 impl ValidationError {
-    pub fn new(code: &'static str) -> ValidationError {
-        ValidationError { code: Cow::from(code), message: None, params: HashMap::new() }
+    /// Creates an error with the given code, which can be a `&'static str`, a `String` or a `Cow`.
+    pub fn new(code: impl Into<Cow<'static, str>>) -> ValidationError {
+        ValidationError { code: code.into(), message: None, params: IndexMap::new() }
     }
 
+    /// Adds a param to the error. A value that can't be serialized to JSON, like a map with
+    /// non-string keys, is left out: use `try_add_param` to know about it.
     pub fn add_param<T: Serialize>(&mut self, name: Cow<'static, str>, val: &T) {
-        self.params.insert(name, to_value(val).unwrap());
+        let _ = self.try_add_param(name, val);
+    }
+
+    /// Adds a param to the error, returning the error of the serialization of the value if it
+    /// fails, in which case the param isn't added.
+    pub fn try_add_param<T: Serialize + ?Sized>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        val: &T,
+    ) -> Result<(), serde_json::Error> {
+        self.params.insert(name.into(), to_value(val)?);
+        Ok(())
+    }
+
+    /// Returns the error with the param added, for building errors in a single expression like
+    /// `ValidationError::new("range").with_param("min", 3)`. Like with `add_param`, a value that
+    /// can't be serialized is left out.
+    pub fn with_param<T: Serialize>(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        val: T,
+    ) -> ValidationError {
+        let _ = self.try_add_param(name, &val);
+        self
     }
 
     /// Adds a custom message to a `ValidationError` that will be used when displaying the
     /// `ValidationError`, instead of an auto-generated description.
-    pub fn with_message(mut self, message: Cow<'static, str>) -> ValidationError {
-        self.message = Some(message);
+    pub fn with_message(mut self, message: impl Into<Cow<'static, str>>) -> ValidationError {
+        self.message = Some(message.into());
         self
     }
 }
@@ -47,18 +77,18 @@ pub enum ValidationErrorsKind {
 }
 
 #[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct ValidationErrors(pub HashMap<Cow<'static, str>, ValidationErrorsKind>);
+pub struct ValidationErrors(pub IndexMap<Cow<'static, str>, ValidationErrorsKind>);
 
 impl ValidationErrors {
     pub fn new() -> ValidationErrors {
-        ValidationErrors(HashMap::new())
+        ValidationErrors(IndexMap::new())
     }
 
     /// Returns a boolean indicating whether a validation result includes validation errors for a
     /// given field. May be used as a condition for performing nested struct validations on a field
     /// in the absence of field-level validation errors.
     #[must_use]
-    pub fn has_error(result: &Result<(), ValidationErrors>, field: &'static str) -> bool {
+    pub fn has_error(result: &Result<(), ValidationErrors>, field: &str) -> bool {
         match result {
             Ok(()) => false,
             Err(ref errs) => errs.contains_key(field),
@@ -67,21 +97,13 @@ impl ValidationErrors {
 
     pub fn merge_self(
         &mut self,
-        field: &'static str,
+        field: impl Into<Cow<'static, str>>,
         child: Result<(), ValidationErrors>,
     ) -> &mut ValidationErrors {
         match child {
             Ok(()) => self,
-            Err(mut errors) => {
-                // This is a bit of a hack to be able to support collections which return a
-                // `ValidationErrors` with a made-up `_tmp_validator` entry which we need to strip
-                // off.
-                if let Some(collection) = errors.0.remove("_tmp_validator") {
-                    self.add_nested(field, collection);
-                } else {
-                    self.add_nested(field, ValidationErrorsKind::Struct(Box::new(errors)));
-                }
-
+            Err(errors) => {
+                self.add_nested(field, ValidationErrorsKind::Struct(Box::new(errors)));
                 self
             }
         }
@@ -91,7 +113,7 @@ impl ValidationErrors {
     /// validation result for one of its fields.
     pub fn merge(
         parent: Result<(), ValidationErrors>,
-        field: &'static str,
+        field: impl Into<Cow<'static, str>>,
         child: Result<(), ValidationErrors>,
     ) -> Result<(), ValidationErrors> {
         match child {
@@ -109,13 +131,14 @@ impl ValidationErrors {
     /// validation result for one of its fields where that field is a vector of validating structs.
     pub fn merge_all(
         parent: Result<(), ValidationErrors>,
-        field: &'static str,
+        field: impl Into<Cow<'static, str>>,
         children: Vec<Result<(), ValidationErrors>>,
     ) -> Result<(), ValidationErrors> {
+        let field = field.into();
         let errors = children
             .into_iter()
             .enumerate()
-            .filter_map(|(i, res)| res.err().map(|mut err| (i, err.remove(field))))
+            .filter_map(|(i, res)| res.err().map(|mut err| (i, err.0.shift_remove(&field))))
             .filter_map(|(i, entry)| match entry {
                 Some(ValidationErrorsKind::Struct(errors)) => Some((i, errors)),
                 _ => None,
@@ -134,23 +157,23 @@ impl ValidationErrors {
 
     /// Returns a map of field-level validation errors found for the struct that was validated and
     /// any of it's nested structs that are tagged for validation.
-    pub fn errors(&self) -> &HashMap<Cow<'static, str>, ValidationErrorsKind> {
+    pub fn errors(&self) -> &IndexMap<Cow<'static, str>, ValidationErrorsKind> {
         &self.0
     }
 
     /// Returns a mutable map of field-level validation errors found for the struct that was validated and
     /// any of it's nested structs that are tagged for validation.
-    pub fn errors_mut(&mut self) -> &mut HashMap<Cow<'static, str>, ValidationErrorsKind> {
+    pub fn errors_mut(&mut self) -> &mut IndexMap<Cow<'static, str>, ValidationErrorsKind> {
         &mut self.0
     }
 
     /// Consume the struct, returning the validation errors found
-    pub fn into_errors(self) -> HashMap<Cow<'static, str>, ValidationErrorsKind> {
+    pub fn into_errors(self) -> IndexMap<Cow<'static, str>, ValidationErrorsKind> {
         self.0
     }
 
     /// Returns a map of only field-level validation errors found for the struct that was validated.
-    pub fn field_errors(&self) -> HashMap<Cow<'static, str>, &Vec<ValidationError>> {
+    pub fn field_errors(&self) -> IndexMap<Cow<'static, str>, &Vec<ValidationError>> {
         self.0
             .iter()
             .filter_map(|(k, v)| {
@@ -160,14 +183,12 @@ impl ValidationErrors {
                     None
                 }
             })
-            .collect::<HashMap<_, _>>()
+            .collect::<IndexMap<_, _>>()
     }
 
-    pub fn add(&mut self, field: &'static str, error: ValidationError) {
-        if let ValidationErrorsKind::Field(ref mut vec) = self
-            .0
-            .entry(Cow::Borrowed(field))
-            .or_insert_with(|| ValidationErrorsKind::Field(vec![]))
+    pub fn add(&mut self, field: impl Into<Cow<'static, str>>, error: ValidationError) {
+        if let ValidationErrorsKind::Field(ref mut vec) =
+            self.0.entry(field.into()).or_insert_with(|| ValidationErrorsKind::Field(vec![]))
         {
             vec.push(error);
         } else {
@@ -175,13 +196,96 @@ impl ValidationErrors {
         }
     }
 
+    /// Adds an error on the item at `index` of the collection `field`, in a
+    /// `ValidationErrorsKind::List` shared with the errors of the nested validation if any.
+    /// `item_field` is the field of the item the error is about, `_item` being used for the item
+    /// itself.
+    ///
+    /// A field can't hold both field-level errors and a list: if the collection already has
+    /// field-level errors, for example from `length`, the error is added to them with the `index`
+    /// as a param instead. If it holds the errors of a nested struct, both are turned into
+    /// field-level errors like with `merge_field`.
+    pub fn add_item(
+        &mut self,
+        field: impl Into<Cow<'static, str>>,
+        index: usize,
+        item_field: impl Into<Cow<'static, str>>,
+        mut error: ValidationError,
+    ) {
+        let field = field.into();
+        match self.0.get_mut(&field) {
+            Some(ValidationErrorsKind::List(items)) => {
+                items.entry(index).or_default().add(item_field, error)
+            }
+            Some(ValidationErrorsKind::Field(errors)) => {
+                error.add_param(Cow::from("index"), &index);
+                errors.push(error);
+            }
+            Some(ValidationErrorsKind::Struct(_)) | None => {
+                let mut item = ValidationErrors::new();
+                item.add(item_field, error);
+                let items = BTreeMap::from([(index, Box::new(item))]);
+                self.merge_field(field, ValidationErrorsKind::List(items));
+            }
+        }
+    }
+
+    /// Merges the errors of `other` into these ones, see `merge_field` for how the errors of a
+    /// field are combined. Unlike `merge` and `add`, it never panics, so it can be used to combine
+    /// the results of several validations of the same value.
+    pub fn merge_from(&mut self, other: ValidationErrors) {
+        for (field, kind) in other.0 {
+            self.merge_field(field, kind);
+        }
+    }
+
+    /// Adds errors to `field`, merging them with the ones it already has instead of panicking:
+    ///
+    /// - field-level errors are concatenated
+    /// - the errors of nested structs are merged recursively
+    /// - the items of lists are merged by index
+    /// - field-level errors added to a nested struct become the `__all__` errors of that struct
+    /// - field-level errors and a list, or a nested struct and a list, can't be represented
+    ///   together so the nested errors are turned into field-level errors, with an `index` param
+    ///   for the list items and a `path` param for their path inside the nested value
+    pub fn merge_field(&mut self, field: impl Into<Cow<'static, str>>, kind: ValidationErrorsKind) {
+        match self.0.entry(field.into()) {
+            Vacant(entry) => {
+                entry.insert(kind);
+            }
+            Occupied(mut entry) => {
+                let existing =
+                    std::mem::replace(entry.get_mut(), ValidationErrorsKind::Field(Vec::new()));
+                *entry.get_mut() = merge_kinds(existing, kind);
+            }
+        }
+    }
+
+    /// Sorts the errors by field name, recursively, instead of keeping them in the order they were
+    /// added, which is the declaration order of the fields for derived validations. The params of
+    /// the errors are sorted by name as well.
+    pub fn sort_alphabetically(&mut self) {
+        self.0.sort_keys();
+        for kind in self.0.values_mut() {
+            match kind {
+                ValidationErrorsKind::Field(errors) => {
+                    errors.iter_mut().for_each(|error| error.params.sort_keys())
+                }
+                ValidationErrorsKind::Struct(nested) => nested.sort_alphabetically(),
+                ValidationErrorsKind::List(items) => {
+                    items.values_mut().for_each(|item| item.sort_alphabetically())
+                }
+            }
+        }
+    }
+
     #[must_use]
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
 
-    fn add_nested(&mut self, field: &'static str, errors: ValidationErrorsKind) {
-        if let Vacant(entry) = self.0.entry(Cow::Borrowed(field)) {
+    fn add_nested(&mut self, field: impl Into<Cow<'static, str>>, errors: ValidationErrorsKind) {
+        if let Vacant(entry) = self.0.entry(field.into()) {
             entry.insert(errors);
         } else {
             panic!("Attempt to replace non-empty ValidationErrors entry");
@@ -189,13 +293,74 @@ impl ValidationErrors {
     }
 
     #[must_use]
-    fn contains_key(&self, field: &'static str) -> bool {
+    fn contains_key(&self, field: &str) -> bool {
         self.0.contains_key(field)
     }
+}
+
+fn merge_kinds(
+    existing: ValidationErrorsKind,
+    other: ValidationErrorsKind,
+) -> ValidationErrorsKind {
+    use ValidationErrorsKind::{Field, List, Struct};
 
-    fn remove(&mut self, field: &'static str) -> Option<ValidationErrorsKind> {
-        self.0.remove(field)
+    match (existing, other) {
+        (Field(mut errors), Field(other_errors)) => {
+            errors.extend(other_errors);
+            Field(errors)
+        }
+        (Struct(mut errors), Struct(other_errors)) => {
+            errors.merge_from(*other_errors);
+            Struct(errors)
+        }
+        (List(mut items), List(other_items)) => {
+            for (index, other_item) in other_items {
+                match items.entry(index) {
+                    btree_map::Entry::Vacant(entry) => {
+                        entry.insert(other_item);
+                    }
+                    btree_map::Entry::Occupied(mut entry) => {
+                        entry.get_mut().merge_from(*other_item)
+                    }
+                }
+            }
+            List(items)
+        }
+        (Struct(mut errors), Field(field_errors)) | (Field(field_errors), Struct(mut errors)) => {
+            errors.merge_field("__all__", Field(field_errors));
+            Struct(errors)
+        }
+        (existing, other) => {
+            let mut errors = into_field_errors(existing);
+            errors.extend(into_field_errors(other));
+            Field(errors)
+        }
+    }
+}
+
+// Turns nested errors into field-level ones, keeping where they were in params
+fn into_field_errors(kind: ValidationErrorsKind) -> Vec<ValidationError> {
+    if let ValidationErrorsKind::Field(errors) = kind {
+        return errors;
     }
+
+    let mut wrapper = ValidationErrors::new();
+    wrapper.0.insert(Cow::Borrowed(""), kind);
+    wrapper
+        .into_iter()
+        .map(|(path, mut error)| {
+            let mut segments = &path.segments()[1..];
+            if let [PathSegment::Index(index), rest @ ..] = segments {
+                error.add_param(Cow::from("index"), index);
+                segments = rest;
+            }
+            if !segments.is_empty() {
+                let path = segments.iter().cloned().collect::<ErrorPath>();
+                error.add_param(Cow::from("path"), &path.to_string());
+            }
+            error
+        })
+        .collect()
 }
 
 impl std::error::Error for ValidationErrors {
@@ -206,3 +371,137 @@ impl std::error::Error for ValidationErrors {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+
+    use super::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+    fn with_errors(entries: &[(&'static str, &'static str)]) -> ValidationErrors {
+        let mut errors = ValidationErrors::new();
+        for &(field, code) in entries {
+            errors.add(field, ValidationError::new(code));
+        }
+        errors
+    }
+
+    fn codes(errors: &ValidationErrors, path: &str) -> Vec<String> {
+        errors.errors_at(path).iter().map(|e| e.code.to_string()).collect()
+    }
+
+    #[test]
+    fn test_merge_from() {
+        let mut first = with_errors(&[("name", "length")]);
+        first.add_item("items", 0, "sku", ValidationError::new("length"));
+        first.merge_field(
+            "address",
+            ValidationErrorsKind::Struct(Box::new(with_errors(&[("zip", "regex")]))),
+        );
+
+        let mut second = with_errors(&[("name", "blocked"), ("mail", "email")]);
+        second.add_item("items", 0, "sku", ValidationError::new("unique"));
+        second.add_item("items", 2, "_item", ValidationError::new("sorted"));
+        second.merge_field(
+            "address",
+            ValidationErrorsKind::Struct(Box::new(with_errors(&[("city", "length")]))),
+        );
+
+        first.merge_from(second);
+        assert_eq!(codes(&first, "name"), ["length", "blocked"]);
+        assert_eq!(codes(&first, "mail"), ["email"]);
+        assert_eq!(codes(&first, "items[0].sku"), ["length", "unique"]);
+        assert_eq!(codes(&first, "items[2]"), ["sorted"]);
+        assert_eq!(codes(&first, "address.zip"), ["regex"]);
+        assert_eq!(codes(&first, "address.city"), ["length"]);
+    }
+
+    #[test]
+    fn test_merge_field_errors_with_nested_errors() {
+        let mut errors = with_errors(&[("address", "required")]);
+        let nested = with_errors(&[("zip", "regex")]);
+        errors.merge_field("address", ValidationErrorsKind::Struct(Box::new(nested)));
+        assert_eq!(codes(&errors, "address.__all__"), ["required"]);
+        assert_eq!(codes(&errors, "address.zip"), ["regex"]);
+
+        let mut errors = with_errors(&[("items", "length")]);
+        let mut items = ValidationErrors::new();
+        items.add_item("items", 1, "sku", ValidationError::new("length"));
+        items.add_item("items", 2, "_item", ValidationError::new("sorted"));
+        errors.merge_from(items);
+
+        let items = errors.errors_at("items");
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[1].code, "length");
+        assert_eq!(items[1].params["index"], 1);
+        assert_eq!(items[1].params["path"], "sku");
+        assert_eq!(items[2].params["index"], 2);
+        assert!(!items[2].params.contains_key("path"));
+
+        let mut errors = ValidationErrors::new();
+        errors.merge_field(
+            "items",
+            ValidationErrorsKind::Struct(Box::new(with_errors(&[("sku", "regex")]))),
+        );
+        errors.add_item("items", 3, "_item", ValidationError::new("sorted"));
+        let items = errors.errors_at("items");
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].code, "regex");
+        assert_eq!(items[0].params["path"], "sku");
+        assert_eq!(items[1].code, "sorted");
+        assert_eq!(items[1].params["index"], 3);
+    }
+
+    #[test]
+    fn test_runtime_field_names() {
+        // Like the fields of a form whose schema is loaded at runtime
+        let fields = vec![("email".to_string(), ""), ("age".to_string(), "abc")];
+
+        let mut errors = ValidationErrors::new();
+        for (name, value) in &fields {
+            if value.is_empty() {
+                errors.add(name.clone(), ValidationError::new("required"));
+            }
+        }
+        errors.add_item(
+            format!("rows_{}", 2),
+            0,
+            fields[1].0.clone(),
+            ValidationError::new("type"),
+        );
+        let mut nested = ValidationErrors::new();
+        nested.add("city", ValidationError::new("length"));
+        let field = String::from("address");
+        let result = ValidationErrors::merge(Ok(()), field.clone(), Err(nested));
+
+        assert_eq!(codes(&errors, "email"), ["required"]);
+        assert_eq!(codes(&errors, "rows_2[0].age"), ["type"]);
+        assert!(ValidationErrors::has_error(&result, &field));
+        assert_eq!(codes(&result.unwrap_err(), "address.city"), ["length"]);
+    }
+
+    #[test]
+    fn test_error_builder() {
+        let code = format!("{}_{}", "too", "long");
+        let error = ValidationError::new(code)
+            .with_param("max", 3)
+            .with_param("value", "abcd")
+            .with_message("Too long");
+        assert_eq!(error.code, "too_long");
+        assert_eq!(error.params["max"], 3);
+        assert_eq!(error.params["value"], "abcd");
+        assert_eq!(error.message.as_deref(), Some("Too long"));
+    }
+
+    #[test]
+    fn test_params_that_cant_be_serialized() {
+        let grid = HashMap::from([((0, 1), "a")]);
+
+        let mut error = ValidationError::new("grid");
+        assert!(error.try_add_param("grid", &grid).is_err());
+        error.add_param(Cow::from("grid"), &grid);
+        let error = error.with_param("grid", &grid).with_param("size", 1);
+        assert_eq!(error.params.keys().collect::<Vec<_>>(), ["size"]);
+    }
+}
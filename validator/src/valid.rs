@@ -0,0 +1,145 @@
+use std::ops::Deref;
+
+use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::{Serialize, Serializer};
+
+use crate::{Validate, ValidateArgs, ValidationErrors};
+
+/// A value that has been validated.
+///
+/// It can only be built by validating the value, so functions can require a `Valid<T>` instead of
+/// a `T` to make sure the validation was done. Since the value can't be modified afterwards, only
+/// shared access is given with `Deref`: use `into_inner` to get the value back.
+///
+/// Deserializing a `Valid<T>` deserializes the `T` and then validates it, the validation errors
+/// being returned as a custom error of the deserializer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Valid<T>(T);
+
+impl<T: Validate> Valid<T> {
+    pub fn new(value: T) -> Result<Valid<T>, ValidationErrors> {
+        value.validate()?;
+        Ok(Valid(value))
+    }
+}
+
+impl<T> Valid<T> {
+    pub fn new_with_args<'v_a>(value: T, args: T::Args) -> Result<Valid<T>, ValidationErrors>
+    where
+        T: ValidateArgs<'v_a>,
+    {
+        value.validate_with_args(args)?;
+        Ok(Valid(value))
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Valid<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsRef<T> for Valid<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Valid<T>
+where
+    T: Deserialize<'de> + Validate,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = T::deserialize(deserializer)?;
+        Valid::new(value).map_err(D::Error::custom)
+    }
+}
+
+impl<T: Serialize> Serialize for Valid<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use serde_derive::Deserialize;
+
+    use super::Valid;
+    use crate::{Validate, ValidateArgs, ValidationError, ValidationErrors};
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Age(u8);
+
+    impl Validate for Age {
+        fn validate(&self) -> Result<(), ValidationErrors> {
+            let mut errors = ValidationErrors::new();
+            if self.0 < 18 {
+                errors.add("age", ValidationError::new("range"));
+            }
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(errors)
+            }
+        }
+    }
+
+    impl<'v_a> ValidateArgs<'v_a> for Age {
+        type Args = u8;
+
+        fn validate_with_args(&self, min: u8) -> Result<(), ValidationErrors> {
+            let mut errors = ValidationErrors::new();
+            if self.0 < min {
+                let mut err = ValidationError::new("range");
+                err.add_param(Cow::from("min"), &min);
+                errors.add("age", err);
+            }
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(errors)
+            }
+        }
+    }
+
+    #[test]
+    fn test_valid_new() {
+        let age = Valid::new(Age(20)).unwrap();
+        assert_eq!(*age, Age(20));
+        assert_eq!(age.into_inner(), Age(20));
+
+        let err = Valid::new(Age(12)).unwrap_err();
+        assert_eq!(err.field_errors()["age"][0].code, "range");
+    }
+
+    #[test]
+    fn test_valid_new_with_args() {
+        assert!(Valid::new_with_args(Age(20), 21).is_err());
+        assert!(Valid::new_with_args(Age(20), 16).is_ok());
+    }
+
+    #[test]
+    fn test_deserialize_valid() {
+        let age: Valid<Age> = serde_json::from_str("30").unwrap();
+        assert_eq!(*age, Age(30));
+
+        let err = serde_json::from_str::<Valid<Age>>("12").unwrap_err();
+        assert_eq!(err.to_string(), "age: out of range");
+        assert!(serde_json::from_str::<Valid<Age>>("\"abc\"").is_err());
+    }
+}
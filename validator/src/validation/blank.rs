@@ -0,0 +1,106 @@
+use crate::ValidateLength;
+
+/// Validates that a string has at least one character that isn't whitespace.
+pub trait ValidateNotBlank {
+    fn validate_not_blank(&self) -> bool {
+        self.as_not_blank_str().chars().any(|c| !c.is_whitespace())
+    }
+
+    fn as_not_blank_str(&self) -> &str;
+}
+
+impl<T: AsRef<str>> ValidateNotBlank for T {
+    fn as_not_blank_str(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+/// Validates that a string or a collection isn't empty.
+/// `None` is valid: use `required(empty_as_none)` to reject both.
+pub trait ValidateNonEmpty {
+    fn validate_non_empty(&self) -> bool;
+}
+
+impl<T: ValidateLength<u64> + ?Sized> ValidateNonEmpty for T {
+    fn validate_non_empty(&self) -> bool {
+        self.length().is_none_or(|length| length > 0)
+    }
+}
+
+/// Validates that a string doesn't start or end with whitespace.
+pub trait ValidateTrimmed {
+    fn validate_trimmed(&self) -> bool {
+        let s = self.as_trimmed_str();
+        s.trim().len() == s.len()
+    }
+
+    fn as_trimmed_str(&self) -> &str;
+}
+
+impl<T: AsRef<str>> ValidateTrimmed for T {
+    fn as_trimmed_str(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+
+    use super::{ValidateNonEmpty, ValidateNotBlank, ValidateTrimmed};
+
+    #[test]
+    fn test_validate_not_blank() {
+        let tests = vec![
+            ("hello", true),
+            (" a ", true),
+            ("", false),
+            ("   ", false),
+            ("\t\n\u{3000}\u{A0}", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(input.validate_not_blank(), expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_non_empty() {
+        assert!("a".validate_non_empty());
+        assert!(!"".validate_non_empty());
+        assert!(" ".validate_non_empty());
+        assert!(!String::new().validate_non_empty());
+        assert!(!Cow::from("").validate_non_empty());
+        assert!(vec![1].validate_non_empty());
+        assert!(!Vec::<u8>::new().validate_non_empty());
+        assert!(!HashMap::<String, u8>::new().validate_non_empty());
+        assert!(None::<String>.validate_non_empty());
+        assert!(!Some(String::new()).validate_non_empty());
+    }
+
+    #[test]
+    fn test_validate_trimmed() {
+        let tests = vec![
+            ("hello world", true),
+            ("", true),
+            (" hello", false),
+            ("hello\n", false),
+            ("\u{3000}hello", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(input.validate_trimmed(), expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_blank_cow() {
+        let test: Cow<'static, str> = "  ".into();
+        assert!(!test.validate_not_blank());
+        assert!(!test.validate_trimmed());
+        let test: Cow<'static, str> = String::from("a").into();
+        assert!(test.validate_not_blank());
+        assert!(test.validate_trimmed());
+    }
+}
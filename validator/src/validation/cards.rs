@@ -1,27 +1,359 @@
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    fmt,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
-#[cfg(feature = "card")]
-use card_validate::Validate as CardValidate;
+use serde::{Serialize, Serializer};
 
+/// The card brands that can be detected from the first digits of a card number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CardBrand {
+    Amex,
+    DinersClub,
+    Discover,
+    Jcb,
+    Maestro,
+    Mastercard,
+    Mir,
+    UnionPay,
+    Visa,
+}
+
+/// The ranges of card numbers prefixes of each brand: `(brand, first prefix, last prefix, prefix length)`.
+/// When several ranges match, the one with the longest prefix wins.
+const BRAND_RANGES: [(CardBrand, u32, u32, usize); 21] = [
+    (CardBrand::Amex, 34, 34, 2),
+    (CardBrand::Amex, 37, 37, 2),
+    (CardBrand::DinersClub, 300, 305, 3),
+    (CardBrand::DinersClub, 3095, 3095, 4),
+    (CardBrand::DinersClub, 36, 36, 2),
+    (CardBrand::DinersClub, 38, 39, 2),
+    (CardBrand::Discover, 6011, 6011, 4),
+    (CardBrand::Discover, 644, 649, 3),
+    (CardBrand::Discover, 65, 65, 2),
+    (CardBrand::Discover, 622126, 622925, 6),
+    (CardBrand::Jcb, 3528, 3589, 4),
+    (CardBrand::Maestro, 50, 50, 2),
+    (CardBrand::Maestro, 56, 58, 2),
+    (CardBrand::Maestro, 639, 639, 3),
+    (CardBrand::Maestro, 67, 67, 2),
+    (CardBrand::Mastercard, 51, 55, 2),
+    (CardBrand::Mastercard, 2221, 2720, 4),
+    (CardBrand::Mir, 2200, 2204, 4),
+    (CardBrand::UnionPay, 62, 62, 2),
+    (CardBrand::UnionPay, 81, 81, 2),
+    (CardBrand::Visa, 4, 4, 1),
+];
+
+impl CardBrand {
+    pub const ALL: [CardBrand; 9] = [
+        CardBrand::Amex,
+        CardBrand::DinersClub,
+        CardBrand::Discover,
+        CardBrand::Jcb,
+        CardBrand::Maestro,
+        CardBrand::Mastercard,
+        CardBrand::Mir,
+        CardBrand::UnionPay,
+        CardBrand::Visa,
+    ];
+
+    /// The name of the brand as used in the `brands` argument of the derive and in error params
+    pub fn name(&self) -> &'static str {
+        match self {
+            CardBrand::Amex => "amex",
+            CardBrand::DinersClub => "diners_club",
+            CardBrand::Discover => "discover",
+            CardBrand::Jcb => "jcb",
+            CardBrand::Maestro => "maestro",
+            CardBrand::Mastercard => "mastercard",
+            CardBrand::Mir => "mir",
+            CardBrand::UnionPay => "unionpay",
+            CardBrand::Visa => "visa",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<CardBrand> {
+        CardBrand::ALL.into_iter().find(|b| b.name() == name)
+    }
+
+    /// Detects the brand from the first digits of the card number, whether the number is valid
+    /// or not.
+    pub fn detect(number: &str) -> Option<CardBrand> {
+        BRAND_RANGES
+            .iter()
+            .filter(|(_, first, last, len)| {
+                number
+                    .get(..*len)
+                    .and_then(|prefix| prefix.parse::<u32>().ok())
+                    .is_some_and(|prefix| (*first..=*last).contains(&prefix))
+            })
+            .max_by_key(|(_, _, _, len)| *len)
+            .map(|(brand, _, _, _)| *brand)
+    }
+
+    fn is_valid_length(&self, len: usize) -> bool {
+        match self {
+            CardBrand::Amex => len == 15,
+            CardBrand::Mastercard => len == 16,
+            CardBrand::Visa => matches!(len, 13 | 16 | 19),
+            CardBrand::DinersClub => (14..=19).contains(&len),
+            CardBrand::Maestro => (12..=19).contains(&len),
+            CardBrand::Discover | CardBrand::Jcb | CardBrand::Mir | CardBrand::UnionPay => {
+                (16..=19).contains(&len)
+            }
+        }
+    }
+
+    /// The number of digits of the security code printed on cards of this brand
+    pub fn cvv_length(&self) -> usize {
+        match self {
+            CardBrand::Amex => 4,
+            _ => 3,
+        }
+    }
+}
+
+impl fmt::Display for CardBrand {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}", self.name())
+    }
+}
+
+impl Serialize for CardBrand {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+/// Why a card number was rejected by `validate_credit_card_with_brands`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CreditCardError {
+    /// The number is not a valid card number
+    Invalid,
+    /// The number is valid but its brand is not one of the accepted ones
+    BrandNotAccepted(CardBrand),
+}
+
+impl CreditCardError {
+    /// The error code used by the derive for this error
+    pub fn code(&self) -> &'static str {
+        match self {
+            CreditCardError::Invalid => "credit_card",
+            CreditCardError::BrandNotAccepted(_) => "credit_card_brand",
+        }
+    }
+}
+
+fn is_luhn_valid(number: &str) -> bool {
+    let sum: u32 = number
+        .bytes()
+        .rev()
+        .enumerate()
+        .map(|(i, b)| {
+            let digit = u32::from(b - b'0');
+            if i % 2 == 1 {
+                let doubled = digit * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                digit
+            }
+        })
+        .sum();
+
+    sum % 10 == 0
+}
+
+/// Validates card numbers: only digits, a known brand, a length valid for that brand and a
+/// correct Luhn checksum.
 pub trait ValidateCreditCard {
     fn validate_credit_card(&self) -> bool {
-        let card_string = self.as_credit_card_string();
-        CardValidate::from(&card_string).is_ok()
+        self.validate_credit_card_with_brands(&[]).is_ok()
+    }
+
+    /// Validates the card number and checks its brand is one of the given ones. An empty list
+    /// accepts every brand.
+    fn validate_credit_card_with_brands(
+        &self,
+        brands: &[CardBrand],
+    ) -> Result<(), CreditCardError> {
+        let Some(number) = self.as_credit_card_string() else {
+            return Ok(());
+        };
+
+        if !number.bytes().all(|b| b.is_ascii_digit()) || !is_luhn_valid(&number) {
+            return Err(CreditCardError::Invalid);
+        }
+
+        match CardBrand::detect(&number) {
+            Some(brand) if brand.is_valid_length(number.len()) => {
+                if brands.is_empty() || brands.contains(&brand) {
+                    Ok(())
+                } else {
+                    Err(CreditCardError::BrandNotAccepted(brand))
+                }
+            }
+            _ => Err(CreditCardError::Invalid),
+        }
+    }
+
+    /// The brand of the card number, detected from its first digits only
+    fn credit_card_brand(&self) -> Option<CardBrand> {
+        self.as_credit_card_string().and_then(|number| CardBrand::detect(&number))
+    }
+
+    fn as_credit_card_string(&self) -> Option<Cow<'_, str>>;
+}
+
+impl<T> ValidateCreditCard for &T
+where
+    T: ValidateCreditCard + ?Sized,
+{
+    fn as_credit_card_string(&self) -> Option<Cow<'_, str>> {
+        T::as_credit_card_string(self)
     }
+}
+
+impl<T> ValidateCreditCard for Option<T>
+where
+    T: ValidateCreditCard,
+{
+    fn as_credit_card_string(&self) -> Option<Cow<'_, str>> {
+        self.as_ref().and_then(T::as_credit_card_string)
+    }
+}
+
+macro_rules! validate_type_of_str {
+    ($type_:ty) => {
+        impl ValidateCreditCard for $type_ {
+            fn as_credit_card_string(&self) -> Option<Cow<'_, str>> {
+                Some(Cow::Borrowed(self.as_ref()))
+            }
+        }
+    };
+}
 
-    fn as_credit_card_string(&self) -> Cow<'_, str>;
+validate_type_of_str!(str);
+validate_type_of_str!(String);
+validate_type_of_str!(Cow<'_, str>);
+
+/// A month of a year, used for card expiry dates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearMonth {
+    pub year: u16,
+    pub month: u8,
 }
 
-impl<T: AsRef<str>> ValidateCreditCard for T {
-    fn as_credit_card_string(&self) -> Cow<'_, str> {
-        Cow::from(self.as_ref())
+/// Gives the current month to `validate_card_expiry`, so that it can be faked in tests
+pub trait Clock {
+    fn current_month(&self) -> YearMonth;
+}
+
+/// Always returns itself as the current month
+impl Clock for YearMonth {
+    fn current_month(&self) -> YearMonth {
+        *self
+    }
+}
+
+/// The current month in UTC, according to the system time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn current_month(&self) -> YearMonth {
+        let days =
+            SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs() / 86_400).unwrap_or(0)
+                as i64;
+
+        // Converts days since the epoch to a civil date, see
+        // https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+
+        YearMonth { year: year as u16, month: month as u8 }
+    }
+}
+
+/// Validates that a card expiry date written as `MM/YY` or `MM/YYYY` is not in the past.
+/// A card expiring this month is still valid.
+pub trait ValidateCardExpiry {
+    fn validate_card_expiry(&self, clock: &dyn Clock) -> bool {
+        let Some(expiry) = self.as_card_expiry_string() else {
+            return true;
+        };
+
+        let Some((month, year)) = expiry.split_once('/') else {
+            return false;
+        };
+        let (month, year) = (month.trim(), year.trim());
+        if month.len() != 2
+            || !matches!(year.len(), 2 | 4)
+            || !month.bytes().chain(year.bytes()).all(|b| b.is_ascii_digit())
+        {
+            return false;
+        }
+
+        let month = month.parse::<u8>().unwrap_or(0);
+        if !(1..=12).contains(&month) {
+            return false;
+        }
+        let year = year.parse::<u16>().unwrap_or(0);
+        let year = if year < 100 { 2000 + year } else { year };
+
+        YearMonth { year, month } >= clock.current_month()
+    }
+
+    fn as_card_expiry_string(&self) -> Option<Cow<'_, str>>;
+}
+
+impl<T: AsRef<str>> ValidateCardExpiry for T {
+    fn as_card_expiry_string(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::from(self.as_ref()))
+    }
+}
+
+/// Validates a card security code: 3 digits, or 4 for brands printing 4 digits like Amex.
+/// When the brand is unknown, both 3 and 4 digits are accepted.
+pub trait ValidateCardCvv {
+    fn validate_card_cvv(&self, brand: Option<CardBrand>) -> bool {
+        let Some(cvv) = self.as_card_cvv_string() else {
+            return true;
+        };
+
+        let valid_length = match brand {
+            Some(b) => cvv.len() == b.cvv_length(),
+            None => matches!(cvv.len(), 3 | 4),
+        };
+        valid_length && cvv.bytes().all(|b| b.is_ascii_digit())
+    }
+
+    fn as_card_cvv_string(&self) -> Option<Cow<'_, str>>;
+}
+
+impl<T: AsRef<str>> ValidateCardCvv for T {
+    fn as_card_cvv_string(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::from(self.as_ref()))
     }
 }
 
 #[cfg(test)]
-#[cfg(feature = "card")]
 mod tests {
-    use super::ValidateCreditCard;
+    use super::{
+        CardBrand, Clock, CreditCardError, SystemClock, ValidateCardCvv, ValidateCardExpiry,
+        ValidateCreditCard, YearMonth,
+    };
     use std::borrow::Cow;
 
     #[test]
@@ -49,4 +381,105 @@ mod tests {
         let test: Cow<'static, str> = String::from("5236313877109141").into();
         assert!(!test.validate_credit_card());
     }
+
+    #[test]
+    fn test_credit_card_brands() {
+        let tests = vec![
+            ("4111111111111111", Some(CardBrand::Visa)),
+            ("4222222222222", Some(CardBrand::Visa)),
+            ("5555555555554444", Some(CardBrand::Mastercard)),
+            ("2223003122003222", Some(CardBrand::Mastercard)),
+            ("378282246310005", Some(CardBrand::Amex)),
+            ("6011111111111117", Some(CardBrand::Discover)),
+            ("6221260000000000", Some(CardBrand::Discover)),
+            ("3056930009020004", Some(CardBrand::DinersClub)),
+            ("3566002020360505", Some(CardBrand::Jcb)),
+            ("6200000000000005", Some(CardBrand::UnionPay)),
+            ("2200000000000004", Some(CardBrand::Mir)),
+            ("6759649826438453", Some(CardBrand::Maestro)),
+            ("9999999999999995", None),
+            ("", None),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(CardBrand::detect(input), expected, "{}", input);
+            if expected.is_some() {
+                assert!(input.validate_credit_card(), "{}", input);
+            }
+        }
+    }
+
+    #[test]
+    fn test_credit_card_invalid_length_for_brand() {
+        // Valid Luhn checksums but lengths that don't exist for those brands
+        assert!(!"37828224631000".validate_credit_card());
+        assert!(!"41111111111111111".validate_credit_card());
+        // Unknown brand
+        assert!(!"9999999999999995".validate_credit_card());
+    }
+
+    #[test]
+    fn test_credit_card_with_brands() {
+        let accepted = [CardBrand::Visa, CardBrand::Mastercard];
+        assert_eq!("4111111111111111".validate_credit_card_with_brands(&accepted), Ok(()));
+        assert_eq!(
+            "378282246310005".validate_credit_card_with_brands(&accepted),
+            Err(CreditCardError::BrandNotAccepted(CardBrand::Amex))
+        );
+        assert_eq!(
+            "4111111111111112".validate_credit_card_with_brands(&accepted),
+            Err(CreditCardError::Invalid)
+        );
+        assert_eq!(None::<String>.validate_credit_card_with_brands(&accepted), Ok(()));
+    }
+
+    #[test]
+    fn test_card_brand_names() {
+        for brand in CardBrand::ALL {
+            assert_eq!(CardBrand::from_name(brand.name()), Some(brand));
+        }
+        assert_eq!(CardBrand::from_name("bob"), None);
+    }
+
+    #[test]
+    fn test_card_expiry() {
+        let now = YearMonth { year: 2026, month: 10 };
+        let tests = vec![
+            ("10/26", true),
+            ("11/26", true),
+            ("01/27", true),
+            ("12/2030", true),
+            ("09/26", false),
+            ("12/25", false),
+            ("13/27", false),
+            ("00/27", false),
+            ("1/27", false),
+            ("10-27", false),
+            ("ab/cd", false),
+            ("", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(input.validate_card_expiry(&now), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_system_clock() {
+        let now = SystemClock.current_month();
+        assert!(now.year >= 2024);
+        assert!((1..=12).contains(&now.month));
+    }
+
+    #[test]
+    fn test_card_cvv() {
+        assert!("123".validate_card_cvv(None));
+        assert!("1234".validate_card_cvv(None));
+        assert!(!"12".validate_card_cvv(None));
+        assert!(!"12a".validate_card_cvv(None));
+        assert!("123".validate_card_cvv(Some(CardBrand::Visa)));
+        assert!(!"1234".validate_card_cvv(Some(CardBrand::Visa)));
+        assert!("1234".validate_card_cvv(Some(CardBrand::Amex)));
+        assert!(!"123".validate_card_cvv(Some(CardBrand::Amex)));
+    }
 }
@@ -0,0 +1,197 @@
+use std::{
+    borrow::Cow,
+    fmt,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+};
+
+use crate::IpVersion;
+
+/// A network written in CIDR notation, eg `10.0.0.0/8` or `2001:db8::/32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IpCidr {
+    address: IpAddr,
+    prefix: u8,
+}
+
+impl IpCidr {
+    /// Creates a network from an address and a prefix length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the prefix is longer than the address (32 bits for IPv4, 128 for IPv6).
+    pub const fn new(address: IpAddr, prefix: u8) -> IpCidr {
+        let max = match address {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+        assert!(prefix <= max, "CIDR prefix is longer than the address");
+        IpCidr { address, prefix }
+    }
+
+    pub fn address(&self) -> IpAddr {
+        self.address
+    }
+
+    pub fn prefix(&self) -> u8 {
+        self.prefix
+    }
+
+    /// Whether the given address is part of this network. An IPv4 address is never part of an
+    /// IPv6 network and vice versa.
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        match (self.address, ip) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - self.prefix as u32).unwrap_or(0);
+                u32::from(network) & mask == u32::from(*ip) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - self.prefix as u32).unwrap_or(0);
+                u128::from(network) & mask == u128::from(*ip) & mask
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether some bits after the prefix are set in the address, like in `10.0.0.1/8`
+    pub fn has_host_bits(&self) -> bool {
+        match self.address {
+            IpAddr::V4(network) => {
+                u32::from(network) & u32::MAX.checked_shr(self.prefix as u32).unwrap_or(0) != 0
+            }
+            IpAddr::V6(network) => {
+                u128::from(network) & u128::MAX.checked_shr(self.prefix as u32).unwrap_or(0) != 0
+            }
+        }
+    }
+}
+
+impl fmt::Display for IpCidr {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}/{}", self.address, self.prefix)
+    }
+}
+
+/// The error returned when parsing a string that is not in CIDR notation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IpCidrParseError;
+
+impl fmt::Display for IpCidrParseError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "invalid CIDR notation")
+    }
+}
+
+impl std::error::Error for IpCidrParseError {}
+
+impl FromStr for IpCidr {
+    type Err = IpCidrParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (address, prefix) = s.split_once('/').ok_or(IpCidrParseError)?;
+        // `u8::from_str` accepts a leading `+`
+        if prefix.is_empty() || !prefix.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(IpCidrParseError);
+        }
+        let prefix = prefix.parse::<u8>().map_err(|_| IpCidrParseError)?;
+        let address = if let Ok(v4) = Ipv4Addr::from_str(address) {
+            IpAddr::V4(v4)
+        } else {
+            IpAddr::V6(Ipv6Addr::from_str(address).map_err(|_| IpCidrParseError)?)
+        };
+
+        let max = if address.is_ipv4() { 32 } else { 128 };
+        if prefix > max {
+            return Err(IpCidrParseError);
+        }
+
+        Ok(IpCidr { address, prefix })
+    }
+}
+
+/// Validates whether the given string is a network in CIDR notation.
+///
+/// If `strict` is set, the bits of the address after the prefix need to be zero: `10.0.0.0/8`
+/// is accepted but not `10.0.0.1/8`.
+pub trait ValidateCidr {
+    fn validate_cidr(&self, version: Option<IpVersion>, strict: bool) -> bool {
+        let Some(s) = self.as_cidr_string() else {
+            return true;
+        };
+
+        match IpCidr::from_str(&s) {
+            Ok(cidr) => {
+                version.is_none_or(|v| v.matches(&cidr.address()))
+                    && (!strict || !cidr.has_host_bits())
+            }
+            Err(_) => false,
+        }
+    }
+
+    fn as_cidr_string(&self) -> Option<Cow<'_, str>>;
+}
+
+impl<T: AsRef<str>> ValidateCidr for T {
+    fn as_cidr_string(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::from(self.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use super::{IpCidr, ValidateCidr};
+    use crate::IpVersion;
+
+    #[test]
+    fn test_parse_cidr() {
+        let cidr = "10.0.0.0/8".parse::<IpCidr>().unwrap();
+        assert_eq!(cidr.address(), "10.0.0.0".parse::<IpAddr>().unwrap());
+        assert_eq!(cidr.prefix(), 8);
+        assert_eq!(cidr.to_string(), "10.0.0.0/8");
+        assert_eq!("2001:db8::/32".parse::<IpCidr>().unwrap().prefix(), 32);
+
+        for invalid in ["10.0.0.0", "10.0.0.0/33", "::/129", "10.0.0/8", "10.0.0.0/", "10.0.0.0/+8"]
+        {
+            assert!(invalid.parse::<IpCidr>().is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_cidr_contains() {
+        let cidr = "172.16.0.0/12".parse::<IpCidr>().unwrap();
+        assert!(cidr.contains(&"172.31.255.255".parse().unwrap()));
+        assert!(!cidr.contains(&"172.32.0.0".parse().unwrap()));
+        assert!(!cidr.contains(&"::1".parse().unwrap()));
+
+        let all = "0.0.0.0/0".parse::<IpCidr>().unwrap();
+        assert!(all.contains(&"8.8.8.8".parse().unwrap()));
+
+        let single = IpCidr::new("::1".parse().unwrap(), 128);
+        assert!(single.contains(&"::1".parse().unwrap()));
+        assert!(!single.contains(&"::2".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_validate_cidr() {
+        let tests = vec![
+            ("10.0.0.0/8", None, false, true),
+            ("10.0.0.1/8", None, false, true),
+            ("10.0.0.1/8", None, true, false),
+            ("10.0.0.1/32", None, true, true),
+            ("0.0.0.0/0", None, true, true),
+            ("2001:db8::/32", None, true, true),
+            ("2001:db8::1/32", None, true, false),
+            ("2001:db8::/32", Some(IpVersion::V4), false, false),
+            ("10.0.0.0/8", Some(IpVersion::V4), false, true),
+            ("10.0.0.0/8", Some(IpVersion::V6), false, false),
+            ("10.0.0.0", None, false, false),
+            ("bob", None, false, false),
+        ];
+
+        for (input, version, strict, expected) in tests {
+            assert_eq!(input.validate_cidr(version, strict), expected, "{}", input);
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use std::{borrow::Cow, rc::Rc, sync::Arc};
+
+mod tables;
+
+use tables::CASE_FOLDING;
+
+#[cfg(feature = "unicode_normalization")]
+use crate::NormalizationForm;
+
+/// How strings are compared by `must_match`, `contains` and `does_not_contain` when they are
+/// given the `normalize` or `case_insensitive` options.
+///
+/// Both strings are normalized first, so that the same text written in NFC and NFD compares equal,
+/// and then case folded.
+///
+/// It is built with `TextComparison::default()` and its builder methods: the fields are private
+/// since the normalization only exists with the `unicode_normalization` feature.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextComparison {
+    #[cfg(feature = "unicode_normalization")]
+    normalization: Option<NormalizationForm>,
+    case_insensitive: bool,
+}
+
+impl TextComparison {
+    #[cfg(feature = "unicode_normalization")]
+    pub fn normalization(mut self, form: NormalizationForm) -> Self {
+        self.normalization = Some(form);
+        self
+    }
+
+    pub fn case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = case_insensitive;
+        self
+    }
+
+    #[cfg(feature = "unicode_normalization")]
+    pub fn normalization_form(&self) -> Option<NormalizationForm> {
+        self.normalization
+    }
+
+    pub fn is_case_insensitive(&self) -> bool {
+        self.case_insensitive
+    }
+
+    /// Returns the string as it is compared, borrowing it if it didn't change
+    pub fn prepare<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        let s = Cow::Borrowed(s);
+
+        #[cfg(feature = "unicode_normalization")]
+        let s = match self.normalization {
+            Some(form) => match form.normalize(&s) {
+                Cow::Borrowed(_) => s,
+                Cow::Owned(normalized) => Cow::Owned(normalized),
+            },
+            None => s,
+        };
+
+        if !self.case_insensitive {
+            return s;
+        }
+
+        let folded = case_fold(&s);
+
+        // Case folding can un-normalize a few strings
+        #[cfg(feature = "unicode_normalization")]
+        let folded = match self.normalization {
+            Some(form) => form.normalize(&folded).into_owned(),
+            None => folded,
+        };
+
+        Cow::Owned(folded)
+    }
+}
+
+/// Folds the case of the string with the full case folding of Unicode 15.0, so that `ß` and
+/// `SS` or `µ` and `Μ` are equal
+fn case_fold(s: &str) -> String {
+    let mut folded = String::with_capacity(s.len());
+    for c in s.chars() {
+        match CASE_FOLDING.binary_search_by_key(&c, |(c, _)| *c) {
+            Ok(i) => folded.push_str(CASE_FOLDING[i].1),
+            Err(_) => folded.push(c),
+        }
+    }
+    folded
+}
+
+/// Gives the string compared with a [`TextComparison`], `None` being always valid
+pub trait AsComparisonStr {
+    fn as_comparison_str(&self) -> Option<&str>;
+}
+
+macro_rules! comparison_str_that_derefs {
+    ($type_:ty) => {
+        impl<T> AsComparisonStr for $type_
+        where
+            T: AsComparisonStr + ?Sized,
+        {
+            fn as_comparison_str(&self) -> Option<&str> {
+                T::as_comparison_str(self)
+            }
+        }
+    };
+}
+
+comparison_str_that_derefs!(&T);
+comparison_str_that_derefs!(Arc<T>);
+comparison_str_that_derefs!(Box<T>);
+comparison_str_that_derefs!(Rc<T>);
+
+impl AsComparisonStr for str {
+    fn as_comparison_str(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl AsComparisonStr for String {
+    fn as_comparison_str(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl AsComparisonStr for Cow<'_, str> {
+    fn as_comparison_str(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl<T: AsComparisonStr> AsComparisonStr for Option<T> {
+    fn as_comparison_str(&self) -> Option<&str> {
+        self.as_ref().and_then(T::as_comparison_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TextComparison, CASE_FOLDING};
+
+    #[test]
+    fn test_case_insensitive_comparison() {
+        let comparison = TextComparison::default().case_insensitive(true);
+        assert_eq!(comparison.prepare("Hello"), "hello");
+        assert_eq!(comparison.prepare("STRASSE"), comparison.prepare("straße"));
+        assert_eq!(comparison.prepare("ΣΟΦΟΣ"), comparison.prepare("σοφος"));
+        assert_eq!(comparison.prepare("ﬁne"), "fine");
+        assert_eq!(comparison.prepare("\u{B5}"), comparison.prepare("μ"));
+        assert_eq!(comparison.prepare("ϐϑϕϖϰϱϵ"), comparison.prepare("βθφπκρε"));
+        assert_eq!(comparison.prepare("ẛ"), comparison.prepare("ṡ"));
+        assert_eq!(comparison.prepare("\u{1FBE}"), comparison.prepare("ι"));
+        assert_eq!(comparison.prepare("ꭰꮃꮎ"), comparison.prepare("ᎠᎳᎾ"));
+    }
+
+    #[test]
+    fn test_case_folding_table_is_sorted() {
+        for window in CASE_FOLDING.windows(2) {
+            assert!(window[0].0 < window[1].0, "{:X?} {:X?}", window[0], window[1]);
+        }
+    }
+
+    #[test]
+    fn test_default_comparison_borrows() {
+        let comparison = TextComparison::default();
+        assert!(matches!(comparison.prepare("Hello"), std::borrow::Cow::Borrowed("Hello")));
+    }
+
+    #[cfg(feature = "unicode_normalization")]
+    #[test]
+    fn test_normalized_comparison() {
+        use crate::NormalizationForm;
+
+        let comparison = TextComparison::default().normalization(NormalizationForm::Nfc);
+        assert_eq!(comparison.prepare("cafe\u{0301}"), comparison.prepare("caf\u{00E9}"));
+        assert_ne!(comparison.prepare("Café"), comparison.prepare("café"));
+
+        let comparison =
+            TextComparison::default().normalization(NormalizationForm::Nfkc).case_insensitive(true);
+        assert_eq!(comparison.normalization_form(), Some(NormalizationForm::Nfkc));
+        assert!(comparison.is_case_insensitive());
+        assert_eq!(comparison.prepare("ＣＡＦＥ\u{0301}"), comparison.prepare("caf\u{00E9}"));
+    }
+}
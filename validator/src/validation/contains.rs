@@ -1,9 +1,17 @@
-use std::borrow::Cow;
-use std::collections::HashMap;
-use std::hash::BuildHasher;
+use std::borrow::{Borrow, Cow};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::hash::{BuildHasher, Hash};
 
-pub trait ValidateContains {
-    fn validate_contains(&self, needle: &str) -> bool;
+use indexmap::{Equivalent, IndexMap, IndexSet};
+
+use crate::{AsComparisonStr, TextComparison};
+
+/// Validates that a value contains the needle: a substring for strings, an element for slices
+/// and sets, or a key for maps.
+///
+/// The needle is a `str` by default but can be any type the elements can be compared with.
+pub trait ValidateContains<N: ?Sized = str> {
+    fn validate_contains(&self, needle: &N) -> bool;
 }
 
 impl ValidateContains for String {
@@ -12,11 +20,18 @@ impl ValidateContains for String {
     }
 }
 
-impl<T> ValidateContains for Option<T>
+impl ValidateContains for str {
+    fn validate_contains(&self, needle: &str) -> bool {
+        self.contains(needle)
+    }
+}
+
+impl<T, N> ValidateContains<N> for Option<T>
 where
-    T: ValidateContains,
+    T: ValidateContains<N>,
+    N: ?Sized,
 {
-    fn validate_contains(&self, needle: &str) -> bool {
+    fn validate_contains(&self, needle: &N) -> bool {
         if let Some(v) = self {
             v.validate_contains(needle)
         } else {
@@ -25,37 +40,134 @@ where
     }
 }
 
-impl<T> ValidateContains for &T
+impl<T, N> ValidateContains<N> for &T
 where
-    T: ValidateContains,
+    T: ValidateContains<N> + ?Sized,
+    N: ?Sized,
 {
-    fn validate_contains(&self, needle: &str) -> bool {
+    fn validate_contains(&self, needle: &N) -> bool {
         T::validate_contains(self, needle)
     }
 }
 
-impl<T> ValidateContains for Cow<'_, T>
+impl<T, N> ValidateContains<N> for Cow<'_, T>
 where
-    T: ToOwned + ?Sized,
-    for<'a> &'a T: ValidateContains,
+    T: ToOwned + ValidateContains<N> + ?Sized,
+    N: ?Sized,
 {
-    fn validate_contains(&self, needle: &str) -> bool {
+    fn validate_contains(&self, needle: &N) -> bool {
         self.as_ref().validate_contains(needle)
     }
 }
 
-impl ValidateContains for &str {
-    fn validate_contains(&self, needle: &str) -> bool {
+impl<T, N> ValidateContains<N> for [T]
+where
+    T: PartialEq<N>,
+    N: ?Sized,
+{
+    fn validate_contains(&self, needle: &N) -> bool {
+        self.iter().any(|v| v == needle)
+    }
+}
+
+impl<T, N, const LEN: usize> ValidateContains<N> for [T; LEN]
+where
+    T: PartialEq<N>,
+    N: ?Sized,
+{
+    fn validate_contains(&self, needle: &N) -> bool {
+        self.iter().any(|v| v == needle)
+    }
+}
+
+impl<T, N> ValidateContains<N> for Vec<T>
+where
+    T: PartialEq<N>,
+    N: ?Sized,
+{
+    fn validate_contains(&self, needle: &N) -> bool {
+        self.iter().any(|v| v == needle)
+    }
+}
+
+impl<T, N, H> ValidateContains<N> for HashSet<T, H>
+where
+    T: Borrow<N> + Hash + Eq,
+    N: Hash + Eq + ?Sized,
+    H: BuildHasher,
+{
+    fn validate_contains(&self, needle: &N) -> bool {
         self.contains(needle)
     }
 }
 
-impl<S, H: BuildHasher> ValidateContains for HashMap<String, S, H> {
-    fn validate_contains(&self, needle: &str) -> bool {
+impl<K, V, N, H> ValidateContains<N> for HashMap<K, V, H>
+where
+    K: Borrow<N> + Hash + Eq,
+    N: Hash + Eq + ?Sized,
+    H: BuildHasher,
+{
+    fn validate_contains(&self, needle: &N) -> bool {
+        self.contains_key(needle)
+    }
+}
+
+impl<T, N> ValidateContains<N> for BTreeSet<T>
+where
+    T: Borrow<N> + Ord,
+    N: Ord + ?Sized,
+{
+    fn validate_contains(&self, needle: &N) -> bool {
+        self.contains(needle)
+    }
+}
+
+impl<K, V, N> ValidateContains<N> for BTreeMap<K, V>
+where
+    K: Borrow<N> + Ord,
+    N: Ord + ?Sized,
+{
+    fn validate_contains(&self, needle: &N) -> bool {
         self.contains_key(needle)
     }
 }
 
+impl<T, N, H> ValidateContains<N> for IndexSet<T, H>
+where
+    T: Hash + Eq,
+    N: Hash + Equivalent<T> + ?Sized,
+    H: BuildHasher,
+{
+    fn validate_contains(&self, needle: &N) -> bool {
+        self.contains(needle)
+    }
+}
+
+impl<K, V, N, H> ValidateContains<N> for IndexMap<K, V, H>
+where
+    K: Hash + Eq,
+    N: Hash + Equivalent<K> + ?Sized,
+    H: BuildHasher,
+{
+    fn validate_contains(&self, needle: &N) -> bool {
+        self.contains_key(needle)
+    }
+}
+
+/// Validates that the string contains the needle once both are prepared by the comparison,
+/// for example ignoring their case.
+#[must_use]
+pub fn validate_contains_with<T>(haystack: &T, needle: &str, comparison: &TextComparison) -> bool
+where
+    T: AsComparisonStr + ?Sized,
+{
+    if let Some(haystack) = haystack.as_comparison_str() {
+        comparison.prepare(haystack).contains(comparison.prepare(needle).as_ref())
+    } else {
+        true
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,4 +211,45 @@ mod tests {
         let test: Cow<'static, str> = String::from("hey").into();
         assert!(!test.validate_contains("o"));
     }
+
+    #[test]
+    fn test_validate_contains_with_case_insensitive() {
+        let comparison = TextComparison::default().case_insensitive(true);
+        assert!(validate_contains_with("Hey", "EY", &comparison));
+        assert!(validate_contains_with("Straße", "SS", &comparison));
+        assert!(!validate_contains_with("Hey", "o", &comparison));
+        assert!(validate_contains_with(&None::<String>, "o", &comparison));
+    }
+
+    #[test]
+    fn test_validate_contains_collections() {
+        let roles = vec!["admin".to_string(), "user".to_string()];
+        assert!(roles.validate_contains("user"));
+        assert!(!roles.validate_contains("guest"));
+        assert!(roles[..].validate_contains("admin"));
+
+        let ports: Vec<u16> = vec![80, 443];
+        assert!(ports.validate_contains(&443));
+        assert!(!ports.validate_contains(&8080));
+        assert!([1, 2, 3].validate_contains(&2));
+
+        let tags = HashSet::from(["internal".to_string()]);
+        assert!(tags.validate_contains("internal"));
+        let tags = BTreeSet::from([1, 2]);
+        assert!(tags.validate_contains(&1));
+        assert!(!tags.validate_contains(&3));
+
+        let map = BTreeMap::from([("hey".to_string(), 1)]);
+        assert!(map.validate_contains("hey"));
+        assert!(!map.validate_contains("bob"));
+    }
+
+    #[test]
+    fn test_validate_contains_indexmap() {
+        let set = IndexSet::from(["a".to_string(), "b".to_string()]);
+        assert!(set.validate_contains("a"));
+        assert!(!set.validate_contains("c"));
+        let map = IndexMap::from([(443u16, "https")]);
+        assert!(map.validate_contains(&443));
+    }
 }
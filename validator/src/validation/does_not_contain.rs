@@ -1,18 +1,37 @@
-use crate::ValidateContains;
+use crate::validation::contains::validate_contains_with;
+use crate::{AsComparisonStr, TextComparison, ValidateContains};
 
-pub trait ValidateDoesNotContain {
-    fn validate_does_not_contain(&self, needle: &str) -> bool;
+/// The opposite of [`ValidateContains`], implemented for all the types implementing it
+pub trait ValidateDoesNotContain<N: ?Sized = str> {
+    fn validate_does_not_contain(&self, needle: &N) -> bool;
 }
 
-impl<T> ValidateDoesNotContain for T
+impl<T, N> ValidateDoesNotContain<N> for T
 where
-    T: ValidateContains,
+    T: ValidateContains<N> + ?Sized,
+    N: ?Sized,
 {
-    fn validate_does_not_contain(&self, needle: &str) -> bool {
+    fn validate_does_not_contain(&self, needle: &N) -> bool {
         !self.validate_contains(needle)
     }
 }
 
+/// Validates that the string doesn't contain the needle once both are prepared by the
+/// comparison, for example ignoring their case.
+#[must_use]
+pub fn validate_does_not_contain_with<T>(
+    haystack: &T,
+    needle: &str,
+    comparison: &TextComparison,
+) -> bool
+where
+    T: AsComparisonStr + ?Sized,
+{
+    haystack
+        .as_comparison_str()
+        .is_none_or(|_| !validate_contains_with(haystack, needle, comparison))
+}
+
 #[cfg(test)]
 mod tests {
     use std::borrow::Cow;
@@ -59,4 +78,12 @@ mod tests {
         let test: Cow<'static, str> = String::from("hey").into();
         assert!(!test.validate_does_not_contain("e"));
     }
+
+    #[test]
+    fn test_validate_does_not_contain_with_case_insensitive() {
+        let comparison = TextComparison::default().case_insensitive(true);
+        assert!(validate_does_not_contain_with("Hey", "o", &comparison));
+        assert!(!validate_does_not_contain_with("Hey", "EY", &comparison));
+        assert!(validate_does_not_contain_with(&None::<String>, "EY", &comparison));
+    }
 }
@@ -1,36 +1,290 @@
-use std::net::IpAddr;
+use std::borrow::Cow;
+use std::net::{AddrParseError, IpAddr, Ipv4Addr, Ipv6Addr};
+use std::rc::Rc;
 use std::str::FromStr;
+use std::sync::Arc;
 
-pub trait ValidateIp {
-    /// Validates whether the given string is an IP V4
-    fn validate_ipv4(&self) -> bool;
-    /// Validates whether the given string is an IP V6
-    fn validate_ipv6(&self) -> bool;
-    /// Validates whether the given string is an IP
-    fn validate_ip(&self) -> bool;
+use crate::IpCidr;
+
+fn ipv4_in_network(ip: &Ipv4Addr, network: [u8; 4], prefix: u32) -> bool {
+    let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+    u32::from(*ip) & mask == u32::from(Ipv4Addr::from(network)) & mask
 }
 
-impl<T> ValidateIp for T
-where
-    T: ToString,
-{
+fn ipv6_in_network(ip: &Ipv6Addr, network: [u16; 8], prefix: u32) -> bool {
+    let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+    u128::from(*ip) & mask == u128::from(Ipv6Addr::from(network)) & mask
+}
+
+/// Whether the address can be reached on the public internet, ie it is not in one of the
+/// private, loopback, link-local, multicast, CGNAT, documentation or otherwise reserved ranges.
+pub(crate) fn is_public_ipv4(ip: &Ipv4Addr) -> bool {
+    const NON_PUBLIC: [([u8; 4], u32); 15] = [
+        ([0, 0, 0, 0], 8),
+        ([10, 0, 0, 0], 8),
+        ([100, 64, 0, 0], 10),
+        ([127, 0, 0, 0], 8),
+        ([169, 254, 0, 0], 16),
+        ([172, 16, 0, 0], 12),
+        ([192, 0, 0, 0], 24),
+        ([192, 0, 2, 0], 24),
+        ([192, 88, 99, 0], 24),
+        ([192, 168, 0, 0], 16),
+        ([198, 18, 0, 0], 15),
+        ([198, 51, 100, 0], 24),
+        ([203, 0, 113, 0], 24),
+        ([224, 0, 0, 0], 4),
+        ([240, 0, 0, 0], 4),
+    ];
+
+    !NON_PUBLIC.iter().any(|(network, prefix)| ipv4_in_network(ip, *network, *prefix))
+}
+
+/// Same as [`is_public_ipv4`] for IPv6. Addresses embedding an IPv4 address (mapped,
+/// compatible, NAT64 and 6to4) are public only if the embedded address is. Teredo and local-use
+/// NAT64 addresses, which can reach any IPv4 address, are never public.
+pub(crate) fn is_public_ipv6(ip: &Ipv6Addr) -> bool {
+    const NON_PUBLIC: [([u16; 8], u32); 9] = [
+        ([0, 0, 0, 0, 0, 0, 0, 0], 127),
+        ([0x64, 0xff9b, 1, 0, 0, 0, 0, 0], 48),
+        ([0x100, 0, 0, 0, 0, 0, 0, 0], 64),
+        ([0x2001, 0, 0, 0, 0, 0, 0, 0], 32),
+        ([0x2001, 0xdb8, 0, 0, 0, 0, 0, 0], 32),
+        ([0xfc00, 0, 0, 0, 0, 0, 0, 0], 7),
+        ([0xfe80, 0, 0, 0, 0, 0, 0, 0], 10),
+        ([0xfec0, 0, 0, 0, 0, 0, 0, 0], 10),
+        ([0xff00, 0, 0, 0, 0, 0, 0, 0], 8),
+    ];
+
+    if let Some(v4) = embedded_ipv4(ip) {
+        return is_public_ipv4(&v4);
+    }
+
+    !NON_PUBLIC.iter().any(|(network, prefix)| ipv6_in_network(ip, *network, *prefix))
+}
+
+pub(crate) fn is_public_ip(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => is_public_ipv4(v4),
+        IpAddr::V6(v6) => is_public_ipv6(v6),
+    }
+}
+
+/// Returns the IPv4 address embedded in an IPv4-mapped (`::ffff:0:0/96`), IPv4-compatible
+/// (`::/96`, except `::` and `::1`), NAT64 (`64:ff9b::/96`) or 6to4 (`2002::/16`) address.
+fn embedded_ipv4(ip: &Ipv6Addr) -> Option<Ipv4Addr> {
+    let [a, b, c, d, e, f, _, _] = ip.segments();
+    // 6to4 addresses have the IPv4 address right after their prefix
+    if a == 0x2002 {
+        return Some(Ipv4Addr::from((u32::from(b) << 16) | u32::from(c)));
+    }
+    let embedded = match (a, b, c, d, e, f) {
+        (0, 0, 0, 0, 0, 0xffff) | (0x64, 0xff9b, 0, 0, 0, 0) => true,
+        (0, 0, 0, 0, 0, 0) => !ip.is_unspecified() && !ip.is_loopback(),
+        _ => false,
+    };
+    embedded.then(|| Ipv4Addr::from((u128::from(*ip) & 0xffff_ffff) as u32))
+}
+
+/// Parses an IPv4 address the way browsers and `inet_aton` do, accepting shorthand
+/// (`127.1`), decimal (`2130706433`), octal (`0177.0.0.1`) and hexadecimal (`0x7f.0.0.1`) forms.
+pub(crate) fn parse_ipv4_lenient(s: &str) -> Option<Ipv4Addr> {
+    let s = s.strip_suffix('.').unwrap_or(s);
+    let parts = s.split('.').collect::<Vec<_>>();
+    if parts.is_empty() || parts.len() > 4 {
+        return None;
+    }
+
+    let mut numbers = Vec::with_capacity(parts.len());
+    for part in &parts {
+        let (digits, radix) =
+            if let Some(hex) = part.strip_prefix("0x").or_else(|| part.strip_prefix("0X")) {
+                (hex, 16)
+            } else if part.len() > 1 && part.starts_with('0') {
+                (&part[1..], 8)
+            } else {
+                (*part, 10)
+            };
+        if digits.is_empty() && radix != 16 {
+            return None;
+        }
+        if !digits.chars().all(|c| c.is_digit(radix)) {
+            return None;
+        }
+        // `0x` alone is 0
+        let n = if digits.is_empty() { 0 } else { u64::from_str_radix(digits, radix).ok()? };
+        numbers.push(n);
+    }
+
+    let (last, init) = numbers.split_last()?;
+    if init.iter().any(|n| *n > 255) || *last >= 256u64.pow(5 - numbers.len() as u32) {
+        return None;
+    }
+
+    let mut addr = *last;
+    for (i, n) in init.iter().enumerate() {
+        addr += n << (8 * (3 - i));
+    }
+    Some(Ipv4Addr::from(addr as u32))
+}
+
+/// The IP version a value is restricted to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IpVersion {
+    V4,
+    V6,
+}
+
+impl IpVersion {
+    pub(crate) fn matches(&self, ip: &IpAddr) -> bool {
+        match self {
+            IpVersion::V4 => ip.is_ipv4(),
+            IpVersion::V6 => ip.is_ipv6(),
+        }
+    }
+}
+
+/// The network rules checked by `validate_ip_with_rules`, an address needs to respect all of them.
+///
+/// IPv4-mapped IPv6 addresses (`::ffff:10.0.0.1`) are checked as the IPv4 address they map to for
+/// every rule except `version`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IpRules<'a> {
+    pub version: Option<IpVersion>,
+    /// The address needs to be publicly routable
+    pub public: bool,
+    /// The address needs to be in a private range: `10.0.0.0/8`, `172.16.0.0/12`,
+    /// `192.168.0.0/16` or `fc00::/7`
+    pub private: bool,
+    pub not_loopback: bool,
+    /// The address needs to be in at least one of those networks
+    pub in_cidr: Option<&'a [IpCidr]>,
+    /// The address must not be in any of those networks
+    pub not_in_cidr: &'a [IpCidr],
+}
+
+fn is_private_ip(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => v4.is_private(),
+        IpAddr::V6(v6) => ipv6_in_network(v6, [0xfc00, 0, 0, 0, 0, 0, 0, 0], 7),
+    }
+}
+
+pub trait ValidateIp {
+    /// Validates whether the given string is an IP V4
     fn validate_ipv4(&self) -> bool {
-        IpAddr::from_str(&self.to_string()).is_ok_and(|i| i.is_ipv4())
+        self.as_ip_addr().is_none_or(|ip| ip.is_ok_and(|i| i.is_ipv4()))
     }
 
+    /// Validates whether the given string is an IP V6
     fn validate_ipv6(&self) -> bool {
-        IpAddr::from_str(&self.to_string()).is_ok_and(|i| i.is_ipv6())
+        self.as_ip_addr().is_none_or(|ip| ip.is_ok_and(|i| i.is_ipv6()))
     }
 
+    /// Validates whether the given string is an IP
     fn validate_ip(&self) -> bool {
-        IpAddr::from_str(&self.to_string()).is_ok()
+        self.as_ip_addr().is_none_or(|ip| ip.is_ok())
+    }
+
+    /// Validates whether the given value is an IP respecting all the given rules
+    fn validate_ip_with_rules(&self, rules: &IpRules<'_>) -> bool {
+        let Some(ip) = self.as_ip_addr() else {
+            return true;
+        };
+        let Ok(ip) = ip else {
+            return false;
+        };
+
+        if rules.version.is_some_and(|v| !v.matches(&ip)) {
+            return false;
+        }
+        if rules.public && !is_public_ip(&ip) {
+            return false;
+        }
+
+        let ip = ip.to_canonical();
+        if rules.private && !is_private_ip(&ip) {
+            return false;
+        }
+        if rules.not_loopback && ip.is_loopback() {
+            return false;
+        }
+        if rules.in_cidr.is_some_and(|cidrs| !cidrs.iter().any(|c| c.contains(&ip))) {
+            return false;
+        }
+        if rules.not_in_cidr.iter().any(|c| c.contains(&ip)) {
+            return false;
+        }
+
+        true
+    }
+
+    /// The address to validate, `None` if there is no value to validate and an error if the
+    /// value is not an IP address
+    fn as_ip_addr(&self) -> Option<Result<IpAddr, AddrParseError>>;
+}
+
+macro_rules! validate_type_that_derefs {
+    ($type_:ty) => {
+        impl<T> ValidateIp for $type_
+        where
+            T: ValidateIp + ?Sized,
+        {
+            fn as_ip_addr(&self) -> Option<Result<IpAddr, AddrParseError>> {
+                T::as_ip_addr(self)
+            }
+        }
+    };
+}
+
+validate_type_that_derefs!(&T);
+validate_type_that_derefs!(Arc<T>);
+validate_type_that_derefs!(Box<T>);
+validate_type_that_derefs!(Rc<T>);
+
+macro_rules! validate_type_of_str {
+    ($type_:ty) => {
+        impl ValidateIp for $type_ {
+            fn as_ip_addr(&self) -> Option<Result<IpAddr, AddrParseError>> {
+                Some(IpAddr::from_str(self))
+            }
+        }
+    };
+}
+
+validate_type_of_str!(str);
+validate_type_of_str!(String);
+validate_type_of_str!(Cow<'_, str>);
+
+macro_rules! validate_type_of_ip {
+    ($type_:ty) => {
+        impl ValidateIp for $type_ {
+            fn as_ip_addr(&self) -> Option<Result<IpAddr, AddrParseError>> {
+                Some(Ok(IpAddr::from(*self)))
+            }
+        }
+    };
+}
+
+validate_type_of_ip!(IpAddr);
+validate_type_of_ip!(Ipv4Addr);
+validate_type_of_ip!(Ipv6Addr);
+
+impl<T> ValidateIp for Option<T>
+where
+    T: ValidateIp,
+{
+    fn as_ip_addr(&self) -> Option<Result<IpAddr, AddrParseError>> {
+        self.as_ref().and_then(T::as_ip_addr)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::ValidateIp;
+    use super::{is_public_ip, parse_ipv4_lenient, IpRules, IpVersion, ValidateIp};
+    use crate::IpCidr;
     use std::borrow::Cow;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
     #[test]
     fn test_validate_ip() {
@@ -135,4 +389,114 @@ mod tests {
         let test: Cow<'static, str> = String::from("::ffff:zzzz:0a0a").into();
         assert!(!test.validate_ipv6());
     }
+
+    #[test]
+    fn test_parse_ipv4_lenient() {
+        let tests = vec![
+            ("127.0.0.1", Some(Ipv4Addr::new(127, 0, 0, 1))),
+            ("127.1", Some(Ipv4Addr::new(127, 0, 0, 1))),
+            ("2130706433", Some(Ipv4Addr::new(127, 0, 0, 1))),
+            ("0177.0.0.1", Some(Ipv4Addr::new(127, 0, 0, 1))),
+            ("0x7f.0.0.1", Some(Ipv4Addr::new(127, 0, 0, 1))),
+            ("0x7F000001", Some(Ipv4Addr::new(127, 0, 0, 1))),
+            ("10.0.0.1.", Some(Ipv4Addr::new(10, 0, 0, 1))),
+            ("1.2.3.4.5", None),
+            ("256.0.0.1", None),
+            ("1.2.65536", None),
+            ("08.0.0.1", None),
+            ("example.com", None),
+            ("", None),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(parse_ipv4_lenient(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_is_public_ip() {
+        let tests = vec![
+            ("8.8.8.8", true),
+            ("93.184.216.34", true),
+            ("2606:4700:4700::1111", true),
+            ("10.0.0.1", false),
+            ("100.127.255.255", false),
+            ("127.0.0.1", false),
+            ("169.254.169.254", false),
+            ("172.31.0.1", false),
+            ("192.168.0.1", false),
+            ("198.51.100.7", false),
+            ("203.0.113.1", false),
+            ("239.255.255.250", false),
+            ("255.255.255.255", false),
+            ("::", false),
+            ("::1", false),
+            ("::ffff:127.0.0.1", false),
+            ("::ffff:8.8.8.8", true),
+            ("fc00::1", false),
+            ("fe80::1", false),
+            ("ff02::1", false),
+            ("2001:db8::1", false),
+            ("2002:808:808::1", true),
+            ("2002:7f00:1::1", false),
+            ("2002:a9fe:a9fe::", false),
+            ("2001:0:4136:e378:8000:63bf:3fff:fdd2", false),
+            ("64:ff9b::808:808", true),
+            ("64:ff9b:1::a00:1", false),
+            ("64:ff9b:1::808:808", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(is_public_ip(&input.parse::<IpAddr>().unwrap()), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_ip_native_types() {
+        assert!(Ipv4Addr::new(1, 1, 1, 1).validate_ipv4());
+        assert!(!Ipv4Addr::new(1, 1, 1, 1).validate_ipv6());
+        assert!(Ipv6Addr::LOCALHOST.validate_ipv6());
+        assert!(IpAddr::from(Ipv6Addr::LOCALHOST).validate_ip());
+        assert!(None::<IpAddr>.validate_ipv4());
+        assert!(!Some("1.1.1").validate_ip());
+    }
+
+    #[test]
+    fn test_validate_ip_with_rules() {
+        let public = IpRules { public: true, ..IpRules::default() };
+        assert!("8.8.8.8".validate_ip_with_rules(&public));
+        assert!(!"10.0.0.1".validate_ip_with_rules(&public));
+        assert!(!"not an ip".validate_ip_with_rules(&public));
+
+        let private = IpRules { private: true, ..IpRules::default() };
+        assert!(Ipv4Addr::new(192, 168, 1, 1).validate_ip_with_rules(&private));
+        assert!("fd12::1".validate_ip_with_rules(&private));
+        assert!("::ffff:10.0.0.1".validate_ip_with_rules(&private));
+        assert!(!"127.0.0.1".validate_ip_with_rules(&private));
+        assert!(!"8.8.8.8".validate_ip_with_rules(&private));
+
+        let not_loopback = IpRules { not_loopback: true, ..IpRules::default() };
+        assert!("10.0.0.1".validate_ip_with_rules(&not_loopback));
+        assert!(!"127.0.0.2".validate_ip_with_rules(&not_loopback));
+        assert!(!Ipv6Addr::LOCALHOST.validate_ip_with_rules(&not_loopback));
+        assert!(!"::ffff:127.0.0.1".validate_ip_with_rules(&not_loopback));
+
+        let v6 = IpRules { version: Some(IpVersion::V6), ..IpRules::default() };
+        assert!("::1".validate_ip_with_rules(&v6));
+        assert!(!"127.0.0.1".validate_ip_with_rules(&v6));
+    }
+
+    #[test]
+    fn test_validate_ip_with_cidr_rules() {
+        let cidrs = ["10.0.0.0/8".parse::<IpCidr>().unwrap(), "2001:db8::/32".parse().unwrap()];
+        let excluded = ["10.1.0.0/16".parse::<IpCidr>().unwrap()];
+        let rules = IpRules { in_cidr: Some(&cidrs), not_in_cidr: &excluded, ..IpRules::default() };
+
+        assert!("10.0.0.1".validate_ip_with_rules(&rules));
+        assert!("::ffff:10.2.0.1".validate_ip_with_rules(&rules));
+        assert!("2001:db8::1".validate_ip_with_rules(&rules));
+        assert!(!"10.1.2.3".validate_ip_with_rules(&rules));
+        assert!(!"11.0.0.1".validate_ip_with_rules(&rules));
+        assert!(!"2001:db9::1".validate_ip_with_rules(&rules));
+    }
 }
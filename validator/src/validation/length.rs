@@ -6,7 +6,6 @@ use std::{
     sync::Arc,
 };
 
-#[cfg(feature = "indexmap")]
 use indexmap::{IndexMap, IndexSet};
 
 /// Validates the length of the value given.
@@ -97,9 +96,7 @@ validate_type_with_len!(HashSet<T, S>, T, S);
 validate_type_with_len!(HashMap<K, V, S>, K, V, S);
 validate_type_with_len!(Vec<T>, T);
 validate_type_with_len!(VecDeque<T>, T);
-#[cfg(feature = "indexmap")]
 validate_type_with_len!(IndexSet<T>, T);
-#[cfg(feature = "indexmap")]
 validate_type_with_len!(IndexMap<K, V>, K, V);
 
 impl<T> ValidateLength<u64> for Cow<'_, T>
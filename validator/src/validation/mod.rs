@@ -1,5 +1,8 @@
+pub mod blank;
 #[cfg(feature = "card")]
 pub mod cards;
+pub mod cidr;
+pub mod comparison;
 pub mod contains;
 pub mod does_not_contain;
 pub mod email;
@@ -8,7 +11,16 @@ pub mod length;
 pub mod must_match;
 // pub mod nested;
 pub mod non_control_character;
+#[cfg(feature = "unicode_normalization")]
+pub mod normalized;
+pub mod one_of;
+pub mod password;
+pub mod pattern_set;
 pub mod range;
 pub mod regex;
 pub mod required;
+pub mod socket_addr;
+pub mod sorted;
+pub mod unicode_security;
+pub mod unique;
 pub mod urls;
@@ -1,3 +1,5 @@
+use crate::{AsComparisonStr, TextComparison};
+
 /// Validates that the 2 given fields match.
 /// Both fields are optionals
 #[must_use]
@@ -5,11 +7,28 @@ pub fn validate_must_match<T: Eq>(a: T, b: T) -> bool {
     a == b
 }
 
+/// Validates that the 2 given strings match once prepared by the comparison, for example
+/// ignoring their case.
+/// Both fields are optionals
+#[must_use]
+pub fn validate_must_match_with<A, B>(a: &A, b: &B, comparison: &TextComparison) -> bool
+where
+    A: AsComparisonStr + ?Sized,
+    B: AsComparisonStr + ?Sized,
+{
+    match (a.as_comparison_str(), b.as_comparison_str()) {
+        (Some(a), Some(b)) => comparison.prepare(a) == comparison.prepare(b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::borrow::Cow;
 
-    use super::validate_must_match;
+    use super::{validate_must_match, validate_must_match_with};
+    use crate::TextComparison;
 
     #[test]
     fn test_validate_must_match_strings_valid() {
@@ -60,4 +79,21 @@ mod tests {
         let a: Option<u64> = None;
         assert!(validate_must_match(a, None));
     }
+
+    #[test]
+    fn test_validate_must_match_with_case_insensitive() {
+        let comparison = TextComparison::default().case_insensitive(true);
+        assert!(validate_must_match_with("Hey", "hEY", &comparison));
+        assert!(!validate_must_match_with("Hey", "Hello", &comparison));
+        assert!(!validate_must_match_with("Hey", "hEY", &TextComparison::default()));
+    }
+
+    #[test]
+    fn test_validate_must_match_with_options() {
+        let comparison = TextComparison::default().case_insensitive(true);
+        let none: Option<String> = None;
+        assert!(validate_must_match_with(&Some("Hey"), &"hey".to_string(), &comparison));
+        assert!(!validate_must_match_with(&none, &"hey".to_string(), &comparison));
+        assert!(validate_must_match_with(&none, &none, &comparison));
+    }
 }
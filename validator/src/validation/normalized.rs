@@ -0,0 +1,99 @@
+use std::borrow::Cow;
+
+use icu_normalizer::ComposingNormalizerBorrowed;
+
+/// The Unicode normalization forms of UAX #15 accepted by the `normalized` validator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NormalizationForm {
+    /// Canonical composition: `e` followed by a combining acute accent becomes `é`
+    Nfc,
+    /// Compatibility composition: also replaces compatibility characters like ligatures,
+    /// fullwidth forms or superscripts by their plain equivalent
+    Nfkc,
+}
+
+impl NormalizationForm {
+    fn normalizer(&self) -> ComposingNormalizerBorrowed<'static> {
+        match self {
+            NormalizationForm::Nfc => ComposingNormalizerBorrowed::new_nfc(),
+            NormalizationForm::Nfkc => ComposingNormalizerBorrowed::new_nfkc(),
+        }
+    }
+
+    /// The name of the form, like `NFC`
+    pub fn name(&self) -> &'static str {
+        match self {
+            NormalizationForm::Nfc => "NFC",
+            NormalizationForm::Nfkc => "NFKC",
+        }
+    }
+
+    /// Returns the string in this normalization form, borrowing it if it already is
+    pub fn normalize<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        self.normalizer().normalize(s)
+    }
+
+    pub fn is_normalized(&self, s: &str) -> bool {
+        self.normalizer().is_normalized(s)
+    }
+}
+
+/// Validates that a string is in the given normalization form.
+pub trait ValidateNormalized {
+    fn validate_normalized(&self, form: NormalizationForm) -> bool {
+        if let Some(s) = self.as_normalized_string() {
+            form.is_normalized(&s)
+        } else {
+            true
+        }
+    }
+
+    fn as_normalized_string(&self) -> Option<Cow<'_, str>>;
+}
+
+impl<T: AsRef<str>> ValidateNormalized for T {
+    fn as_normalized_string(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::from(self.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{NormalizationForm, ValidateNormalized};
+
+    #[test]
+    fn test_validate_normalized() {
+        let tests = vec![
+            ("hello", NormalizationForm::Nfc, true),
+            ("hello", NormalizationForm::Nfkc, true),
+            ("caf\u{00E9}", NormalizationForm::Nfc, true),
+            ("cafe\u{0301}", NormalizationForm::Nfc, false),
+            ("\u{FB01}ne", NormalizationForm::Nfc, true),
+            ("\u{FB01}ne", NormalizationForm::Nfkc, false),
+            ("ｈｅｌｌｏ", NormalizationForm::Nfkc, false),
+            ("\u{1100}\u{1161}", NormalizationForm::Nfc, false),
+            ("\u{AC00}", NormalizationForm::Nfc, true),
+        ];
+
+        for (input, form, expected) in tests {
+            assert_eq!(input.validate_normalized(form), expected, "{} {:?}", input, form);
+        }
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(NormalizationForm::Nfc.normalize("cafe\u{0301}"), "caf\u{00E9}");
+        assert_eq!(NormalizationForm::Nfkc.normalize("\u{FB01}ne ｈｉ"), "fine hi");
+        assert!(matches!(NormalizationForm::Nfc.normalize("hello"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_validate_normalized_cow() {
+        let test: Cow<'static, str> = "caf\u{00E9}".into();
+        assert!(test.validate_normalized(NormalizationForm::Nfc));
+        let test: Cow<'static, str> = String::from("cafe\u{0301}").into();
+        assert!(!test.validate_normalized(NormalizationForm::Nfc));
+    }
+}
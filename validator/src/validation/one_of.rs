@@ -0,0 +1,190 @@
+use std::{borrow::Cow, rc::Rc, sync::Arc};
+
+use crate::{AsComparisonStr, TextComparison};
+
+/// Validates that a value is, or isn't, one of a list of values.
+///
+/// The lists can be given either as a slice of references, which is what the derive uses for
+/// literal lists, or as any iterator of values, like a static slice or set.
+pub trait ValidateOneOf {
+    /// The type the values are compared as, `str` for all the strings
+    type Value: PartialEq + ?Sized;
+
+    /// The value to compare, `None` being always valid
+    fn as_one_of_value(&self) -> Option<&Self::Value>;
+
+    #[must_use]
+    fn validate_one_of(&self, allowed: &[&Self::Value]) -> bool {
+        self.as_one_of_value().is_none_or(|value| allowed.contains(&value))
+    }
+
+    #[must_use]
+    fn validate_none_of(&self, denied: &[&Self::Value]) -> bool {
+        self.as_one_of_value().is_none_or(|value| !denied.contains(&value))
+    }
+
+    #[must_use]
+    fn validate_one_of_in<I>(&self, allowed: I) -> bool
+    where
+        I: IntoIterator,
+        I::Item: ValidateOneOf<Value = Self::Value>,
+    {
+        self.as_one_of_value().is_none_or(|value| {
+            allowed.into_iter().any(|a| a.as_one_of_value().is_some_and(|a| a == value))
+        })
+    }
+
+    #[must_use]
+    fn validate_none_of_in<I>(&self, denied: I) -> bool
+    where
+        I: IntoIterator,
+        I::Item: ValidateOneOf<Value = Self::Value>,
+    {
+        self.as_one_of_value().is_none_or(|value| {
+            !denied.into_iter().any(|d| d.as_one_of_value().is_some_and(|d| d == value))
+        })
+    }
+}
+
+macro_rules! one_of_that_derefs {
+    ($type_:ty) => {
+        impl<T> ValidateOneOf for $type_
+        where
+            T: ValidateOneOf + ?Sized,
+        {
+            type Value = T::Value;
+
+            fn as_one_of_value(&self) -> Option<&Self::Value> {
+                T::as_one_of_value(self)
+            }
+        }
+    };
+}
+
+one_of_that_derefs!(&T);
+one_of_that_derefs!(Arc<T>);
+one_of_that_derefs!(Box<T>);
+one_of_that_derefs!(Rc<T>);
+
+impl<T: ValidateOneOf> ValidateOneOf for Option<T> {
+    type Value = T::Value;
+
+    fn as_one_of_value(&self) -> Option<&Self::Value> {
+        self.as_ref().and_then(T::as_one_of_value)
+    }
+}
+
+macro_rules! one_of_str {
+    ($type_:ty) => {
+        impl ValidateOneOf for $type_ {
+            type Value = str;
+
+            fn as_one_of_value(&self) -> Option<&str> {
+                Some(self)
+            }
+        }
+    };
+}
+
+one_of_str!(str);
+one_of_str!(String);
+one_of_str!(Cow<'_, str>);
+
+macro_rules! one_of_self {
+    ($($type_:ty),*) => {
+        $(
+            impl ValidateOneOf for $type_ {
+                type Value = $type_;
+
+                fn as_one_of_value(&self) -> Option<&Self::Value> {
+                    Some(self)
+                }
+            }
+        )*
+    };
+}
+
+one_of_self!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, char, bool);
+
+/// Validates that the string is one of the allowed strings once all of them are prepared by the
+/// comparison, for example ignoring their case.
+#[must_use]
+pub fn validate_one_of_with<T, I>(value: &T, allowed: I, comparison: &TextComparison) -> bool
+where
+    T: AsComparisonStr + ?Sized,
+    I: IntoIterator,
+    I::Item: AsComparisonStr,
+{
+    if let Some(value) = value.as_comparison_str() {
+        let value = comparison.prepare(value);
+        allowed
+            .into_iter()
+            .any(|a| a.as_comparison_str().is_some_and(|a| comparison.prepare(a) == value))
+    } else {
+        true
+    }
+}
+
+/// The opposite of [`validate_one_of_with`]
+#[must_use]
+pub fn validate_none_of_with<T, I>(value: &T, denied: I, comparison: &TextComparison) -> bool
+where
+    T: AsComparisonStr + ?Sized,
+    I: IntoIterator,
+    I::Item: AsComparisonStr,
+{
+    value.as_comparison_str().is_none() || !validate_one_of_with(value, denied, comparison)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashSet};
+
+    use super::*;
+
+    #[test]
+    fn test_validate_one_of_str() {
+        assert!("draft".validate_one_of(&["draft", "published"]));
+        assert!(!"Draft".validate_one_of(&["draft", "published"]));
+        assert!(String::from("published").validate_one_of(&["draft", "published"]));
+        assert!(Cow::from("draft").validate_one_of(&["draft"]));
+        assert!(None::<String>.validate_one_of(&["draft"]));
+        assert!(!Some("archived").validate_one_of(&["draft"]));
+    }
+
+    #[test]
+    fn test_validate_one_of_primitives() {
+        assert!(443u16.validate_one_of(&[&80, &443]));
+        assert!(!8080u16.validate_one_of(&[&80, &443]));
+        assert!((-1i32).validate_none_of(&[&0]));
+        assert!('a'.validate_one_of(&[&'a', &'b']));
+        assert!(!'c'.validate_one_of(&[&'a', &'b']));
+        assert!(!Some(0i64).validate_none_of(&[&0]));
+    }
+
+    #[test]
+    fn test_validate_one_of_in_collections() {
+        let allowed: HashSet<&str> = ["draft", "published"].into();
+        assert!("draft".validate_one_of_in(allowed.iter()));
+        assert!(!"archived".validate_one_of_in(allowed.iter()));
+
+        let denied: BTreeSet<String> = ["admin".to_string()].into();
+        assert!(!String::from("admin").validate_none_of_in(denied.iter()));
+        assert!("keats".validate_none_of_in(&denied));
+
+        let ports: &[u16] = &[80, 443];
+        assert!(Some(80u16).validate_one_of_in(ports));
+        assert!(None::<u16>.validate_none_of_in(ports));
+    }
+
+    #[test]
+    fn test_validate_one_of_with_case_insensitive() {
+        let comparison = TextComparison::default().case_insensitive(true);
+        assert!(validate_one_of_with("DRAFT", ["draft", "published"], &comparison));
+        assert!(!validate_one_of_with("archived", ["draft", "published"], &comparison));
+        assert!(validate_one_of_with(&None::<String>, ["draft"], &comparison));
+        assert!(!validate_none_of_with("Admin", &["admin".to_string()], &comparison));
+        assert!(validate_none_of_with("keats", &["admin".to_string()], &comparison));
+        assert!(validate_none_of_with(&None::<String>, ["admin"], &comparison));
+    }
+}
@@ -0,0 +1,694 @@
+use std::{
+    borrow::Cow,
+    collections::{BTreeSet, HashSet},
+    hash::BuildHasher,
+    rc::Rc,
+    sync::Arc,
+};
+
+use serde::{Serialize, Serializer};
+
+/// Common passwords and words found in password dumps, most common first
+static COMMON_PASSWORDS: [&str; 119] = [
+    "123456",
+    "password",
+    "12345678",
+    "qwerty",
+    "123456789",
+    "12345",
+    "1234",
+    "111111",
+    "1234567",
+    "dragon",
+    "123123",
+    "baseball",
+    "abc123",
+    "football",
+    "monkey",
+    "letmein",
+    "696969",
+    "shadow",
+    "master",
+    "666666",
+    "qwertyuiop",
+    "123321",
+    "mustang",
+    "1234567890",
+    "michael",
+    "654321",
+    "superman",
+    "1qaz2wsx",
+    "7777777",
+    "121212",
+    "000000",
+    "qazwsx",
+    "123qwe",
+    "killer",
+    "trustno1",
+    "jordan",
+    "jennifer",
+    "zxcvbnm",
+    "asdfgh",
+    "hunter",
+    "buster",
+    "soccer",
+    "harley",
+    "batman",
+    "andrew",
+    "tigger",
+    "sunshine",
+    "iloveyou",
+    "2000",
+    "charlie",
+    "robert",
+    "thomas",
+    "hockey",
+    "ranger",
+    "daniel",
+    "starwars",
+    "klaster",
+    "112233",
+    "george",
+    "computer",
+    "michelle",
+    "jessica",
+    "pepper",
+    "1111",
+    "zxcvbn",
+    "555555",
+    "11111111",
+    "131313",
+    "freedom",
+    "777777",
+    "pass",
+    "maggie",
+    "159753",
+    "aaaaaa",
+    "ginger",
+    "princess",
+    "joshua",
+    "cheese",
+    "amanda",
+    "summer",
+    "love",
+    "ashley",
+    "nicole",
+    "chelsea",
+    "biteme",
+    "matthew",
+    "access",
+    "yankees",
+    "987654321",
+    "dallas",
+    "austin",
+    "thunder",
+    "taylor",
+    "matrix",
+    "welcome",
+    "admin",
+    "login",
+    "secret",
+    "passw0rd",
+    "hello",
+    "flower",
+    "whatever",
+    "qwerty123",
+    "football1",
+    "monkey1",
+    "changeme",
+    "default",
+    "root",
+    "test",
+    "guest",
+    "winter",
+    "spring",
+    "autumn",
+    "password1",
+    "abcdef",
+    "abcd1234",
+    "azerty",
+    "solo",
+    "loveme",
+];
+
+/// Rows of a QWERTY keyboard, used to detect walks like `asdf`
+static KEYBOARD_ROWS: [&str; 4] = ["1234567890", "qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// Only the start of longer passwords is searched for patterns, the rest counting as random
+/// characters, so that the estimation stays cheap whatever the length of the input. This is the
+/// limit used by zxcvbn.
+const MAX_ANALYSED_CHARS: usize = 100;
+
+/// How a password could be made stronger
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PasswordSuggestion {
+    UseLongerPassword,
+    UseMoreCharacterClasses,
+    AvoidCommonPasswords,
+    AvoidSequences,
+    AvoidRepeats,
+    AvoidKeyboardPatterns,
+    AvoidYears,
+    AvoidPersonalInformation,
+}
+
+impl PasswordSuggestion {
+    /// A short identifier of the suggestion, used when serializing it
+    pub fn code(&self) -> &'static str {
+        match self {
+            PasswordSuggestion::UseLongerPassword => "use_longer_password",
+            PasswordSuggestion::UseMoreCharacterClasses => "use_more_character_classes",
+            PasswordSuggestion::AvoidCommonPasswords => "avoid_common_passwords",
+            PasswordSuggestion::AvoidSequences => "avoid_sequences",
+            PasswordSuggestion::AvoidRepeats => "avoid_repeats",
+            PasswordSuggestion::AvoidKeyboardPatterns => "avoid_keyboard_patterns",
+            PasswordSuggestion::AvoidYears => "avoid_years",
+            PasswordSuggestion::AvoidPersonalInformation => "avoid_personal_information",
+        }
+    }
+
+    /// An english sentence describing the suggestion
+    pub fn message(&self) -> &'static str {
+        match self {
+            PasswordSuggestion::UseLongerPassword => "Use a longer password",
+            PasswordSuggestion::UseMoreCharacterClasses => {
+                "Mix uppercase and lowercase letters, digits and symbols"
+            }
+            PasswordSuggestion::AvoidCommonPasswords => "Avoid common passwords and words",
+            PasswordSuggestion::AvoidSequences => "Avoid sequences like abc or 6543",
+            PasswordSuggestion::AvoidRepeats => "Avoid repeated characters like aaa",
+            PasswordSuggestion::AvoidKeyboardPatterns => "Avoid keyboard patterns like qwerty",
+            PasswordSuggestion::AvoidYears => "Avoid years and dates",
+            PasswordSuggestion::AvoidPersonalInformation => {
+                "Avoid using your name, username or email"
+            }
+        }
+    }
+}
+
+impl Serialize for PasswordSuggestion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+/// An estimation of how hard a password is to guess
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasswordStrength {
+    /// From 0 (trivial to guess) to 4 (very hard to guess)
+    pub score: u8,
+    /// The base 2 logarithm of the estimated number of guesses needed
+    pub guesses_log2: f64,
+    pub suggestions: Vec<PasswordSuggestion>,
+}
+
+/// Replaces the common substitutions of letters by digits and symbols
+fn unleet(c: char) -> char {
+    match c {
+        '@' | '4' => 'a',
+        '3' => 'e',
+        '1' | '!' => 'i',
+        '0' => 'o',
+        '$' | '5' => 's',
+        '7' => 't',
+        c => c,
+    }
+}
+
+fn charset_size(password: &str) -> f64 {
+    let mut size = 0;
+    if password.chars().any(|c| c.is_ascii_lowercase()) {
+        size += 26;
+    }
+    if password.chars().any(|c| c.is_ascii_uppercase()) {
+        size += 26;
+    }
+    if password.chars().any(|c| c.is_ascii_digit()) {
+        size += 10;
+    }
+    if password.chars().any(|c| c.is_ascii_punctuation() || c == ' ') {
+        size += 33;
+    }
+    if !password.is_ascii() {
+        size += 100;
+    }
+    f64::from(size.max(10))
+}
+
+/// The number of character classes (lowercase, uppercase, digits, symbols) used in the password
+pub(crate) fn character_classes(password: &str) -> usize {
+    [
+        password.chars().any(char::is_lowercase),
+        password.chars().any(char::is_uppercase),
+        password.chars().any(|c| c.is_ascii_digit()),
+        password.chars().any(|c| !c.is_alphanumeric()),
+    ]
+    .into_iter()
+    .filter(|c| *c)
+    .count()
+}
+
+/// Finds the longest pattern starting at `start`, returning its length in chars, the bits
+/// needed to guess it and the matching suggestion
+fn longest_pattern(
+    chars: &[char],
+    unleeted: &[char],
+    start: usize,
+    dictionary: &[(Vec<char>, usize)],
+    keyboard_rows: &[Vec<char>],
+) -> Option<(usize, f64, PasswordSuggestion)> {
+    let mut best: Option<(usize, f64, PasswordSuggestion)> = None;
+    let mut consider = |len: usize, bits: f64, suggestion: PasswordSuggestion| {
+        if best.is_none_or(|(best_len, best_bits, _)| {
+            len > best_len || (len == best_len && bits < best_bits)
+        }) {
+            best = Some((len, bits, suggestion));
+        }
+    };
+
+    // Dictionary words, with their leet and uppercase variations
+    for (word, rank) in dictionary {
+        let end = start + word.len();
+        if end > chars.len() {
+            continue;
+        }
+        let plain = chars[start..end].iter().map(|c| c.to_lowercase().next().unwrap_or(*c));
+        if plain.clone().eq(word.iter().copied()) || unleeted[start..end] == word[..] {
+            let mut bits = (*rank as f64 + 1.0).log2() + 1.0;
+            if chars[start..end].iter().any(|c| c.is_uppercase()) {
+                bits += 1.0;
+            }
+            if !plain.eq(word.iter().copied()) {
+                bits += 1.0;
+            }
+            let suggestion = if *rank == 0 {
+                PasswordSuggestion::AvoidPersonalInformation
+            } else {
+                PasswordSuggestion::AvoidCommonPasswords
+            };
+            consider(word.len(), bits, suggestion);
+        }
+    }
+
+    // Repeated characters: `aaaa`
+    let repeat = chars[start..].iter().take_while(|c| **c == chars[start]).count();
+    if repeat >= 3 {
+        consider(
+            repeat,
+            charset_size(&chars[start].to_string()).log2() + (repeat as f64).log2(),
+            PasswordSuggestion::AvoidRepeats,
+        );
+    }
+
+    // Sequences with a constant step of 1: `abcd`, `4321`
+    if start + 1 < chars.len() {
+        let step = chars[start + 1] as i64 - chars[start] as i64;
+        if step.abs() == 1 {
+            let len = 1 + chars[start..]
+                .windows(2)
+                .take_while(|w| w[1] as i64 - w[0] as i64 == step)
+                .count();
+            if len >= 3 {
+                consider(len, (26.0 * len as f64).log2(), PasswordSuggestion::AvoidSequences);
+            }
+        }
+    }
+
+    // Keyboard walks: `qwer`, `asdf`
+    for row in keyboard_rows {
+        let lower = chars[start..]
+            .iter()
+            .take(row.len())
+            .map(|c| c.to_ascii_lowercase())
+            .collect::<Vec<_>>();
+        let len = (4..=lower.len().min(row.len()))
+            .rev()
+            .find(|len| row.windows(*len).any(|w| w == &lower[..*len]))
+            .unwrap_or(0);
+        if len >= 4 {
+            consider(len, (40.0 * len as f64).log2(), PasswordSuggestion::AvoidKeyboardPatterns);
+        }
+    }
+
+    // Years: 1900 to 2099
+    if start + 4 <= chars.len() {
+        let year = chars[start..start + 4].iter().collect::<String>();
+        if (year.starts_with("19") || year.starts_with("20"))
+            && year.chars().all(|c| c.is_ascii_digit())
+        {
+            consider(4, 200f64.log2(), PasswordSuggestion::AvoidYears);
+        }
+    }
+
+    best
+}
+
+/// Estimates how hard the password is to guess, by splitting it in known patterns
+/// (common passwords, words from `user_inputs`, sequences, repeats, keyboard walks and years)
+/// and random characters.
+///
+/// `user_inputs` are values like the username or email of the user, guessed first by attackers.
+///
+/// Only the first 100 characters are searched for patterns, the following ones being counted as
+/// random characters.
+pub fn estimate_password_strength(password: &str, user_inputs: &[&str]) -> PasswordStrength {
+    let chars = password.chars().take(MAX_ANALYSED_CHARS).collect::<Vec<_>>();
+    let length = password.chars().count();
+    let unleeted =
+        chars.iter().map(|c| unleet(c.to_lowercase().next().unwrap_or(*c))).collect::<Vec<_>>();
+    let char_bits = charset_size(password).log2();
+
+    // User inputs come first, ranked 0, then common passwords by popularity
+    let dictionary = user_inputs
+        .iter()
+        .flat_map(|input| {
+            let input = input.to_lowercase();
+            let local_part = input.split('@').next().map(str::to_owned);
+            std::iter::once(input).chain(local_part)
+        })
+        .filter(|input| input.chars().count() >= 3)
+        .map(|input| (input.chars().collect::<Vec<_>>(), 0))
+        .chain(
+            COMMON_PASSWORDS
+                .iter()
+                .enumerate()
+                .map(|(rank, word)| (word.chars().collect::<Vec<_>>(), rank + 1)),
+        )
+        .collect::<Vec<_>>();
+    let keyboard_rows =
+        KEYBOARD_ROWS.iter().map(|row| row.chars().collect::<Vec<_>>()).collect::<Vec<_>>();
+
+    let mut bits = 0.0;
+    let mut suggestions = BTreeSet::new();
+    let mut position = 0;
+    while position < chars.len() {
+        match longest_pattern(&chars, &unleeted, position, &dictionary, &keyboard_rows) {
+            Some((len, pattern_bits, suggestion)) if pattern_bits < char_bits * len as f64 => {
+                bits += pattern_bits;
+                suggestions.insert(suggestion);
+                position += len;
+            }
+            _ => {
+                bits += char_bits;
+                position += 1;
+            }
+        }
+    }
+
+    bits += char_bits * (length - chars.len()) as f64;
+
+    let score = match bits {
+        b if b < 10.0 => 0,
+        b if b < 20.0 => 1,
+        b if b < 27.0 => 2,
+        b if b < 33.0 => 3,
+        _ => 4,
+    };
+
+    if score < 4 {
+        if length < 12 {
+            suggestions.insert(PasswordSuggestion::UseLongerPassword);
+        }
+        if character_classes(password) < 3 {
+            suggestions.insert(PasswordSuggestion::UseMoreCharacterClasses);
+        }
+    }
+
+    PasswordStrength { score, guesses_log2: bits, suggestions: suggestions.into_iter().collect() }
+}
+
+/// A list of passwords that are not allowed, for example passwords found in breaches.
+///
+/// It can be implemented on top of a k-anonymity API like Have I Been Pwned or a local list.
+pub trait PasswordBlocklist {
+    fn is_blocked(&self, password: &str) -> bool;
+}
+
+impl PasswordBlocklist for [&str] {
+    fn is_blocked(&self, password: &str) -> bool {
+        self.contains(&password)
+    }
+}
+
+impl<const N: usize> PasswordBlocklist for [&str; N] {
+    fn is_blocked(&self, password: &str) -> bool {
+        self.contains(&password)
+    }
+}
+
+impl<S: BuildHasher> PasswordBlocklist for HashSet<String, S> {
+    fn is_blocked(&self, password: &str) -> bool {
+        self.contains(password)
+    }
+}
+
+impl<T: PasswordBlocklist + ?Sized> PasswordBlocklist for &T {
+    fn is_blocked(&self, password: &str) -> bool {
+        T::is_blocked(self, password)
+    }
+}
+
+/// The rules checked by `validate_password`
+#[derive(Clone, Copy, Default)]
+pub struct PasswordRules<'a> {
+    /// The minimum score, from 0 to 4, given by [`estimate_password_strength`]
+    pub min_score: Option<u8>,
+    /// The minimum number of characters
+    pub min_length: Option<usize>,
+    /// The minimum number of character classes (lowercase, uppercase, digits, symbols)
+    pub require_classes: Option<usize>,
+    /// Values the password must not contain, compared case-insensitively. They are also used
+    /// when estimating the strength of the password.
+    pub forbidden: &'a [&'a str],
+    pub blocklist: Option<&'a dyn PasswordBlocklist>,
+}
+
+/// The first rule of [`PasswordRules`] that a password broke
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordErrorKind {
+    TooShort,
+    MissingClasses,
+    ContainsUserInput,
+    Blocklisted,
+    TooWeak,
+}
+
+impl PasswordErrorKind {
+    /// The error code used by the derive for this error
+    pub fn code(&self) -> &'static str {
+        match self {
+            PasswordErrorKind::TooShort => "password_length",
+            PasswordErrorKind::MissingClasses => "password_classes",
+            PasswordErrorKind::ContainsUserInput => "password_user_input",
+            PasswordErrorKind::Blocklisted => "password_blocklisted",
+            PasswordErrorKind::TooWeak => "password_strength",
+        }
+    }
+}
+
+/// Why a password was rejected along with its estimated strength
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasswordError {
+    pub kind: PasswordErrorKind,
+    pub strength: PasswordStrength,
+}
+
+/// Validates the strength of a password.
+///
+/// The value of the password is never added to the error params by the derive.
+pub trait ValidatePassword {
+    fn validate_password(&self, rules: &PasswordRules<'_>) -> Result<(), PasswordError> {
+        let Some(password) = self.as_password_string() else {
+            return Ok(());
+        };
+
+        // The cheap rules are checked first, the strength only being estimated when needed
+        let fail = |kind| {
+            let strength = estimate_password_strength(&password, rules.forbidden);
+            Err(PasswordError { kind, strength })
+        };
+
+        if rules.min_length.is_some_and(|min| password.chars().count() < min) {
+            return fail(PasswordErrorKind::TooShort);
+        }
+        if rules.require_classes.is_some_and(|min| character_classes(&password) < min) {
+            return fail(PasswordErrorKind::MissingClasses);
+        }
+
+        let lowercase = password.to_lowercase();
+        let contains_user_input = rules.forbidden.iter().any(|input| {
+            let input = input.to_lowercase();
+            let local_part = input.split('@').next().unwrap_or_default();
+            let found = [input.as_str(), local_part]
+                .into_iter()
+                .any(|v| v.chars().count() >= 3 && lowercase.contains(v));
+            found
+        });
+        if contains_user_input {
+            return fail(PasswordErrorKind::ContainsUserInput);
+        }
+
+        if rules.blocklist.is_some_and(|b| b.is_blocked(&password)) {
+            return fail(PasswordErrorKind::Blocklisted);
+        }
+        if let Some(min_score) = rules.min_score {
+            let strength = estimate_password_strength(&password, rules.forbidden);
+            if strength.score < min_score {
+                return Err(PasswordError { kind: PasswordErrorKind::TooWeak, strength });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn as_password_string(&self) -> Option<Cow<'_, str>>;
+}
+
+impl<T: AsRef<str>> ValidatePassword for T {
+    fn as_password_string(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::from(self.as_ref()))
+    }
+}
+
+/// Gives the value of a field listed in `forbid_fields` of the `password` validator
+pub trait PasswordUserInput {
+    fn as_user_input(&self) -> Option<&str>;
+}
+
+macro_rules! user_input_that_derefs {
+    ($type_:ty) => {
+        impl<T> PasswordUserInput for $type_
+        where
+            T: PasswordUserInput + ?Sized,
+        {
+            fn as_user_input(&self) -> Option<&str> {
+                T::as_user_input(self)
+            }
+        }
+    };
+}
+
+user_input_that_derefs!(&T);
+user_input_that_derefs!(Arc<T>);
+user_input_that_derefs!(Box<T>);
+user_input_that_derefs!(Rc<T>);
+
+impl PasswordUserInput for str {
+    fn as_user_input(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl PasswordUserInput for String {
+    fn as_user_input(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl PasswordUserInput for Cow<'_, str> {
+    fn as_user_input(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl<T: PasswordUserInput> PasswordUserInput for Option<T> {
+    fn as_user_input(&self) -> Option<&str> {
+        self.as_ref().and_then(T::as_user_input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{
+        estimate_password_strength, PasswordErrorKind, PasswordRules, PasswordSuggestion,
+        ValidatePassword,
+    };
+
+    #[test]
+    fn test_password_strength_scores() {
+        let tests = vec![
+            ("password", 0),
+            ("123456", 0),
+            ("P@ssw0rd", 0),
+            ("qwertyuiop", 0),
+            ("aaaaaaaaaaaa", 0),
+            ("abcdefgh", 0),
+            ("summer2019", 1),
+            ("kX9#mQ2$vL7!", 4),
+            ("correct horse battery staple", 4),
+        ];
+
+        for (input, expected) in tests {
+            let strength = estimate_password_strength(input, &[]);
+            assert_eq!(strength.score, expected, "{}: {:?}", input, strength);
+        }
+    }
+
+    #[test]
+    fn test_password_strength_suggestions() {
+        let strength = estimate_password_strength("password1234", &[]);
+        assert!(strength.suggestions.contains(&PasswordSuggestion::AvoidCommonPasswords));
+        assert!(strength.suggestions.contains(&PasswordSuggestion::AvoidSequences));
+
+        let strength = estimate_password_strength("bob1987", &["bob@example.com"]);
+        assert!(strength.suggestions.contains(&PasswordSuggestion::AvoidPersonalInformation));
+        assert!(strength.suggestions.contains(&PasswordSuggestion::AvoidYears));
+        assert!(strength.suggestions.contains(&PasswordSuggestion::UseLongerPassword));
+
+        let strength = estimate_password_strength("kX9#mQ2$vL7!wE", &[]);
+        assert!(strength.suggestions.is_empty());
+    }
+
+    #[test]
+    fn test_user_inputs_lower_strength() {
+        let without = estimate_password_strength("vincentprouillet", &[]);
+        let with = estimate_password_strength("vincentprouillet", &["vincentprouillet"]);
+        assert!(with.guesses_log2 < without.guesses_log2);
+        assert_eq!(with.score, 0);
+    }
+
+    #[test]
+    fn test_validate_password() {
+        let blocklist = HashSet::from(["Tr0ub4dor&3".to_string()]);
+        let rules = PasswordRules {
+            min_score: Some(3),
+            min_length: Some(10),
+            require_classes: Some(3),
+            forbidden: &["bob@example.com", "bobby"],
+            blocklist: Some(&blocklist),
+        };
+
+        assert_eq!("kX9#mQ2$vL7!".validate_password(&rules), Ok(()));
+
+        let tests = vec![
+            ("kX9#mQ2", PasswordErrorKind::TooShort),
+            ("kxamqbvlpwoe", PasswordErrorKind::MissingClasses),
+            ("Xx9#BOBBY-2$vL", PasswordErrorKind::ContainsUserInput),
+            ("kX9#bob-2$vL", PasswordErrorKind::ContainsUserInput),
+            ("Tr0ub4dor&3", PasswordErrorKind::Blocklisted),
+            ("Password1234", PasswordErrorKind::TooWeak),
+        ];
+
+        for (input, expected) in tests {
+            let err = input.validate_password(&rules).unwrap_err();
+            assert_eq!(err.kind, expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_long_passwords_are_only_analysed_up_to_a_limit() {
+        let password = "a".repeat(1_000_000);
+        let strength = estimate_password_strength(&password, &["aaaa"]);
+        assert_eq!(strength.score, 4);
+
+        let rules = PasswordRules { min_length: Some(8), min_score: Some(3), ..Default::default() };
+        assert!(password.validate_password(&rules).is_ok());
+    }
+
+    #[test]
+    fn test_validate_password_without_rules() {
+        assert!("a".validate_password(&PasswordRules::default()).is_ok());
+    }
+}
@@ -0,0 +1,126 @@
+use aho_corasick::{AhoCorasick, MatchKind};
+
+use crate::{AsComparisonStr, TextComparison};
+
+/// A list of patterns compiled once into a multi-pattern matcher, used by `contains` and
+/// `does_not_contain` to search for hundreds of words in a single pass.
+///
+/// The patterns and the searched strings are both prepared by the [`TextComparison`], which
+/// allows matching regardless of the case or of the Unicode normalization form.
+#[derive(Debug, Clone)]
+pub struct PatternSet {
+    matcher: AhoCorasick,
+    patterns: Vec<String>,
+    comparison: TextComparison,
+    whole_word: bool,
+}
+
+impl PatternSet {
+    /// Compiles the patterns. If `whole_word` is set, a pattern only matches if it is not
+    /// surrounded by letters, digits or underscores.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the patterns are too large to be compiled.
+    pub fn new<I>(patterns: I, comparison: TextComparison, whole_word: bool) -> PatternSet
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let patterns = patterns.into_iter().map(|p| p.as_ref().to_string()).collect::<Vec<_>>();
+        let matcher = AhoCorasick::builder()
+            .match_kind(MatchKind::Standard)
+            .build(patterns.iter().map(|p| comparison.prepare(p).into_owned()))
+            .expect("The patterns are too large to be compiled");
+        PatternSet { matcher, patterns, comparison, whole_word }
+    }
+
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    /// Returns the first pattern found in the haystack, as it was given to [`PatternSet::new`]
+    pub fn find(&self, haystack: &str) -> Option<&str> {
+        let haystack = self.comparison.prepare(haystack);
+        let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+
+        self.matcher
+            .find_overlapping_iter(haystack.as_ref())
+            .find(|m| {
+                !self.whole_word
+                    || (!haystack[..m.start()].chars().next_back().is_some_and(is_word_char)
+                        && !haystack[m.end()..].chars().next().is_some_and(is_word_char))
+            })
+            .map(|m| self.patterns[m.pattern().as_usize()].as_str())
+    }
+}
+
+/// Validates that the string contains at least one of the patterns
+#[must_use]
+pub fn validate_contains_any<T>(haystack: &T, patterns: &PatternSet) -> bool
+where
+    T: AsComparisonStr + ?Sized,
+{
+    haystack.as_comparison_str().is_none_or(|haystack| patterns.find(haystack).is_some())
+}
+
+/// Validates that the string doesn't contain any of the patterns, returning the pattern found
+/// otherwise
+pub fn validate_does_not_contain_any<'a, T>(
+    haystack: &T,
+    patterns: &'a PatternSet,
+) -> Result<(), &'a str>
+where
+    T: AsComparisonStr + ?Sized,
+{
+    match haystack.as_comparison_str().and_then(|haystack| patterns.find(haystack)) {
+        Some(pattern) => Err(pattern),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate_contains_any, validate_does_not_contain_any, PatternSet};
+    use crate::TextComparison;
+
+    #[test]
+    fn test_pattern_set_find() {
+        let set = PatternSet::new(["admin", "root", "mod"], TextComparison::default(), false);
+        assert_eq!(set.find("the admin"), Some("admin"));
+        assert_eq!(set.find("rooted"), Some("root"));
+        assert_eq!(set.find("models"), Some("mod"));
+        assert_eq!(set.find("ADMIN"), None);
+        assert_eq!(set.find("keats"), None);
+    }
+
+    #[test]
+    fn test_pattern_set_case_insensitive() {
+        let comparison = TextComparison::default().case_insensitive(true);
+        let set = PatternSet::new(["Admin", "straße"], comparison, false);
+        assert_eq!(set.find("ADMIN"), Some("Admin"));
+        assert_eq!(set.find("Hauptstrasse"), Some("straße"));
+    }
+
+    #[test]
+    fn test_pattern_set_whole_word() {
+        let set = PatternSet::new(["ass", "bass"], TextComparison::default(), true);
+        assert_eq!(set.find("classic"), None);
+        assert_eq!(set.find("my_ass"), None);
+        assert_eq!(set.find("a bass guitar"), Some("bass"));
+        assert_eq!(set.find("ass"), Some("ass"));
+        assert_eq!(set.find("classic ass!"), Some("ass"));
+    }
+
+    #[test]
+    fn test_validate_with_pattern_set() {
+        let set = PatternSet::new(vec!["admin".to_string()], TextComparison::default(), false);
+        assert!(validate_contains_any("superadmin", &set));
+        assert!(!validate_contains_any("keats", &set));
+        assert!(validate_contains_any(&None::<String>, &set));
+
+        assert_eq!(validate_does_not_contain_any("keats", &set), Ok(()));
+        assert_eq!(validate_does_not_contain_any("superadmin", &set), Err("admin"));
+        assert_eq!(validate_does_not_contain_any(&None::<String>, &set), Ok(()));
+    }
+}
@@ -0,0 +1,80 @@
+use std::{borrow::Cow, net::SocketAddr, str::FromStr};
+
+/// Whether the given string is a valid DNS hostname made of letters, digits and hyphens
+fn is_hostname(host: &str) -> bool {
+    let host = host.strip_suffix('.').unwrap_or(host);
+    !host.is_empty()
+        && host.len() <= 253
+        && host.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        })
+}
+
+/// Validates whether the given string is a socket address like `127.0.0.1:8080` or `[::1]:443`.
+///
+/// If `allow_hostname` is set, `host:port` strings like `example.com:8080` are also accepted.
+pub trait ValidateSocketAddr {
+    fn validate_socket_addr(&self, allow_hostname: bool) -> bool {
+        let Some(s) = self.as_socket_addr_string() else {
+            return true;
+        };
+
+        if SocketAddr::from_str(&s).is_ok() {
+            return true;
+        }
+
+        if !allow_hostname {
+            return false;
+        }
+
+        match s.rsplit_once(':') {
+            Some((host, port)) => {
+                !port.starts_with('+') && port.parse::<u16>().is_ok() && is_hostname(host)
+            }
+            None => false,
+        }
+    }
+
+    fn as_socket_addr_string(&self) -> Option<Cow<'_, str>>;
+}
+
+impl<T: AsRef<str>> ValidateSocketAddr for T {
+    fn as_socket_addr_string(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::from(self.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValidateSocketAddr;
+
+    #[test]
+    fn test_validate_socket_addr() {
+        let tests = vec![
+            ("127.0.0.1:8080", true, true),
+            ("[::1]:443", true, true),
+            ("[fe80::1%2]:443", true, true),
+            ("::1:443", false, false),
+            ("127.0.0.1", false, false),
+            ("127.0.0.1:65536", false, false),
+            ("example.com:8080", false, true),
+            ("localhost:80", false, true),
+            ("example.com.:80", false, true),
+            ("example.com", false, false),
+            ("example.com:", false, false),
+            ("example.com:+80", false, false),
+            ("-example.com:80", false, false),
+            ("exa_mple.com:80", false, false),
+            (":80", false, false),
+        ];
+
+        for (input, without_hostname, with_hostname) in tests {
+            assert_eq!(input.validate_socket_addr(false), without_hostname, "{}", input);
+            assert_eq!(input.validate_socket_addr(true), with_hostname, "{}", input);
+        }
+    }
+}
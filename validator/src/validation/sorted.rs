@@ -0,0 +1,63 @@
+/// The order checked by the `sorted` validator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+/// Validates that the items are sorted, returning otherwise the index of every item that is out
+/// of order compared to the one before it.
+/// If `strict` is set, 2 consecutive equal items are out of order as well. Items that can't be
+/// compared, like `NaN`, are always out of order.
+pub fn validate_sorted<T>(items: &[T], order: SortOrder, strict: bool) -> Result<(), Vec<usize>>
+where
+    T: PartialOrd,
+{
+    let in_order = |previous: &T, item: &T| match (order, strict) {
+        (SortOrder::Ascending, false) => previous <= item,
+        (SortOrder::Ascending, true) => previous < item,
+        (SortOrder::Descending, false) => previous >= item,
+        (SortOrder::Descending, true) => previous > item,
+    };
+
+    let out_of_order = items
+        .windows(2)
+        .enumerate()
+        .filter(|(_, pair)| !in_order(&pair[0], &pair[1]))
+        .map(|(index, _)| index + 1)
+        .collect::<Vec<_>>();
+
+    if out_of_order.is_empty() {
+        Ok(())
+    } else {
+        Err(out_of_order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate_sorted, SortOrder};
+
+    #[test]
+    fn test_validate_sorted_ascending() {
+        assert_eq!(validate_sorted::<u8>(&[], SortOrder::Ascending, true), Ok(()));
+        assert_eq!(validate_sorted(&[1, 2, 2, 3], SortOrder::Ascending, false), Ok(()));
+        assert_eq!(validate_sorted(&[1, 2, 2, 3], SortOrder::Ascending, true), Err(vec![2]));
+        assert_eq!(validate_sorted(&[1, 3, 2, 4, 0], SortOrder::Ascending, false), Err(vec![2, 4]));
+    }
+
+    #[test]
+    fn test_validate_sorted_descending() {
+        assert_eq!(validate_sorted(&["c", "b", "a"], SortOrder::Descending, true), Ok(()));
+        assert_eq!(validate_sorted(&["c", "c", "a"], SortOrder::Descending, false), Ok(()));
+        assert_eq!(validate_sorted(&["a", "b"], SortOrder::Descending, false), Err(vec![1]));
+    }
+
+    #[test]
+    fn test_validate_sorted_nan() {
+        assert_eq!(
+            validate_sorted(&[1.0, f64::NAN, 2.0], SortOrder::Ascending, false),
+            Err(vec![1, 2])
+        );
+    }
+}
@@ -0,0 +1,500 @@
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+mod tables;
+
+use tables::{Script, CONFUSABLES, SCRIPT_EXTENSIONS, SCRIPT_RANGES};
+
+// The extra bits used for the augmented script sets of UTS #39: Han can be mixed with
+// Hiragana and Katakana (Japanese), Hangul (Korean) or Bopomofo
+const JPAN: usize = Script::Unknown as usize + 1;
+const KORE: usize = JPAN + 1;
+const HANB: usize = KORE + 1;
+
+/// A set of scripts, as a bitset indexed by `Script` followed by the extra bits above
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ScriptSet([u64; 3]);
+
+const _: () = assert!(HANB < 3 * 64, "ScriptSet is too small for all the scripts");
+
+impl ScriptSet {
+    const ALL: ScriptSet = ScriptSet([u64::MAX; 3]);
+
+    fn of(bits: &[usize]) -> ScriptSet {
+        let mut set = [0; 3];
+        for bit in bits {
+            set[bit / 64] |= 1 << (bit % 64);
+        }
+        ScriptSet(set)
+    }
+
+    fn union(self, other: ScriptSet) -> ScriptSet {
+        ScriptSet([0, 1, 2].map(|i| self.0[i] | other.0[i]))
+    }
+
+    fn intersection(self, other: ScriptSet) -> ScriptSet {
+        ScriptSet([0, 1, 2].map(|i| self.0[i] & other.0[i]))
+    }
+
+    fn is_empty(self) -> bool {
+        self.0.iter().all(|bits| *bits == 0)
+    }
+}
+
+/// Finds the value of the inclusive range containing `c` in a table sorted by range
+fn find_range<T: Copy>(table: &[(u32, u32, T)], c: char) -> Option<(u32, T)> {
+    let c = c as u32;
+    table
+        .binary_search_by(|(start, end, _)| {
+            if c < *start {
+                std::cmp::Ordering::Greater
+            } else if c > *end {
+                std::cmp::Ordering::Less
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|i| (table[i].0, table[i].2))
+}
+
+impl Script {
+    fn of(c: char) -> Script {
+        find_range(&SCRIPT_RANGES, c).map_or(Script::Unknown, |(_, script)| script)
+    }
+
+    /// The augmented script set of the script, characters of the `Common` and `Inherited`
+    /// scripts being compatible with every script
+    fn set(self) -> ScriptSet {
+        match self {
+            Script::Common | Script::Inherited => ScriptSet::ALL,
+            Script::Han => ScriptSet::of(&[Script::Han as usize, JPAN, KORE, HANB]),
+            Script::Hiragana | Script::Katakana => ScriptSet::of(&[JPAN]),
+            Script::Hangul => ScriptSet::of(&[KORE]),
+            Script::Bopomofo => ScriptSet::of(&[HANB]),
+            s => ScriptSet::of(&[s as usize]),
+        }
+    }
+}
+
+/// The augmented script set of the Script_Extensions of the character, like Arabic, Syriac,
+/// Thaana and a few others for the Arabic comma `،`
+fn script_set(c: char) -> ScriptSet {
+    match find_range(&SCRIPT_EXTENSIONS, c) {
+        Some((_, scripts)) => {
+            scripts.iter().fold(ScriptSet::of(&[]), |set, script| set.union(script.set()))
+        }
+        None => Script::of(c).set(),
+    }
+}
+
+/// Whether the character is a bidirectional formatting character of UAX #9, which can
+/// reorder how the text around it is displayed
+fn is_bidi_control(c: char) -> bool {
+    matches!(c, '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}')
+}
+
+/// Whether the character is not rendered: zero-width, format characters and other
+/// default ignorable code points
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}'
+            | '\u{034F}'
+            | '\u{0600}'..='\u{0605}'
+            | '\u{061C}'
+            | '\u{06DD}'
+            | '\u{070F}'
+            | '\u{08E2}'
+            | '\u{115F}'..='\u{1160}'
+            | '\u{17B4}'..='\u{17B5}'
+            | '\u{180B}'..='\u{180F}'
+            | '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{206F}'
+            | '\u{3164}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{FEFF}'
+            | '\u{FFA0}'
+            | '\u{FFF0}'..='\u{FFFB}'
+            | '\u{110BD}'
+            | '\u{110CD}'
+            | '\u{1BCA0}'..='\u{1BCA3}'
+            | '\u{1D173}'..='\u{1D17A}'
+            | '\u{E0000}'..='\u{E0FFF}'
+    )
+}
+
+const HANGUL_FIRST: u32 = 0xAC00;
+const HANGUL_COUNT: u32 = 11172;
+
+/// The skeleton of a character: the prototypes of `confusables.txt` of the characters of its
+/// NFD form
+fn prototype(c: char) -> Cow<'static, str> {
+    // Hangul syllables are decomposed into their jamos, which have their own prototypes
+    let index = (c as u32).wrapping_sub(HANGUL_FIRST);
+    if index < HANGUL_COUNT {
+        let leading = char::from_u32(0x1100 + index / 588).unwrap();
+        let vowel = char::from_u32(0x1161 + (index % 588) / 28).unwrap();
+        let trailing = char::from_u32(0x11A7 + index % 28).filter(|_| index % 28 != 0);
+        return [leading, vowel]
+            .into_iter()
+            .chain(trailing)
+            .map(prototype)
+            .collect::<String>()
+            .into();
+    }
+    match CONFUSABLES.binary_search_by_key(&c, |(c, _)| *c) {
+        Ok(i) => Cow::Borrowed(CONFUSABLES[i].1),
+        Err(_) => Cow::Owned(c.to_string()),
+    }
+}
+
+/// The prototype of every character of the string, along with its offset in the skeleton
+fn skeleton_segments(s: &str) -> Vec<(usize, char, Cow<'static, str>)> {
+    let mut offset = 0;
+    s.chars()
+        .map(|c| {
+            let prototype = if is_invisible(c) { Cow::Borrowed("") } else { prototype(c) };
+            let segment = (offset, c, prototype);
+            offset += segment.2.len();
+            segment
+        })
+        .collect()
+}
+
+/// Returns the skeleton of the string as defined by UTS #39: two strings having the same
+/// skeleton look the same and can be confused for one another, like `paypal` written with a
+/// Cyrillic `а`.
+///
+/// Invisible characters are removed and every other character is replaced by the prototypes of
+/// the characters of its NFD form from the Unicode 15.0 confusables data, so that `𝐚𝐝𝐦𝐢𝐧`
+/// and `аdmіn` both have the skeleton of `admin`. Unlike UTS #39, the combining marks of the
+/// skeleton are not put back in their canonical order. The confusables data doesn't map every
+/// compatibility character, like the fullwidth `ｒ`: normalize the strings to NFKC first to
+/// catch them.
+///
+/// Skeletons are only meant to be compared with each other, for example to check that a username
+/// is not confusable with an existing one by storing the skeleton in a unique column.
+pub fn skeleton(s: &str) -> String {
+    skeleton_segments(s).into_iter().map(|(_, _, prototype)| prototype).collect()
+}
+
+/// Returns the positions of the characters matching the predicate, counted in chars
+fn positions(s: &str, predicate: impl Fn(char) -> bool) -> Result<(), Vec<usize>> {
+    let positions =
+        s.chars().enumerate().filter(|(_, c)| predicate(*c)).map(|(i, _)| i).collect::<Vec<_>>();
+    if positions.is_empty() {
+        Ok(())
+    } else {
+        Err(positions)
+    }
+}
+
+/// Validates strings against spoofing attacks, following UTS #39 and UAX #9.
+///
+/// Every validation returns the positions of the offending characters, counted in chars,
+/// when it fails.
+pub trait ValidateUnicodeSecurity {
+    /// Validates that all the characters belong to a single script, where characters like
+    /// digits and punctuation belong to every script, the ones shared by a few scripts like the
+    /// Arabic comma belong to all of them (their Script_Extensions in UAX #24) and Han can be
+    /// mixed with the Japanese and Korean scripts. The characters that are not of the most used
+    /// script are returned.
+    fn validate_single_script(&self) -> Result<(), Vec<usize>> {
+        let Some(s) = self.as_unicode_security_str() else {
+            return Ok(());
+        };
+
+        // The number of characters of each script and the position of the first one, the
+        // characters shared by several scripts like digits and the Arabic comma not counting
+        let mut counts = HashMap::new();
+        for (i, c) in s.chars().enumerate() {
+            let script = Script::of(c);
+            if !matches!(script, Script::Common | Script::Inherited)
+                && find_range(&SCRIPT_EXTENSIONS, c).is_none()
+            {
+                counts.entry(script).or_insert((0, i)).0 += 1;
+            }
+        }
+        // Ties are won by the first script used
+        let main_script = counts
+            .into_iter()
+            .max_by_key(|(_, (count, first))| (*count, Reverse(*first)))
+            .map(|(script, _)| script);
+
+        let mut allowed = main_script.map_or(ScriptSet::ALL, Script::set);
+        let mut positions = Vec::new();
+        for (i, c) in s.chars().enumerate() {
+            let set = allowed.intersection(script_set(c));
+            if set.is_empty() {
+                positions.push(i);
+            } else {
+                allowed = set;
+            }
+        }
+
+        if positions.is_empty() {
+            Ok(())
+        } else {
+            Err(positions)
+        }
+    }
+
+    /// Validates that the string doesn't look the same as any of `others`, by comparing their
+    /// [`skeleton`]. The characters that were replaced to build the skeleton are returned.
+    fn validate_no_confusable_with(&self, others: &[&str]) -> Result<(), Vec<usize>> {
+        let Some(s) = self.as_unicode_security_str() else {
+            return Ok(());
+        };
+
+        let segments = skeleton_segments(&s);
+        let s_skeleton = segments.iter().map(|(_, _, p)| p.as_ref()).collect::<String>();
+        let Some(other) = others.iter().find(|other| skeleton(other) == s_skeleton) else {
+            return Ok(());
+        };
+
+        // The characters that differ from the ones at the same place in the skeleton of the
+        // other string are the ones used to spoof it
+        let other_segments = skeleton_segments(other);
+        let positions = segments
+            .iter()
+            .enumerate()
+            .filter(|(_, (offset, c, prototype))| {
+                prototype.is_empty()
+                    || !other_segments.iter().any(|(o, other_c, _)| o == offset && other_c == c)
+            })
+            .map(|(i, _)| i)
+            .collect();
+        Err(positions)
+    }
+
+    /// Validates that the string doesn't contain bidirectional formatting characters, used in
+    /// Trojan Source attacks to display text in a different order than it is stored
+    fn validate_no_bidi_controls(&self) -> Result<(), Vec<usize>> {
+        match self.as_unicode_security_str() {
+            Some(s) => positions(&s, is_bidi_control),
+            None => Ok(()),
+        }
+    }
+
+    /// Validates that the string doesn't contain invisible characters like zero-width spaces,
+    /// format characters and variation selectors
+    fn validate_no_invisible(&self) -> Result<(), Vec<usize>> {
+        match self.as_unicode_security_str() {
+            Some(s) => positions(&s, is_invisible),
+            None => Ok(()),
+        }
+    }
+
+    fn as_unicode_security_str(&self) -> Option<Cow<'_, str>>;
+}
+
+impl<T> ValidateUnicodeSecurity for &T
+where
+    T: ValidateUnicodeSecurity + ?Sized,
+{
+    fn as_unicode_security_str(&self) -> Option<Cow<'_, str>> {
+        T::as_unicode_security_str(self)
+    }
+}
+
+impl<T> ValidateUnicodeSecurity for Option<T>
+where
+    T: ValidateUnicodeSecurity,
+{
+    fn as_unicode_security_str(&self) -> Option<Cow<'_, str>> {
+        self.as_ref().and_then(T::as_unicode_security_str)
+    }
+}
+
+macro_rules! validate_type_of_str {
+    ($type_:ty) => {
+        impl ValidateUnicodeSecurity for $type_ {
+            fn as_unicode_security_str(&self) -> Option<Cow<'_, str>> {
+                Some(Cow::Borrowed(self.as_ref()))
+            }
+        }
+    };
+}
+
+validate_type_of_str!(str);
+validate_type_of_str!(String);
+validate_type_of_str!(Cow<'_, str>);
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{
+        skeleton, Script, ValidateUnicodeSecurity, CONFUSABLES, SCRIPT_EXTENSIONS, SCRIPT_RANGES,
+    };
+
+    #[test]
+    fn test_tables_are_sorted() {
+        for window in SCRIPT_RANGES.windows(2) {
+            assert!(window[0].0 <= window[0].1, "{:X?}", window[0]);
+            assert!(window[0].1 < window[1].0, "{:X?} {:X?}", window[0], window[1]);
+        }
+        for window in SCRIPT_EXTENSIONS.windows(2) {
+            assert!(window[0].0 <= window[0].1, "{:X?}", window[0]);
+            assert!(window[0].1 < window[1].0, "{:X?} {:X?}", window[0], window[1]);
+        }
+        for window in CONFUSABLES.windows(2) {
+            assert!(window[0].0 < window[1].0, "{:X?} {:X?}", window[0], window[1]);
+        }
+    }
+
+    #[test]
+    fn test_script_of() {
+        let tests = vec![
+            ('a', Script::Latin),
+            ('é', Script::Latin),
+            ('1', Script::Common),
+            ('\u{0301}', Script::Inherited),
+            ('а', Script::Cyrillic),
+            ('α', Script::Greek),
+            ('ש', Script::Hebrew),
+            ('ب', Script::Arabic),
+            ('ก', Script::Thai),
+            ('한', Script::Hangul),
+            ('ひ', Script::Hiragana),
+            ('カ', Script::Katakana),
+            ('漢', Script::Han),
+            ('😀', Script::Common),
+            ('ᠠ', Script::Mongolian),
+            ('\u{1820}', Script::Mongolian),
+            ('\u{1802}', Script::Common),
+            ('ܐ', Script::Syriac),
+            ('ހ', Script::Thaana),
+            ('ក', Script::Khmer),
+            ('ꓮ', Script::Lisu),
+            ('\u{A727}', Script::Latin),
+            ('\u{A720}', Script::Common),
+            ('ꆈ', Script::Yi),
+            ('𐌰', Script::Gothic),
+            ('𝐚', Script::Common),
+            ('\u{2FE0}', Script::Unknown),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(Script::of(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_single_script() {
+        let tests = vec![
+            ("paypal", Ok(())),
+            ("Vincent_Prouillet-42", Ok(())),
+            ("café", Ok(())),
+            ("cafe\u{0301}", Ok(())),
+            ("небо", Ok(())),
+            ("東京タワー", Ok(())),
+            ("ひらがなと漢字", Ok(())),
+            ("한국어와漢字", Ok(())),
+            ("123 !?", Ok(())),
+            ("", Ok(())),
+            ("pаypal", Err(vec![1])),
+            ("аpple", Err(vec![0])),
+            ("небоsky", Err(vec![4, 5, 6])),
+            ("ひらがなと한국어", Err(vec![5, 6, 7])),
+            ("សួស្តី", Ok(())),
+            ("ᠮᠣᠩᠭᠣᠯ", Ok(())),
+            ("ꓮdmin", Err(vec![0])),
+            ("ᠮᠣᠩᠭᠣᠯ mongol", Err(vec![7, 8, 9, 10, 11, 12])),
+            ("𐌰𐌱ꆈ", Err(vec![2])),
+            ("سلام، دنیا", Ok(())),
+            ("ܫܠܡ، ܥܠܡܐ", Ok(())),
+            ("hello، world", Err(vec![5])),
+            ("ー", Ok(())),
+            ("カー", Ok(())),
+            ("aー", Err(vec![1])),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(input.validate_single_script(), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_skeleton() {
+        assert_eq!(skeleton("paypal"), skeleton("pаypal"));
+        assert_eq!(skeleton("paypal"), skeleton("ｐａｙｐａｌ"));
+        assert_eq!(skeleton("paypal"), skeleton("pay\u{200B}pal"));
+        assert_eq!(skeleton("admin"), skeleton("аdmіn"));
+        assert_eq!(skeleton("Il1|"), "llll");
+        assert_eq!(skeleton("modern"), skeleton("rnodern"));
+        assert_eq!(skeleton("admin"), skeleton("𝐚𝐝𝐦𝐢𝐧"));
+        assert_eq!(skeleton("admin"), skeleton("𝕒𝕕𝕞𝕚𝕟"));
+        assert_eq!(skeleton("ADMIN"), skeleton("ꓮꓓꓟꓲꓠ"));
+        assert_eq!(skeleton("file"), skeleton("ﬁle"));
+        assert_eq!(skeleton("BOT"), skeleton("ᏴꓳᎢ"));
+        assert_eq!(skeleton("a-b"), skeleton("a\u{FE58}b"));
+        assert_ne!(skeleton("paypal"), skeleton("PayPal"));
+        assert_ne!(skeleton("paypal"), skeleton("paypai"));
+    }
+
+    #[test]
+    fn test_validate_no_confusable_with() {
+        let reserved = ["admin", "root"];
+        let tests = vec![
+            ("keats", Ok(())),
+            ("administrator", Ok(())),
+            ("admin", Err(vec![])),
+            ("аdmіn", Err(vec![0, 3])),
+            ("adrnin", Err(vec![2, 3])),
+            ("ro\u{200B}ot", Err(vec![2])),
+            ("rｏｏt", Err(vec![1, 2])),
+            // Not every fullwidth letter is in the confusables data
+            ("ｒｏｏｔ", Ok(())),
+            ("𝐚𝐝𝐦𝐢𝐧", Err(vec![0, 1, 2, 3, 4])),
+            ("r𝗼ot", Err(vec![1])),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(input.validate_no_confusable_with(&reserved), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_no_bidi_controls() {
+        let tests = vec![
+            ("hello", Ok(())),
+            ("שלום", Ok(())),
+            ("access\u{202E}\u{2066}level", Err(vec![6, 7])),
+            ("\u{200F}hello", Err(vec![0])),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(input.validate_no_bidi_controls(), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_no_invisible() {
+        let tests = vec![
+            ("hello world", Ok(())),
+            ("hel\u{200B}lo", Err(vec![3])),
+            ("\u{FEFF}hello\u{00AD}", Err(vec![0, 6])),
+            ("a\u{2062}b\u{E0041}", Err(vec![1, 3])),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(input.validate_no_invisible(), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_unicode_security_option_and_cow() {
+        let test: Option<String> = None;
+        assert!(test.validate_single_script().is_ok());
+        let test = Some("pаypal".to_string());
+        assert!(test.validate_single_script().is_err());
+        let test: Cow<'static, str> = "pаypal".into();
+        assert!(test.validate_single_script().is_err());
+        let test: Cow<'static, str> = String::from("paypal").into();
+        assert!(test.validate_single_script().is_ok());
+    }
+}
@@ -0,0 +1,8572 @@
+// Generated by scripts/unicode_security_tables.py from the Unicode 15.0.0 data of ICU 72,
+// do not edit by hand
+
+/// The scripts of UAX #24.
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(super) enum Script {
+    Common,
+    Inherited,
+    Adlam,
+    Ahom,
+    AnatolianHieroglyphs,
+    Arabic,
+    Armenian,
+    Avestan,
+    Balinese,
+    Bamum,
+    BassaVah,
+    Batak,
+    Bengali,
+    Bhaiksuki,
+    Bopomofo,
+    Brahmi,
+    Braille,
+    Buginese,
+    Buhid,
+    CanadianAboriginal,
+    Carian,
+    CaucasianAlbanian,
+    Chakma,
+    Cham,
+    Cherokee,
+    Chorasmian,
+    Coptic,
+    Cuneiform,
+    Cypriot,
+    CyproMinoan,
+    Cyrillic,
+    Deseret,
+    Devanagari,
+    DivesAkuru,
+    Dogra,
+    Duployan,
+    EgyptianHieroglyphs,
+    Elbasan,
+    Elymaic,
+    Ethiopic,
+    Georgian,
+    Glagolitic,
+    Gothic,
+    Grantha,
+    Greek,
+    Gujarati,
+    GunjalaGondi,
+    Gurmukhi,
+    Han,
+    Hangul,
+    HanifiRohingya,
+    Hanunoo,
+    Hatran,
+    Hebrew,
+    Hiragana,
+    ImperialAramaic,
+    InscriptionalPahlavi,
+    InscriptionalParthian,
+    Javanese,
+    Kaithi,
+    Kannada,
+    Katakana,
+    Kawi,
+    KayahLi,
+    Kharoshthi,
+    KhitanSmallScript,
+    Khmer,
+    Khojki,
+    Khudawadi,
+    Lao,
+    Latin,
+    Lepcha,
+    Limbu,
+    LinearA,
+    LinearB,
+    Lisu,
+    Lycian,
+    Lydian,
+    Mahajani,
+    Makasar,
+    Malayalam,
+    Mandaic,
+    Manichaean,
+    Marchen,
+    MasaramGondi,
+    Medefaidrin,
+    MeeteiMayek,
+    MendeKikakui,
+    MeroiticCursive,
+    MeroiticHieroglyphs,
+    Miao,
+    Modi,
+    Mongolian,
+    Mro,
+    Multani,
+    Myanmar,
+    Nabataean,
+    NagMundari,
+    Nandinagari,
+    NewTaiLue,
+    Newa,
+    Nko,
+    Nushu,
+    NyiakengPuachueHmong,
+    Ogham,
+    OlChiki,
+    OldHungarian,
+    OldItalic,
+    OldNorthArabian,
+    OldPermic,
+    OldPersian,
+    OldSogdian,
+    OldSouthArabian,
+    OldTurkic,
+    OldUyghur,
+    Oriya,
+    Osage,
+    Osmanya,
+    PahawhHmong,
+    Palmyrene,
+    PauCinHau,
+    PhagsPa,
+    Phoenician,
+    PsalterPahlavi,
+    Rejang,
+    Runic,
+    Samaritan,
+    Saurashtra,
+    Sharada,
+    Shavian,
+    Siddham,
+    SignWriting,
+    Sinhala,
+    Sogdian,
+    SoraSompeng,
+    Soyombo,
+    Sundanese,
+    SylotiNagri,
+    Syriac,
+    Tagalog,
+    Tagbanwa,
+    TaiLe,
+    TaiTham,
+    TaiViet,
+    Takri,
+    Tamil,
+    Tangsa,
+    Tangut,
+    Telugu,
+    Thaana,
+    Thai,
+    Tibetan,
+    Tifinagh,
+    Tirhuta,
+    Toto,
+    Ugaritic,
+    Vai,
+    Vithkuqi,
+    Wancho,
+    WarangCiti,
+    Yezidi,
+    Yi,
+    ZanabazarSquare,
+    Unknown,
+}
+
+/// Inclusive ranges of code points and their Script property, sorted and without overlaps.
+/// The code points that are not listed are of the `Unknown` script.
+#[rustfmt::skip]
+pub(super) static SCRIPT_RANGES: [(u32, u32, Script); 952] = [
+    (0x0000, 0x0040, Script::Common),
+    (0x0041, 0x005A, Script::Latin),
+    (0x005B, 0x0060, Script::Common),
+    (0x0061, 0x007A, Script::Latin),
+    (0x007B, 0x00A9, Script::Common),
+    (0x00AA, 0x00AA, Script::Latin),
+    (0x00AB, 0x00B9, Script::Common),
+    (0x00BA, 0x00BA, Script::Latin),
+    (0x00BB, 0x00BF, Script::Common),
+    (0x00C0, 0x00D6, Script::Latin),
+    (0x00D7, 0x00D7, Script::Common),
+    (0x00D8, 0x00F6, Script::Latin),
+    (0x00F7, 0x00F7, Script::Common),
+    (0x00F8, 0x02B8, Script::Latin),
+    (0x02B9, 0x02DF, Script::Common),
+    (0x02E0, 0x02E4, Script::Latin),
+    (0x02E5, 0x02E9, Script::Common),
+    (0x02EA, 0x02EB, Script::Bopomofo),
+    (0x02EC, 0x02FF, Script::Common),
+    (0x0300, 0x036F, Script::Inherited),
+    (0x0370, 0x0373, Script::Greek),
+    (0x0374, 0x0374, Script::Common),
+    (0x0375, 0x0377, Script::Greek),
+    (0x037A, 0x037D, Script::Greek),
+    (0x037E, 0x037E, Script::Common),
+    (0x037F, 0x037F, Script::Greek),
+    (0x0384, 0x0384, Script::Greek),
+    (0x0385, 0x0385, Script::Common),
+    (0x0386, 0x0386, Script::Greek),
+    (0x0387, 0x0387, Script::Common),
+    (0x0388, 0x038A, Script::Greek),
+    (0x038C, 0x038C, Script::Greek),
+    (0x038E, 0x03A1, Script::Greek),
+    (0x03A3, 0x03E1, Script::Greek),
+    (0x03E2, 0x03EF, Script::Coptic),
+    (0x03F0, 0x03FF, Script::Greek),
+    (0x0400, 0x0484, Script::Cyrillic),
+    (0x0485, 0x0486, Script::Inherited),
+    (0x0487, 0x052F, Script::Cyrillic),
+    (0x0531, 0x0556, Script::Armenian),
+    (0x0559, 0x058A, Script::Armenian),
+    (0x058D, 0x058F, Script::Armenian),
+    (0x0591, 0x05C7, Script::Hebrew),
+    (0x05D0, 0x05EA, Script::Hebrew),
+    (0x05EF, 0x05F4, Script::Hebrew),
+    (0x0600, 0x0604, Script::Arabic),
+    (0x0605, 0x0605, Script::Common),
+    (0x0606, 0x060B, Script::Arabic),
+    (0x060C, 0x060C, Script::Common),
+    (0x060D, 0x061A, Script::Arabic),
+    (0x061B, 0x061B, Script::Common),
+    (0x061C, 0x061E, Script::Arabic),
+    (0x061F, 0x061F, Script::Common),
+    (0x0620, 0x063F, Script::Arabic),
+    (0x0640, 0x0640, Script::Common),
+    (0x0641, 0x064A, Script::Arabic),
+    (0x064B, 0x0655, Script::Inherited),
+    (0x0656, 0x066F, Script::Arabic),
+    (0x0670, 0x0670, Script::Inherited),
+    (0x0671, 0x06DC, Script::Arabic),
+    (0x06DD, 0x06DD, Script::Common),
+    (0x06DE, 0x06FF, Script::Arabic),
+    (0x0700, 0x070D, Script::Syriac),
+    (0x070F, 0x074A, Script::Syriac),
+    (0x074D, 0x074F, Script::Syriac),
+    (0x0750, 0x077F, Script::Arabic),
+    (0x0780, 0x07B1, Script::Thaana),
+    (0x07C0, 0x07FA, Script::Nko),
+    (0x07FD, 0x07FF, Script::Nko),
+    (0x0800, 0x082D, Script::Samaritan),
+    (0x0830, 0x083E, Script::Samaritan),
+    (0x0840, 0x085B, Script::Mandaic),
+    (0x085E, 0x085E, Script::Mandaic),
+    (0x0860, 0x086A, Script::Syriac),
+    (0x0870, 0x088E, Script::Arabic),
+    (0x0890, 0x0891, Script::Arabic),
+    (0x0898, 0x08E1, Script::Arabic),
+    (0x08E2, 0x08E2, Script::Common),
+    (0x08E3, 0x08FF, Script::Arabic),
+    (0x0900, 0x0950, Script::Devanagari),
+    (0x0951, 0x0954, Script::Inherited),
+    (0x0955, 0x0963, Script::Devanagari),
+    (0x0964, 0x0965, Script::Common),
+    (0x0966, 0x097F, Script::Devanagari),
+    (0x0980, 0x0983, Script::Bengali),
+    (0x0985, 0x098C, Script::Bengali),
+    (0x098F, 0x0990, Script::Bengali),
+    (0x0993, 0x09A8, Script::Bengali),
+    (0x09AA, 0x09B0, Script::Bengali),
+    (0x09B2, 0x09B2, Script::Bengali),
+    (0x09B6, 0x09B9, Script::Bengali),
+    (0x09BC, 0x09C4, Script::Bengali),
+    (0x09C7, 0x09C8, Script::Bengali),
+    (0x09CB, 0x09CE, Script::Bengali),
+    (0x09D7, 0x09D7, Script::Bengali),
+    (0x09DC, 0x09DD, Script::Bengali),
+    (0x09DF, 0x09E3, Script::Bengali),
+    (0x09E6, 0x09FE, Script::Bengali),
+    (0x0A01, 0x0A03, Script::Gurmukhi),
+    (0x0A05, 0x0A0A, Script::Gurmukhi),
+    (0x0A0F, 0x0A10, Script::Gurmukhi),
+    (0x0A13, 0x0A28, Script::Gurmukhi),
+    (0x0A2A, 0x0A30, Script::Gurmukhi),
+    (0x0A32, 0x0A33, Script::Gurmukhi),
+    (0x0A35, 0x0A36, Script::Gurmukhi),
+    (0x0A38, 0x0A39, Script::Gurmukhi),
+    (0x0A3C, 0x0A3C, Script::Gurmukhi),
+    (0x0A3E, 0x0A42, Script::Gurmukhi),
+    (0x0A47, 0x0A48, Script::Gurmukhi),
+    (0x0A4B, 0x0A4D, Script::Gurmukhi),
+    (0x0A51, 0x0A51, Script::Gurmukhi),
+    (0x0A59, 0x0A5C, Script::Gurmukhi),
+    (0x0A5E, 0x0A5E, Script::Gurmukhi),
+    (0x0A66, 0x0A76, Script::Gurmukhi),
+    (0x0A81, 0x0A83, Script::Gujarati),
+    (0x0A85, 0x0A8D, Script::Gujarati),
+    (0x0A8F, 0x0A91, Script::Gujarati),
+    (0x0A93, 0x0AA8, Script::Gujarati),
+    (0x0AAA, 0x0AB0, Script::Gujarati),
+    (0x0AB2, 0x0AB3, Script::Gujarati),
+    (0x0AB5, 0x0AB9, Script::Gujarati),
+    (0x0ABC, 0x0AC5, Script::Gujarati),
+    (0x0AC7, 0x0AC9, Script::Gujarati),
+    (0x0ACB, 0x0ACD, Script::Gujarati),
+    (0x0AD0, 0x0AD0, Script::Gujarati),
+    (0x0AE0, 0x0AE3, Script::Gujarati),
+    (0x0AE6, 0x0AF1, Script::Gujarati),
+    (0x0AF9, 0x0AFF, Script::Gujarati),
+    (0x0B01, 0x0B03, Script::Oriya),
+    (0x0B05, 0x0B0C, Script::Oriya),
+    (0x0B0F, 0x0B10, Script::Oriya),
+    (0x0B13, 0x0B28, Script::Oriya),
+    (0x0B2A, 0x0B30, Script::Oriya),
+    (0x0B32, 0x0B33, Script::Oriya),
+    (0x0B35, 0x0B39, Script::Oriya),
+    (0x0B3C, 0x0B44, Script::Oriya),
+    (0x0B47, 0x0B48, Script::Oriya),
+    (0x0B4B, 0x0B4D, Script::Oriya),
+    (0x0B55, 0x0B57, Script::Oriya),
+    (0x0B5C, 0x0B5D, Script::Oriya),
+    (0x0B5F, 0x0B63, Script::Oriya),
+    (0x0B66, 0x0B77, Script::Oriya),
+    (0x0B82, 0x0B83, Script::Tamil),
+    (0x0B85, 0x0B8A, Script::Tamil),
+    (0x0B8E, 0x0B90, Script::Tamil),
+    (0x0B92, 0x0B95, Script::Tamil),
+    (0x0B99, 0x0B9A, Script::Tamil),
+    (0x0B9C, 0x0B9C, Script::Tamil),
+    (0x0B9E, 0x0B9F, Script::Tamil),
+    (0x0BA3, 0x0BA4, Script::Tamil),
+    (0x0BA8, 0x0BAA, Script::Tamil),
+    (0x0BAE, 0x0BB9, Script::Tamil),
+    (0x0BBE, 0x0BC2, Script::Tamil),
+    (0x0BC6, 0x0BC8, Script::Tamil),
+    (0x0BCA, 0x0BCD, Script::Tamil),
+    (0x0BD0, 0x0BD0, Script::Tamil),
+    (0x0BD7, 0x0BD7, Script::Tamil),
+    (0x0BE6, 0x0BFA, Script::Tamil),
+    (0x0C00, 0x0C0C, Script::Telugu),
+    (0x0C0E, 0x0C10, Script::Telugu),
+    (0x0C12, 0x0C28, Script::Telugu),
+    (0x0C2A, 0x0C39, Script::Telugu),
+    (0x0C3C, 0x0C44, Script::Telugu),
+    (0x0C46, 0x0C48, Script::Telugu),
+    (0x0C4A, 0x0C4D, Script::Telugu),
+    (0x0C55, 0x0C56, Script::Telugu),
+    (0x0C58, 0x0C5A, Script::Telugu),
+    (0x0C5D, 0x0C5D, Script::Telugu),
+    (0x0C60, 0x0C63, Script::Telugu),
+    (0x0C66, 0x0C6F, Script::Telugu),
+    (0x0C77, 0x0C7F, Script::Telugu),
+    (0x0C80, 0x0C8C, Script::Kannada),
+    (0x0C8E, 0x0C90, Script::Kannada),
+    (0x0C92, 0x0CA8, Script::Kannada),
+    (0x0CAA, 0x0CB3, Script::Kannada),
+    (0x0CB5, 0x0CB9, Script::Kannada),
+    (0x0CBC, 0x0CC4, Script::Kannada),
+    (0x0CC6, 0x0CC8, Script::Kannada),
+    (0x0CCA, 0x0CCD, Script::Kannada),
+    (0x0CD5, 0x0CD6, Script::Kannada),
+    (0x0CDD, 0x0CDE, Script::Kannada),
+    (0x0CE0, 0x0CE3, Script::Kannada),
+    (0x0CE6, 0x0CEF, Script::Kannada),
+    (0x0CF1, 0x0CF3, Script::Kannada),
+    (0x0D00, 0x0D0C, Script::Malayalam),
+    (0x0D0E, 0x0D10, Script::Malayalam),
+    (0x0D12, 0x0D44, Script::Malayalam),
+    (0x0D46, 0x0D48, Script::Malayalam),
+    (0x0D4A, 0x0D4F, Script::Malayalam),
+    (0x0D54, 0x0D63, Script::Malayalam),
+    (0x0D66, 0x0D7F, Script::Malayalam),
+    (0x0D81, 0x0D83, Script::Sinhala),
+    (0x0D85, 0x0D96, Script::Sinhala),
+    (0x0D9A, 0x0DB1, Script::Sinhala),
+    (0x0DB3, 0x0DBB, Script::Sinhala),
+    (0x0DBD, 0x0DBD, Script::Sinhala),
+    (0x0DC0, 0x0DC6, Script::Sinhala),
+    (0x0DCA, 0x0DCA, Script::Sinhala),
+    (0x0DCF, 0x0DD4, Script::Sinhala),
+    (0x0DD6, 0x0DD6, Script::Sinhala),
+    (0x0DD8, 0x0DDF, Script::Sinhala),
+    (0x0DE6, 0x0DEF, Script::Sinhala),
+    (0x0DF2, 0x0DF4, Script::Sinhala),
+    (0x0E01, 0x0E3A, Script::Thai),
+    (0x0E3F, 0x0E3F, Script::Common),
+    (0x0E40, 0x0E5B, Script::Thai),
+    (0x0E81, 0x0E82, Script::Lao),
+    (0x0E84, 0x0E84, Script::Lao),
+    (0x0E86, 0x0E8A, Script::Lao),
+    (0x0E8C, 0x0EA3, Script::Lao),
+    (0x0EA5, 0x0EA5, Script::Lao),
+    (0x0EA7, 0x0EBD, Script::Lao),
+    (0x0EC0, 0x0EC4, Script::Lao),
+    (0x0EC6, 0x0EC6, Script::Lao),
+    (0x0EC8, 0x0ECE, Script::Lao),
+    (0x0ED0, 0x0ED9, Script::Lao),
+    (0x0EDC, 0x0EDF, Script::Lao),
+    (0x0F00, 0x0F47, Script::Tibetan),
+    (0x0F49, 0x0F6C, Script::Tibetan),
+    (0x0F71, 0x0F97, Script::Tibetan),
+    (0x0F99, 0x0FBC, Script::Tibetan),
+    (0x0FBE, 0x0FCC, Script::Tibetan),
+    (0x0FCE, 0x0FD4, Script::Tibetan),
+    (0x0FD5, 0x0FD8, Script::Common),
+    (0x0FD9, 0x0FDA, Script::Tibetan),
+    (0x1000, 0x109F, Script::Myanmar),
+    (0x10A0, 0x10C5, Script::Georgian),
+    (0x10C7, 0x10C7, Script::Georgian),
+    (0x10CD, 0x10CD, Script::Georgian),
+    (0x10D0, 0x10FA, Script::Georgian),
+    (0x10FB, 0x10FB, Script::Common),
+    (0x10FC, 0x10FF, Script::Georgian),
+    (0x1100, 0x11FF, Script::Hangul),
+    (0x1200, 0x1248, Script::Ethiopic),
+    (0x124A, 0x124D, Script::Ethiopic),
+    (0x1250, 0x1256, Script::Ethiopic),
+    (0x1258, 0x1258, Script::Ethiopic),
+    (0x125A, 0x125D, Script::Ethiopic),
+    (0x1260, 0x1288, Script::Ethiopic),
+    (0x128A, 0x128D, Script::Ethiopic),
+    (0x1290, 0x12B0, Script::Ethiopic),
+    (0x12B2, 0x12B5, Script::Ethiopic),
+    (0x12B8, 0x12BE, Script::Ethiopic),
+    (0x12C0, 0x12C0, Script::Ethiopic),
+    (0x12C2, 0x12C5, Script::Ethiopic),
+    (0x12C8, 0x12D6, Script::Ethiopic),
+    (0x12D8, 0x1310, Script::Ethiopic),
+    (0x1312, 0x1315, Script::Ethiopic),
+    (0x1318, 0x135A, Script::Ethiopic),
+    (0x135D, 0x137C, Script::Ethiopic),
+    (0x1380, 0x1399, Script::Ethiopic),
+    (0x13A0, 0x13F5, Script::Cherokee),
+    (0x13F8, 0x13FD, Script::Cherokee),
+    (0x1400, 0x167F, Script::CanadianAboriginal),
+    (0x1680, 0x169C, Script::Ogham),
+    (0x16A0, 0x16EA, Script::Runic),
+    (0x16EB, 0x16ED, Script::Common),
+    (0x16EE, 0x16F8, Script::Runic),
+    (0x1700, 0x1715, Script::Tagalog),
+    (0x171F, 0x171F, Script::Tagalog),
+    (0x1720, 0x1734, Script::Hanunoo),
+    (0x1735, 0x1736, Script::Common),
+    (0x1740, 0x1753, Script::Buhid),
+    (0x1760, 0x176C, Script::Tagbanwa),
+    (0x176E, 0x1770, Script::Tagbanwa),
+    (0x1772, 0x1773, Script::Tagbanwa),
+    (0x1780, 0x17DD, Script::Khmer),
+    (0x17E0, 0x17E9, Script::Khmer),
+    (0x17F0, 0x17F9, Script::Khmer),
+    (0x1800, 0x1801, Script::Mongolian),
+    (0x1802, 0x1803, Script::Common),
+    (0x1804, 0x1804, Script::Mongolian),
+    (0x1805, 0x1805, Script::Common),
+    (0x1806, 0x1819, Script::Mongolian),
+    (0x1820, 0x1878, Script::Mongolian),
+    (0x1880, 0x18AA, Script::Mongolian),
+    (0x18B0, 0x18F5, Script::CanadianAboriginal),
+    (0x1900, 0x191E, Script::Limbu),
+    (0x1920, 0x192B, Script::Limbu),
+    (0x1930, 0x193B, Script::Limbu),
+    (0x1940, 0x1940, Script::Limbu),
+    (0x1944, 0x194F, Script::Limbu),
+    (0x1950, 0x196D, Script::TaiLe),
+    (0x1970, 0x1974, Script::TaiLe),
+    (0x1980, 0x19AB, Script::NewTaiLue),
+    (0x19B0, 0x19C9, Script::NewTaiLue),
+    (0x19D0, 0x19DA, Script::NewTaiLue),
+    (0x19DE, 0x19DF, Script::NewTaiLue),
+    (0x19E0, 0x19FF, Script::Khmer),
+    (0x1A00, 0x1A1B, Script::Buginese),
+    (0x1A1E, 0x1A1F, Script::Buginese),
+    (0x1A20, 0x1A5E, Script::TaiTham),
+    (0x1A60, 0x1A7C, Script::TaiTham),
+    (0x1A7F, 0x1A89, Script::TaiTham),
+    (0x1A90, 0x1A99, Script::TaiTham),
+    (0x1AA0, 0x1AAD, Script::TaiTham),
+    (0x1AB0, 0x1ACE, Script::Inherited),
+    (0x1B00, 0x1B4C, Script::Balinese),
+    (0x1B50, 0x1B7E, Script::Balinese),
+    (0x1B80, 0x1BBF, Script::Sundanese),
+    (0x1BC0, 0x1BF3, Script::Batak),
+    (0x1BFC, 0x1BFF, Script::Batak),
+    (0x1C00, 0x1C37, Script::Lepcha),
+    (0x1C3B, 0x1C49, Script::Lepcha),
+    (0x1C4D, 0x1C4F, Script::Lepcha),
+    (0x1C50, 0x1C7F, Script::OlChiki),
+    (0x1C80, 0x1C88, Script::Cyrillic),
+    (0x1C90, 0x1CBA, Script::Georgian),
+    (0x1CBD, 0x1CBF, Script::Georgian),
+    (0x1CC0, 0x1CC7, Script::Sundanese),
+    (0x1CD0, 0x1CD2, Script::Inherited),
+    (0x1CD3, 0x1CD3, Script::Common),
+    (0x1CD4, 0x1CE0, Script::Inherited),
+    (0x1CE1, 0x1CE1, Script::Common),
+    (0x1CE2, 0x1CE8, Script::Inherited),
+    (0x1CE9, 0x1CEC, Script::Common),
+    (0x1CED, 0x1CED, Script::Inherited),
+    (0x1CEE, 0x1CF3, Script::Common),
+    (0x1CF4, 0x1CF4, Script::Inherited),
+    (0x1CF5, 0x1CF7, Script::Common),
+    (0x1CF8, 0x1CF9, Script::Inherited),
+    (0x1CFA, 0x1CFA, Script::Common),
+    (0x1D00, 0x1D25, Script::Latin),
+    (0x1D26, 0x1D2A, Script::Greek),
+    (0x1D2B, 0x1D2B, Script::Cyrillic),
+    (0x1D2C, 0x1D5C, Script::Latin),
+    (0x1D5D, 0x1D61, Script::Greek),
+    (0x1D62, 0x1D65, Script::Latin),
+    (0x1D66, 0x1D6A, Script::Greek),
+    (0x1D6B, 0x1D77, Script::Latin),
+    (0x1D78, 0x1D78, Script::Cyrillic),
+    (0x1D79, 0x1DBE, Script::Latin),
+    (0x1DBF, 0x1DBF, Script::Greek),
+    (0x1DC0, 0x1DFF, Script::Inherited),
+    (0x1E00, 0x1EFF, Script::Latin),
+    (0x1F00, 0x1F15, Script::Greek),
+    (0x1F18, 0x1F1D, Script::Greek),
+    (0x1F20, 0x1F45, Script::Greek),
+    (0x1F48, 0x1F4D, Script::Greek),
+    (0x1F50, 0x1F57, Script::Greek),
+    (0x1F59, 0x1F59, Script::Greek),
+    (0x1F5B, 0x1F5B, Script::Greek),
+    (0x1F5D, 0x1F5D, Script::Greek),
+    (0x1F5F, 0x1F7D, Script::Greek),
+    (0x1F80, 0x1FB4, Script::Greek),
+    (0x1FB6, 0x1FC4, Script::Greek),
+    (0x1FC6, 0x1FD3, Script::Greek),
+    (0x1FD6, 0x1FDB, Script::Greek),
+    (0x1FDD, 0x1FEF, Script::Greek),
+    (0x1FF2, 0x1FF4, Script::Greek),
+    (0x1FF6, 0x1FFE, Script::Greek),
+    (0x2000, 0x200B, Script::Common),
+    (0x200C, 0x200D, Script::Inherited),
+    (0x200E, 0x2064, Script::Common),
+    (0x2066, 0x2070, Script::Common),
+    (0x2071, 0x2071, Script::Latin),
+    (0x2074, 0x207E, Script::Common),
+    (0x207F, 0x207F, Script::Latin),
+    (0x2080, 0x208E, Script::Common),
+    (0x2090, 0x209C, Script::Latin),
+    (0x20A0, 0x20C0, Script::Common),
+    (0x20D0, 0x20F0, Script::Inherited),
+    (0x2100, 0x2125, Script::Common),
+    (0x2126, 0x2126, Script::Greek),
+    (0x2127, 0x2129, Script::Common),
+    (0x212A, 0x212B, Script::Latin),
+    (0x212C, 0x2131, Script::Common),
+    (0x2132, 0x2132, Script::Latin),
+    (0x2133, 0x214D, Script::Common),
+    (0x214E, 0x214E, Script::Latin),
+    (0x214F, 0x215F, Script::Common),
+    (0x2160, 0x2188, Script::Latin),
+    (0x2189, 0x218B, Script::Common),
+    (0x2190, 0x2426, Script::Common),
+    (0x2440, 0x244A, Script::Common),
+    (0x2460, 0x27FF, Script::Common),
+    (0x2800, 0x28FF, Script::Braille),
+    (0x2900, 0x2B73, Script::Common),
+    (0x2B76, 0x2B95, Script::Common),
+    (0x2B97, 0x2BFF, Script::Common),
+    (0x2C00, 0x2C5F, Script::Glagolitic),
+    (0x2C60, 0x2C7F, Script::Latin),
+    (0x2C80, 0x2CF3, Script::Coptic),
+    (0x2CF9, 0x2CFF, Script::Coptic),
+    (0x2D00, 0x2D25, Script::Georgian),
+    (0x2D27, 0x2D27, Script::Georgian),
+    (0x2D2D, 0x2D2D, Script::Georgian),
+    (0x2D30, 0x2D67, Script::Tifinagh),
+    (0x2D6F, 0x2D70, Script::Tifinagh),
+    (0x2D7F, 0x2D7F, Script::Tifinagh),
+    (0x2D80, 0x2D96, Script::Ethiopic),
+    (0x2DA0, 0x2DA6, Script::Ethiopic),
+    (0x2DA8, 0x2DAE, Script::Ethiopic),
+    (0x2DB0, 0x2DB6, Script::Ethiopic),
+    (0x2DB8, 0x2DBE, Script::Ethiopic),
+    (0x2DC0, 0x2DC6, Script::Ethiopic),
+    (0x2DC8, 0x2DCE, Script::Ethiopic),
+    (0x2DD0, 0x2DD6, Script::Ethiopic),
+    (0x2DD8, 0x2DDE, Script::Ethiopic),
+    (0x2DE0, 0x2DFF, Script::Cyrillic),
+    (0x2E00, 0x2E5D, Script::Common),
+    (0x2E80, 0x2E99, Script::Han),
+    (0x2E9B, 0x2EF3, Script::Han),
+    (0x2F00, 0x2FD5, Script::Han),
+    (0x2FF0, 0x2FFB, Script::Common),
+    (0x3000, 0x3004, Script::Common),
+    (0x3005, 0x3005, Script::Han),
+    (0x3006, 0x3006, Script::Common),
+    (0x3007, 0x3007, Script::Han),
+    (0x3008, 0x3020, Script::Common),
+    (0x3021, 0x3029, Script::Han),
+    (0x302A, 0x302D, Script::Inherited),
+    (0x302E, 0x302F, Script::Hangul),
+    (0x3030, 0x3037, Script::Common),
+    (0x3038, 0x303B, Script::Han),
+    (0x303C, 0x303F, Script::Common),
+    (0x3041, 0x3096, Script::Hiragana),
+    (0x3099, 0x309A, Script::Inherited),
+    (0x309B, 0x309C, Script::Common),
+    (0x309D, 0x309F, Script::Hiragana),
+    (0x30A0, 0x30A0, Script::Common),
+    (0x30A1, 0x30FA, Script::Katakana),
+    (0x30FB, 0x30FC, Script::Common),
+    (0x30FD, 0x30FF, Script::Katakana),
+    (0x3105, 0x312F, Script::Bopomofo),
+    (0x3131, 0x318E, Script::Hangul),
+    (0x3190, 0x319F, Script::Common),
+    (0x31A0, 0x31BF, Script::Bopomofo),
+    (0x31C0, 0x31E3, Script::Common),
+    (0x31F0, 0x31FF, Script::Katakana),
+    (0x3200, 0x321E, Script::Hangul),
+    (0x3220, 0x325F, Script::Common),
+    (0x3260, 0x327E, Script::Hangul),
+    (0x327F, 0x32CF, Script::Common),
+    (0x32D0, 0x32FE, Script::Katakana),
+    (0x32FF, 0x32FF, Script::Common),
+    (0x3300, 0x3357, Script::Katakana),
+    (0x3358, 0x33FF, Script::Common),
+    (0x3400, 0x4DBF, Script::Han),
+    (0x4DC0, 0x4DFF, Script::Common),
+    (0x4E00, 0x9FFF, Script::Han),
+    (0xA000, 0xA48C, Script::Yi),
+    (0xA490, 0xA4C6, Script::Yi),
+    (0xA4D0, 0xA4FF, Script::Lisu),
+    (0xA500, 0xA62B, Script::Vai),
+    (0xA640, 0xA69F, Script::Cyrillic),
+    (0xA6A0, 0xA6F7, Script::Bamum),
+    (0xA700, 0xA721, Script::Common),
+    (0xA722, 0xA787, Script::Latin),
+    (0xA788, 0xA78A, Script::Common),
+    (0xA78B, 0xA7CA, Script::Latin),
+    (0xA7D0, 0xA7D1, Script::Latin),
+    (0xA7D3, 0xA7D3, Script::Latin),
+    (0xA7D5, 0xA7D9, Script::Latin),
+    (0xA7F2, 0xA7FF, Script::Latin),
+    (0xA800, 0xA82C, Script::SylotiNagri),
+    (0xA830, 0xA839, Script::Common),
+    (0xA840, 0xA877, Script::PhagsPa),
+    (0xA880, 0xA8C5, Script::Saurashtra),
+    (0xA8CE, 0xA8D9, Script::Saurashtra),
+    (0xA8E0, 0xA8FF, Script::Devanagari),
+    (0xA900, 0xA92D, Script::KayahLi),
+    (0xA92E, 0xA92E, Script::Common),
+    (0xA92F, 0xA92F, Script::KayahLi),
+    (0xA930, 0xA953, Script::Rejang),
+    (0xA95F, 0xA95F, Script::Rejang),
+    (0xA960, 0xA97C, Script::Hangul),
+    (0xA980, 0xA9CD, Script::Javanese),
+    (0xA9CF, 0xA9CF, Script::Common),
+    (0xA9D0, 0xA9D9, Script::Javanese),
+    (0xA9DE, 0xA9DF, Script::Javanese),
+    (0xA9E0, 0xA9FE, Script::Myanmar),
+    (0xAA00, 0xAA36, Script::Cham),
+    (0xAA40, 0xAA4D, Script::Cham),
+    (0xAA50, 0xAA59, Script::Cham),
+    (0xAA5C, 0xAA5F, Script::Cham),
+    (0xAA60, 0xAA7F, Script::Myanmar),
+    (0xAA80, 0xAAC2, Script::TaiViet),
+    (0xAADB, 0xAADF, Script::TaiViet),
+    (0xAAE0, 0xAAF6, Script::MeeteiMayek),
+    (0xAB01, 0xAB06, Script::Ethiopic),
+    (0xAB09, 0xAB0E, Script::Ethiopic),
+    (0xAB11, 0xAB16, Script::Ethiopic),
+    (0xAB20, 0xAB26, Script::Ethiopic),
+    (0xAB28, 0xAB2E, Script::Ethiopic),
+    (0xAB30, 0xAB5A, Script::Latin),
+    (0xAB5B, 0xAB5B, Script::Common),
+    (0xAB5C, 0xAB64, Script::Latin),
+    (0xAB65, 0xAB65, Script::Greek),
+    (0xAB66, 0xAB69, Script::Latin),
+    (0xAB6A, 0xAB6B, Script::Common),
+    (0xAB70, 0xABBF, Script::Cherokee),
+    (0xABC0, 0xABED, Script::MeeteiMayek),
+    (0xABF0, 0xABF9, Script::MeeteiMayek),
+    (0xAC00, 0xD7A3, Script::Hangul),
+    (0xD7B0, 0xD7C6, Script::Hangul),
+    (0xD7CB, 0xD7FB, Script::Hangul),
+    (0xF900, 0xFA6D, Script::Han),
+    (0xFA70, 0xFAD9, Script::Han),
+    (0xFB00, 0xFB06, Script::Latin),
+    (0xFB13, 0xFB17, Script::Armenian),
+    (0xFB1D, 0xFB36, Script::Hebrew),
+    (0xFB38, 0xFB3C, Script::Hebrew),
+    (0xFB3E, 0xFB3E, Script::Hebrew),
+    (0xFB40, 0xFB41, Script::Hebrew),
+    (0xFB43, 0xFB44, Script::Hebrew),
+    (0xFB46, 0xFB4F, Script::Hebrew),
+    (0xFB50, 0xFBC2, Script::Arabic),
+    (0xFBD3, 0xFD3D, Script::Arabic),
+    (0xFD3E, 0xFD3F, Script::Common),
+    (0xFD40, 0xFD8F, Script::Arabic),
+    (0xFD92, 0xFDC7, Script::Arabic),
+    (0xFDCF, 0xFDCF, Script::Arabic),
+    (0xFDF0, 0xFDFF, Script::Arabic),
+    (0xFE00, 0xFE0F, Script::Inherited),
+    (0xFE10, 0xFE19, Script::Common),
+    (0xFE20, 0xFE2D, Script::Inherited),
+    (0xFE2E, 0xFE2F, Script::Cyrillic),
+    (0xFE30, 0xFE52, Script::Common),
+    (0xFE54, 0xFE66, Script::Common),
+    (0xFE68, 0xFE6B, Script::Common),
+    (0xFE70, 0xFE74, Script::Arabic),
+    (0xFE76, 0xFEFC, Script::Arabic),
+    (0xFEFF, 0xFEFF, Script::Common),
+    (0xFF01, 0xFF20, Script::Common),
+    (0xFF21, 0xFF3A, Script::Latin),
+    (0xFF3B, 0xFF40, Script::Common),
+    (0xFF41, 0xFF5A, Script::Latin),
+    (0xFF5B, 0xFF65, Script::Common),
+    (0xFF66, 0xFF6F, Script::Katakana),
+    (0xFF70, 0xFF70, Script::Common),
+    (0xFF71, 0xFF9D, Script::Katakana),
+    (0xFF9E, 0xFF9F, Script::Common),
+    (0xFFA0, 0xFFBE, Script::Hangul),
+    (0xFFC2, 0xFFC7, Script::Hangul),
+    (0xFFCA, 0xFFCF, Script::Hangul),
+    (0xFFD2, 0xFFD7, Script::Hangul),
+    (0xFFDA, 0xFFDC, Script::Hangul),
+    (0xFFE0, 0xFFE6, Script::Common),
+    (0xFFE8, 0xFFEE, Script::Common),
+    (0xFFF9, 0xFFFD, Script::Common),
+    (0x10000, 0x1000B, Script::LinearB),
+    (0x1000D, 0x10026, Script::LinearB),
+    (0x10028, 0x1003A, Script::LinearB),
+    (0x1003C, 0x1003D, Script::LinearB),
+    (0x1003F, 0x1004D, Script::LinearB),
+    (0x10050, 0x1005D, Script::LinearB),
+    (0x10080, 0x100FA, Script::LinearB),
+    (0x10100, 0x10102, Script::Common),
+    (0x10107, 0x10133, Script::Common),
+    (0x10137, 0x1013F, Script::Common),
+    (0x10140, 0x1018E, Script::Greek),
+    (0x10190, 0x1019C, Script::Common),
+    (0x101A0, 0x101A0, Script::Greek),
+    (0x101D0, 0x101FC, Script::Common),
+    (0x101FD, 0x101FD, Script::Inherited),
+    (0x10280, 0x1029C, Script::Lycian),
+    (0x102A0, 0x102D0, Script::Carian),
+    (0x102E0, 0x102E0, Script::Inherited),
+    (0x102E1, 0x102FB, Script::Common),
+    (0x10300, 0x10323, Script::OldItalic),
+    (0x1032D, 0x1032F, Script::OldItalic),
+    (0x10330, 0x1034A, Script::Gothic),
+    (0x10350, 0x1037A, Script::OldPermic),
+    (0x10380, 0x1039D, Script::Ugaritic),
+    (0x1039F, 0x1039F, Script::Ugaritic),
+    (0x103A0, 0x103C3, Script::OldPersian),
+    (0x103C8, 0x103D5, Script::OldPersian),
+    (0x10400, 0x1044F, Script::Deseret),
+    (0x10450, 0x1047F, Script::Shavian),
+    (0x10480, 0x1049D, Script::Osmanya),
+    (0x104A0, 0x104A9, Script::Osmanya),
+    (0x104B0, 0x104D3, Script::Osage),
+    (0x104D8, 0x104FB, Script::Osage),
+    (0x10500, 0x10527, Script::Elbasan),
+    (0x10530, 0x10563, Script::CaucasianAlbanian),
+    (0x1056F, 0x1056F, Script::CaucasianAlbanian),
+    (0x10570, 0x1057A, Script::Vithkuqi),
+    (0x1057C, 0x1058A, Script::Vithkuqi),
+    (0x1058C, 0x10592, Script::Vithkuqi),
+    (0x10594, 0x10595, Script::Vithkuqi),
+    (0x10597, 0x105A1, Script::Vithkuqi),
+    (0x105A3, 0x105B1, Script::Vithkuqi),
+    (0x105B3, 0x105B9, Script::Vithkuqi),
+    (0x105BB, 0x105BC, Script::Vithkuqi),
+    (0x10600, 0x10736, Script::LinearA),
+    (0x10740, 0x10755, Script::LinearA),
+    (0x10760, 0x10767, Script::LinearA),
+    (0x10780, 0x10785, Script::Latin),
+    (0x10787, 0x107B0, Script::Latin),
+    (0x107B2, 0x107BA, Script::Latin),
+    (0x10800, 0x10805, Script::Cypriot),
+    (0x10808, 0x10808, Script::Cypriot),
+    (0x1080A, 0x10835, Script::Cypriot),
+    (0x10837, 0x10838, Script::Cypriot),
+    (0x1083C, 0x1083C, Script::Cypriot),
+    (0x1083F, 0x1083F, Script::Cypriot),
+    (0x10840, 0x10855, Script::ImperialAramaic),
+    (0x10857, 0x1085F, Script::ImperialAramaic),
+    (0x10860, 0x1087F, Script::Palmyrene),
+    (0x10880, 0x1089E, Script::Nabataean),
+    (0x108A7, 0x108AF, Script::Nabataean),
+    (0x108E0, 0x108F2, Script::Hatran),
+    (0x108F4, 0x108F5, Script::Hatran),
+    (0x108FB, 0x108FF, Script::Hatran),
+    (0x10900, 0x1091B, Script::Phoenician),
+    (0x1091F, 0x1091F, Script::Phoenician),
+    (0x10920, 0x10939, Script::Lydian),
+    (0x1093F, 0x1093F, Script::Lydian),
+    (0x10980, 0x1099F, Script::MeroiticHieroglyphs),
+    (0x109A0, 0x109B7, Script::MeroiticCursive),
+    (0x109BC, 0x109CF, Script::MeroiticCursive),
+    (0x109D2, 0x109FF, Script::MeroiticCursive),
+    (0x10A00, 0x10A03, Script::Kharoshthi),
+    (0x10A05, 0x10A06, Script::Kharoshthi),
+    (0x10A0C, 0x10A13, Script::Kharoshthi),
+    (0x10A15, 0x10A17, Script::Kharoshthi),
+    (0x10A19, 0x10A35, Script::Kharoshthi),
+    (0x10A38, 0x10A3A, Script::Kharoshthi),
+    (0x10A3F, 0x10A48, Script::Kharoshthi),
+    (0x10A50, 0x10A58, Script::Kharoshthi),
+    (0x10A60, 0x10A7F, Script::OldSouthArabian),
+    (0x10A80, 0x10A9F, Script::OldNorthArabian),
+    (0x10AC0, 0x10AE6, Script::Manichaean),
+    (0x10AEB, 0x10AF6, Script::Manichaean),
+    (0x10B00, 0x10B35, Script::Avestan),
+    (0x10B39, 0x10B3F, Script::Avestan),
+    (0x10B40, 0x10B55, Script::InscriptionalParthian),
+    (0x10B58, 0x10B5F, Script::InscriptionalParthian),
+    (0x10B60, 0x10B72, Script::InscriptionalPahlavi),
+    (0x10B78, 0x10B7F, Script::InscriptionalPahlavi),
+    (0x10B80, 0x10B91, Script::PsalterPahlavi),
+    (0x10B99, 0x10B9C, Script::PsalterPahlavi),
+    (0x10BA9, 0x10BAF, Script::PsalterPahlavi),
+    (0x10C00, 0x10C48, Script::OldTurkic),
+    (0x10C80, 0x10CB2, Script::OldHungarian),
+    (0x10CC0, 0x10CF2, Script::OldHungarian),
+    (0x10CFA, 0x10CFF, Script::OldHungarian),
+    (0x10D00, 0x10D27, Script::HanifiRohingya),
+    (0x10D30, 0x10D39, Script::HanifiRohingya),
+    (0x10E60, 0x10E7E, Script::Arabic),
+    (0x10E80, 0x10EA9, Script::Yezidi),
+    (0x10EAB, 0x10EAD, Script::Yezidi),
+    (0x10EB0, 0x10EB1, Script::Yezidi),
+    (0x10EFD, 0x10EFF, Script::Arabic),
+    (0x10F00, 0x10F27, Script::OldSogdian),
+    (0x10F30, 0x10F59, Script::Sogdian),
+    (0x10F70, 0x10F89, Script::OldUyghur),
+    (0x10FB0, 0x10FCB, Script::Chorasmian),
+    (0x10FE0, 0x10FF6, Script::Elymaic),
+    (0x11000, 0x1104D, Script::Brahmi),
+    (0x11052, 0x11075, Script::Brahmi),
+    (0x1107F, 0x1107F, Script::Brahmi),
+    (0x11080, 0x110C2, Script::Kaithi),
+    (0x110CD, 0x110CD, Script::Kaithi),
+    (0x110D0, 0x110E8, Script::SoraSompeng),
+    (0x110F0, 0x110F9, Script::SoraSompeng),
+    (0x11100, 0x11134, Script::Chakma),
+    (0x11136, 0x11147, Script::Chakma),
+    (0x11150, 0x11176, Script::Mahajani),
+    (0x11180, 0x111DF, Script::Sharada),
+    (0x111E1, 0x111F4, Script::Sinhala),
+    (0x11200, 0x11211, Script::Khojki),
+    (0x11213, 0x11241, Script::Khojki),
+    (0x11280, 0x11286, Script::Multani),
+    (0x11288, 0x11288, Script::Multani),
+    (0x1128A, 0x1128D, Script::Multani),
+    (0x1128F, 0x1129D, Script::Multani),
+    (0x1129F, 0x112A9, Script::Multani),
+    (0x112B0, 0x112EA, Script::Khudawadi),
+    (0x112F0, 0x112F9, Script::Khudawadi),
+    (0x11300, 0x11303, Script::Grantha),
+    (0x11305, 0x1130C, Script::Grantha),
+    (0x1130F, 0x11310, Script::Grantha),
+    (0x11313, 0x11328, Script::Grantha),
+    (0x1132A, 0x11330, Script::Grantha),
+    (0x11332, 0x11333, Script::Grantha),
+    (0x11335, 0x11339, Script::Grantha),
+    (0x1133B, 0x1133B, Script::Inherited),
+    (0x1133C, 0x11344, Script::Grantha),
+    (0x11347, 0x11348, Script::Grantha),
+    (0x1134B, 0x1134D, Script::Grantha),
+    (0x11350, 0x11350, Script::Grantha),
+    (0x11357, 0x11357, Script::Grantha),
+    (0x1135D, 0x11363, Script::Grantha),
+    (0x11366, 0x1136C, Script::Grantha),
+    (0x11370, 0x11374, Script::Grantha),
+    (0x11400, 0x1145B, Script::Newa),
+    (0x1145D, 0x11461, Script::Newa),
+    (0x11480, 0x114C7, Script::Tirhuta),
+    (0x114D0, 0x114D9, Script::Tirhuta),
+    (0x11580, 0x115B5, Script::Siddham),
+    (0x115B8, 0x115DD, Script::Siddham),
+    (0x11600, 0x11644, Script::Modi),
+    (0x11650, 0x11659, Script::Modi),
+    (0x11660, 0x1166C, Script::Mongolian),
+    (0x11680, 0x116B9, Script::Takri),
+    (0x116C0, 0x116C9, Script::Takri),
+    (0x11700, 0x1171A, Script::Ahom),
+    (0x1171D, 0x1172B, Script::Ahom),
+    (0x11730, 0x11746, Script::Ahom),
+    (0x11800, 0x1183B, Script::Dogra),
+    (0x118A0, 0x118F2, Script::WarangCiti),
+    (0x118FF, 0x118FF, Script::WarangCiti),
+    (0x11900, 0x11906, Script::DivesAkuru),
+    (0x11909, 0x11909, Script::DivesAkuru),
+    (0x1190C, 0x11913, Script::DivesAkuru),
+    (0x11915, 0x11916, Script::DivesAkuru),
+    (0x11918, 0x11935, Script::DivesAkuru),
+    (0x11937, 0x11938, Script::DivesAkuru),
+    (0x1193B, 0x11946, Script::DivesAkuru),
+    (0x11950, 0x11959, Script::DivesAkuru),
+    (0x119A0, 0x119A7, Script::Nandinagari),
+    (0x119AA, 0x119D7, Script::Nandinagari),
+    (0x119DA, 0x119E4, Script::Nandinagari),
+    (0x11A00, 0x11A47, Script::ZanabazarSquare),
+    (0x11A50, 0x11AA2, Script::Soyombo),
+    (0x11AB0, 0x11ABF, Script::CanadianAboriginal),
+    (0x11AC0, 0x11AF8, Script::PauCinHau),
+    (0x11B00, 0x11B09, Script::Devanagari),
+    (0x11C00, 0x11C08, Script::Bhaiksuki),
+    (0x11C0A, 0x11C36, Script::Bhaiksuki),
+    (0x11C38, 0x11C45, Script::Bhaiksuki),
+    (0x11C50, 0x11C6C, Script::Bhaiksuki),
+    (0x11C70, 0x11C8F, Script::Marchen),
+    (0x11C92, 0x11CA7, Script::Marchen),
+    (0x11CA9, 0x11CB6, Script::Marchen),
+    (0x11D00, 0x11D06, Script::MasaramGondi),
+    (0x11D08, 0x11D09, Script::MasaramGondi),
+    (0x11D0B, 0x11D36, Script::MasaramGondi),
+    (0x11D3A, 0x11D3A, Script::MasaramGondi),
+    (0x11D3C, 0x11D3D, Script::MasaramGondi),
+    (0x11D3F, 0x11D47, Script::MasaramGondi),
+    (0x11D50, 0x11D59, Script::MasaramGondi),
+    (0x11D60, 0x11D65, Script::GunjalaGondi),
+    (0x11D67, 0x11D68, Script::GunjalaGondi),
+    (0x11D6A, 0x11D8E, Script::GunjalaGondi),
+    (0x11D90, 0x11D91, Script::GunjalaGondi),
+    (0x11D93, 0x11D98, Script::GunjalaGondi),
+    (0x11DA0, 0x11DA9, Script::GunjalaGondi),
+    (0x11EE0, 0x11EF8, Script::Makasar),
+    (0x11F00, 0x11F10, Script::Kawi),
+    (0x11F12, 0x11F3A, Script::Kawi),
+    (0x11F3E, 0x11F59, Script::Kawi),
+    (0x11FB0, 0x11FB0, Script::Lisu),
+    (0x11FC0, 0x11FF1, Script::Tamil),
+    (0x11FFF, 0x11FFF, Script::Tamil),
+    (0x12000, 0x12399, Script::Cuneiform),
+    (0x12400, 0x1246E, Script::Cuneiform),
+    (0x12470, 0x12474, Script::Cuneiform),
+    (0x12480, 0x12543, Script::Cuneiform),
+    (0x12F90, 0x12FF2, Script::CyproMinoan),
+    (0x13000, 0x13455, Script::EgyptianHieroglyphs),
+    (0x14400, 0x14646, Script::AnatolianHieroglyphs),
+    (0x16800, 0x16A38, Script::Bamum),
+    (0x16A40, 0x16A5E, Script::Mro),
+    (0x16A60, 0x16A69, Script::Mro),
+    (0x16A6E, 0x16A6F, Script::Mro),
+    (0x16A70, 0x16ABE, Script::Tangsa),
+    (0x16AC0, 0x16AC9, Script::Tangsa),
+    (0x16AD0, 0x16AED, Script::BassaVah),
+    (0x16AF0, 0x16AF5, Script::BassaVah),
+    (0x16B00, 0x16B45, Script::PahawhHmong),
+    (0x16B50, 0x16B59, Script::PahawhHmong),
+    (0x16B5B, 0x16B61, Script::PahawhHmong),
+    (0x16B63, 0x16B77, Script::PahawhHmong),
+    (0x16B7D, 0x16B8F, Script::PahawhHmong),
+    (0x16E40, 0x16E9A, Script::Medefaidrin),
+    (0x16F00, 0x16F4A, Script::Miao),
+    (0x16F4F, 0x16F87, Script::Miao),
+    (0x16F8F, 0x16F9F, Script::Miao),
+    (0x16FE0, 0x16FE0, Script::Tangut),
+    (0x16FE1, 0x16FE1, Script::Nushu),
+    (0x16FE2, 0x16FE3, Script::Han),
+    (0x16FE4, 0x16FE4, Script::KhitanSmallScript),
+    (0x16FF0, 0x16FF1, Script::Han),
+    (0x17000, 0x187F7, Script::Tangut),
+    (0x18800, 0x18AFF, Script::Tangut),
+    (0x18B00, 0x18CD5, Script::KhitanSmallScript),
+    (0x18D00, 0x18D08, Script::Tangut),
+    (0x1AFF0, 0x1AFF3, Script::Katakana),
+    (0x1AFF5, 0x1AFFB, Script::Katakana),
+    (0x1AFFD, 0x1AFFE, Script::Katakana),
+    (0x1B000, 0x1B000, Script::Katakana),
+    (0x1B001, 0x1B11F, Script::Hiragana),
+    (0x1B120, 0x1B122, Script::Katakana),
+    (0x1B132, 0x1B132, Script::Hiragana),
+    (0x1B150, 0x1B152, Script::Hiragana),
+    (0x1B155, 0x1B155, Script::Katakana),
+    (0x1B164, 0x1B167, Script::Katakana),
+    (0x1B170, 0x1B2FB, Script::Nushu),
+    (0x1BC00, 0x1BC6A, Script::Duployan),
+    (0x1BC70, 0x1BC7C, Script::Duployan),
+    (0x1BC80, 0x1BC88, Script::Duployan),
+    (0x1BC90, 0x1BC99, Script::Duployan),
+    (0x1BC9C, 0x1BC9F, Script::Duployan),
+    (0x1BCA0, 0x1BCA3, Script::Common),
+    (0x1CF00, 0x1CF2D, Script::Inherited),
+    (0x1CF30, 0x1CF46, Script::Inherited),
+    (0x1CF50, 0x1CFC3, Script::Common),
+    (0x1D000, 0x1D0F5, Script::Common),
+    (0x1D100, 0x1D126, Script::Common),
+    (0x1D129, 0x1D166, Script::Common),
+    (0x1D167, 0x1D169, Script::Inherited),
+    (0x1D16A, 0x1D17A, Script::Common),
+    (0x1D17B, 0x1D182, Script::Inherited),
+    (0x1D183, 0x1D184, Script::Common),
+    (0x1D185, 0x1D18B, Script::Inherited),
+    (0x1D18C, 0x1D1A9, Script::Common),
+    (0x1D1AA, 0x1D1AD, Script::Inherited),
+    (0x1D1AE, 0x1D1EA, Script::Common),
+    (0x1D200, 0x1D245, Script::Greek),
+    (0x1D2C0, 0x1D2D3, Script::Common),
+    (0x1D2E0, 0x1D2F3, Script::Common),
+    (0x1D300, 0x1D356, Script::Common),
+    (0x1D360, 0x1D378, Script::Common),
+    (0x1D400, 0x1D454, Script::Common),
+    (0x1D456, 0x1D49C, Script::Common),
+    (0x1D49E, 0x1D49F, Script::Common),
+    (0x1D4A2, 0x1D4A2, Script::Common),
+    (0x1D4A5, 0x1D4A6, Script::Common),
+    (0x1D4A9, 0x1D4AC, Script::Common),
+    (0x1D4AE, 0x1D4B9, Script::Common),
+    (0x1D4BB, 0x1D4BB, Script::Common),
+    (0x1D4BD, 0x1D4C3, Script::Common),
+    (0x1D4C5, 0x1D505, Script::Common),
+    (0x1D507, 0x1D50A, Script::Common),
+    (0x1D50D, 0x1D514, Script::Common),
+    (0x1D516, 0x1D51C, Script::Common),
+    (0x1D51E, 0x1D539, Script::Common),
+    (0x1D53B, 0x1D53E, Script::Common),
+    (0x1D540, 0x1D544, Script::Common),
+    (0x1D546, 0x1D546, Script::Common),
+    (0x1D54A, 0x1D550, Script::Common),
+    (0x1D552, 0x1D6A5, Script::Common),
+    (0x1D6A8, 0x1D7CB, Script::Common),
+    (0x1D7CE, 0x1D7FF, Script::Common),
+    (0x1D800, 0x1DA8B, Script::SignWriting),
+    (0x1DA9B, 0x1DA9F, Script::SignWriting),
+    (0x1DAA1, 0x1DAAF, Script::SignWriting),
+    (0x1DF00, 0x1DF1E, Script::Latin),
+    (0x1DF25, 0x1DF2A, Script::Latin),
+    (0x1E000, 0x1E006, Script::Glagolitic),
+    (0x1E008, 0x1E018, Script::Glagolitic),
+    (0x1E01B, 0x1E021, Script::Glagolitic),
+    (0x1E023, 0x1E024, Script::Glagolitic),
+    (0x1E026, 0x1E02A, Script::Glagolitic),
+    (0x1E030, 0x1E06D, Script::Cyrillic),
+    (0x1E08F, 0x1E08F, Script::Cyrillic),
+    (0x1E100, 0x1E12C, Script::NyiakengPuachueHmong),
+    (0x1E130, 0x1E13D, Script::NyiakengPuachueHmong),
+    (0x1E140, 0x1E149, Script::NyiakengPuachueHmong),
+    (0x1E14E, 0x1E14F, Script::NyiakengPuachueHmong),
+    (0x1E290, 0x1E2AE, Script::Toto),
+    (0x1E2C0, 0x1E2F9, Script::Wancho),
+    (0x1E2FF, 0x1E2FF, Script::Wancho),
+    (0x1E4D0, 0x1E4F9, Script::NagMundari),
+    (0x1E7E0, 0x1E7E6, Script::Ethiopic),
+    (0x1E7E8, 0x1E7EB, Script::Ethiopic),
+    (0x1E7ED, 0x1E7EE, Script::Ethiopic),
+    (0x1E7F0, 0x1E7FE, Script::Ethiopic),
+    (0x1E800, 0x1E8C4, Script::MendeKikakui),
+    (0x1E8C7, 0x1E8D6, Script::MendeKikakui),
+    (0x1E900, 0x1E94B, Script::Adlam),
+    (0x1E950, 0x1E959, Script::Adlam),
+    (0x1E95E, 0x1E95F, Script::Adlam),
+    (0x1EC71, 0x1ECB4, Script::Common),
+    (0x1ED01, 0x1ED3D, Script::Common),
+    (0x1EE00, 0x1EE03, Script::Arabic),
+    (0x1EE05, 0x1EE1F, Script::Arabic),
+    (0x1EE21, 0x1EE22, Script::Arabic),
+    (0x1EE24, 0x1EE24, Script::Arabic),
+    (0x1EE27, 0x1EE27, Script::Arabic),
+    (0x1EE29, 0x1EE32, Script::Arabic),
+    (0x1EE34, 0x1EE37, Script::Arabic),
+    (0x1EE39, 0x1EE39, Script::Arabic),
+    (0x1EE3B, 0x1EE3B, Script::Arabic),
+    (0x1EE42, 0x1EE42, Script::Arabic),
+    (0x1EE47, 0x1EE47, Script::Arabic),
+    (0x1EE49, 0x1EE49, Script::Arabic),
+    (0x1EE4B, 0x1EE4B, Script::Arabic),
+    (0x1EE4D, 0x1EE4F, Script::Arabic),
+    (0x1EE51, 0x1EE52, Script::Arabic),
+    (0x1EE54, 0x1EE54, Script::Arabic),
+    (0x1EE57, 0x1EE57, Script::Arabic),
+    (0x1EE59, 0x1EE59, Script::Arabic),
+    (0x1EE5B, 0x1EE5B, Script::Arabic),
+    (0x1EE5D, 0x1EE5D, Script::Arabic),
+    (0x1EE5F, 0x1EE5F, Script::Arabic),
+    (0x1EE61, 0x1EE62, Script::Arabic),
+    (0x1EE64, 0x1EE64, Script::Arabic),
+    (0x1EE67, 0x1EE6A, Script::Arabic),
+    (0x1EE6C, 0x1EE72, Script::Arabic),
+    (0x1EE74, 0x1EE77, Script::Arabic),
+    (0x1EE79, 0x1EE7C, Script::Arabic),
+    (0x1EE7E, 0x1EE7E, Script::Arabic),
+    (0x1EE80, 0x1EE89, Script::Arabic),
+    (0x1EE8B, 0x1EE9B, Script::Arabic),
+    (0x1EEA1, 0x1EEA3, Script::Arabic),
+    (0x1EEA5, 0x1EEA9, Script::Arabic),
+    (0x1EEAB, 0x1EEBB, Script::Arabic),
+    (0x1EEF0, 0x1EEF1, Script::Arabic),
+    (0x1F000, 0x1F02B, Script::Common),
+    (0x1F030, 0x1F093, Script::Common),
+    (0x1F0A0, 0x1F0AE, Script::Common),
+    (0x1F0B1, 0x1F0BF, Script::Common),
+    (0x1F0C1, 0x1F0CF, Script::Common),
+    (0x1F0D1, 0x1F0F5, Script::Common),
+    (0x1F100, 0x1F1AD, Script::Common),
+    (0x1F1E6, 0x1F1FF, Script::Common),
+    (0x1F200, 0x1F200, Script::Hiragana),
+    (0x1F201, 0x1F202, Script::Common),
+    (0x1F210, 0x1F23B, Script::Common),
+    (0x1F240, 0x1F248, Script::Common),
+    (0x1F250, 0x1F251, Script::Common),
+    (0x1F260, 0x1F265, Script::Common),
+    (0x1F300, 0x1F6D7, Script::Common),
+    (0x1F6DC, 0x1F6EC, Script::Common),
+    (0x1F6F0, 0x1F6FC, Script::Common),
+    (0x1F700, 0x1F776, Script::Common),
+    (0x1F77B, 0x1F7D9, Script::Common),
+    (0x1F7E0, 0x1F7EB, Script::Common),
+    (0x1F7F0, 0x1F7F0, Script::Common),
+    (0x1F800, 0x1F80B, Script::Common),
+    (0x1F810, 0x1F847, Script::Common),
+    (0x1F850, 0x1F859, Script::Common),
+    (0x1F860, 0x1F887, Script::Common),
+    (0x1F890, 0x1F8AD, Script::Common),
+    (0x1F8B0, 0x1F8B1, Script::Common),
+    (0x1F900, 0x1FA53, Script::Common),
+    (0x1FA60, 0x1FA6D, Script::Common),
+    (0x1FA70, 0x1FA7C, Script::Common),
+    (0x1FA80, 0x1FA88, Script::Common),
+    (0x1FA90, 0x1FABD, Script::Common),
+    (0x1FABF, 0x1FAC5, Script::Common),
+    (0x1FACE, 0x1FADB, Script::Common),
+    (0x1FAE0, 0x1FAE8, Script::Common),
+    (0x1FAF0, 0x1FAF8, Script::Common),
+    (0x1FB00, 0x1FB92, Script::Common),
+    (0x1FB94, 0x1FBCA, Script::Common),
+    (0x1FBF0, 0x1FBF9, Script::Common),
+    (0x20000, 0x2A6DF, Script::Han),
+    (0x2A700, 0x2B739, Script::Han),
+    (0x2B740, 0x2B81D, Script::Han),
+    (0x2B820, 0x2CEA1, Script::Han),
+    (0x2CEB0, 0x2EBE0, Script::Han),
+    (0x2F800, 0x2FA1D, Script::Han),
+    (0x30000, 0x3134A, Script::Han),
+    (0x31350, 0x323AF, Script::Han),
+    (0xE0001, 0xE0001, Script::Common),
+    (0xE0020, 0xE007F, Script::Common),
+    (0xE0100, 0xE01EF, Script::Inherited),
+];
+
+/// Inclusive ranges of code points and their Script_Extensions property, for the code points
+/// used by other scripts than their own, like the Arabic comma `،` used by Syriac and Thaana
+#[rustfmt::skip]
+pub(super) static SCRIPT_EXTENSIONS: [(u32, u32, &[Script]); 121] = [
+    (0x0342, 0x0342, &[Script::Greek]),
+    (0x0345, 0x0345, &[Script::Greek]),
+    (0x0363, 0x036F, &[Script::Latin]),
+    (0x0483, 0x0483, &[Script::Cyrillic, Script::OldPermic]),
+    (0x0484, 0x0484, &[Script::Cyrillic, Script::Glagolitic]),
+    (0x0485, 0x0486, &[Script::Cyrillic, Script::Latin]),
+    (0x0487, 0x0487, &[Script::Cyrillic, Script::Glagolitic]),
+    (0x060C, 0x060C, &[Script::Arabic, Script::HanifiRohingya, Script::Nko, Script::Syriac, Script::Thaana, Script::Yezidi]),
+    (0x061B, 0x061B, &[Script::Arabic, Script::HanifiRohingya, Script::Nko, Script::Syriac, Script::Thaana, Script::Yezidi]),
+    (0x061C, 0x061C, &[Script::Arabic, Script::Syriac, Script::Thaana]),
+    (0x061F, 0x061F, &[Script::Adlam, Script::Arabic, Script::HanifiRohingya, Script::Nko, Script::Syriac, Script::Thaana, Script::Yezidi]),
+    (0x0640, 0x0640, &[Script::Adlam, Script::Arabic, Script::HanifiRohingya, Script::Mandaic, Script::Manichaean, Script::OldUyghur, Script::PsalterPahlavi, Script::Sogdian, Script::Syriac]),
+    (0x064B, 0x0655, &[Script::Arabic, Script::Syriac]),
+    (0x0660, 0x0669, &[Script::Arabic, Script::Thaana, Script::Yezidi]),
+    (0x0670, 0x0670, &[Script::Arabic, Script::Syriac]),
+    (0x06D4, 0x06D4, &[Script::Arabic, Script::HanifiRohingya]),
+    (0x0951, 0x0951, &[Script::Bengali, Script::Devanagari, Script::Grantha, Script::Gujarati, Script::Gurmukhi, Script::Kannada, Script::Latin, Script::Malayalam, Script::Oriya, Script::Sharada, Script::Tamil, Script::Telugu, Script::Tirhuta]),
+    (0x0952, 0x0952, &[Script::Bengali, Script::Devanagari, Script::Grantha, Script::Gujarati, Script::Gurmukhi, Script::Kannada, Script::Latin, Script::Malayalam, Script::Oriya, Script::Tamil, Script::Telugu, Script::Tirhuta]),
+    (0x0964, 0x0964, &[Script::Bengali, Script::Devanagari, Script::Dogra, Script::Grantha, Script::Gujarati, Script::GunjalaGondi, Script::Gurmukhi, Script::Kannada, Script::Khudawadi, Script::Mahajani, Script::Malayalam, Script::MasaramGondi, Script::Nandinagari, Script::Oriya, Script::Sinhala, Script::SylotiNagri, Script::Takri, Script::Tamil, Script::Telugu, Script::Tirhuta]),
+    (0x0965, 0x0965, &[Script::Bengali, Script::Devanagari, Script::Dogra, Script::Grantha, Script::Gujarati, Script::GunjalaGondi, Script::Gurmukhi, Script::Kannada, Script::Khudawadi, Script::Limbu, Script::Mahajani, Script::Malayalam, Script::MasaramGondi, Script::Nandinagari, Script::Oriya, Script::Sinhala, Script::SylotiNagri, Script::Takri, Script::Tamil, Script::Telugu, Script::Tirhuta]),
+    (0x0966, 0x096F, &[Script::Devanagari, Script::Dogra, Script::Kaithi, Script::Mahajani]),
+    (0x09E6, 0x09EF, &[Script::Bengali, Script::Chakma, Script::SylotiNagri]),
+    (0x0A66, 0x0A6F, &[Script::Gurmukhi, Script::Multani]),
+    (0x0AE6, 0x0AEF, &[Script::Gujarati, Script::Khojki]),
+    (0x0BE6, 0x0BF3, &[Script::Grantha, Script::Tamil]),
+    (0x0CE6, 0x0CEF, &[Script::Kannada, Script::Nandinagari]),
+    (0x1040, 0x1049, &[Script::Chakma, Script::Myanmar, Script::TaiLe]),
+    (0x10FB, 0x10FB, &[Script::Georgian, Script::Latin]),
+    (0x1735, 0x1736, &[Script::Buhid, Script::Hanunoo, Script::Tagalog, Script::Tagbanwa]),
+    (0x1802, 0x1803, &[Script::Mongolian, Script::PhagsPa]),
+    (0x1805, 0x1805, &[Script::Mongolian, Script::PhagsPa]),
+    (0x1CD0, 0x1CD0, &[Script::Bengali, Script::Devanagari, Script::Grantha, Script::Kannada]),
+    (0x1CD1, 0x1CD1, &[Script::Devanagari]),
+    (0x1CD2, 0x1CD2, &[Script::Bengali, Script::Devanagari, Script::Grantha, Script::Kannada]),
+    (0x1CD3, 0x1CD3, &[Script::Devanagari, Script::Grantha]),
+    (0x1CD4, 0x1CD4, &[Script::Devanagari]),
+    (0x1CD5, 0x1CD6, &[Script::Bengali, Script::Devanagari]),
+    (0x1CD7, 0x1CD7, &[Script::Devanagari, Script::Sharada]),
+    (0x1CD8, 0x1CD8, &[Script::Bengali, Script::Devanagari]),
+    (0x1CD9, 0x1CD9, &[Script::Devanagari, Script::Sharada]),
+    (0x1CDA, 0x1CDA, &[Script::Devanagari, Script::Kannada, Script::Malayalam, Script::Oriya, Script::Tamil, Script::Telugu]),
+    (0x1CDB, 0x1CDB, &[Script::Devanagari]),
+    (0x1CDC, 0x1CDD, &[Script::Devanagari, Script::Sharada]),
+    (0x1CDE, 0x1CDF, &[Script::Devanagari]),
+    (0x1CE0, 0x1CE0, &[Script::Devanagari, Script::Sharada]),
+    (0x1CE1, 0x1CE1, &[Script::Bengali, Script::Devanagari]),
+    (0x1CE2, 0x1CE8, &[Script::Devanagari]),
+    (0x1CE9, 0x1CE9, &[Script::Devanagari, Script::Nandinagari]),
+    (0x1CEA, 0x1CEA, &[Script::Bengali, Script::Devanagari]),
+    (0x1CEB, 0x1CEC, &[Script::Devanagari]),
+    (0x1CED, 0x1CED, &[Script::Bengali, Script::Devanagari]),
+    (0x1CEE, 0x1CF1, &[Script::Devanagari]),
+    (0x1CF2, 0x1CF2, &[Script::Bengali, Script::Devanagari, Script::Grantha, Script::Kannada, Script::Nandinagari, Script::Oriya, Script::Telugu, Script::Tirhuta]),
+    (0x1CF3, 0x1CF3, &[Script::Devanagari, Script::Grantha]),
+    (0x1CF4, 0x1CF4, &[Script::Devanagari, Script::Grantha, Script::Kannada]),
+    (0x1CF5, 0x1CF6, &[Script::Bengali, Script::Devanagari]),
+    (0x1CF7, 0x1CF7, &[Script::Bengali]),
+    (0x1CF8, 0x1CF9, &[Script::Devanagari, Script::Grantha]),
+    (0x1CFA, 0x1CFA, &[Script::Nandinagari]),
+    (0x1DC0, 0x1DC1, &[Script::Greek]),
+    (0x1DF8, 0x1DF8, &[Script::Cyrillic, Script::Syriac]),
+    (0x1DFA, 0x1DFA, &[Script::Syriac]),
+    (0x202F, 0x202F, &[Script::Latin, Script::Mongolian]),
+    (0x20F0, 0x20F0, &[Script::Devanagari, Script::Grantha, Script::Latin]),
+    (0x2E43, 0x2E43, &[Script::Cyrillic, Script::Glagolitic]),
+    (0x3001, 0x3002, &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Yi]),
+    (0x3003, 0x3003, &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana]),
+    (0x3006, 0x3006, &[Script::Han]),
+    (0x3008, 0x3011, &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Yi]),
+    (0x3013, 0x3013, &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana]),
+    (0x3014, 0x301B, &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Yi]),
+    (0x301C, 0x301F, &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana]),
+    (0x302A, 0x302D, &[Script::Bopomofo, Script::Han]),
+    (0x3030, 0x3030, &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana]),
+    (0x3031, 0x3035, &[Script::Hiragana, Script::Katakana]),
+    (0x3037, 0x3037, &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana]),
+    (0x303C, 0x303D, &[Script::Han, Script::Hiragana, Script::Katakana]),
+    (0x303E, 0x303F, &[Script::Han]),
+    (0x3099, 0x309C, &[Script::Hiragana, Script::Katakana]),
+    (0x30A0, 0x30A0, &[Script::Hiragana, Script::Katakana]),
+    (0x30FB, 0x30FB, &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Yi]),
+    (0x30FC, 0x30FC, &[Script::Hiragana, Script::Katakana]),
+    (0x3190, 0x319F, &[Script::Han]),
+    (0x31C0, 0x31E3, &[Script::Han]),
+    (0x3220, 0x3247, &[Script::Han]),
+    (0x3280, 0x32B0, &[Script::Han]),
+    (0x32C0, 0x32CB, &[Script::Han]),
+    (0x32FF, 0x32FF, &[Script::Han]),
+    (0x3358, 0x3370, &[Script::Han]),
+    (0x337B, 0x337F, &[Script::Han]),
+    (0x33E0, 0x33FE, &[Script::Han]),
+    (0xA66F, 0xA66F, &[Script::Cyrillic, Script::Glagolitic]),
+    (0xA700, 0xA707, &[Script::Han, Script::Latin]),
+    (0xA830, 0xA832, &[Script::Devanagari, Script::Dogra, Script::Gujarati, Script::Gurmukhi, Script::Kaithi, Script::Kannada, Script::Khojki, Script::Khudawadi, Script::Mahajani, Script::Malayalam, Script::Modi, Script::Nandinagari, Script::Takri, Script::Tirhuta]),
+    (0xA833, 0xA835, &[Script::Devanagari, Script::Dogra, Script::Gujarati, Script::Gurmukhi, Script::Kaithi, Script::Kannada, Script::Khojki, Script::Khudawadi, Script::Mahajani, Script::Modi, Script::Nandinagari, Script::Takri, Script::Tirhuta]),
+    (0xA836, 0xA839, &[Script::Devanagari, Script::Dogra, Script::Gujarati, Script::Gurmukhi, Script::Kaithi, Script::Khojki, Script::Khudawadi, Script::Mahajani, Script::Modi, Script::Takri, Script::Tirhuta]),
+    (0xA8F1, 0xA8F1, &[Script::Bengali, Script::Devanagari]),
+    (0xA8F3, 0xA8F3, &[Script::Devanagari, Script::Tamil]),
+    (0xA92E, 0xA92E, &[Script::KayahLi, Script::Latin, Script::Myanmar]),
+    (0xA9CF, 0xA9CF, &[Script::Buginese, Script::Javanese]),
+    (0xFD3E, 0xFD3F, &[Script::Arabic, Script::Nko]),
+    (0xFDF2, 0xFDF2, &[Script::Arabic, Script::Thaana]),
+    (0xFDFD, 0xFDFD, &[Script::Arabic, Script::Thaana]),
+    (0xFE45, 0xFE46, &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana]),
+    (0xFF61, 0xFF65, &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Yi]),
+    (0xFF70, 0xFF70, &[Script::Hiragana, Script::Katakana]),
+    (0xFF9E, 0xFF9F, &[Script::Hiragana, Script::Katakana]),
+    (0x10100, 0x10101, &[Script::Cypriot, Script::CyproMinoan, Script::LinearB]),
+    (0x10102, 0x10102, &[Script::Cypriot, Script::LinearB]),
+    (0x10107, 0x10133, &[Script::Cypriot, Script::LinearA, Script::LinearB]),
+    (0x10137, 0x1013F, &[Script::Cypriot, Script::LinearB]),
+    (0x102E0, 0x102FB, &[Script::Arabic, Script::Coptic]),
+    (0x10AF2, 0x10AF2, &[Script::Manichaean, Script::OldUyghur]),
+    (0x11301, 0x11301, &[Script::Grantha, Script::Tamil]),
+    (0x11303, 0x11303, &[Script::Grantha, Script::Tamil]),
+    (0x1133B, 0x1133C, &[Script::Grantha, Script::Tamil]),
+    (0x11FD0, 0x11FD1, &[Script::Grantha, Script::Tamil]),
+    (0x11FD3, 0x11FD3, &[Script::Grantha, Script::Tamil]),
+    (0x1BCA0, 0x1BCA3, &[Script::Duployan]),
+    (0x1D360, 0x1D371, &[Script::Han]),
+    (0x1F250, 0x1F251, &[Script::Han]),
+];
+
+/// The skeleton of the characters that are not their own skeleton: the prototype of
+/// `confusables.txt` of each character of their NFD form, like `𝐚` being `a` and `é` being
+/// `e\u{301}`. Hangul syllables are not listed.
+#[rustfmt::skip]
+pub(super) static CONFUSABLES: [(char, &str); 7308] = [
+    ('\u{22}', "\u{27}\u{27}"),
+    ('\u{25}', "\u{BA}/\u{2080}"),
+    ('\u{30}', "O"),
+    ('\u{31}', "l"),
+    ('\u{49}', "l"),
+    ('\u{60}', "\u{27}"),
+    ('\u{6D}', "rn"),
+    ('\u{7C}', "l"),
+    ('\u{A0}', " "),
+    ('\u{A2}', "c\u{338}"),
+    ('\u{A5}', "Y\u{335}"),
+    ('\u{AF}', "\u{2C9}"),
+    ('\u{B4}', "\u{27}"),
+    ('\u{B5}', "\u{3BC}"),
+    ('\u{B8}', ","),
+    ('\u{C0}', "A\u{300}"),
+    ('\u{C1}', "A\u{301}"),
+    ('\u{C2}', "A\u{302}"),
+    ('\u{C3}', "A\u{303}"),
+    ('\u{C4}', "A\u{308}"),
+    ('\u{C5}', "A\u{30A}"),
+    ('\u{C6}', "AE"),
+    ('\u{C7}', "C\u{326}"),
+    ('\u{C8}', "E\u{300}"),
+    ('\u{C9}', "E\u{301}"),
+    ('\u{CA}', "E\u{302}"),
+    ('\u{CB}', "E\u{308}"),
+    ('\u{CC}', "l\u{300}"),
+    ('\u{CD}', "l\u{301}"),
+    ('\u{CE}', "l\u{302}"),
+    ('\u{CF}', "l\u{308}"),
+    ('\u{D0}', "D\u{335}"),
+    ('\u{D1}', "N\u{303}"),
+    ('\u{D2}', "O\u{300}"),
+    ('\u{D3}', "O\u{301}"),
+    ('\u{D4}', "O\u{302}"),
+    ('\u{D5}', "O\u{303}"),
+    ('\u{D6}', "O\u{308}"),
+    ('\u{D7}', "x"),
+    ('\u{D8}', "O\u{338}"),
+    ('\u{D9}', "U\u{300}"),
+    ('\u{DA}', "U\u{301}"),
+    ('\u{DB}', "U\u{302}"),
+    ('\u{DC}', "U\u{308}"),
+    ('\u{DD}', "Y\u{301}"),
+    ('\u{E0}', "a\u{300}"),
+    ('\u{E1}', "a\u{301}"),
+    ('\u{E2}', "a\u{302}"),
+    ('\u{E3}', "a\u{303}"),
+    ('\u{E4}', "a\u{308}"),
+    ('\u{E5}', "a\u{30A}"),
+    ('\u{E6}', "ae"),
+    ('\u{E7}', "c\u{326}"),
+    ('\u{E8}', "e\u{300}"),
+    ('\u{E9}', "e\u{301}"),
+    ('\u{EA}', "e\u{302}"),
+    ('\u{EB}', "e\u{308}"),
+    ('\u{EC}', "i\u{300}"),
+    ('\u{ED}', "i\u{301}"),
+    ('\u{EE}', "i\u{302}"),
+    ('\u{EF}', "i\u{308}"),
+    ('\u{F0}', "\u{2202}\u{335}"),
+    ('\u{F1}', "n\u{303}"),
+    ('\u{F2}', "o\u{300}"),
+    ('\u{F3}', "o\u{301}"),
+    ('\u{F4}', "o\u{302}"),
+    ('\u{F5}', "o\u{303}"),
+    ('\u{F6}', "o\u{308}"),
+    ('\u{F8}', "o\u{338}"),
+    ('\u{F9}', "u\u{300}"),
+    ('\u{FA}', "u\u{301}"),
+    ('\u{FB}', "u\u{302}"),
+    ('\u{FC}', "u\u{308}"),
+    ('\u{FD}', "y\u{301}"),
+    ('\u{FF}', "y\u{308}"),
+    ('\u{100}', "A\u{304}"),
+    ('\u{101}', "a\u{304}"),
+    ('\u{102}', "A\u{306}"),
+    ('\u{103}', "a\u{306}"),
+    ('\u{104}', "A\u{328}"),
+    ('\u{105}', "a\u{328}"),
+    ('\u{106}', "C\u{301}"),
+    ('\u{107}', "c\u{301}"),
+    ('\u{108}', "C\u{302}"),
+    ('\u{109}', "c\u{302}"),
+    ('\u{10A}', "C\u{307}"),
+    ('\u{10B}', "c\u{307}"),
+    ('\u{10C}', "C\u{306}"),
+    ('\u{10D}', "c\u{306}"),
+    ('\u{10E}', "D\u{306}"),
+    ('\u{10F}', "d\u{306}"),
+    ('\u{110}', "D\u{335}"),
+    ('\u{111}', "d\u{335}"),
+    ('\u{112}', "E\u{304}"),
+    ('\u{113}', "e\u{304}"),
+    ('\u{114}', "E\u{306}"),
+    ('\u{115}', "e\u{306}"),
+    ('\u{116}', "E\u{307}"),
+    ('\u{117}', "e\u{307}"),
+    ('\u{118}', "E\u{328}"),
+    ('\u{119}', "e\u{328}"),
+    ('\u{11A}', "E\u{306}"),
+    ('\u{11B}', "e\u{306}"),
+    ('\u{11C}', "G\u{302}"),
+    ('\u{11D}', "g\u{302}"),
+    ('\u{11E}', "G\u{306}"),
+    ('\u{11F}', "g\u{306}"),
+    ('\u{120}', "G\u{307}"),
+    ('\u{121}', "g\u{307}"),
+    ('\u{122}', "G\u{326}"),
+    ('\u{123}', "g\u{326}"),
+    ('\u{124}', "H\u{302}"),
+    ('\u{125}', "h\u{302}"),
+    ('\u{126}', "H\u{335}"),
+    ('\u{127}', "h\u{335}"),
+    ('\u{128}', "l\u{303}"),
+    ('\u{129}', "i\u{303}"),
+    ('\u{12A}', "l\u{304}"),
+    ('\u{12B}', "i\u{304}"),
+    ('\u{12C}', "l\u{306}"),
+    ('\u{12D}', "i\u{306}"),
+    ('\u{12E}', "l\u{328}"),
+    ('\u{12F}', "i\u{328}"),
+    ('\u{130}', "l\u{307}"),
+    ('\u{131}', "i"),
+    ('\u{132}', "lJ"),
+    ('\u{133}', "ij"),
+    ('\u{134}', "J\u{302}"),
+    ('\u{135}', "j\u{302}"),
+    ('\u{136}', "K\u{326}"),
+    ('\u{137}', "k\u{326}"),
+    ('\u{139}', "L\u{301}"),
+    ('\u{13A}', "l\u{301}"),
+    ('\u{13B}', "L\u{326}"),
+    ('\u{13C}', "l\u{326}"),
+    ('\u{13D}', "L\u{306}"),
+    ('\u{13E}', "l\u{306}"),
+    ('\u{13F}', "l\u{B7}"),
+    ('\u{140}', "l\u{B7}"),
+    ('\u{141}', "L\u{338}"),
+    ('\u{142}', "l\u{338}"),
+    ('\u{143}', "N\u{301}"),
+    ('\u{144}', "n\u{301}"),
+    ('\u{145}', "N\u{326}"),
+    ('\u{146}', "n\u{326}"),
+    ('\u{147}', "N\u{306}"),
+    ('\u{148}', "n\u{306}"),
+    ('\u{149}', "\u{27}n"),
+    ('\u{14C}', "O\u{304}"),
+    ('\u{14D}', "o\u{304}"),
+    ('\u{14E}', "O\u{306}"),
+    ('\u{14F}', "o\u{306}"),
+    ('\u{150}', "O\u{30B}"),
+    ('\u{151}', "o\u{30B}"),
+    ('\u{152}', "OE"),
+    ('\u{153}', "oe"),
+    ('\u{154}', "R\u{301}"),
+    ('\u{155}', "r\u{301}"),
+    ('\u{156}', "R\u{326}"),
+    ('\u{157}', "r\u{326}"),
+    ('\u{158}', "R\u{306}"),
+    ('\u{159}', "r\u{306}"),
+    ('\u{15A}', "S\u{301}"),
+    ('\u{15B}', "s\u{301}"),
+    ('\u{15C}', "S\u{302}"),
+    ('\u{15D}', "s\u{302}"),
+    ('\u{15E}', "S\u{326}"),
+    ('\u{15F}', "s\u{326}"),
+    ('\u{160}', "S\u{306}"),
+    ('\u{161}', "s\u{306}"),
+    ('\u{162}', "T\u{326}"),
+    ('\u{163}', "t\u{326}"),
+    ('\u{164}', "T\u{306}"),
+    ('\u{165}', "t\u{306}"),
+    ('\u{166}', "T\u{335}"),
+    ('\u{167}', "t\u{335}"),
+    ('\u{168}', "U\u{303}"),
+    ('\u{169}', "u\u{303}"),
+    ('\u{16A}', "U\u{304}"),
+    ('\u{16B}', "u\u{304}"),
+    ('\u{16C}', "U\u{306}"),
+    ('\u{16D}', "u\u{306}"),
+    ('\u{16E}', "U\u{30A}"),
+    ('\u{16F}', "u\u{30A}"),
+    ('\u{170}', "U\u{30B}"),
+    ('\u{171}', "u\u{30B}"),
+    ('\u{172}', "U\u{328}"),
+    ('\u{173}', "u\u{328}"),
+    ('\u{174}', "W\u{302}"),
+    ('\u{175}', "w\u{302}"),
+    ('\u{176}', "Y\u{302}"),
+    ('\u{177}', "y\u{302}"),
+    ('\u{178}', "Y\u{308}"),
+    ('\u{179}', "Z\u{301}"),
+    ('\u{17A}', "z\u{301}"),
+    ('\u{17B}', "Z\u{307}"),
+    ('\u{17C}', "z\u{307}"),
+    ('\u{17D}', "Z\u{306}"),
+    ('\u{17E}', "z\u{306}"),
+    ('\u{17F}', "f"),
+    ('\u{180}', "b\u{335}"),
+    ('\u{181}', "\u{27}B"),
+    ('\u{182}', "b\u{304}"),
+    ('\u{183}', "b\u{304}"),
+    ('\u{184}', "b"),
+    ('\u{187}', "C\u{27}"),
+    ('\u{189}', "D\u{335}"),
+    ('\u{18A}', "\u{27}D"),
+    ('\u{18C}', "d\u{304}"),
+    ('\u{18D}', "g"),
+    ('\u{191}', "F\u{326}"),
+    ('\u{192}', "f\u{326}"),
+    ('\u{193}', "G\u{27}"),
+    ('\u{196}', "l"),
+    ('\u{197}', "l\u{335}"),
+    ('\u{198}', "K\u{27}"),
+    ('\u{199}', "k\u{314}"),
+    ('\u{19A}', "l\u{335}"),
+    ('\u{19D}', "N\u{326}"),
+    ('\u{19E}', "n\u{329}"),
+    ('\u{19F}', "O\u{335}"),
+    ('\u{1A0}', "O\u{31B}"),
+    ('\u{1A1}', "o\u{31B}"),
+    ('\u{1A4}', "\u{27}P"),
+    ('\u{1A5}', "p\u{314}"),
+    ('\u{1A6}', "R"),
+    ('\u{1A7}', "2"),
+    ('\u{1AC}', "\u{27}T"),
+    ('\u{1AD}', "t\u{314}"),
+    ('\u{1AE}', "T\u{328}"),
+    ('\u{1AF}', "U\u{31B}"),
+    ('\u{1B0}', "u\u{31B}"),
+    ('\u{1B3}', "\u{27}Y"),
+    ('\u{1B4}', "y\u{314}"),
+    ('\u{1B5}', "Z\u{335}"),
+    ('\u{1B6}', "z\u{335}"),
+    ('\u{1B7}', "3"),
+    ('\u{1BB}', "2\u{335}"),
+    ('\u{1BC}', "5"),
+    ('\u{1BD}', "s"),
+    ('\u{1BF}', "\u{FE}"),
+    ('\u{1C0}', "l"),
+    ('\u{1C1}', "ll"),
+    ('\u{1C3}', "!"),
+    ('\u{1C4}', "DZ\u{30C}"),
+    ('\u{1C5}', "Dz\u{30C}"),
+    ('\u{1C6}', "dz\u{30C}"),
+    ('\u{1C7}', "LJ"),
+    ('\u{1C8}', "Lj"),
+    ('\u{1C9}', "lj"),
+    ('\u{1CA}', "NJ"),
+    ('\u{1CB}', "Nj"),
+    ('\u{1CC}', "nj"),
+    ('\u{1CD}', "A\u{306}"),
+    ('\u{1CE}', "a\u{306}"),
+    ('\u{1CF}', "l\u{306}"),
+    ('\u{1D0}', "i\u{306}"),
+    ('\u{1D1}', "O\u{306}"),
+    ('\u{1D2}', "o\u{306}"),
+    ('\u{1D3}', "U\u{306}"),
+    ('\u{1D4}', "u\u{306}"),
+    ('\u{1D5}', "U\u{308}\u{304}"),
+    ('\u{1D6}', "u\u{308}\u{304}"),
+    ('\u{1D7}', "U\u{308}\u{301}"),
+    ('\u{1D8}', "u\u{308}\u{301}"),
+    ('\u{1D9}', "U\u{308}\u{306}"),
+    ('\u{1DA}', "u\u{308}\u{306}"),
+    ('\u{1DB}', "U\u{308}\u{300}"),
+    ('\u{1DC}', "u\u{308}\u{300}"),
+    ('\u{1DE}', "A\u{308}\u{304}"),
+    ('\u{1DF}', "a\u{308}\u{304}"),
+    ('\u{1E0}', "A\u{307}\u{304}"),
+    ('\u{1E1}', "a\u{307}\u{304}"),
+    ('\u{1E2}', "AE\u{304}"),
+    ('\u{1E3}', "ae\u{304}"),
+    ('\u{1E4}', "G\u{335}"),
+    ('\u{1E5}', "g\u{335}"),
+    ('\u{1E6}', "G\u{306}"),
+    ('\u{1E7}', "g\u{306}"),
+    ('\u{1E8}', "K\u{306}"),
+    ('\u{1E9}', "k\u{306}"),
+    ('\u{1EA}', "O\u{328}"),
+    ('\u{1EB}', "o\u{328}"),
+    ('\u{1EC}', "O\u{328}\u{304}"),
+    ('\u{1ED}', "o\u{328}\u{304}"),
+    ('\u{1EE}', "3\u{306}"),
+    ('\u{1EF}', "\u{21D}\u{306}"),
+    ('\u{1F0}', "j\u{306}"),
+    ('\u{1F1}', "DZ"),
+    ('\u{1F2}', "Dz"),
+    ('\u{1F3}', "dz"),
+    ('\u{1F4}', "G\u{301}"),
+    ('\u{1F5}', "g\u{301}"),
+    ('\u{1F8}', "N\u{300}"),
+    ('\u{1F9}', "n\u{300}"),
+    ('\u{1FA}', "A\u{30A}\u{301}"),
+    ('\u{1FB}', "a\u{30A}\u{301}"),
+    ('\u{1FC}', "AE\u{301}"),
+    ('\u{1FD}', "ae\u{301}"),
+    ('\u{1FE}', "O\u{338}\u{301}"),
+    ('\u{1FF}', "o\u{338}\u{301}"),
+    ('\u{200}', "A\u{30F}"),
+    ('\u{201}', "a\u{30F}"),
+    ('\u{202}', "A\u{302}"),
+    ('\u{203}', "a\u{302}"),
+    ('\u{204}', "E\u{30F}"),
+    ('\u{205}', "e\u{30F}"),
+    ('\u{206}', "E\u{302}"),
+    ('\u{207}', "e\u{302}"),
+    ('\u{208}', "l\u{30F}"),
+    ('\u{209}', "i\u{30F}"),
+    ('\u{20A}', "l\u{302}"),
+    ('\u{20B}', "i\u{302}"),
+    ('\u{20C}', "O\u{30F}"),
+    ('\u{20D}', "o\u{30F}"),
+    ('\u{20E}', "O\u{302}"),
+    ('\u{20F}', "o\u{302}"),
+    ('\u{210}', "R\u{30F}"),
+    ('\u{211}', "r\u{30F}"),
+    ('\u{212}', "R\u{302}"),
+    ('\u{213}', "r\u{302}"),
+    ('\u{214}', "U\u{30F}"),
+    ('\u{215}', "u\u{30F}"),
+    ('\u{216}', "U\u{302}"),
+    ('\u{217}', "u\u{302}"),
+    ('\u{218}', "S\u{326}"),
+    ('\u{219}', "s\u{326}"),
+    ('\u{21A}', "T\u{326}"),
+    ('\u{21B}', "t\u{326}"),
+    ('\u{21C}', "3"),
+    ('\u{21E}', "H\u{306}"),
+    ('\u{21F}', "h\u{306}"),
+    ('\u{222}', "8"),
+    ('\u{223}', "8"),
+    ('\u{224}', "Z\u{326}"),
+    ('\u{225}', "z\u{326}"),
+    ('\u{226}', "A\u{307}"),
+    ('\u{227}', "a\u{307}"),
+    ('\u{228}', "E\u{326}"),
+    ('\u{229}', "e\u{326}"),
+    ('\u{22A}', "O\u{308}\u{304}"),
+    ('\u{22B}', "o\u{308}\u{304}"),
+    ('\u{22C}', "O\u{303}\u{304}"),
+    ('\u{22D}', "o\u{303}\u{304}"),
+    ('\u{22E}', "O\u{307}"),
+    ('\u{22F}', "o\u{307}"),
+    ('\u{230}', "O\u{307}\u{304}"),
+    ('\u{231}', "o\u{307}\u{304}"),
+    ('\u{232}', "Y\u{304}"),
+    ('\u{233}', "y\u{304}"),
+    ('\u{23C}', "c\u{338}"),
+    ('\u{23E}', "T\u{338}"),
+    ('\u{241}', "?"),
+    ('\u{244}', "U\u{335}"),
+    ('\u{246}', "E\u{338}"),
+    ('\u{247}', "e\u{338}"),
+    ('\u{248}', "J\u{335}"),
+    ('\u{249}', "j\u{335}"),
+    ('\u{24D}', "r\u{335}"),
+    ('\u{24E}', "Y\u{335}"),
+    ('\u{24F}', "y\u{335}"),
+    ('\u{251}', "a"),
+    ('\u{253}', "b\u{314}"),
+    ('\u{256}', "d\u{328}"),
+    ('\u{257}', "d\u{314}"),
+    ('\u{259}', "\u{1DD}"),
+    ('\u{25A}', "\u{1DD}\u{2DE}"),
+    ('\u{25B}', "\u{A793}"),
+    ('\u{260}', "g\u{314}"),
+    ('\u{261}', "g"),
+    ('\u{263}', "y"),
+    ('\u{266}', "h\u{314}"),
+    ('\u{268}', "i\u{335}"),
+    ('\u{269}', "i"),
+    ('\u{26A}', "i"),
+    ('\u{26B}', "l\u{334}"),
+    ('\u{26D}', "l\u{328}"),
+    ('\u{26E}', "l\u{21D}"),
+    ('\u{26F}', "w"),
+    ('\u{271}', "rn\u{326}"),
+    ('\u{273}', "n\u{328}"),
+    ('\u{275}', "o\u{335}"),
+    ('\u{276}', "o\u{1D07}"),
+    ('\u{27C}', "r\u{329}"),
+    ('\u{27D}', "r\u{328}"),
+    ('\u{282}', "s\u{328}"),
+    ('\u{28B}', "u"),
+    ('\u{28F}', "y"),
+    ('\u{290}', "z\u{328}"),
+    ('\u{292}', "\u{21D}"),
+    ('\u{294}', "?"),
+    ('\u{2A0}', "q\u{314}"),
+    ('\u{2A3}', "dz"),
+    ('\u{2A4}', "d\u{21D}"),
+    ('\u{2A5}', "d\u{291}"),
+    ('\u{2A6}', "ts"),
+    ('\u{2A7}', "t\u{283}"),
+    ('\u{2A8}', "t\u{255}"),
+    ('\u{2A9}', "f\u{14B}"),
+    ('\u{2AA}', "ls"),
+    ('\u{2AB}', "lz"),
+    ('\u{2B3}', "\u{18F4}"),
+    ('\u{2B9}', "\u{27}"),
+    ('\u{2BA}', "\u{27}\u{27}"),
+    ('\u{2BB}', "\u{27}"),
+    ('\u{2BC}', "\u{27}"),
+    ('\u{2BD}', "\u{27}"),
+    ('\u{2BE}', "\u{27}"),
+    ('\u{2BF}', "\u{559}"),
+    ('\u{2C2}', "<"),
+    ('\u{2C3}', ">"),
+    ('\u{2C4}', "^"),
+    ('\u{2C6}', "^"),
+    ('\u{2C8}', "\u{27}"),
+    ('\u{2CA}', "\u{27}"),
+    ('\u{2CB}', "\u{27}"),
+    ('\u{2D0}', ":"),
+    ('\u{2D3}', "\u{559}"),
+    ('\u{2D7}', "-"),
+    ('\u{2D8}', "\u{2C7}"),
+    ('\u{2D9}', "\u{971}"),
+    ('\u{2DA}', "\u{B0}"),
+    ('\u{2DB}', "i"),
+    ('\u{2DC}', "~"),
+    ('\u{2DD}', "\u{27}\u{27}"),
+    ('\u{2E1}', "\u{18F3}"),
+    ('\u{2E2}', "\u{18F5}"),
+    ('\u{2E4}', "\u{2C1}"),
+    ('\u{2EE}', "\u{27}\u{27}"),
+    ('\u{2F4}', "\u{27}"),
+    ('\u{2F6}', "\u{27}\u{27}"),
+    ('\u{2F8}', ":"),
+    ('\u{2FB}', "\u{2EA}"),
+    ('\u{305}', "\u{304}"),
+    ('\u{30C}', "\u{306}"),
+    ('\u{30D}', "\u{670}"),
+    ('\u{310}', "\u{306}\u{307}"),
+    ('\u{311}', "\u{302}"),
+    ('\u{315}', "\u{313}"),
+    ('\u{317}', "\u{650}"),
+    ('\u{320}', "\u{331}"),
+    ('\u{321}', "\u{326}"),
+    ('\u{322}', "\u{328}"),
+    ('\u{327}', "\u{326}"),
+    ('\u{336}', "\u{335}"),
+    ('\u{337}', "\u{338}"),
+    ('\u{339}', "\u{326}"),
+    ('\u{340}', "\u{300}"),
+    ('\u{341}', "\u{301}"),
+    ('\u{342}', "\u{303}"),
+    ('\u{343}', "\u{313}"),
+    ('\u{344}', "\u{308}\u{301}"),
+    ('\u{345}', "\u{328}"),
+    ('\u{347}', "\u{333}"),
+    ('\u{357}', "\u{350}"),
+    ('\u{358}', "\u{307}"),
+    ('\u{366}', "\u{30A}"),
+    ('\u{36E}', "\u{306}"),
+    ('\u{370}', "\u{2C75}"),
+    ('\u{374}', "\u{27}"),
+    ('\u{375}', "\u{2CF}"),
+    ('\u{376}', "\u{418}"),
+    ('\u{377}', "\u{1D0E}"),
+    ('\u{37A}', "i"),
+    ('\u{37B}', "\u{254}"),
+    ('\u{37D}', "\u{A73F}"),
+    ('\u{37E}', ";"),
+    ('\u{37F}', "J"),
+    ('\u{384}', "\u{27}"),
+    ('\u{385}', "\u{A8}\u{301}"),
+    ('\u{386}', "A\u{301}"),
+    ('\u{387}', "\u{B7}"),
+    ('\u{388}', "E\u{301}"),
+    ('\u{389}', "H\u{301}"),
+    ('\u{38A}', "l\u{301}"),
+    ('\u{38C}', "O\u{301}"),
+    ('\u{38E}', "Y\u{301}"),
+    ('\u{38F}', "\u{3A9}\u{301}"),
+    ('\u{390}', "i\u{308}\u{301}"),
+    ('\u{391}', "A"),
+    ('\u{392}', "B"),
+    ('\u{395}', "E"),
+    ('\u{396}', "Z"),
+    ('\u{397}', "H"),
+    ('\u{398}', "O\u{335}"),
+    ('\u{399}', "l"),
+    ('\u{39A}', "K"),
+    ('\u{39B}', "\u{245}"),
+    ('\u{39C}', "M"),
+    ('\u{39D}', "N"),
+    ('\u{39F}', "O"),
+    ('\u{3A1}', "P"),
+    ('\u{3A3}', "\u{1A9}"),
+    ('\u{3A4}', "T"),
+    ('\u{3A5}', "Y"),
+    ('\u{3A7}', "X"),
+    ('\u{3AA}', "l\u{308}"),
+    ('\u{3AB}', "Y\u{308}"),
+    ('\u{3AC}', "a\u{301}"),
+    ('\u{3AD}', "\u{A793}\u{301}"),
+    ('\u{3AE}', "n\u{329}\u{301}"),
+    ('\u{3AF}', "i\u{301}"),
+    ('\u{3B0}', "u\u{308}\u{301}"),
+    ('\u{3B1}', "a"),
+    ('\u{3B2}', "\u{DF}"),
+    ('\u{3B3}', "y"),
+    ('\u{3B4}', "\u{1E9F}"),
+    ('\u{3B5}', "\u{A793}"),
+    ('\u{3B7}', "n\u{329}"),
+    ('\u{3B8}', "O\u{335}"),
+    ('\u{3B9}', "i"),
+    ('\u{3BA}', "\u{138}"),
+    ('\u{3BD}', "v"),
+    ('\u{3BF}', "o"),
+    ('\u{3C1}', "p"),
+    ('\u{3C3}', "o"),
+    ('\u{3C4}', "\u{1D1B}"),
+    ('\u{3C5}', "u"),
+    ('\u{3C6}', "\u{278}"),
+    ('\u{3CA}', "i\u{308}"),
+    ('\u{3CB}', "u\u{308}"),
+    ('\u{3CC}', "o\u{301}"),
+    ('\u{3CD}', "u\u{301}"),
+    ('\u{3CE}', "\u{3C9}\u{301}"),
+    ('\u{3D0}', "\u{DF}"),
+    ('\u{3D1}', "O\u{335}"),
+    ('\u{3D2}', "Y"),
+    ('\u{3D3}', "Y\u{301}"),
+    ('\u{3D4}', "Y\u{308}"),
+    ('\u{3D5}', "\u{278}"),
+    ('\u{3D6}', "\u{3C0}"),
+    ('\u{3DB}', "\u{3C2}"),
+    ('\u{3DC}', "F"),
+    ('\u{3E8}', "2"),
+    ('\u{3E9}', "\u{1A8}"),
+    ('\u{3F0}', "\u{138}"),
+    ('\u{3F1}', "p"),
+    ('\u{3F2}', "c"),
+    ('\u{3F3}', "j"),
+    ('\u{3F4}', "O\u{335}"),
+    ('\u{3F5}', "\u{A793}"),
+    ('\u{3F7}', "\u{DE}"),
+    ('\u{3F8}', "\u{FE}"),
+    ('\u{3F9}', "C"),
+    ('\u{3FA}', "M"),
+    ('\u{3FD}', "\u{186}"),
+    ('\u{3FF}', "\u{A73E}"),
+    ('\u{400}', "E\u{300}"),
+    ('\u{401}', "E\u{308}"),
+    ('\u{403}', "\u{393}\u{301}"),
+    ('\u{404}', "\u{A792}"),
+    ('\u{405}', "S"),
+    ('\u{406}', "l"),
+    ('\u{407}', "l\u{308}"),
+    ('\u{408}', "J"),
+    ('\u{40C}', "K\u{301}"),
+    ('\u{40D}', "\u{418}\u{300}"),
+    ('\u{40E}', "Y\u{306}"),
+    ('\u{410}', "A"),
+    ('\u{411}', "b\u{304}"),
+    ('\u{412}', "B"),
+    ('\u{413}', "\u{393}"),
+    ('\u{415}', "E"),
+    ('\u{417}', "3"),
+    ('\u{419}', "\u{418}\u{306}"),
+    ('\u{41A}', "K"),
+    ('\u{41B}', "\u{245}"),
+    ('\u{41C}', "M"),
+    ('\u{41D}', "H"),
+    ('\u{41E}', "O"),
+    ('\u{41F}', "\u{3A0}"),
+    ('\u{420}', "P"),
+    ('\u{421}', "C"),
+    ('\u{422}', "T"),
+    ('\u{423}', "Y"),
+    ('\u{424}', "\u{3A6}"),
+    ('\u{425}', "X"),
+    ('\u{42B}', "bl"),
+    ('\u{42C}', "b"),
+    ('\u{42E}', "lO"),
+    ('\u{430}', "a"),
+    ('\u{431}', "6"),
+    ('\u{432}', "\u{299}"),
+    ('\u{433}', "r"),
+    ('\u{435}', "e"),
+    ('\u{437}', "\u{25C}"),
+    ('\u{438}', "\u{1D0E}"),
+    ('\u{439}', "\u{1D0E}\u{306}"),
+    ('\u{43A}', "\u{138}"),
+    ('\u{43C}', "\u{28D}"),
+    ('\u{43D}', "\u{29C}"),
+    ('\u{43E}', "o"),
+    ('\u{43F}', "\u{3C0}"),
+    ('\u{440}', "p"),
+    ('\u{441}', "c"),
+    ('\u{442}', "\u{1D1B}"),
+    ('\u{443}', "y"),
+    ('\u{444}', "\u{278}"),
+    ('\u{445}', "x"),
+    ('\u{44A}', "\u{2C9}b"),
+    ('\u{44B}', "\u{185}i"),
+    ('\u{44C}', "\u{185}"),
+    ('\u{44F}', "\u{1D19}"),
+    ('\u{450}', "e\u{300}"),
+    ('\u{451}', "e\u{308}"),
+    ('\u{453}', "r\u{301}"),
+    ('\u{454}', "\u{A793}"),
+    ('\u{455}', "s"),
+    ('\u{456}', "i"),
+    ('\u{457}', "i\u{308}"),
+    ('\u{458}', "j"),
+    ('\u{45B}', "h\u{335}"),
+    ('\u{45C}', "\u{138}\u{301}"),
+    ('\u{45D}', "\u{1D0E}\u{300}"),
+    ('\u{45E}', "y\u{306}"),
+    ('\u{461}', "w"),
+    ('\u{462}', "b\u{335}"),
+    ('\u{463}', "b\u{335}"),
+    ('\u{470}', "\u{3A8}"),
+    ('\u{471}', "\u{3C8}"),
+    ('\u{472}', "O\u{335}"),
+    ('\u{473}', "o\u{335}"),
+    ('\u{474}', "V"),
+    ('\u{475}', "v"),
+    ('\u{476}', "V\u{30F}"),
+    ('\u{477}', "v\u{30F}"),
+    ('\u{47C}', "\u{460}\u{486}\u{487}"),
+    ('\u{47D}', "w\u{486}\u{487}"),
+    ('\u{48A}', "\u{418}\u{326}\u{300}"),
+    ('\u{48B}', "\u{438}\u{326}\u{306}"),
+    ('\u{48C}', "b\u{335}"),
+    ('\u{48D}', "b\u{335}"),
+    ('\u{490}', "\u{393}\u{27}"),
+    ('\u{491}', "r\u{27}"),
+    ('\u{492}', "\u{393}\u{335}"),
+    ('\u{493}', "r\u{335}"),
+    ('\u{496}', "\u{416}\u{329}"),
+    ('\u{497}', "\u{436}\u{329}"),
+    ('\u{498}', "3\u{326}"),
+    ('\u{499}', "\u{25C}\u{326}"),
+    ('\u{49A}', "K\u{329}"),
+    ('\u{49B}', "\u{138}\u{329}"),
+    ('\u{49E}', "K\u{335}"),
+    ('\u{49F}', "\u{138}\u{335}"),
+    ('\u{4A2}', "H\u{329}"),
+    ('\u{4A3}', "\u{29C}\u{329}"),
+    ('\u{4AA}', "C\u{326}"),
+    ('\u{4AB}', "c\u{326}"),
+    ('\u{4AC}', "T\u{329}"),
+    ('\u{4AD}', "\u{1D1B}\u{329}"),
+    ('\u{4AE}', "Y"),
+    ('\u{4AF}', "y"),
+    ('\u{4B0}', "Y\u{335}"),
+    ('\u{4B1}', "y\u{335}"),
+    ('\u{4B2}', "X\u{329}"),
+    ('\u{4BB}', "h"),
+    ('\u{4BD}', "e"),
+    ('\u{4BE}', "\u{4BC}\u{328}"),
+    ('\u{4BF}', "e\u{328}"),
+    ('\u{4C0}', "l"),
+    ('\u{4C1}', "\u{416}\u{306}"),
+    ('\u{4C2}', "\u{436}\u{306}"),
+    ('\u{4C5}', "\u{245}\u{326}"),
+    ('\u{4C6}', "\u{43B}\u{326}"),
+    ('\u{4C7}', "H\u{326}"),
+    ('\u{4C8}', "\u{29C}\u{326}"),
+    ('\u{4C9}', "H\u{326}"),
+    ('\u{4CA}', "\u{29C}\u{326}"),
+    ('\u{4CB}', "\u{4B6}"),
+    ('\u{4CC}', "\u{4B7}"),
+    ('\u{4CD}', "M\u{326}"),
+    ('\u{4CE}', "\u{28D}\u{326}"),
+    ('\u{4CF}', "i"),
+    ('\u{4D0}', "A\u{306}"),
+    ('\u{4D1}', "a\u{306}"),
+    ('\u{4D2}', "A\u{308}"),
+    ('\u{4D3}', "a\u{308}"),
+    ('\u{4D4}', "AE"),
+    ('\u{4D5}', "ae"),
+    ('\u{4D6}', "E\u{306}"),
+    ('\u{4D7}', "e\u{306}"),
+    ('\u{4D8}', "\u{18F}"),
+    ('\u{4D9}', "\u{1DD}"),
+    ('\u{4DA}', "\u{18F}\u{308}"),
+    ('\u{4DB}', "\u{1DD}\u{308}"),
+    ('\u{4DC}', "\u{416}\u{308}"),
+    ('\u{4DD}', "\u{436}\u{308}"),
+    ('\u{4DE}', "3\u{308}"),
+    ('\u{4DF}', "\u{25C}\u{308}"),
+    ('\u{4E0}', "3"),
+    ('\u{4E1}', "\u{21D}"),
+    ('\u{4E2}', "\u{418}\u{304}"),
+    ('\u{4E3}', "\u{1D0E}\u{304}"),
+    ('\u{4E4}', "\u{418}\u{308}"),
+    ('\u{4E5}', "\u{1D0E}\u{308}"),
+    ('\u{4E6}', "O\u{308}"),
+    ('\u{4E7}', "o\u{308}"),
+    ('\u{4E8}', "O\u{335}"),
+    ('\u{4E9}', "o\u{335}"),
+    ('\u{4EA}', "O\u{335}\u{308}"),
+    ('\u{4EB}', "o\u{335}\u{308}"),
+    ('\u{4EC}', "\u{42D}\u{308}"),
+    ('\u{4ED}', "\u{44D}\u{308}"),
+    ('\u{4EE}', "Y\u{304}"),
+    ('\u{4EF}', "y\u{304}"),
+    ('\u{4F0}', "Y\u{308}"),
+    ('\u{4F1}', "y\u{308}"),
+    ('\u{4F2}', "Y\u{30B}"),
+    ('\u{4F3}', "y\u{30B}"),
+    ('\u{4F4}', "\u{427}\u{308}"),
+    ('\u{4F5}', "\u{447}\u{308}"),
+    ('\u{4F8}', "bl\u{308}"),
+    ('\u{4F9}', "\u{185}i\u{308}"),
+    ('\u{501}', "d"),
+    ('\u{50A}', "\u{1F6}"),
+    ('\u{50C}', "G"),
+    ('\u{50D}', "\u{262}"),
+    ('\u{510}', "\u{190}"),
+    ('\u{511}', "\u{A793}"),
+    ('\u{51B}', "q"),
+    ('\u{51C}', "W"),
+    ('\u{51D}', "w"),
+    ('\u{53B}', "\u{12AE}"),
+    ('\u{544}', "\u{1206}"),
+    ('\u{54A}', "\u{1323}"),
+    ('\u{54C}', "\u{1261}"),
+    ('\u{54D}', "U"),
+    ('\u{54F}', "S"),
+    ('\u{553}', "\u{3A6}"),
+    ('\u{555}', "O"),
+    ('\u{55A}', "\u{27}"),
+    ('\u{55D}', "\u{27}"),
+    ('\u{561}', "w"),
+    ('\u{563}', "q"),
+    ('\u{566}', "q"),
+    ('\u{56E}', "\u{1E9F}"),
+    ('\u{570}', "h"),
+    ('\u{575}', "\u{237}"),
+    ('\u{578}', "n"),
+    ('\u{57A}', "\u{270}"),
+    ('\u{57C}', "n"),
+    ('\u{57D}', "u"),
+    ('\u{581}', "g"),
+    ('\u{584}', "f"),
+    ('\u{585}', "o"),
+    ('\u{587}', "\u{565}\u{582}"),
+    ('\u{589}', ":"),
+    ('\u{59C}', "\u{301}"),
+    ('\u{59D}', "\u{301}"),
+    ('\u{5A4}', "\u{59A}"),
+    ('\u{5A8}', "\u{599}"),
+    ('\u{5AD}', "\u{596}"),
+    ('\u{5AE}', "\u{598}"),
+    ('\u{5AF}', "\u{30A}"),
+    ('\u{5B4}', "\u{323}"),
+    ('\u{5B9}', "\u{307}"),
+    ('\u{5BA}', "\u{307}"),
+    ('\u{5C0}', "l"),
+    ('\u{5C1}', "\u{307}"),
+    ('\u{5C2}', "\u{307}"),
+    ('\u{5C3}', ":"),
+    ('\u{5C4}', "\u{307}"),
+    ('\u{5C5}', "\u{323}"),
+    ('\u{5D5}', "l"),
+    ('\u{5D8}', "v"),
+    ('\u{5D9}', "\u{27}"),
+    ('\u{5DF}', "l"),
+    ('\u{5E1}', "o"),
+    ('\u{5F0}', "ll"),
+    ('\u{5F1}', "l\u{27}"),
+    ('\u{5F2}', "\u{27}\u{27}"),
+    ('\u{5F3}', "\u{27}"),
+    ('\u{5F4}', "\u{27}\u{27}"),
+    ('\u{609}', "\u{BA}/\u{2080}\u{2080}"),
+    ('\u{60A}', "\u{BA}/\u{2080}\u{2080}\u{2080}"),
+    ('\u{60D}', ","),
+    ('\u{60F}', "\u{639}"),
+    ('\u{618}', "\u{301}"),
+    ('\u{619}', "\u{313}"),
+    ('\u{61A}', "\u{650}"),
+    ('\u{622}', "l\u{303}"),
+    ('\u{623}', "l\u{654}"),
+    ('\u{624}', "\u{648}\u{654}"),
+    ('\u{625}', "l\u{655}"),
+    ('\u{626}', "\u{649}\u{654}"),
+    ('\u{627}', "l"),
+    ('\u{62B}', "\u{649}\u{6DB}"),
+    ('\u{634}', "\u{633}\u{6DB}"),
+    ('\u{63D}', "\u{649}\u{302}"),
+    ('\u{63F}', "\u{649}\u{6DB}"),
+    ('\u{647}', "o"),
+    ('\u{64A}', "\u{649}"),
+    ('\u{64B}', "\u{30B}"),
+    ('\u{64E}', "\u{301}"),
+    ('\u{64F}', "\u{313}"),
+    ('\u{652}', "\u{30A}"),
+    ('\u{653}', "\u{303}"),
+    ('\u{656}', "\u{329}"),
+    ('\u{657}', "\u{312}"),
+    ('\u{658}', "\u{306}"),
+    ('\u{659}', "\u{304}"),
+    ('\u{65A}', "\u{306}"),
+    ('\u{65B}', "\u{302}"),
+    ('\u{65C}', "\u{323}"),
+    ('\u{65D}', "\u{314}"),
+    ('\u{65F}', "\u{655}"),
+    ('\u{660}', "."),
+    ('\u{661}', "l"),
+    ('\u{665}', "o"),
+    ('\u{667}', "V"),
+    ('\u{668}', "\u{245}"),
+    ('\u{66A}', "\u{BA}/\u{2080}"),
+    ('\u{66B}', ","),
+    ('\u{66C}', "\u{60C}"),
+    ('\u{66D}', "*"),
+    ('\u{66E}', "\u{649}"),
+    ('\u{66F}', "\u{6A1}"),
+    ('\u{672}', "l\u{674}"),
+    ('\u{673}', "l\u{655}"),
+    ('\u{675}', "l\u{674}"),
+    ('\u{676}', "\u{648}\u{674}"),
+    ('\u{677}', "\u{648}\u{313}\u{674}"),
+    ('\u{678}', "\u{649}\u{674}"),
+    ('\u{679}', "\u{649}\u{615}"),
+    ('\u{67E}', "\u{649}\u{6DB}"),
+    ('\u{681}', "\u{62D}\u{654}"),
+    ('\u{685}', "\u{62D}\u{6DB}"),
+    ('\u{688}', "\u{62F}\u{615}"),
+    ('\u{68B}', "\u{68A}\u{615}"),
+    ('\u{68E}', "\u{62F}\u{6DB}"),
+    ('\u{691}', "\u{631}\u{615}"),
+    ('\u{692}', "\u{631}\u{306}"),
+    ('\u{698}', "\u{631}\u{6DB}"),
+    ('\u{69E}', "\u{635}\u{6DB}"),
+    ('\u{69F}', "\u{637}\u{6DB}"),
+    ('\u{6A4}', "\u{6A1}\u{6DB}"),
+    ('\u{6A7}', "\u{641}"),
+    ('\u{6A8}', "\u{6A1}\u{6DB}"),
+    ('\u{6A9}', "\u{643}"),
+    ('\u{6AA}', "\u{643}"),
+    ('\u{6AD}', "\u{643}\u{6DB}"),
+    ('\u{6B4}', "\u{6AF}\u{6DB}"),
+    ('\u{6B5}', "\u{644}\u{306}"),
+    ('\u{6B7}', "\u{644}\u{6DB}"),
+    ('\u{6BA}', "\u{649}"),
+    ('\u{6BB}', "\u{649}\u{615}"),
+    ('\u{6BD}', "\u{649}\u{6DB}"),
+    ('\u{6BE}', "o"),
+    ('\u{6C0}', "o\u{654}"),
+    ('\u{6C1}', "o"),
+    ('\u{6C2}', "o\u{654}"),
+    ('\u{6C3}', "\u{629}"),
+    ('\u{6C6}', "\u{648}\u{306}"),
+    ('\u{6C7}', "\u{648}\u{313}"),
+    ('\u{6C8}', "\u{648}\u{670}"),
+    ('\u{6C9}', "\u{648}\u{302}"),
+    ('\u{6CB}', "\u{648}\u{6DB}"),
+    ('\u{6CC}', "\u{649}"),
+    ('\u{6CE}', "\u{649}\u{306}"),
+    ('\u{6D0}', "\u{67B}"),
+    ('\u{6D1}', "\u{649}\u{6DB}"),
+    ('\u{6D2}', "\u{649}"),
+    ('\u{6D3}', "\u{649}\u{654}"),
+    ('\u{6D4}', "-"),
+    ('\u{6D5}', "o"),
+    ('\u{6DF}', "\u{30A}"),
+    ('\u{6E8}', "\u{306}\u{307}"),
+    ('\u{6EC}', "\u{307}"),
+    ('\u{6EE}', "\u{62F}\u{302}"),
+    ('\u{6EF}', "\u{631}\u{302}"),
+    ('\u{6F0}', "."),
+    ('\u{6F1}', "l"),
+    ('\u{6F2}', "\u{662}"),
+    ('\u{6F3}', "\u{663}"),
+    ('\u{6F4}', "\u{664}"),
+    ('\u{6F5}', "o"),
+    ('\u{6F6}', "\u{666}"),
+    ('\u{6F7}', "V"),
+    ('\u{6F8}', "\u{245}"),
+    ('\u{6F9}', "\u{669}"),
+    ('\u{6FD}', "\u{621}\u{348}"),
+    ('\u{6FE}', "\u{645}\u{348}"),
+    ('\u{6FF}', "o\u{302}"),
+    ('\u{701}', "."),
+    ('\u{702}', "."),
+    ('\u{703}', ":"),
+    ('\u{704}', ":"),
+    ('\u{740}', "\u{307}"),
+    ('\u{741}', "\u{307}"),
+    ('\u{742}', "\u{73C}"),
+    ('\u{747}', "\u{301}"),
+    ('\u{751}', "\u{628}\u{6DB}"),
+    ('\u{756}', "\u{649}\u{306}"),
+    ('\u{762}', "\u{6AC}"),
+    ('\u{763}', "\u{643}\u{6DB}"),
+    ('\u{767}', "\u{754}"),
+    ('\u{768}', "\u{646}\u{615}"),
+    ('\u{769}', "\u{646}\u{306}"),
+    ('\u{76C}', "\u{631}\u{654}"),
+    ('\u{771}', "\u{697}\u{615}"),
+    ('\u{772}', "\u{62D}\u{654}"),
+    ('\u{77E}', "\u{633}\u{302}"),
+    ('\u{7C0}', "O"),
+    ('\u{7CA}', "l"),
+    ('\u{7EB}', "\u{304}"),
+    ('\u{7ED}', "\u{307}"),
+    ('\u{7EE}', "\u{302}"),
+    ('\u{7F3}', "\u{308}"),
+    ('\u{7F4}', "\u{27}"),
+    ('\u{7F5}', "\u{27}"),
+    ('\u{7FA}', "_"),
+    ('\u{8A1}', "\u{628}\u{654}"),
+    ('\u{8A4}', "\u{6A2}\u{6DB}"),
+    ('\u{8A7}', "\u{645}\u{6DB}"),
+    ('\u{8A8}', "\u{649}\u{654}"),
+    ('\u{8A9}', "\u{754}"),
+    ('\u{8AE}', "\u{62F}\u{324}\u{323}"),
+    ('\u{8AF}', "\u{635}\u{324}\u{323}"),
+    ('\u{8B0}', "\u{6AF}"),
+    ('\u{8B1}', "\u{648}"),
+    ('\u{8B2}', "\u{632}\u{302}"),
+    ('\u{8B6}', "\u{628}\u{6E2}"),
+    ('\u{8B7}', "\u{649}\u{6DB}\u{6E2}"),
+    ('\u{8B9}', "\u{631}\u{306}\u{307}"),
+    ('\u{8BA}', "\u{649}\u{306}\u{307}"),
+    ('\u{8BB}', "\u{6A1}"),
+    ('\u{8BC}', "\u{6A1}"),
+    ('\u{8BD}', "\u{649}"),
+    ('\u{8E5}', "\u{64C}"),
+    ('\u{8E8}', "\u{64C}"),
+    ('\u{8EA}', "\u{307}"),
+    ('\u{8EB}', "\u{308}"),
+    ('\u{8ED}', "\u{323}"),
+    ('\u{8EE}', "\u{324}"),
+    ('\u{8F0}', "\u{30B}"),
+    ('\u{8F1}', "\u{64C}"),
+    ('\u{8F2}', "\u{64D}"),
+    ('\u{8F3}', "\u{313}"),
+    ('\u{8F8}', "\u{350}"),
+    ('\u{8F9}', "\u{354}"),
+    ('\u{8FA}', "\u{355}"),
+    ('\u{8FF}', "\u{350}"),
+    ('\u{900}', "\u{352}"),
+    ('\u{901}', "\u{306}\u{307}"),
+    ('\u{902}', "\u{307}"),
+    ('\u{903}', ":"),
+    ('\u{904}', "\u{905}\u{946}"),
+    ('\u{906}', "\u{905}\u{93E}"),
+    ('\u{908}', "\u{930}\u{94D}\u{907}"),
+    ('\u{90D}', "\u{90F}\u{945}"),
+    ('\u{90E}', "\u{90F}\u{946}"),
+    ('\u{910}', "\u{90F}\u{947}"),
+    ('\u{911}', "\u{905}\u{949}"),
+    ('\u{912}', "\u{905}\u{93E}\u{946}"),
+    ('\u{913}', "\u{905}\u{93E}\u{947}"),
+    ('\u{914}', "\u{905}\u{93E}\u{948}"),
+    ('\u{929}', "\u{928}\u{323}"),
+    ('\u{931}', "\u{930}\u{323}"),
+    ('\u{934}', "\u{933}\u{323}"),
+    ('\u{93C}', "\u{323}"),
+    ('\u{952}', "\u{331}"),
+    ('\u{953}', "\u{300}"),
+    ('\u{954}', "\u{301}"),
+    ('\u{958}', "\u{915}\u{323}"),
+    ('\u{959}', "\u{916}\u{323}"),
+    ('\u{95A}', "\u{917}\u{323}"),
+    ('\u{95B}', "\u{91C}\u{323}"),
+    ('\u{95C}', "\u{921}\u{323}"),
+    ('\u{95D}', "\u{922}\u{323}"),
+    ('\u{95E}', "\u{92B}\u{323}"),
+    ('\u{95F}', "\u{92F}\u{323}"),
+    ('\u{965}', "\u{964}\u{964}"),
+    ('\u{966}', "o"),
+    ('\u{967}', "\u{669}"),
+    ('\u{97D}', "?"),
+    ('\u{981}', "\u{306}\u{307}"),
+    ('\u{986}', "\u{985}\u{9BE}"),
+    ('\u{9BC}', "\u{323}"),
+    ('\u{9CB}', "\u{9C7}\u{9BE}"),
+    ('\u{9CC}', "\u{9C7}\u{9D7}"),
+    ('\u{9DC}', "\u{9A1}\u{323}"),
+    ('\u{9DD}', "\u{9A2}\u{323}"),
+    ('\u{9DF}', "\u{9AF}\u{323}"),
+    ('\u{9E0}', "\u{98B}\u{9C3}"),
+    ('\u{9E1}', "\u{98B}\u{9C3}"),
+    ('\u{9E6}', "O"),
+    ('\u{9EA}', "8"),
+    ('\u{9ED}', "9"),
+    ('\u{A02}', "\u{307}"),
+    ('\u{A03}', "\u{983}"),
+    ('\u{A06}', "\u{A05}\u{A3E}"),
+    ('\u{A07}', "\u{A72}\u{A3F}"),
+    ('\u{A08}', "\u{A72}\u{A40}"),
+    ('\u{A09}', "\u{A73}\u{A41}"),
+    ('\u{A0A}', "\u{A73}\u{A42}"),
+    ('\u{A0F}', "\u{A72}\u{A47}"),
+    ('\u{A10}', "\u{A05}\u{A48}"),
+    ('\u{A14}', "\u{A05}\u{A4C}"),
+    ('\u{A33}', "\u{A32}\u{323}"),
+    ('\u{A36}', "\u{A38}\u{323}"),
+    ('\u{A3C}', "\u{323}"),
+    ('\u{A4B}', "\u{946}"),
+    ('\u{A4D}', "\u{94D}"),
+    ('\u{A59}', "\u{A16}\u{323}"),
+    ('\u{A5A}', "\u{A17}\u{323}"),
+    ('\u{A5B}', "\u{A1C}\u{323}"),
+    ('\u{A5E}', "\u{A2B}\u{323}"),
+    ('\u{A66}', "o"),
+    ('\u{A67}', "9"),
+    ('\u{A6A}', "8"),
+    ('\u{A81}', "\u{306}\u{307}"),
+    ('\u{A82}', "\u{307}"),
+    ('\u{A83}', ":"),
+    ('\u{A86}', "\u{A85}\u{ABE}"),
+    ('\u{A8D}', "\u{A85}\u{AC5}"),
+    ('\u{A8F}', "\u{A85}\u{AC7}"),
+    ('\u{A90}', "\u{A85}\u{AC8}"),
+    ('\u{A91}', "\u{A85}\u{ABE}\u{AC5}"),
+    ('\u{A93}', "\u{A85}\u{ABE}\u{AC7}"),
+    ('\u{A94}', "\u{A85}\u{ABE}\u{AC8}"),
+    ('\u{ABC}', "\u{323}"),
+    ('\u{ABD}', "\u{93D}"),
+    ('\u{AC1}', "\u{941}"),
+    ('\u{AC2}', "\u{942}"),
+    ('\u{ACD}', "\u{94D}"),
+    ('\u{AE6}', "o"),
+    ('\u{AE8}', "\u{968}"),
+    ('\u{AE9}', "\u{969}"),
+    ('\u{AEA}', "\u{96A}"),
+    ('\u{AEE}', "\u{96E}"),
+    ('\u{AF0}', "\u{970}"),
+    ('\u{B01}', "\u{306}\u{307}"),
+    ('\u{B03}', "8"),
+    ('\u{B06}', "\u{B05}\u{B3E}"),
+    ('\u{B20}', "O"),
+    ('\u{B3C}', "\u{323}"),
+    ('\u{B48}', "\u{B47}\u{B56}"),
+    ('\u{B4B}', "\u{B47}\u{B3E}"),
+    ('\u{B4C}', "\u{B47}\u{B57}"),
+    ('\u{B5C}', "\u{B21}\u{323}"),
+    ('\u{B5D}', "\u{B22}\u{323}"),
+    ('\u{B66}', "O"),
+    ('\u{B68}', "9"),
+    ('\u{B82}', "\u{30A}"),
+    ('\u{B8A}', "\u{B89}\u{BB3}"),
+    ('\u{B94}', "\u{B92}\u{BB3}"),
+    ('\u{B9C}', "\u{B90}"),
+    ('\u{BB0}', "\u{B88}"),
+    ('\u{BBE}', "\u{B88}"),
+    ('\u{BC8}', "\u{BA9}"),
+    ('\u{BCA}', "\u{BC6}\u{B88}"),
+    ('\u{BCB}', "\u{BC7}\u{B88}"),
+    ('\u{BCC}', "\u{BC6}\u{BB3}"),
+    ('\u{BCD}', "\u{307}"),
+    ('\u{BD7}', "\u{BB3}"),
+    ('\u{BE6}', "o"),
+    ('\u{BE7}', "\u{B95}"),
+    ('\u{BE8}', "\u{B89}"),
+    ('\u{BEA}', "\u{B9A}"),
+    ('\u{BEB}', "\u{B88}\u{BC1}"),
+    ('\u{BEC}', "\u{B9A}\u{BC1}"),
+    ('\u{BED}', "\u{B8E}"),
+    ('\u{BEE}', "\u{B85}"),
+    ('\u{BF0}', "\u{BAF}"),
+    ('\u{BF2}', "\u{B9A}\u{BC2}"),
+    ('\u{BF4}', "\u{BAE}\u{BC0}"),
+    ('\u{BF5}', "\u{BF3}"),
+    ('\u{BF7}', "\u{B8E}\u{BB5}"),
+    ('\u{BF8}', "\u{BB7}"),
+    ('\u{BFA}', "\u{BA8}\u{BC0}"),
+    ('\u{C00}', "\u{306}\u{307}"),
+    ('\u{C02}', "o"),
+    ('\u{C03}', "\u{983}"),
+    ('\u{C13}', "\u{C12}\u{C55}"),
+    ('\u{C14}', "\u{C12}\u{C4C}"),
+    ('\u{C20}', "\u{C30}\u{5BC}"),
+    ('\u{C22}', "\u{C21}\u{323}"),
+    ('\u{C25}', "\u{C27}\u{5BC}"),
+    ('\u{C2D}', "\u{C2C}\u{323}"),
+    ('\u{C2E}', "\u{C35}\u{C41}"),
+    ('\u{C37}', "\u{C35}\u{323}"),
+    ('\u{C39}', "\u{C35}\u{C3E}"),
+    ('\u{C42}', "\u{C41}\u{C3E}"),
+    ('\u{C44}', "\u{C43}\u{C3E}"),
+    ('\u{C48}', "\u{C46}\u{C56}"),
+    ('\u{C60}', "\u{C0B}\u{C3E}"),
+    ('\u{C61}', "\u{C0C}\u{C3E}"),
+    ('\u{C66}', "o"),
+    ('\u{C81}', "\u{306}\u{307}"),
+    ('\u{C82}', "o"),
+    ('\u{C83}', "\u{983}"),
+    ('\u{C85}', "\u{C05}"),
+    ('\u{C86}', "\u{C06}"),
+    ('\u{C87}', "\u{C07}"),
+    ('\u{C92}', "\u{C12}"),
+    ('\u{C93}', "\u{C12}\u{C55}"),
+    ('\u{C94}', "\u{C12}\u{C4C}"),
+    ('\u{C9C}', "\u{C1C}"),
+    ('\u{C9E}', "\u{C1E}"),
+    ('\u{CA3}', "\u{C23}"),
+    ('\u{CAF}', "\u{C2F}"),
+    ('\u{CB1}', "\u{C31}"),
+    ('\u{CB2}', "\u{C32}"),
+    ('\u{CC0}', "\u{CBF}\u{CD5}"),
+    ('\u{CC7}', "\u{CC6}\u{CD5}"),
+    ('\u{CC8}', "\u{CC6}\u{CD6}"),
+    ('\u{CCA}', "\u{CC6}\u{CC2}"),
+    ('\u{CCB}', "\u{CC6}\u{CC2}\u{CD5}"),
+    ('\u{CE1}', "\u{C8C}\u{CBE}"),
+    ('\u{CE6}', "o"),
+    ('\u{CE7}', "\u{C67}"),
+    ('\u{CE8}', "\u{C68}"),
+    ('\u{CEF}', "\u{C6F}"),
+    ('\u{D01}', "\u{306}\u{307}"),
+    ('\u{D02}', "o"),
+    ('\u{D03}', "\u{983}"),
+    ('\u{D08}', "\u{D07}\u{D57}"),
+    ('\u{D09}', "\u{B89}"),
+    ('\u{D0A}', "\u{B89}\u{D57}"),
+    ('\u{D0C}', "\u{D28}\u{D41}"),
+    ('\u{D10}', "\u{D0E}\u{D46}"),
+    ('\u{D13}', "\u{D12}\u{D3E}"),
+    ('\u{D14}', "\u{D12}\u{D57}"),
+    ('\u{D19}', "\u{D28}\u{D41}"),
+    ('\u{D1C}', "\u{B90}"),
+    ('\u{D20}', "o"),
+    ('\u{D23}', "\u{BA3}"),
+    ('\u{D31}', "\u{D30}"),
+    ('\u{D34}', "\u{BB4}"),
+    ('\u{D36}', "\u{BB6}"),
+    ('\u{D3A}', "\u{B9F}\u{BBF}"),
+    ('\u{D3F}', "\u{BBF}"),
+    ('\u{D40}', "\u{BBF}"),
+    ('\u{D42}', "\u{D41}"),
+    ('\u{D43}', "\u{D41}"),
+    ('\u{D48}', "\u{D46}\u{D46}"),
+    ('\u{D4A}', "\u{D46}\u{D3E}"),
+    ('\u{D4B}', "\u{D47}\u{D3E}"),
+    ('\u{D4C}', "\u{D46}\u{D57}"),
+    ('\u{D4E}', "\u{971}"),
+    ('\u{D5A}', "\u{D28}\u{D4D}\u{D2E}"),
+    ('\u{D5F}', "o\u{D30}o"),
+    ('\u{D61}', "\u{D1E}"),
+    ('\u{D66}', "o"),
+    ('\u{D6A}', "\u{D30}\u{D4D}"),
+    ('\u{D6B}', "\u{D26}\u{D4D}\u{D30}"),
+    ('\u{D6C}', "\u{D28}\u{D4D}\u{D28}"),
+    ('\u{D6D}', "9"),
+    ('\u{D6E}', "\u{D35}\u{D4D}\u{D30}"),
+    ('\u{D6F}', "\u{D28}\u{D4D}"),
+    ('\u{D76}', "\u{D39}\u{D4D}\u{D2E}"),
+    ('\u{D79}', "\u{D28}\u{D41}"),
+    ('\u{D7B}', "\u{D28}\u{D4D}"),
+    ('\u{D7C}', "\u{D30}\u{D4D}"),
+    ('\u{D82}', "o"),
+    ('\u{D83}', "\u{983}"),
+    ('\u{DDA}', "\u{DD9}\u{DCA}"),
+    ('\u{DDC}', "\u{DD9}\u{DCF}"),
+    ('\u{DDD}', "\u{DD9}\u{DCF}\u{DCA}"),
+    ('\u{DDE}', "\u{DD9}\u{DDF}"),
+    ('\u{DE9}', "\u{DE8}\u{DCF}"),
+    ('\u{DEA}', "\u{DA2}"),
+    ('\u{DEB}', "\u{DAF}"),
+    ('\u{DEF}', "\u{DE8}\u{DD3}"),
+    ('\u{E03}', "\u{E02}"),
+    ('\u{E0B}', "\u{E0A}"),
+    ('\u{E0F}', "\u{E0E}"),
+    ('\u{E14}', "\u{E04}"),
+    ('\u{E15}', "\u{E04}"),
+    ('\u{E17}', "\u{E11}"),
+    ('\u{E21}', "\u{E06}"),
+    ('\u{E26}', "\u{E20}"),
+    ('\u{E33}', "\u{30A}\u{E32}"),
+    ('\u{E41}', "\u{E40}\u{E40}"),
+    ('\u{E45}', "\u{E32}"),
+    ('\u{E4D}', "\u{30A}"),
+    ('\u{E50}', "o"),
+    ('\u{E88}', "\u{E08}"),
+    ('\u{E8D}', "\u{E22}"),
+    ('\u{E9A}', "\u{E1A}"),
+    ('\u{E9B}', "\u{E1B}"),
+    ('\u{E9D}', "\u{E1D}"),
+    ('\u{E9E}', "\u{E1E}"),
+    ('\u{E9F}', "\u{E1F}"),
+    ('\u{EB3}', "\u{30A}\u{EB2}"),
+    ('\u{EB8}', "\u{E38}"),
+    ('\u{EB9}', "\u{E39}"),
+    ('\u{EC8}', "\u{E48}"),
+    ('\u{EC9}', "\u{E49}"),
+    ('\u{ECA}', "\u{E4A}"),
+    ('\u{ECB}', "\u{E4B}"),
+    ('\u{ECD}', "\u{30A}"),
+    ('\u{ED0}', "o"),
+    ('\u{EDC}', "\u{EAB}\u{E99}"),
+    ('\u{EDD}', "\u{EAB}\u{EA1}"),
+    ('\u{F00}', "\u{F68}\u{F7C}\u{F7E}"),
+    ('\u{F02}', "\u{F60}\u{F74}\u{F82}\u{F7F}"),
+    ('\u{F03}', "\u{F60}\u{F74}\u{F82}\u{F14}"),
+    ('\u{F0C}', "\u{F0B}"),
+    ('\u{F0E}', "\u{F0D}\u{F0D}"),
+    ('\u{F1B}', "\u{F1A}\u{F1A}"),
+    ('\u{F1E}', "\u{F1D}\u{F1D}"),
+    ('\u{F1F}', "\u{F1A}\u{F1D}"),
+    ('\u{F37}', "\u{325}"),
+    ('\u{F43}', "\u{F42}\u{FB7}"),
+    ('\u{F4D}', "\u{F4C}\u{FB7}"),
+    ('\u{F52}', "\u{F51}\u{FB7}"),
+    ('\u{F57}', "\u{F56}\u{FB7}"),
+    ('\u{F5C}', "\u{F5B}\u{FB7}"),
+    ('\u{F69}', "\u{F40}\u{FB5}"),
+    ('\u{F6A}', "\u{F62}"),
+    ('\u{F73}', "\u{F71}\u{F72}"),
+    ('\u{F75}', "\u{F71}\u{F74}"),
+    ('\u{F76}', "\u{FB2}\u{F80}"),
+    ('\u{F77}', "\u{FB2}\u{F71}\u{F80}"),
+    ('\u{F78}', "\u{FB3}\u{F80}"),
+    ('\u{F79}', "\u{FB3}\u{F71}\u{F80}"),
+    ('\u{F81}', "\u{F71}\u{F80}"),
+    ('\u{F93}', "\u{F92}\u{FB7}"),
+    ('\u{F9D}', "\u{F9C}\u{FB7}"),
+    ('\u{FA2}', "\u{FA1}\u{FB7}"),
+    ('\u{FA7}', "\u{FA6}\u{FB7}"),
+    ('\u{FAC}', "\u{FAB}\u{FB7}"),
+    ('\u{FB9}', "\u{F90}\u{FB5}"),
+    ('\u{FCE}', "\u{F1D}\u{F1A}"),
+    ('\u{FD5}', "\u{5350}"),
+    ('\u{FD6}', "\u{534D}"),
+    ('\u{1000}', "\u{1002}\u{102C}"),
+    ('\u{1010}', "o\u{102C}"),
+    ('\u{101D}', "o"),
+    ('\u{101F}', "\u{1015}\u{102C}"),
+    ('\u{1026}', "\u{1025}\u{102E}"),
+    ('\u{1029}', "\u{101E}\u{103C}"),
+    ('\u{102A}', "\u{101E}\u{103C}\u{1031}\u{102C}\u{103A}"),
+    ('\u{1036}', "\u{30A}"),
+    ('\u{1038}', "\u{983}"),
+    ('\u{1040}', "o"),
+    ('\u{104B}', "\u{104A}\u{104A}"),
+    ('\u{1065}', "\u{1041}"),
+    ('\u{1066}', "\u{1015}\u{103E}"),
+    ('\u{106F}', "\u{1015}\u{102C}\u{103E}"),
+    ('\u{1070}', "\u{1003}\u{103E}"),
+    ('\u{107E}', "\u{107D}\u{103E}"),
+    ('\u{1081}', "\u{1002}\u{103E}"),
+    ('\u{109E}', "\u{1083}\u{30A}"),
+    ('\u{10A0}', "\u{A786}"),
+    ('\u{10E7}', "y"),
+    ('\u{10F3}', "\u{21D}"),
+    ('\u{10FF}', "o"),
+    ('\u{1101}', "\u{1100}\u{1100}"),
+    ('\u{1104}', "\u{1103}\u{1103}"),
+    ('\u{1108}', "\u{1107}\u{1107}"),
+    ('\u{110A}', "\u{1109}\u{1109}"),
+    ('\u{110D}', "\u{110C}\u{110C}"),
+    ('\u{1113}', "\u{1102}\u{1100}"),
+    ('\u{1114}', "\u{1102}\u{1102}"),
+    ('\u{1115}', "\u{1102}\u{1103}"),
+    ('\u{1116}', "\u{1102}\u{1107}"),
+    ('\u{1117}', "\u{1103}\u{1100}"),
+    ('\u{1118}', "\u{1105}\u{1102}"),
+    ('\u{1119}', "\u{1105}\u{1105}"),
+    ('\u{111A}', "\u{1105}\u{1112}"),
+    ('\u{111B}', "\u{1105}\u{110B}"),
+    ('\u{111C}', "\u{1106}\u{1107}"),
+    ('\u{111D}', "\u{1106}\u{110B}"),
+    ('\u{111E}', "\u{1107}\u{1100}"),
+    ('\u{111F}', "\u{1107}\u{1102}"),
+    ('\u{1120}', "\u{1107}\u{1103}"),
+    ('\u{1121}', "\u{1107}\u{1109}"),
+    ('\u{1122}', "\u{1107}\u{1109}\u{1100}"),
+    ('\u{1123}', "\u{1107}\u{1109}\u{1103}"),
+    ('\u{1124}', "\u{1107}\u{1109}\u{1107}"),
+    ('\u{1125}', "\u{1107}\u{1109}\u{1109}"),
+    ('\u{1126}', "\u{1107}\u{1109}\u{110C}"),
+    ('\u{1127}', "\u{1107}\u{110C}"),
+    ('\u{1128}', "\u{1107}\u{110E}"),
+    ('\u{1129}', "\u{1107}\u{1110}"),
+    ('\u{112A}', "\u{1107}\u{1111}"),
+    ('\u{112B}', "\u{1107}\u{110B}"),
+    ('\u{112C}', "\u{1107}\u{1107}\u{110B}"),
+    ('\u{112D}', "\u{1109}\u{1100}"),
+    ('\u{112E}', "\u{1109}\u{1102}"),
+    ('\u{112F}', "\u{1109}\u{1103}"),
+    ('\u{1130}', "\u{1109}\u{1105}"),
+    ('\u{1131}', "\u{1109}\u{1106}"),
+    ('\u{1132}', "\u{1109}\u{1107}"),
+    ('\u{1133}', "\u{1109}\u{1107}\u{1100}"),
+    ('\u{1134}', "\u{1109}\u{1109}\u{1109}"),
+    ('\u{1135}', "\u{1109}\u{110B}"),
+    ('\u{1136}', "\u{1109}\u{110C}"),
+    ('\u{1137}', "\u{1109}\u{110E}"),
+    ('\u{1138}', "\u{1109}\u{110F}"),
+    ('\u{1139}', "\u{1109}\u{1110}"),
+    ('\u{113A}', "\u{1109}\u{1111}"),
+    ('\u{113B}', "\u{1105}\u{1112}"),
+    ('\u{113D}', "\u{113C}\u{113C}"),
+    ('\u{113F}', "\u{113E}\u{113E}"),
+    ('\u{1141}', "\u{110B}\u{1100}"),
+    ('\u{1142}', "\u{110B}\u{1103}"),
+    ('\u{1143}', "\u{110B}\u{1106}"),
+    ('\u{1144}', "\u{110B}\u{1107}"),
+    ('\u{1145}', "\u{110B}\u{1109}"),
+    ('\u{1146}', "\u{110B}\u{1140}"),
+    ('\u{1147}', "\u{110B}\u{110B}"),
+    ('\u{1148}', "\u{110B}\u{110C}"),
+    ('\u{1149}', "\u{110B}\u{110E}"),
+    ('\u{114A}', "\u{110B}\u{1110}"),
+    ('\u{114B}', "\u{110B}\u{1111}"),
+    ('\u{114D}', "\u{110C}\u{110B}"),
+    ('\u{114F}', "\u{114E}\u{114E}"),
+    ('\u{1151}', "\u{1150}\u{1150}"),
+    ('\u{1152}', "\u{110E}\u{110F}"),
+    ('\u{1153}', "\u{110E}\u{1112}"),
+    ('\u{1156}', "\u{1111}\u{1107}"),
+    ('\u{1157}', "\u{1111}\u{110B}"),
+    ('\u{1158}', "\u{1112}\u{1112}"),
+    ('\u{115A}', "\u{1100}\u{1103}"),
+    ('\u{115B}', "\u{1102}\u{1109}"),
+    ('\u{115C}', "\u{1102}\u{110C}"),
+    ('\u{115D}', "\u{1102}\u{1112}"),
+    ('\u{115E}', "\u{1103}\u{1105}"),
+    ('\u{1162}', "\u{1161}\u{4E28}"),
+    ('\u{1164}', "\u{1163}\u{4E28}"),
+    ('\u{1166}', "\u{1165}\u{4E28}"),
+    ('\u{1168}', "\u{1167}\u{4E28}"),
+    ('\u{116A}', "\u{1169}\u{1161}"),
+    ('\u{116B}', "\u{1169}\u{1161}\u{4E28}"),
+    ('\u{116C}', "\u{1169}\u{4E28}"),
+    ('\u{116F}', "\u{116E}\u{1165}"),
+    ('\u{1170}', "\u{116E}\u{1165}\u{4E28}"),
+    ('\u{1171}', "\u{116E}\u{4E28}"),
+    ('\u{1173}', "\u{30FC}"),
+    ('\u{1174}', "\u{30FC}\u{4E28}"),
+    ('\u{1175}', "\u{4E28}"),
+    ('\u{1176}', "\u{1161}\u{1169}"),
+    ('\u{1177}', "\u{1161}\u{116E}"),
+    ('\u{1178}', "\u{1163}\u{1169}"),
+    ('\u{1179}', "\u{1163}\u{116D}"),
+    ('\u{117A}', "\u{1165}\u{1169}"),
+    ('\u{117B}', "\u{1165}\u{116E}"),
+    ('\u{117C}', "\u{1165}\u{30FC}"),
+    ('\u{117D}', "\u{1167}\u{1169}"),
+    ('\u{117E}', "\u{1167}\u{116E}"),
+    ('\u{117F}', "\u{1169}\u{1165}"),
+    ('\u{1180}', "\u{1169}\u{1165}\u{4E28}"),
+    ('\u{1181}', "\u{1169}\u{1167}\u{4E28}"),
+    ('\u{1182}', "\u{1169}\u{1169}"),
+    ('\u{1183}', "\u{1169}\u{116E}"),
+    ('\u{1184}', "\u{116D}\u{1163}"),
+    ('\u{1185}', "\u{116D}\u{1163}\u{4E28}"),
+    ('\u{1186}', "\u{116D}\u{1163}"),
+    ('\u{1187}', "\u{116D}\u{1169}"),
+    ('\u{1188}', "\u{116D}\u{4E28}"),
+    ('\u{1189}', "\u{116E}\u{1161}"),
+    ('\u{118A}', "\u{116E}\u{1161}\u{4E28}"),
+    ('\u{118B}', "\u{116E}\u{1165}\u{30FC}"),
+    ('\u{118C}', "\u{116E}\u{1167}\u{4E28}"),
+    ('\u{118D}', "\u{116E}\u{116E}"),
+    ('\u{118E}', "\u{1172}\u{1161}"),
+    ('\u{118F}', "\u{1172}\u{1165}"),
+    ('\u{1190}', "\u{1172}\u{1165}\u{4E28}"),
+    ('\u{1191}', "\u{1172}\u{1167}"),
+    ('\u{1192}', "\u{1172}\u{1167}\u{4E28}"),
+    ('\u{1193}', "\u{1172}\u{116E}"),
+    ('\u{1194}', "\u{1172}\u{4E28}"),
+    ('\u{1195}', "\u{30FC}\u{116E}"),
+    ('\u{1196}', "\u{30FC}\u{30FC}"),
+    ('\u{1197}', "\u{30FC}\u{4E28}\u{116E}"),
+    ('\u{1198}', "\u{4E28}\u{1161}"),
+    ('\u{1199}', "\u{4E28}\u{1163}"),
+    ('\u{119A}', "\u{4E28}\u{1169}"),
+    ('\u{119B}', "\u{4E28}\u{116E}"),
+    ('\u{119C}', "\u{4E28}\u{30FC}"),
+    ('\u{119D}', "\u{4E28}\u{119E}"),
+    ('\u{119F}', "\u{119E}\u{1165}"),
+    ('\u{11A0}', "\u{119E}\u{116E}"),
+    ('\u{11A1}', "\u{119E}\u{4E28}"),
+    ('\u{11A2}', "\u{119E}\u{119E}"),
+    ('\u{11A3}', "\u{1161}\u{30FC}"),
+    ('\u{11A4}', "\u{1163}\u{116E}"),
+    ('\u{11A5}', "\u{1167}\u{1163}"),
+    ('\u{11A6}', "\u{1169}\u{1163}"),
+    ('\u{11A7}', "\u{1169}\u{1163}\u{4E28}"),
+    ('\u{11A8}', "\u{1100}"),
+    ('\u{11A9}', "\u{1100}\u{1100}"),
+    ('\u{11AA}', "\u{1100}\u{1109}"),
+    ('\u{11AB}', "\u{1102}"),
+    ('\u{11AC}', "\u{1102}\u{110C}"),
+    ('\u{11AD}', "\u{1102}\u{1112}"),
+    ('\u{11AE}', "\u{1103}"),
+    ('\u{11AF}', "\u{1105}"),
+    ('\u{11B0}', "\u{1105}\u{1100}"),
+    ('\u{11B1}', "\u{1105}\u{1106}"),
+    ('\u{11B2}', "\u{1105}\u{1107}"),
+    ('\u{11B3}', "\u{1105}\u{1109}"),
+    ('\u{11B4}', "\u{1105}\u{1110}"),
+    ('\u{11B5}', "\u{1105}\u{1111}"),
+    ('\u{11B6}', "\u{1105}\u{1112}"),
+    ('\u{11B7}', "\u{1106}"),
+    ('\u{11B8}', "\u{1107}"),
+    ('\u{11B9}', "\u{1107}\u{1109}"),
+    ('\u{11BA}', "\u{1109}"),
+    ('\u{11BB}', "\u{1109}\u{1109}"),
+    ('\u{11BC}', "\u{110B}"),
+    ('\u{11BD}', "\u{110C}"),
+    ('\u{11BE}', "\u{110E}"),
+    ('\u{11BF}', "\u{110F}"),
+    ('\u{11C0}', "\u{1110}"),
+    ('\u{11C1}', "\u{1111}"),
+    ('\u{11C2}', "\u{1112}"),
+    ('\u{11C3}', "\u{1100}\u{1105}"),
+    ('\u{11C4}', "\u{1100}\u{1109}\u{1100}"),
+    ('\u{11C5}', "\u{1102}\u{1100}"),
+    ('\u{11C6}', "\u{1102}\u{1103}"),
+    ('\u{11C7}', "\u{1102}\u{1109}"),
+    ('\u{11C8}', "\u{1102}\u{1140}"),
+    ('\u{11C9}', "\u{1102}\u{1110}"),
+    ('\u{11CA}', "\u{1103}\u{1100}"),
+    ('\u{11CB}', "\u{1103}\u{1105}"),
+    ('\u{11CC}', "\u{1105}\u{1100}\u{1109}"),
+    ('\u{11CD}', "\u{1105}\u{1102}"),
+    ('\u{11CE}', "\u{1105}\u{1103}"),
+    ('\u{11CF}', "\u{1105}\u{1103}\u{1112}"),
+    ('\u{11D0}', "\u{1105}\u{1105}"),
+    ('\u{11D1}', "\u{1105}\u{1106}\u{1100}"),
+    ('\u{11D2}', "\u{1105}\u{1106}\u{1109}"),
+    ('\u{11D3}', "\u{1105}\u{1107}\u{1109}"),
+    ('\u{11D4}', "\u{1105}\u{1107}\u{1112}"),
+    ('\u{11D5}', "\u{1105}\u{1107}\u{110B}"),
+    ('\u{11D6}', "\u{1105}\u{1109}\u{1109}"),
+    ('\u{11D7}', "\u{1105}\u{1140}"),
+    ('\u{11D8}', "\u{1105}\u{110F}"),
+    ('\u{11D9}', "\u{1105}\u{1159}"),
+    ('\u{11DA}', "\u{1106}\u{1100}"),
+    ('\u{11DB}', "\u{1106}\u{1105}"),
+    ('\u{11DC}', "\u{1106}\u{1107}"),
+    ('\u{11DD}', "\u{1106}\u{1109}"),
+    ('\u{11DE}', "\u{1106}\u{1109}\u{1109}"),
+    ('\u{11DF}', "\u{1106}\u{1140}"),
+    ('\u{11E0}', "\u{1106}\u{110E}"),
+    ('\u{11E1}', "\u{1106}\u{1112}"),
+    ('\u{11E2}', "\u{1106}\u{110B}"),
+    ('\u{11E3}', "\u{1107}\u{1105}"),
+    ('\u{11E4}', "\u{1107}\u{1111}"),
+    ('\u{11E5}', "\u{1107}\u{1112}"),
+    ('\u{11E6}', "\u{1107}\u{110B}"),
+    ('\u{11E7}', "\u{1109}\u{1100}"),
+    ('\u{11E8}', "\u{1109}\u{1103}"),
+    ('\u{11E9}', "\u{1109}\u{1105}"),
+    ('\u{11EA}', "\u{1109}\u{1107}"),
+    ('\u{11EB}', "\u{1140}"),
+    ('\u{11EC}', "\u{110B}\u{1100}"),
+    ('\u{11ED}', "\u{110B}\u{1100}\u{1100}"),
+    ('\u{11EE}', "\u{110B}\u{110B}"),
+    ('\u{11EF}', "\u{110B}\u{110F}"),
+    ('\u{11F0}', "\u{114C}"),
+    ('\u{11F1}', "\u{110B}\u{1109}"),
+    ('\u{11F2}', "\u{110B}\u{1140}"),
+    ('\u{11F3}', "\u{1111}\u{1107}"),
+    ('\u{11F4}', "\u{1111}\u{110B}"),
+    ('\u{11F5}', "\u{1112}\u{1102}"),
+    ('\u{11F6}', "\u{1112}\u{1105}"),
+    ('\u{11F7}', "\u{1112}\u{1106}"),
+    ('\u{11F8}', "\u{1112}\u{1107}"),
+    ('\u{11F9}', "\u{1159}"),
+    ('\u{11FA}', "\u{1100}\u{1102}"),
+    ('\u{11FB}', "\u{1100}\u{1107}"),
+    ('\u{11FC}', "\u{1100}\u{110E}"),
+    ('\u{11FD}', "\u{1100}\u{110F}"),
+    ('\u{11FE}', "\u{1100}\u{1112}"),
+    ('\u{11FF}', "\u{1102}\u{1102}"),
+    ('\u{1200}', "U"),
+    ('\u{1223}', "\u{270}"),
+    ('\u{1240}', "\u{3A6}"),
+    ('\u{1260}', "\u{548}"),
+    ('\u{1294}', "\u{571}"),
+    ('\u{12D0}', "O"),
+    ('\u{13A0}', "D"),
+    ('\u{13A1}', "R"),
+    ('\u{13A2}', "T"),
+    ('\u{13A4}', "O\u{27}"),
+    ('\u{13A5}', "i"),
+    ('\u{13A8}', "\u{2C75}"),
+    ('\u{13A9}', "Y"),
+    ('\u{13AA}', "A"),
+    ('\u{13AB}', "J"),
+    ('\u{13AC}', "E"),
+    ('\u{13AE}', "?"),
+    ('\u{13B0}', "\u{2C75}"),
+    ('\u{13B1}', "\u{393}"),
+    ('\u{13B3}', "W"),
+    ('\u{13B7}', "M"),
+    ('\u{13BB}', "H"),
+    ('\u{13BD}', "Y"),
+    ('\u{13BE}', "O\u{335}"),
+    ('\u{13BF}', "\u{1AB}"),
+    ('\u{13C0}', "G"),
+    ('\u{13C2}', "h"),
+    ('\u{13C3}', "Z"),
+    ('\u{13C7}', "\u{460}"),
+    ('\u{13CB}', "\u{190}"),
+    ('\u{13CC}', "U\u{335}"),
+    ('\u{13CE}', "4"),
+    ('\u{13CF}', "b"),
+    ('\u{13D2}', "R"),
+    ('\u{13D4}', "W"),
+    ('\u{13D5}', "S"),
+    ('\u{13D9}', "V"),
+    ('\u{13DA}', "S"),
+    ('\u{13DE}', "L"),
+    ('\u{13DF}', "C"),
+    ('\u{13E2}', "P"),
+    ('\u{13E6}', "K"),
+    ('\u{13E7}', "d"),
+    ('\u{13EB}', "O\u{335}"),
+    ('\u{13EE}', "6"),
+    ('\u{13F0}', "\u{DF}"),
+    ('\u{13F2}', "h\u{314}"),
+    ('\u{13F3}', "G"),
+    ('\u{13F4}', "B"),
+    ('\u{13FB}', "\u{262}"),
+    ('\u{13FC}', "\u{299}"),
+    ('\u{1400}', "="),
+    ('\u{1403}', "\u{394}"),
+    ('\u{140C}', "\u{B7}\u{1401}"),
+    ('\u{140D}', "\u{1401}\u{B7}"),
+    ('\u{140E}', "\u{B7}\u{394}"),
+    ('\u{140F}', "\u{394}\u{B7}"),
+    ('\u{1410}', "\u{B7}\u{1404}"),
+    ('\u{1411}', "\u{1404}\u{B7}"),
+    ('\u{1412}', "\u{B7}\u{1405}"),
+    ('\u{1413}', "\u{1405}\u{B7}"),
+    ('\u{1414}', "\u{B7}\u{1406}"),
+    ('\u{1415}', "\u{1406}\u{B7}"),
+    ('\u{1417}', "\u{B7}\u{140A}"),
+    ('\u{1418}', "\u{140A}\u{B7}"),
+    ('\u{1419}', "\u{B7}\u{140B}"),
+    ('\u{141A}', "\u{140B}\u{B7}"),
+    ('\u{1427}', "\u{B7}"),
+    ('\u{142B}', "\u{1401}\u{1420}"),
+    ('\u{142C}', "\u{394}\u{1420}"),
+    ('\u{142D}', "\u{1405}\u{1420}"),
+    ('\u{142E}', "\u{140A}\u{1420}"),
+    ('\u{142F}', "V"),
+    ('\u{1431}', "\u{245}"),
+    ('\u{1433}', ">"),
+    ('\u{1437}', "\u{B7}>"),
+    ('\u{1438}', "<"),
+    ('\u{143A}', "\u{B7}V"),
+    ('\u{143B}', "V\u{B7}"),
+    ('\u{143C}', "\u{B7}\u{245}"),
+    ('\u{143D}', "\u{245}\u{B7}"),
+    ('\u{143E}', "\u{B7}\u{1432}"),
+    ('\u{143F}', "\u{1432}\u{B7}"),
+    ('\u{1440}', "\u{B7}>"),
+    ('\u{1441}', ">\u{B7}"),
+    ('\u{1442}', "\u{B7}\u{1434}"),
+    ('\u{1443}', "\u{1434}\u{B7}"),
+    ('\u{1444}', "\u{B7}<"),
+    ('\u{1445}', "<\u{B7}"),
+    ('\u{1446}', "\u{B7}\u{1439}"),
+    ('\u{1447}', "\u{1439}\u{B7}"),
+    ('\u{144A}', "\u{27}"),
+    ('\u{144C}', "U"),
+    ('\u{144E}', "\u{548}"),
+    ('\u{1454}', "\u{B7}\u{1450}"),
+    ('\u{1457}', "\u{B7}U"),
+    ('\u{1458}', "U\u{B7}"),
+    ('\u{1459}', "\u{B7}\u{548}"),
+    ('\u{145A}', "\u{548}\u{B7}"),
+    ('\u{145B}', "\u{B7}\u{144F}"),
+    ('\u{145C}', "\u{144F}\u{B7}"),
+    ('\u{145D}', "\u{B7}\u{1450}"),
+    ('\u{145E}', "\u{1450}\u{B7}"),
+    ('\u{145F}', "\u{B7}\u{1451}"),
+    ('\u{1460}', "\u{1451}\u{B7}"),
+    ('\u{1461}', "\u{B7}\u{1455}"),
+    ('\u{1462}', "\u{1455}\u{B7}"),
+    ('\u{1463}', "\u{B7}\u{1456}"),
+    ('\u{1464}', "\u{1456}\u{B7}"),
+    ('\u{1467}', "U\u{27}"),
+    ('\u{1468}', "\u{548}\u{27}"),
+    ('\u{1469}', "\u{1450}\u{27}"),
+    ('\u{146A}', "\u{1455}\u{27}"),
+    ('\u{146D}', "P"),
+    ('\u{146F}', "d"),
+    ('\u{1472}', "b"),
+    ('\u{1473}', "b\u{307}"),
+    ('\u{1474}', "\u{B7}\u{146B}"),
+    ('\u{1475}', "\u{146B}\u{B7}"),
+    ('\u{1476}', "\u{B7}P"),
+    ('\u{1477}', "p\u{B7}"),
+    ('\u{1478}', "\u{B7}\u{146E}"),
+    ('\u{1479}', "\u{146E}\u{B7}"),
+    ('\u{147A}', "\u{B7}d"),
+    ('\u{147B}', "d\u{B7}"),
+    ('\u{147C}', "\u{B7}\u{1470}"),
+    ('\u{147D}', "\u{1470}\u{B7}"),
+    ('\u{147E}', "\u{B7}b"),
+    ('\u{147F}', "b\u{B7}"),
+    ('\u{1480}', "\u{B7}b\u{307}"),
+    ('\u{1481}', "b\u{307}\u{B7}"),
+    ('\u{1485}', "\u{146B}\u{27}"),
+    ('\u{1486}', "P\u{27}"),
+    ('\u{1487}', "d\u{27}"),
+    ('\u{1488}', "b\u{27}"),
+    ('\u{148D}', "J"),
+    ('\u{1492}', "\u{B7}\u{1489}"),
+    ('\u{1493}', "\u{1489}\u{B7}"),
+    ('\u{1494}', "\u{B7}\u{148B}"),
+    ('\u{1495}', "\u{148B}\u{B7}"),
+    ('\u{1496}', "\u{B7}\u{148C}"),
+    ('\u{1497}', "\u{148C}\u{B7}"),
+    ('\u{1498}', "\u{B7}J"),
+    ('\u{1499}', "J\u{B7}"),
+    ('\u{149A}', "\u{B7}\u{148E}"),
+    ('\u{149B}', "\u{148E}\u{B7}"),
+    ('\u{149C}', "\u{B7}\u{1490}"),
+    ('\u{149D}', "\u{1490}\u{B7}"),
+    ('\u{149E}', "\u{B7}\u{1491}"),
+    ('\u{149F}', "\u{1491}\u{B7}"),
+    ('\u{14A5}', "\u{393}"),
+    ('\u{14AA}', "L"),
+    ('\u{14AC}', "\u{B7}\u{14A3}"),
+    ('\u{14AD}', "\u{14A3}\u{B7}"),
+    ('\u{14AE}', "\u{B7}\u{393}"),
+    ('\u{14AF}', "\u{393}\u{B7}"),
+    ('\u{14B0}', "\u{B7}\u{14A6}"),
+    ('\u{14B1}', "\u{14A6}\u{B7}"),
+    ('\u{14B2}', "\u{B7}\u{14A7}"),
+    ('\u{14B3}', "\u{14A7}\u{B7}"),
+    ('\u{14B4}', "\u{B7}\u{14A8}"),
+    ('\u{14B5}', "\u{14A8}\u{B7}"),
+    ('\u{14B6}', "\u{B7}L"),
+    ('\u{14B7}', "l\u{B7}"),
+    ('\u{14B8}', "\u{B7}\u{14AB}"),
+    ('\u{14B9}', "\u{14AB}\u{B7}"),
+    ('\u{14BF}', "2"),
+    ('\u{14C9}', "\u{B7}\u{14C0}"),
+    ('\u{14CA}', "\u{14C0}\u{B7}"),
+    ('\u{14CB}', "\u{B7}\u{14C7}"),
+    ('\u{14CC}', "\u{14C7}\u{B7}"),
+    ('\u{14CD}', "\u{B7}\u{14C8}"),
+    ('\u{14CE}', "\u{14C8}\u{B7}"),
+    ('\u{14D1}', "\u{1421}"),
+    ('\u{14DC}', "\u{B7}\u{14D3}"),
+    ('\u{14DD}', "\u{14D3}\u{B7}"),
+    ('\u{14DE}', "\u{B7}\u{14D5}"),
+    ('\u{14DF}', "\u{14D5}\u{B7}"),
+    ('\u{14E0}', "\u{B7}\u{14D6}"),
+    ('\u{14E1}', "\u{14D6}\u{B7}"),
+    ('\u{14E2}', "\u{B7}\u{14D7}"),
+    ('\u{14E3}', "\u{14D7}\u{B7}"),
+    ('\u{14E4}', "\u{B7}\u{14D8}"),
+    ('\u{14E5}', "\u{14D8}\u{B7}"),
+    ('\u{14E6}', "\u{B7}\u{14DA}"),
+    ('\u{14E7}', "\u{14DA}\u{B7}"),
+    ('\u{14E8}', "\u{B7}\u{14DB}"),
+    ('\u{14E9}', "\u{14DB}\u{B7}"),
+    ('\u{14F6}', "\u{B7}\u{14ED}"),
+    ('\u{14F7}', "\u{14ED}\u{B7}"),
+    ('\u{14F8}', "\u{B7}\u{14EF}"),
+    ('\u{14F9}', "\u{14EF}\u{B7}"),
+    ('\u{14FA}', "\u{B7}\u{14F0}"),
+    ('\u{14FB}', "\u{14F0}\u{B7}"),
+    ('\u{14FC}', "\u{B7}\u{14F1}"),
+    ('\u{14FD}', "\u{14F1}\u{B7}"),
+    ('\u{14FE}', "\u{B7}\u{14F2}"),
+    ('\u{14FF}', "\u{14F2}\u{B7}"),
+    ('\u{1500}', "\u{B7}\u{14F4}"),
+    ('\u{1501}', "\u{14F4}\u{B7}"),
+    ('\u{1502}', "\u{B7}\u{14F5}"),
+    ('\u{1503}', "\u{14F5}\u{B7}"),
+    ('\u{150C}', "\u{150B}<"),
+    ('\u{150D}', "\u{150B}\u{1455}"),
+    ('\u{150E}', "\u{150B}b"),
+    ('\u{150F}', "\u{150B}\u{1490}"),
+    ('\u{1517}', "\u{B7}\u{1510}"),
+    ('\u{1518}', "\u{1510}\u{B7}"),
+    ('\u{1519}', "\u{B7}\u{1511}"),
+    ('\u{151A}', "\u{1511}\u{B7}"),
+    ('\u{151B}', "\u{B7}\u{1512}"),
+    ('\u{151C}', "\u{1512}\u{B7}"),
+    ('\u{151D}', "\u{B7}\u{1513}"),
+    ('\u{151E}', "\u{1513}\u{B7}"),
+    ('\u{151F}', "\u{B7}\u{1514}"),
+    ('\u{1520}', "\u{1514}\u{B7}"),
+    ('\u{1521}', "\u{B7}\u{1515}"),
+    ('\u{1522}', "\u{1515}\u{B7}"),
+    ('\u{1523}', "\u{B7}\u{1516}"),
+    ('\u{1524}', "\u{1516}\u{B7}"),
+    ('\u{152F}', "\u{B7}4"),
+    ('\u{1530}', "4\u{B7}"),
+    ('\u{1531}', "\u{B7}\u{1528}"),
+    ('\u{1532}', "\u{1528}\u{B7}"),
+    ('\u{1533}', "\u{B7}\u{1529}"),
+    ('\u{1534}', "\u{1529}\u{B7}"),
+    ('\u{1535}', "\u{B7}\u{152A}"),
+    ('\u{1536}', "\u{152A}\u{B7}"),
+    ('\u{1537}', "\u{B7}\u{152B}"),
+    ('\u{1538}', "\u{152B}\u{B7}"),
+    ('\u{1539}', "\u{B7}\u{152D}"),
+    ('\u{153A}', "\u{152D}\u{B7}"),
+    ('\u{153B}', "\u{B7}\u{152E}"),
+    ('\u{153C}', "\u{152E}\u{B7}"),
+    ('\u{1540}', "\u{1429}"),
+    ('\u{1541}', "x"),
+    ('\u{154E}', "\u{B7}\u{154C}"),
+    ('\u{154F}', "\u{154C}\u{B7}"),
+    ('\u{155B}', "\u{B7}\u{155A}"),
+    ('\u{155C}', "\u{155A}\u{B7}"),
+    ('\u{1568}', "\u{B7}\u{1567}"),
+    ('\u{1569}', "\u{1567}\u{B7}"),
+    ('\u{1577}', "\u{1E9F}"),
+    ('\u{157C}', "H"),
+    ('\u{157D}', "x"),
+    ('\u{157E}', "\u{1550}\u{146C}"),
+    ('\u{157F}', "\u{1550}P"),
+    ('\u{1580}', "\u{1550}\u{146E}"),
+    ('\u{1581}', "\u{1550}d"),
+    ('\u{1582}', "\u{1550}\u{1470}"),
+    ('\u{1583}', "\u{1550}b"),
+    ('\u{1584}', "\u{1550}b\u{307}"),
+    ('\u{1585}', "\u{1550}\u{1483}"),
+    ('\u{1587}', "R"),
+    ('\u{158E}', "\u{1595}\u{148A}"),
+    ('\u{158F}', "\u{1595}\u{148B}"),
+    ('\u{1590}', "\u{1595}\u{148C}"),
+    ('\u{1591}', "\u{1595}J"),
+    ('\u{1592}', "\u{1595}\u{148E}"),
+    ('\u{1593}', "\u{1595}\u{1490}"),
+    ('\u{1594}', "\u{1595}\u{1491}"),
+    ('\u{15AF}', "b"),
+    ('\u{15B4}', "F"),
+    ('\u{15B5}', "\u{2132}"),
+    ('\u{15B7}', "\u{A7FB}"),
+    ('\u{15C4}', "\u{2C6F}"),
+    ('\u{15C5}', "A"),
+    ('\u{15DE}', "D"),
+    ('\u{15EA}', "D"),
+    ('\u{15EF}', "\u{460}"),
+    ('\u{15F0}', "M"),
+    ('\u{15F7}', "B"),
+    ('\u{1602}', "\u{1490}"),
+    ('\u{1603}', "\u{1489}"),
+    ('\u{1604}', "\u{14D3}"),
+    ('\u{1607}', "\u{14DA}"),
+    ('\u{1622}', "\u{1543}"),
+    ('\u{1623}', "\u{1546}"),
+    ('\u{1624}', "\u{154A}"),
+    ('\u{162E}', "\u{1B1}"),
+    ('\u{162F}', "\u{3A9}"),
+    ('\u{1634}', "\u{1B1}"),
+    ('\u{1635}', "\u{3A9}"),
+    ('\u{166D}', "X"),
+    ('\u{166E}', "x"),
+    ('\u{166F}', "\u{1550}\u{146B}"),
+    ('\u{1670}', "\u{1595}\u{1489}"),
+    ('\u{1671}', "\u{1596}\u{148B}"),
+    ('\u{1672}', "\u{1596}\u{148C}"),
+    ('\u{1673}', "\u{1596}J"),
+    ('\u{1674}', "\u{1596}\u{148E}"),
+    ('\u{1675}', "\u{1596}\u{1490}"),
+    ('\u{1676}', "\u{1596}\u{1491}"),
+    ('\u{1677}', "\u{15A7}\u{B7}"),
+    ('\u{1678}', "\u{15A8}\u{B7}"),
+    ('\u{1679}', "\u{15A9}\u{B7}"),
+    ('\u{167A}', "\u{15AA}\u{B7}"),
+    ('\u{167B}', "\u{15AB}\u{B7}"),
+    ('\u{167C}', "\u{15AC}\u{B7}"),
+    ('\u{167D}', "\u{15AD}\u{B7}"),
+    ('\u{1680}', " "),
+    ('\u{16B2}', "<"),
+    ('\u{16B7}', "X"),
+    ('\u{16C1}', "l"),
+    ('\u{16C2}', "\u{16BD}"),
+    ('\u{16CC}', "\u{27}"),
+    ('\u{16D5}', "K"),
+    ('\u{16D6}', "M"),
+    ('\u{16D8}', "\u{3A8}"),
+    ('\u{16E1}', "\u{16BC}"),
+    ('\u{16EB}', "\u{B7}"),
+    ('\u{16EC}', ":"),
+    ('\u{16ED}', "+"),
+    ('\u{16F0}', "\u{3A6}"),
+    ('\u{1735}', "/"),
+    ('\u{17A3}', "\u{17A2}"),
+    ('\u{17B7}', "\u{E34}"),
+    ('\u{17B8}', "\u{E35}"),
+    ('\u{17B9}', "\u{E36}"),
+    ('\u{17BA}', "\u{E37}"),
+    ('\u{17C6}', "\u{30A}"),
+    ('\u{17CB}', "\u{E48}"),
+    ('\u{17D3}', "\u{30A}"),
+    ('\u{17D4}', "\u{E2F}"),
+    ('\u{17D5}', "\u{E5A}"),
+    ('\u{17D9}', "\u{E4F}"),
+    ('\u{17DA}', "\u{E5B}"),
+    ('\u{1803}', ":"),
+    ('\u{1809}', ":"),
+    ('\u{1855}', "\u{1835}"),
+    ('\u{1896}', "\u{185C}"),
+    ('\u{18B3}', "\u{B7}\u{18B1}"),
+    ('\u{18B6}', "\u{B7}\u{18B4}"),
+    ('\u{18B9}', "\u{B7}\u{18B8}"),
+    ('\u{18C2}', "\u{B7}\u{18C0}"),
+    ('\u{18C6}', "\u{B7}\u{14C2}"),
+    ('\u{18C7}', "\u{14C2}\u{B7}"),
+    ('\u{18C8}', "\u{B7}\u{14C3}"),
+    ('\u{18C9}', "\u{14C3}\u{B7}"),
+    ('\u{18CA}', "\u{B7}\u{14C4}"),
+    ('\u{18CB}', "\u{14C4}\u{B7}"),
+    ('\u{18CC}', "\u{B7}\u{14C5}"),
+    ('\u{18CD}', "\u{14C5}\u{B7}"),
+    ('\u{18CE}', "\u{B7}\u{1543}"),
+    ('\u{18CF}', "\u{B7}\u{1546}"),
+    ('\u{18D0}', "\u{B7}\u{1547}"),
+    ('\u{18D1}', "\u{B7}\u{1548}"),
+    ('\u{18D2}', "\u{B7}\u{1549}"),
+    ('\u{18D3}', "\u{B7}\u{154B}"),
+    ('\u{18DB}', "\u{18F5}"),
+    ('\u{18DC}', "\u{18DF}\u{141E}"),
+    ('\u{18DD}', "\u{141E}\u{18DF}"),
+    ('\u{18E0}', "\u{1543}\u{B7}"),
+    ('\u{18E3}', "\u{155E}\u{B7}"),
+    ('\u{18E4}', "\u{1566}\u{B7}"),
+    ('\u{18E5}', "\u{156B}\u{B7}"),
+    ('\u{18E8}', "\u{1586}\u{B7}"),
+    ('\u{18EA}', "\u{1597}\u{B7}"),
+    ('\u{18ED}', "\u{460}\u{B7}"),
+    ('\u{18F0}', "\u{15F4}\u{B7}"),
+    ('\u{18F2}', "\u{161B}\u{B7}"),
+    ('\u{19D0}', "\u{199E}"),
+    ('\u{19D1}', "\u{19B1}"),
+    ('\u{1A80}', "\u{1A45}"),
+    ('\u{1A90}', "\u{1A45}"),
+    ('\u{1AA9}', "\u{1AA8}\u{1AA8}"),
+    ('\u{1AAB}', "\u{1AAA}\u{1AA8}"),
+    ('\u{1AB4}', "\u{6DB}"),
+    ('\u{1AB7}', "\u{328}"),
+    ('\u{1B06}', "\u{1B05}\u{1B35}"),
+    ('\u{1B08}', "\u{1B07}\u{1B35}"),
+    ('\u{1B0A}', "\u{1B09}\u{1B35}"),
+    ('\u{1B0C}', "\u{1B0B}\u{1B35}"),
+    ('\u{1B0E}', "\u{1B0D}\u{1B35}"),
+    ('\u{1B12}', "\u{1B11}\u{1B35}"),
+    ('\u{1B3B}', "\u{1B3A}\u{1B35}"),
+    ('\u{1B3D}', "\u{1B3C}\u{1B35}"),
+    ('\u{1B40}', "\u{1B3E}\u{1B35}"),
+    ('\u{1B41}', "\u{1B3F}\u{1B35}"),
+    ('\u{1B43}', "\u{1B42}\u{1B35}"),
+    ('\u{1B52}', "\u{1B0D}"),
+    ('\u{1B53}', "\u{1B11}"),
+    ('\u{1B58}', "\u{1B28}"),
+    ('\u{1B5C}', "\u{1B50}"),
+    ('\u{1B5F}', "\u{1B5E}\u{1B5E}"),
+    ('\u{1C3C}', "\u{1C3B}\u{1C3B}"),
+    ('\u{1C7F}', "\u{1C7E}\u{1C7E}"),
+    ('\u{1CD0}', "\u{302}"),
+    ('\u{1CD2}', "\u{304}"),
+    ('\u{1CD3}', "\u{27}\u{27}"),
+    ('\u{1CD5}', "\u{32B}"),
+    ('\u{1CD8}', "\u{32E}"),
+    ('\u{1CD9}', "\u{32D}"),
+    ('\u{1CDA}', "\u{30E}"),
+    ('\u{1CDC}', "\u{329}"),
+    ('\u{1CDD}', "\u{323}"),
+    ('\u{1CDE}', "\u{324}"),
+    ('\u{1CED}', "\u{316}"),
+    ('\u{1D04}', "c"),
+    ('\u{1D08}', "\u{25C}"),
+    ('\u{1D0B}', "\u{138}"),
+    ('\u{1D0D}', "\u{28D}"),
+    ('\u{1D0F}', "o"),
+    ('\u{1D10}', "\u{254}"),
+    ('\u{1D11}', "o"),
+    ('\u{1D14}', "\u{1DD}o"),
+    ('\u{1D1C}', "u"),
+    ('\u{1D20}', "v"),
+    ('\u{1D21}', "w"),
+    ('\u{1D22}', "z"),
+    ('\u{1D24}', "\u{1A8}"),
+    ('\u{1D26}', "r"),
+    ('\u{1D27}', "\u{28C}"),
+    ('\u{1D28}', "\u{3C0}"),
+    ('\u{1D29}', "\u{1D18}"),
+    ('\u{1D2B}', "\u{43B}"),
+    ('\u{1D3E}', "\u{18D6}"),
+    ('\u{1D52}', "\u{BA}"),
+    ('\u{1D6B}', "ue"),
+    ('\u{1D6E}', "f\u{334}"),
+    ('\u{1D6F}', "rn\u{334}"),
+    ('\u{1D70}', "n\u{334}"),
+    ('\u{1D72}', "r\u{334}"),
+    ('\u{1D73}', "\u{27E}\u{334}"),
+    ('\u{1D74}', "s\u{334}"),
+    ('\u{1D75}', "t\u{334}"),
+    ('\u{1D76}', "z\u{334}"),
+    ('\u{1D78}', "\u{1D34}"),
+    ('\u{1D7B}', "i\u{335}"),
+    ('\u{1D7C}', "i\u{335}"),
+    ('\u{1D7D}', "p\u{335}"),
+    ('\u{1D7E}', "u\u{335}"),
+    ('\u{1D7F}', "\u{28A}\u{335}"),
+    ('\u{1D83}', "g"),
+    ('\u{1D8C}', "y"),
+    ('\u{1D90}', "\u{24B}"),
+    ('\u{1D9F}', "\u{1D4B}"),
+    ('\u{1DA2}', "\u{1D4D}"),
+    ('\u{1DBA}', "\u{18D4}"),
+    ('\u{1DBB}', "\u{1646}"),
+    ('\u{1DEE}', "\u{2DEC}"),
+    ('\u{1E00}', "A\u{325}"),
+    ('\u{1E01}', "a\u{325}"),
+    ('\u{1E02}', "B\u{307}"),
+    ('\u{1E03}', "b\u{307}"),
+    ('\u{1E04}', "B\u{323}"),
+    ('\u{1E05}', "b\u{323}"),
+    ('\u{1E06}', "B\u{331}"),
+    ('\u{1E07}', "b\u{331}"),
+    ('\u{1E08}', "C\u{326}\u{301}"),
+    ('\u{1E09}', "c\u{326}\u{301}"),
+    ('\u{1E0A}', "D\u{307}"),
+    ('\u{1E0B}', "d\u{307}"),
+    ('\u{1E0C}', "D\u{323}"),
+    ('\u{1E0D}', "d\u{323}"),
+    ('\u{1E0E}', "D\u{331}"),
+    ('\u{1E0F}', "d\u{331}"),
+    ('\u{1E10}', "D\u{326}"),
+    ('\u{1E11}', "d\u{326}"),
+    ('\u{1E12}', "D\u{32D}"),
+    ('\u{1E13}', "d\u{32D}"),
+    ('\u{1E14}', "E\u{304}\u{300}"),
+    ('\u{1E15}', "e\u{304}\u{300}"),
+    ('\u{1E16}', "E\u{304}\u{301}"),
+    ('\u{1E17}', "e\u{304}\u{301}"),
+    ('\u{1E18}', "E\u{32D}"),
+    ('\u{1E19}', "e\u{32D}"),
+    ('\u{1E1A}', "E\u{330}"),
+    ('\u{1E1B}', "e\u{330}"),
+    ('\u{1E1C}', "E\u{326}\u{306}"),
+    ('\u{1E1D}', "e\u{326}\u{306}"),
+    ('\u{1E1E}', "F\u{307}"),
+    ('\u{1E1F}', "f\u{307}"),
+    ('\u{1E20}', "G\u{304}"),
+    ('\u{1E21}', "g\u{304}"),
+    ('\u{1E22}', "H\u{307}"),
+    ('\u{1E23}', "h\u{307}"),
+    ('\u{1E24}', "H\u{323}"),
+    ('\u{1E25}', "h\u{323}"),
+    ('\u{1E26}', "H\u{308}"),
+    ('\u{1E27}', "h\u{308}"),
+    ('\u{1E28}', "H\u{326}"),
+    ('\u{1E29}', "h\u{326}"),
+    ('\u{1E2A}', "H\u{32E}"),
+    ('\u{1E2B}', "h\u{32E}"),
+    ('\u{1E2C}', "l\u{330}"),
+    ('\u{1E2D}', "i\u{330}"),
+    ('\u{1E2E}', "l\u{308}\u{301}"),
+    ('\u{1E2F}', "i\u{308}\u{301}"),
+    ('\u{1E30}', "K\u{301}"),
+    ('\u{1E31}', "k\u{301}"),
+    ('\u{1E32}', "K\u{323}"),
+    ('\u{1E33}', "k\u{323}"),
+    ('\u{1E34}', "K\u{331}"),
+    ('\u{1E35}', "k\u{331}"),
+    ('\u{1E36}', "L\u{323}"),
+    ('\u{1E37}', "l\u{323}"),
+    ('\u{1E38}', "L\u{323}\u{304}"),
+    ('\u{1E39}', "l\u{323}\u{304}"),
+    ('\u{1E3A}', "L\u{331}"),
+    ('\u{1E3B}', "l\u{331}"),
+    ('\u{1E3C}', "L\u{32D}"),
+    ('\u{1E3D}', "l\u{32D}"),
+    ('\u{1E3E}', "M\u{301}"),
+    ('\u{1E3F}', "rn\u{301}"),
+    ('\u{1E40}', "M\u{307}"),
+    ('\u{1E41}', "rn\u{307}"),
+    ('\u{1E42}', "M\u{323}"),
+    ('\u{1E43}', "rn\u{323}"),
+    ('\u{1E44}', "N\u{307}"),
+    ('\u{1E45}', "n\u{307}"),
+    ('\u{1E46}', "N\u{323}"),
+    ('\u{1E47}', "n\u{323}"),
+    ('\u{1E48}', "N\u{331}"),
+    ('\u{1E49}', "n\u{331}"),
+    ('\u{1E4A}', "N\u{32D}"),
+    ('\u{1E4B}', "n\u{32D}"),
+    ('\u{1E4C}', "O\u{303}\u{301}"),
+    ('\u{1E4D}', "o\u{303}\u{301}"),
+    ('\u{1E4E}', "O\u{303}\u{308}"),
+    ('\u{1E4F}', "o\u{303}\u{308}"),
+    ('\u{1E50}', "O\u{304}\u{300}"),
+    ('\u{1E51}', "o\u{304}\u{300}"),
+    ('\u{1E52}', "O\u{304}\u{301}"),
+    ('\u{1E53}', "o\u{304}\u{301}"),
+    ('\u{1E54}', "P\u{301}"),
+    ('\u{1E55}', "p\u{301}"),
+    ('\u{1E56}', "P\u{307}"),
+    ('\u{1E57}', "p\u{307}"),
+    ('\u{1E58}', "R\u{307}"),
+    ('\u{1E59}', "r\u{307}"),
+    ('\u{1E5A}', "R\u{323}"),
+    ('\u{1E5B}', "r\u{323}"),
+    ('\u{1E5C}', "R\u{323}\u{304}"),
+    ('\u{1E5D}', "r\u{323}\u{304}"),
+    ('\u{1E5E}', "R\u{331}"),
+    ('\u{1E5F}', "r\u{331}"),
+    ('\u{1E60}', "S\u{307}"),
+    ('\u{1E61}', "s\u{307}"),
+    ('\u{1E62}', "S\u{323}"),
+    ('\u{1E63}', "s\u{323}"),
+    ('\u{1E64}', "S\u{301}\u{307}"),
+    ('\u{1E65}', "s\u{301}\u{307}"),
+    ('\u{1E66}', "S\u{306}\u{307}"),
+    ('\u{1E67}', "s\u{306}\u{307}"),
+    ('\u{1E68}', "S\u{323}\u{307}"),
+    ('\u{1E69}', "s\u{323}\u{307}"),
+    ('\u{1E6A}', "T\u{307}"),
+    ('\u{1E6B}', "t\u{307}"),
+    ('\u{1E6C}', "T\u{323}"),
+    ('\u{1E6D}', "t\u{323}"),
+    ('\u{1E6E}', "T\u{331}"),
+    ('\u{1E6F}', "t\u{331}"),
+    ('\u{1E70}', "T\u{32D}"),
+    ('\u{1E71}', "t\u{32D}"),
+    ('\u{1E72}', "U\u{324}"),
+    ('\u{1E73}', "u\u{324}"),
+    ('\u{1E74}', "U\u{330}"),
+    ('\u{1E75}', "u\u{330}"),
+    ('\u{1E76}', "U\u{32D}"),
+    ('\u{1E77}', "u\u{32D}"),
+    ('\u{1E78}', "U\u{303}\u{301}"),
+    ('\u{1E79}', "u\u{303}\u{301}"),
+    ('\u{1E7A}', "U\u{304}\u{308}"),
+    ('\u{1E7B}', "u\u{304}\u{308}"),
+    ('\u{1E7C}', "V\u{303}"),
+    ('\u{1E7D}', "v\u{303}"),
+    ('\u{1E7E}', "V\u{323}"),
+    ('\u{1E7F}', "v\u{323}"),
+    ('\u{1E80}', "W\u{300}"),
+    ('\u{1E81}', "w\u{300}"),
+    ('\u{1E82}', "W\u{301}"),
+    ('\u{1E83}', "w\u{301}"),
+    ('\u{1E84}', "W\u{308}"),
+    ('\u{1E85}', "w\u{308}"),
+    ('\u{1E86}', "W\u{307}"),
+    ('\u{1E87}', "w\u{307}"),
+    ('\u{1E88}', "W\u{323}"),
+    ('\u{1E89}', "w\u{323}"),
+    ('\u{1E8A}', "X\u{307}"),
+    ('\u{1E8B}', "x\u{307}"),
+    ('\u{1E8C}', "X\u{308}"),
+    ('\u{1E8D}', "x\u{308}"),
+    ('\u{1E8E}', "Y\u{307}"),
+    ('\u{1E8F}', "y\u{307}"),
+    ('\u{1E90}', "Z\u{302}"),
+    ('\u{1E91}', "z\u{302}"),
+    ('\u{1E92}', "Z\u{323}"),
+    ('\u{1E93}', "z\u{323}"),
+    ('\u{1E94}', "Z\u{331}"),
+    ('\u{1E95}', "z\u{331}"),
+    ('\u{1E96}', "h\u{331}"),
+    ('\u{1E97}', "t\u{308}"),
+    ('\u{1E98}', "w\u{30A}"),
+    ('\u{1E99}', "y\u{30A}"),
+    ('\u{1E9A}', "a\u{309}"),
+    ('\u{1E9B}', "f\u{307}"),
+    ('\u{1E9D}', "f"),
+    ('\u{1EA0}', "A\u{323}"),
+    ('\u{1EA1}', "a\u{323}"),
+    ('\u{1EA2}', "A\u{309}"),
+    ('\u{1EA3}', "a\u{309}"),
+    ('\u{1EA4}', "A\u{302}\u{301}"),
+    ('\u{1EA5}', "a\u{302}\u{301}"),
+    ('\u{1EA6}', "A\u{302}\u{300}"),
+    ('\u{1EA7}', "a\u{302}\u{300}"),
+    ('\u{1EA8}', "A\u{302}\u{309}"),
+    ('\u{1EA9}', "a\u{302}\u{309}"),
+    ('\u{1EAA}', "A\u{302}\u{303}"),
+    ('\u{1EAB}', "a\u{302}\u{303}"),
+    ('\u{1EAC}', "A\u{323}\u{302}"),
+    ('\u{1EAD}', "a\u{323}\u{302}"),
+    ('\u{1EAE}', "A\u{306}\u{301}"),
+    ('\u{1EAF}', "a\u{306}\u{301}"),
+    ('\u{1EB0}', "A\u{306}\u{300}"),
+    ('\u{1EB1}', "a\u{306}\u{300}"),
+    ('\u{1EB2}', "A\u{306}\u{309}"),
+    ('\u{1EB3}', "a\u{306}\u{309}"),
+    ('\u{1EB4}', "A\u{306}\u{303}"),
+    ('\u{1EB5}', "a\u{306}\u{303}"),
+    ('\u{1EB6}', "A\u{323}\u{306}"),
+    ('\u{1EB7}', "a\u{323}\u{306}"),
+    ('\u{1EB8}', "E\u{323}"),
+    ('\u{1EB9}', "e\u{323}"),
+    ('\u{1EBA}', "E\u{309}"),
+    ('\u{1EBB}', "e\u{309}"),
+    ('\u{1EBC}', "E\u{303}"),
+    ('\u{1EBD}', "e\u{303}"),
+    ('\u{1EBE}', "E\u{302}\u{301}"),
+    ('\u{1EBF}', "e\u{302}\u{301}"),
+    ('\u{1EC0}', "E\u{302}\u{300}"),
+    ('\u{1EC1}', "e\u{302}\u{300}"),
+    ('\u{1EC2}', "E\u{302}\u{309}"),
+    ('\u{1EC3}', "e\u{302}\u{309}"),
+    ('\u{1EC4}', "E\u{302}\u{303}"),
+    ('\u{1EC5}', "e\u{302}\u{303}"),
+    ('\u{1EC6}', "E\u{323}\u{302}"),
+    ('\u{1EC7}', "e\u{323}\u{302}"),
+    ('\u{1EC8}', "l\u{309}"),
+    ('\u{1EC9}', "i\u{309}"),
+    ('\u{1ECA}', "l\u{323}"),
+    ('\u{1ECB}', "i\u{323}"),
+    ('\u{1ECC}', "O\u{323}"),
+    ('\u{1ECD}', "o\u{323}"),
+    ('\u{1ECE}', "O\u{309}"),
+    ('\u{1ECF}', "o\u{309}"),
+    ('\u{1ED0}', "O\u{302}\u{301}"),
+    ('\u{1ED1}', "o\u{302}\u{301}"),
+    ('\u{1ED2}', "O\u{302}\u{300}"),
+    ('\u{1ED3}', "o\u{302}\u{300}"),
+    ('\u{1ED4}', "O\u{302}\u{309}"),
+    ('\u{1ED5}', "o\u{302}\u{309}"),
+    ('\u{1ED6}', "O\u{302}\u{303}"),
+    ('\u{1ED7}', "o\u{302}\u{303}"),
+    ('\u{1ED8}', "O\u{323}\u{302}"),
+    ('\u{1ED9}', "o\u{323}\u{302}"),
+    ('\u{1EDA}', "O\u{31B}\u{301}"),
+    ('\u{1EDB}', "o\u{31B}\u{301}"),
+    ('\u{1EDC}', "O\u{31B}\u{300}"),
+    ('\u{1EDD}', "o\u{31B}\u{300}"),
+    ('\u{1EDE}', "O\u{31B}\u{309}"),
+    ('\u{1EDF}', "o\u{31B}\u{309}"),
+    ('\u{1EE0}', "O\u{31B}\u{303}"),
+    ('\u{1EE1}', "o\u{31B}\u{303}"),
+    ('\u{1EE2}', "O\u{31B}\u{323}"),
+    ('\u{1EE3}', "o\u{31B}\u{323}"),
+    ('\u{1EE4}', "U\u{323}"),
+    ('\u{1EE5}', "u\u{323}"),
+    ('\u{1EE6}', "U\u{309}"),
+    ('\u{1EE7}', "u\u{309}"),
+    ('\u{1EE8}', "U\u{31B}\u{301}"),
+    ('\u{1EE9}', "u\u{31B}\u{301}"),
+    ('\u{1EEA}', "U\u{31B}\u{300}"),
+    ('\u{1EEB}', "u\u{31B}\u{300}"),
+    ('\u{1EEC}', "U\u{31B}\u{309}"),
+    ('\u{1EED}', "u\u{31B}\u{309}"),
+    ('\u{1EEE}', "U\u{31B}\u{303}"),
+    ('\u{1EEF}', "u\u{31B}\u{303}"),
+    ('\u{1EF0}', "U\u{31B}\u{323}"),
+    ('\u{1EF1}', "u\u{31B}\u{323}"),
+    ('\u{1EF2}', "Y\u{300}"),
+    ('\u{1EF3}', "y\u{300}"),
+    ('\u{1EF4}', "Y\u{323}"),
+    ('\u{1EF5}', "y\u{323}"),
+    ('\u{1EF6}', "Y\u{309}"),
+    ('\u{1EF7}', "y\u{309}"),
+    ('\u{1EF8}', "Y\u{303}"),
+    ('\u{1EF9}', "y\u{303}"),
+    ('\u{1EFF}', "y"),
+    ('\u{1F00}', "a\u{313}"),
+    ('\u{1F01}', "a\u{314}"),
+    ('\u{1F02}', "a\u{313}\u{300}"),
+    ('\u{1F03}', "a\u{314}\u{300}"),
+    ('\u{1F04}', "a\u{313}\u{301}"),
+    ('\u{1F05}', "a\u{314}\u{301}"),
+    ('\u{1F06}', "a\u{313}\u{303}"),
+    ('\u{1F07}', "a\u{314}\u{303}"),
+    ('\u{1F08}', "A\u{313}"),
+    ('\u{1F09}', "A\u{314}"),
+    ('\u{1F0A}', "A\u{313}\u{300}"),
+    ('\u{1F0B}', "A\u{314}\u{300}"),
+    ('\u{1F0C}', "A\u{313}\u{301}"),
+    ('\u{1F0D}', "A\u{314}\u{301}"),
+    ('\u{1F0E}', "A\u{313}\u{303}"),
+    ('\u{1F0F}', "A\u{314}\u{303}"),
+    ('\u{1F10}', "\u{A793}\u{313}"),
+    ('\u{1F11}', "\u{A793}\u{314}"),
+    ('\u{1F12}', "\u{A793}\u{313}\u{300}"),
+    ('\u{1F13}', "\u{A793}\u{314}\u{300}"),
+    ('\u{1F14}', "\u{A793}\u{313}\u{301}"),
+    ('\u{1F15}', "\u{A793}\u{314}\u{301}"),
+    ('\u{1F18}', "E\u{313}"),
+    ('\u{1F19}', "E\u{314}"),
+    ('\u{1F1A}', "E\u{313}\u{300}"),
+    ('\u{1F1B}', "E\u{314}\u{300}"),
+    ('\u{1F1C}', "E\u{313}\u{301}"),
+    ('\u{1F1D}', "E\u{314}\u{301}"),
+    ('\u{1F20}', "n\u{329}\u{313}"),
+    ('\u{1F21}', "n\u{329}\u{314}"),
+    ('\u{1F22}', "n\u{329}\u{313}\u{300}"),
+    ('\u{1F23}', "n\u{329}\u{314}\u{300}"),
+    ('\u{1F24}', "n\u{329}\u{313}\u{301}"),
+    ('\u{1F25}', "n\u{329}\u{314}\u{301}"),
+    ('\u{1F26}', "n\u{329}\u{313}\u{303}"),
+    ('\u{1F27}', "n\u{329}\u{314}\u{303}"),
+    ('\u{1F28}', "H\u{313}"),
+    ('\u{1F29}', "H\u{314}"),
+    ('\u{1F2A}', "H\u{313}\u{300}"),
+    ('\u{1F2B}', "H\u{314}\u{300}"),
+    ('\u{1F2C}', "H\u{313}\u{301}"),
+    ('\u{1F2D}', "H\u{314}\u{301}"),
+    ('\u{1F2E}', "H\u{313}\u{303}"),
+    ('\u{1F2F}', "H\u{314}\u{303}"),
+    ('\u{1F30}', "i\u{313}"),
+    ('\u{1F31}', "i\u{314}"),
+    ('\u{1F32}', "i\u{313}\u{300}"),
+    ('\u{1F33}', "i\u{314}\u{300}"),
+    ('\u{1F34}', "i\u{313}\u{301}"),
+    ('\u{1F35}', "i\u{314}\u{301}"),
+    ('\u{1F36}', "i\u{313}\u{303}"),
+    ('\u{1F37}', "i\u{314}\u{303}"),
+    ('\u{1F38}', "l\u{313}"),
+    ('\u{1F39}', "l\u{314}"),
+    ('\u{1F3A}', "l\u{313}\u{300}"),
+    ('\u{1F3B}', "l\u{314}\u{300}"),
+    ('\u{1F3C}', "l\u{313}\u{301}"),
+    ('\u{1F3D}', "l\u{314}\u{301}"),
+    ('\u{1F3E}', "l\u{313}\u{303}"),
+    ('\u{1F3F}', "l\u{314}\u{303}"),
+    ('\u{1F40}', "o\u{313}"),
+    ('\u{1F41}', "o\u{314}"),
+    ('\u{1F42}', "o\u{313}\u{300}"),
+    ('\u{1F43}', "o\u{314}\u{300}"),
+    ('\u{1F44}', "o\u{313}\u{301}"),
+    ('\u{1F45}', "o\u{314}\u{301}"),
+    ('\u{1F48}', "O\u{313}"),
+    ('\u{1F49}', "O\u{314}"),
+    ('\u{1F4A}', "O\u{313}\u{300}"),
+    ('\u{1F4B}', "O\u{314}\u{300}"),
+    ('\u{1F4C}', "O\u{313}\u{301}"),
+    ('\u{1F4D}', "O\u{314}\u{301}"),
+    ('\u{1F50}', "u\u{313}"),
+    ('\u{1F51}', "u\u{314}"),
+    ('\u{1F52}', "u\u{313}\u{300}"),
+    ('\u{1F53}', "u\u{314}\u{300}"),
+    ('\u{1F54}', "u\u{313}\u{301}"),
+    ('\u{1F55}', "u\u{314}\u{301}"),
+    ('\u{1F56}', "u\u{313}\u{303}"),
+    ('\u{1F57}', "u\u{314}\u{303}"),
+    ('\u{1F59}', "Y\u{314}"),
+    ('\u{1F5B}', "Y\u{314}\u{300}"),
+    ('\u{1F5D}', "Y\u{314}\u{301}"),
+    ('\u{1F5F}', "Y\u{314}\u{303}"),
+    ('\u{1F60}', "\u{3C9}\u{313}"),
+    ('\u{1F61}', "\u{3C9}\u{314}"),
+    ('\u{1F62}', "\u{3C9}\u{313}\u{300}"),
+    ('\u{1F63}', "\u{3C9}\u{314}\u{300}"),
+    ('\u{1F64}', "\u{3C9}\u{313}\u{301}"),
+    ('\u{1F65}', "\u{3C9}\u{314}\u{301}"),
+    ('\u{1F66}', "\u{3C9}\u{313}\u{303}"),
+    ('\u{1F67}', "\u{3C9}\u{314}\u{303}"),
+    ('\u{1F68}', "\u{3A9}\u{313}"),
+    ('\u{1F69}', "\u{3A9}\u{314}"),
+    ('\u{1F6A}', "\u{3A9}\u{313}\u{300}"),
+    ('\u{1F6B}', "\u{3A9}\u{314}\u{300}"),
+    ('\u{1F6C}', "\u{3A9}\u{313}\u{301}"),
+    ('\u{1F6D}', "\u{3A9}\u{314}\u{301}"),
+    ('\u{1F6E}', "\u{3A9}\u{313}\u{303}"),
+    ('\u{1F6F}', "\u{3A9}\u{314}\u{303}"),
+    ('\u{1F70}', "a\u{300}"),
+    ('\u{1F71}', "a\u{301}"),
+    ('\u{1F72}', "\u{A793}\u{300}"),
+    ('\u{1F73}', "\u{A793}\u{301}"),
+    ('\u{1F74}', "n\u{329}\u{300}"),
+    ('\u{1F75}', "n\u{329}\u{301}"),
+    ('\u{1F76}', "i\u{300}"),
+    ('\u{1F77}', "i\u{301}"),
+    ('\u{1F78}', "o\u{300}"),
+    ('\u{1F79}', "o\u{301}"),
+    ('\u{1F7A}', "u\u{300}"),
+    ('\u{1F7B}', "u\u{301}"),
+    ('\u{1F7C}', "\u{3C9}\u{300}"),
+    ('\u{1F7D}', "\u{3C9}\u{301}"),
+    ('\u{1F80}', "a\u{328}\u{313}"),
+    ('\u{1F81}', "a\u{328}\u{314}"),
+    ('\u{1F82}', "a\u{328}\u{313}\u{300}"),
+    ('\u{1F83}', "a\u{328}\u{314}\u{300}"),
+    ('\u{1F84}', "a\u{328}\u{313}\u{301}"),
+    ('\u{1F85}', "a\u{328}\u{314}\u{301}"),
+    ('\u{1F86}', "a\u{328}\u{313}\u{303}"),
+    ('\u{1F87}', "a\u{328}\u{314}\u{303}"),
+    ('\u{1F88}', "A\u{328}\u{313}"),
+    ('\u{1F89}', "A\u{328}\u{314}"),
+    ('\u{1F8A}', "A\u{328}\u{313}\u{300}"),
+    ('\u{1F8B}', "A\u{328}\u{314}\u{300}"),
+    ('\u{1F8C}', "A\u{328}\u{313}\u{301}"),
+    ('\u{1F8D}', "A\u{328}\u{314}\u{301}"),
+    ('\u{1F8E}', "A\u{328}\u{313}\u{303}"),
+    ('\u{1F8F}', "A\u{328}\u{314}\u{303}"),
+    ('\u{1F90}', "n\u{328}\u{329}\u{313}"),
+    ('\u{1F91}', "n\u{328}\u{329}\u{314}"),
+    ('\u{1F92}', "n\u{328}\u{329}\u{313}\u{300}"),
+    ('\u{1F93}', "n\u{328}\u{329}\u{314}\u{300}"),
+    ('\u{1F94}', "n\u{328}\u{329}\u{313}\u{301}"),
+    ('\u{1F95}', "n\u{328}\u{329}\u{314}\u{301}"),
+    ('\u{1F96}', "n\u{328}\u{329}\u{313}\u{303}"),
+    ('\u{1F97}', "n\u{328}\u{329}\u{314}\u{303}"),
+    ('\u{1F98}', "H\u{328}\u{313}"),
+    ('\u{1F99}', "H\u{328}\u{314}"),
+    ('\u{1F9A}', "H\u{328}\u{313}\u{300}"),
+    ('\u{1F9B}', "H\u{328}\u{314}\u{300}"),
+    ('\u{1F9C}', "H\u{328}\u{313}\u{301}"),
+    ('\u{1F9D}', "H\u{328}\u{314}\u{301}"),
+    ('\u{1F9E}', "H\u{328}\u{313}\u{303}"),
+    ('\u{1F9F}', "H\u{328}\u{314}\u{303}"),
+    ('\u{1FA0}', "\u{3C9}\u{328}\u{313}"),
+    ('\u{1FA1}', "\u{3C9}\u{328}\u{314}"),
+    ('\u{1FA2}', "\u{3C9}\u{328}\u{313}\u{300}"),
+    ('\u{1FA3}', "\u{3C9}\u{328}\u{314}\u{300}"),
+    ('\u{1FA4}', "\u{3C9}\u{328}\u{313}\u{301}"),
+    ('\u{1FA5}', "\u{3C9}\u{328}\u{314}\u{301}"),
+    ('\u{1FA6}', "\u{3C9}\u{328}\u{313}\u{303}"),
+    ('\u{1FA7}', "\u{3C9}\u{328}\u{314}\u{303}"),
+    ('\u{1FA8}', "\u{3A9}\u{328}\u{313}"),
+    ('\u{1FA9}', "\u{3A9}\u{328}\u{314}"),
+    ('\u{1FAA}', "\u{3A9}\u{328}\u{313}\u{300}"),
+    ('\u{1FAB}', "\u{3A9}\u{328}\u{314}\u{300}"),
+    ('\u{1FAC}', "\u{3A9}\u{328}\u{313}\u{301}"),
+    ('\u{1FAD}', "\u{3A9}\u{328}\u{314}\u{301}"),
+    ('\u{1FAE}', "\u{3A9}\u{328}\u{313}\u{303}"),
+    ('\u{1FAF}', "\u{3A9}\u{328}\u{314}\u{303}"),
+    ('\u{1FB0}', "a\u{306}"),
+    ('\u{1FB1}', "a\u{304}"),
+    ('\u{1FB2}', "a\u{328}\u{300}"),
+    ('\u{1FB3}', "a\u{328}"),
+    ('\u{1FB4}', "a\u{328}\u{301}"),
+    ('\u{1FB6}', "a\u{303}"),
+    ('\u{1FB7}', "a\u{328}\u{303}"),
+    ('\u{1FB8}', "A\u{306}"),
+    ('\u{1FB9}', "A\u{304}"),
+    ('\u{1FBA}', "A\u{300}"),
+    ('\u{1FBB}', "A\u{301}"),
+    ('\u{1FBC}', "A\u{328}"),
+    ('\u{1FBD}', "\u{27}"),
+    ('\u{1FBE}', "i"),
+    ('\u{1FBF}', "\u{27}"),
+    ('\u{1FC0}', "~"),
+    ('\u{1FC1}', "\u{A8}\u{303}"),
+    ('\u{1FC2}', "n\u{328}\u{329}\u{300}"),
+    ('\u{1FC3}', "n\u{328}\u{329}"),
+    ('\u{1FC4}', "n\u{328}\u{329}\u{301}"),
+    ('\u{1FC6}', "n\u{329}\u{303}"),
+    ('\u{1FC7}', "n\u{328}\u{329}\u{303}"),
+    ('\u{1FC8}', "E\u{300}"),
+    ('\u{1FC9}', "E\u{301}"),
+    ('\u{1FCA}', "H\u{300}"),
+    ('\u{1FCB}', "H\u{301}"),
+    ('\u{1FCC}', "H\u{328}"),
+    ('\u{1FCD}', "\u{27}\u{300}"),
+    ('\u{1FCE}', "\u{27}\u{301}"),
+    ('\u{1FCF}', "\u{27}\u{303}"),
+    ('\u{1FD0}', "i\u{306}"),
+    ('\u{1FD1}', "i\u{304}"),
+    ('\u{1FD2}', "i\u{308}\u{300}"),
+    ('\u{1FD3}', "i\u{308}\u{301}"),
+    ('\u{1FD6}', "i\u{303}"),
+    ('\u{1FD7}', "i\u{308}\u{303}"),
+    ('\u{1FD8}', "l\u{306}"),
+    ('\u{1FD9}', "l\u{304}"),
+    ('\u{1FDA}', "l\u{300}"),
+    ('\u{1FDB}', "l\u{301}"),
+    ('\u{1FDD}', "\u{27}\u{300}"),
+    ('\u{1FDE}', "\u{27}\u{301}"),
+    ('\u{1FDF}', "\u{27}\u{303}"),
+    ('\u{1FE0}', "u\u{306}"),
+    ('\u{1FE1}', "u\u{304}"),
+    ('\u{1FE2}', "u\u{308}\u{300}"),
+    ('\u{1FE3}', "u\u{308}\u{301}"),
+    ('\u{1FE4}', "p\u{313}"),
+    ('\u{1FE5}', "p\u{314}"),
+    ('\u{1FE6}', "u\u{303}"),
+    ('\u{1FE7}', "u\u{308}\u{303}"),
+    ('\u{1FE8}', "Y\u{306}"),
+    ('\u{1FE9}', "Y\u{304}"),
+    ('\u{1FEA}', "Y\u{300}"),
+    ('\u{1FEB}', "Y\u{301}"),
+    ('\u{1FEC}', "P\u{314}"),
+    ('\u{1FED}', "\u{A8}\u{300}"),
+    ('\u{1FEE}', "\u{A8}\u{301}"),
+    ('\u{1FEF}', "\u{27}"),
+    ('\u{1FF2}', "\u{3C9}\u{328}\u{300}"),
+    ('\u{1FF3}', "\u{3C9}\u{328}"),
+    ('\u{1FF4}', "\u{3C9}\u{328}\u{301}"),
+    ('\u{1FF6}', "\u{3C9}\u{303}"),
+    ('\u{1FF7}', "\u{3C9}\u{328}\u{303}"),
+    ('\u{1FF8}', "O\u{300}"),
+    ('\u{1FF9}', "O\u{301}"),
+    ('\u{1FFA}', "\u{3A9}\u{300}"),
+    ('\u{1FFB}', "\u{3A9}\u{301}"),
+    ('\u{1FFC}', "\u{3A9}\u{328}"),
+    ('\u{1FFD}', "\u{27}"),
+    ('\u{1FFE}', "\u{27}"),
+    ('\u{2000}', " "),
+    ('\u{2001}', " "),
+    ('\u{2002}', " "),
+    ('\u{2003}', " "),
+    ('\u{2004}', " "),
+    ('\u{2005}', " "),
+    ('\u{2006}', " "),
+    ('\u{2007}', " "),
+    ('\u{2008}', " "),
+    ('\u{2009}', " "),
+    ('\u{200A}', " "),
+    ('\u{2010}', "-"),
+    ('\u{2011}', "-"),
+    ('\u{2012}', "-"),
+    ('\u{2013}', "-"),
+    ('\u{2014}', "\u{30FC}"),
+    ('\u{2015}', "\u{30FC}"),
+    ('\u{2016}', "ll"),
+    ('\u{2018}', "\u{27}"),
+    ('\u{2019}', "\u{27}"),
+    ('\u{201A}', ","),
+    ('\u{201B}', "\u{27}"),
+    ('\u{201C}', "\u{27}\u{27}"),
+    ('\u{201D}', "\u{27}\u{27}"),
+    ('\u{201F}', "\u{27}\u{27}"),
+    ('\u{2022}', "\u{B7}"),
+    ('\u{2024}', "."),
+    ('\u{2025}', ".."),
+    ('\u{2026}', "..."),
+    ('\u{2027}', "\u{B7}"),
+    ('\u{2028}', " "),
+    ('\u{2029}', " "),
+    ('\u{202F}', " "),
+    ('\u{2030}', "\u{BA}/\u{2080}\u{2080}"),
+    ('\u{2031}', "\u{BA}/\u{2080}\u{2080}\u{2080}"),
+    ('\u{2032}', "\u{27}"),
+    ('\u{2033}', "\u{27}\u{27}"),
+    ('\u{2034}', "\u{27}\u{27}\u{27}"),
+    ('\u{2035}', "\u{27}"),
+    ('\u{2036}', "\u{27}\u{27}"),
+    ('\u{2037}', "\u{27}\u{27}\u{27}"),
+    ('\u{2039}', "<"),
+    ('\u{203A}', ">"),
+    ('\u{203C}', "!!"),
+    ('\u{203E}', "\u{2C9}"),
+    ('\u{2041}', "/"),
+    ('\u{2043}', "-"),
+    ('\u{2044}', "/"),
+    ('\u{2047}', "??"),
+    ('\u{2048}', "?!"),
+    ('\u{2049}', "!?"),
+    ('\u{204E}', "*"),
+    ('\u{2052}', "\u{BA}/\u{2080}"),
+    ('\u{2053}', "~"),
+    ('\u{2057}', "\u{27}\u{27}\u{27}\u{27}"),
+    ('\u{205A}', ":"),
+    ('\u{205D}', "\u{2D57}"),
+    ('\u{205E}', "\u{2D42}"),
+    ('\u{205F}', " "),
+    ('\u{2070}', "\u{BA}"),
+    ('\u{2079}', "\u{A770}"),
+    ('\u{20A1}', "C\u{20EB}"),
+    ('\u{20A4}', "\u{A3}"),
+    ('\u{20A5}', "rn\u{338}"),
+    ('\u{20A8}', "Rs"),
+    ('\u{20A9}', "W\u{335}"),
+    ('\u{20AB}', "d\u{335}\u{331}"),
+    ('\u{20AC}', "\u{A792}"),
+    ('\u{20AD}', "K\u{335}"),
+    ('\u{20AE}', "T\u{20EB}"),
+    ('\u{20B6}', "lt"),
+    ('\u{20BD}', "\u{554}"),
+    ('\u{20DB}', "\u{6DB}"),
+    ('\u{2100}', "a/c"),
+    ('\u{2101}', "a/s"),
+    ('\u{2102}', "C"),
+    ('\u{2103}', "\u{B0}C"),
+    ('\u{2105}', "c/o"),
+    ('\u{2106}', "c/u"),
+    ('\u{2107}', "\u{190}"),
+    ('\u{2108}', "\u{42D}"),
+    ('\u{2109}', "\u{B0}F"),
+    ('\u{210A}', "g"),
+    ('\u{210B}', "H"),
+    ('\u{210C}', "H"),
+    ('\u{210D}', "H"),
+    ('\u{210E}', "h"),
+    ('\u{210F}', "h\u{335}"),
+    ('\u{2110}', "l"),
+    ('\u{2111}', "l"),
+    ('\u{2112}', "L"),
+    ('\u{2113}', "l"),
+    ('\u{2115}', "N"),
+    ('\u{2116}', "No"),
+    ('\u{2119}', "P"),
+    ('\u{211A}', "Q"),
+    ('\u{211B}', "R"),
+    ('\u{211C}', "R"),
+    ('\u{211D}', "R"),
+    ('\u{2121}', "TEL"),
+    ('\u{2124}', "Z"),
+    ('\u{2126}', "\u{3A9}"),
+    ('\u{2127}', "\u{1B1}"),
+    ('\u{2128}', "Z"),
+    ('\u{2129}', "\u{27F}"),
+    ('\u{212A}', "K"),
+    ('\u{212B}', "A\u{30A}"),
+    ('\u{212C}', "B"),
+    ('\u{212D}', "C"),
+    ('\u{212E}', "e"),
+    ('\u{212F}', "e"),
+    ('\u{2130}', "E"),
+    ('\u{2131}', "F"),
+    ('\u{2133}', "M"),
+    ('\u{2134}', "o"),
+    ('\u{2135}', "\u{5D0}"),
+    ('\u{2136}', "\u{5D1}"),
+    ('\u{2137}', "\u{5D2}"),
+    ('\u{2138}', "\u{5D3}"),
+    ('\u{2139}', "i"),
+    ('\u{213B}', "FAX"),
+    ('\u{213C}', "\u{3C0}"),
+    ('\u{213D}', "y"),
+    ('\u{213E}', "\u{393}"),
+    ('\u{213F}', "\u{3A0}"),
+    ('\u{2140}', "\u{1A9}"),
+    ('\u{2141}', "\u{A4E8}"),
+    ('\u{2142}', "\u{A4F6}"),
+    ('\u{2143}', "\u{16F00}"),
+    ('\u{2145}', "D"),
+    ('\u{2146}', "d"),
+    ('\u{2147}', "e"),
+    ('\u{2148}', "i"),
+    ('\u{2149}', "j"),
+    ('\u{2160}', "l"),
+    ('\u{2161}', "ll"),
+    ('\u{2162}', "lll"),
+    ('\u{2163}', "lV"),
+    ('\u{2164}', "V"),
+    ('\u{2165}', "Vl"),
+    ('\u{2166}', "Vll"),
+    ('\u{2167}', "Vlll"),
+    ('\u{2168}', "lX"),
+    ('\u{2169}', "X"),
+    ('\u{216A}', "Xl"),
+    ('\u{216B}', "Xll"),
+    ('\u{216C}', "L"),
+    ('\u{216D}', "C"),
+    ('\u{216E}', "D"),
+    ('\u{216F}', "M"),
+    ('\u{2170}', "i"),
+    ('\u{2171}', "ii"),
+    ('\u{2172}', "iii"),
+    ('\u{2173}', "iv"),
+    ('\u{2174}', "v"),
+    ('\u{2175}', "vi"),
+    ('\u{2176}', "vii"),
+    ('\u{2177}', "viii"),
+    ('\u{2178}', "ix"),
+    ('\u{2179}', "x"),
+    ('\u{217A}', "xi"),
+    ('\u{217B}', "xii"),
+    ('\u{217C}', "l"),
+    ('\u{217D}', "c"),
+    ('\u{217E}', "d"),
+    ('\u{217F}', "rn"),
+    ('\u{2183}', "\u{186}"),
+    ('\u{2184}', "\u{254}"),
+    ('\u{2191}', "\u{16CF}"),
+    ('\u{2195}', "\u{16E8}"),
+    ('\u{219A}', "\u{2190}\u{338}"),
+    ('\u{219B}', "\u{2192}\u{338}"),
+    ('\u{21AE}', "\u{2194}\u{338}"),
+    ('\u{21B5}', "\u{21B2}"),
+    ('\u{21BA}', "\u{1F10E}"),
+    ('\u{21BE}', "\u{16DA}"),
+    ('\u{21BF}', "\u{16D0}"),
+    ('\u{21CD}', "\u{21D0}\u{338}"),
+    ('\u{21CE}', "\u{21D4}\u{338}"),
+    ('\u{21CF}', "\u{21D2}\u{338}"),
+    ('\u{2200}', "\u{2C6F}"),
+    ('\u{2203}', "\u{18E}"),
+    ('\u{2204}', "\u{18E}\u{338}"),
+    ('\u{2206}', "\u{394}"),
+    ('\u{2209}', "\u{2208}\u{338}"),
+    ('\u{220C}', "\u{220B}\u{338}"),
+    ('\u{220F}', "\u{3A0}"),
+    ('\u{2211}', "\u{1A9}"),
+    ('\u{2212}', "-"),
+    ('\u{2214}', "+\u{307}"),
+    ('\u{2215}', "/"),
+    ('\u{2216}', "\u{5C}"),
+    ('\u{2217}', "*"),
+    ('\u{2218}', "\u{B0}"),
+    ('\u{2219}', "\u{B7}"),
+    ('\u{221E}', "oo"),
+    ('\u{2223}', "l"),
+    ('\u{2224}', "l\u{338}"),
+    ('\u{2225}', "ll"),
+    ('\u{2226}', "ll\u{338}"),
+    ('\u{2228}', "v"),
+    ('\u{2229}', "\u{548}"),
+    ('\u{222A}', "U"),
+    ('\u{222B}', "\u{283}"),
+    ('\u{222C}', "\u{283}\u{283}"),
+    ('\u{222D}', "\u{283}\u{283}\u{283}"),
+    ('\u{222F}', "\u{222E}\u{222E}"),
+    ('\u{2230}', "\u{222E}\u{222E}\u{222E}"),
+    ('\u{2236}', ":"),
+    ('\u{2238}', "-\u{307}"),
+    ('\u{223C}', "~"),
+    ('\u{2241}', "~\u{338}"),
+    ('\u{2244}', "\u{2243}\u{338}"),
+    ('\u{2247}', "\u{2245}\u{338}"),
+    ('\u{2249}', "\u{2248}\u{338}"),
+    ('\u{2250}', "=\u{307}"),
+    ('\u{2251}', "=\u{323}\u{307}"),
+    ('\u{2257}', "=\u{30A}"),
+    ('\u{2259}', "=\u{302}"),
+    ('\u{225A}', "=\u{306}"),
+    ('\u{225E}', "=\u{36B}"),
+    ('\u{2260}', "=\u{338}"),
+    ('\u{2262}', "\u{2261}\u{338}"),
+    ('\u{2263}', "\u{2261}"),
+    ('\u{226A}', "<<"),
+    ('\u{226B}', ">>"),
+    ('\u{226D}', "\u{224D}\u{338}"),
+    ('\u{226E}', "<\u{338}"),
+    ('\u{226F}', ">\u{338}"),
+    ('\u{2270}', "\u{2264}\u{338}"),
+    ('\u{2271}', "\u{2265}\u{338}"),
+    ('\u{2274}', "\u{2272}\u{338}"),
+    ('\u{2275}', "\u{2273}\u{338}"),
+    ('\u{2278}', "\u{2276}\u{338}"),
+    ('\u{2279}', "\u{2277}\u{338}"),
+    ('\u{2280}', "\u{227A}\u{338}"),
+    ('\u{2281}', "\u{227B}\u{338}"),
+    ('\u{2282}', "\u{1455}"),
+    ('\u{2283}', "\u{1450}"),
+    ('\u{2284}', "\u{1455}\u{338}"),
+    ('\u{2285}', "\u{1450}\u{338}"),
+    ('\u{2288}', "\u{2286}\u{338}"),
+    ('\u{2289}', "\u{2287}\u{338}"),
+    ('\u{2295}', "\u{102A8}"),
+    ('\u{2296}', "O\u{335}"),
+    ('\u{2299}', "\u{298}"),
+    ('\u{229D}', "O\u{335}"),
+    ('\u{22A4}', "T"),
+    ('\u{22A5}', "\u{A4D5}"),
+    ('\u{22AC}', "\u{22A2}\u{338}"),
+    ('\u{22AD}', "\u{22A8}\u{338}"),
+    ('\u{22AE}', "\u{22A9}\u{338}"),
+    ('\u{22AF}', "\u{22AB}\u{338}"),
+    ('\u{22C0}', "\u{2227}"),
+    ('\u{22C1}', "v"),
+    ('\u{22C2}', "\u{548}"),
+    ('\u{22C3}', "U"),
+    ('\u{22C4}', "\u{16DC}"),
+    ('\u{22C5}', "\u{B7}"),
+    ('\u{22C8}', "\u{16DE}"),
+    ('\u{22D6}', "<\u{B7}"),
+    ('\u{22D7}', "\u{B7}>"),
+    ('\u{22D8}', "<<<"),
+    ('\u{22D9}', ">>>"),
+    ('\u{22E0}', "\u{227C}\u{338}"),
+    ('\u{22E1}', "\u{227D}\u{338}"),
+    ('\u{22E2}', "\u{2291}\u{338}"),
+    ('\u{22E3}', "\u{2292}\u{338}"),
+    ('\u{22EA}', "\u{22B2}\u{338}"),
+    ('\u{22EB}', "\u{22B3}\u{338}"),
+    ('\u{22EC}', "\u{22B4}\u{338}"),
+    ('\u{22ED}', "\u{22B5}\u{338}"),
+    ('\u{22EE}', "\u{2D57}"),
+    ('\u{22EF}', "\u{B7}\u{B7}\u{B7}"),
+    ('\u{22F4}', "\u{A793}"),
+    ('\u{22FF}', "E"),
+    ('\u{2300}', "\u{2205}"),
+    ('\u{2325}', "\u{2324}"),
+    ('\u{2329}', "\u{276C}"),
+    ('\u{232A}', "\u{276D}"),
+    ('\u{2341}', "\u{303C}"),
+    ('\u{2359}', "\u{394}\u{332}"),
+    ('\u{235A}', "\u{16DC}\u{332}"),
+    ('\u{235C}', "\u{B0}\u{332}"),
+    ('\u{235F}', "\u{229B}"),
+    ('\u{2361}', "T\u{308}"),
+    ('\u{2362}', "\u{2207}\u{308}"),
+    ('\u{2363}', "\u{22C6}\u{308}"),
+    ('\u{2364}', "\u{B0}\u{308}"),
+    ('\u{2365}', "\u{629}"),
+    ('\u{2368}', "~\u{308}"),
+    ('\u{2369}', "\u{1435}"),
+    ('\u{236B}', "\u{2207}\u{334}"),
+    ('\u{236C}', "O\u{335}"),
+    ('\u{2373}', "i"),
+    ('\u{2374}', "p"),
+    ('\u{2375}', "\u{3C9}"),
+    ('\u{2376}', "a\u{332}"),
+    ('\u{2377}', "\u{A793}\u{332}"),
+    ('\u{2378}', "i\u{332}"),
+    ('\u{2379}', "\u{3C9}\u{332}"),
+    ('\u{237A}', "a"),
+    ('\u{237F}', "\u{16BD}"),
+    ('\u{239C}', "\u{4E28}"),
+    ('\u{239F}', "\u{4E28}"),
+    ('\u{23A2}', "\u{4E28}"),
+    ('\u{23A5}', "\u{4E28}"),
+    ('\u{23AA}', "\u{4E28}"),
+    ('\u{23AE}', "\u{4E28}"),
+    ('\u{23C1}', "\u{2355}"),
+    ('\u{23C2}', "\u{234E}"),
+    ('\u{23C3}', "\u{234B}"),
+    ('\u{23C6}', "\u{236D}"),
+    ('\u{23E8}', "\u{2081}\u{2080}"),
+    ('\u{23FC}', "\u{23FB}"),
+    ('\u{23FD}', "l"),
+    ('\u{23FE}', "\u{263E}"),
+    ('\u{244A}', "\u{5C}\u{5C}"),
+    ('\u{2460}', "\u{2780}"),
+    ('\u{2461}', "\u{2781}"),
+    ('\u{2462}', "\u{2782}"),
+    ('\u{2463}', "\u{2783}"),
+    ('\u{2464}', "\u{2784}"),
+    ('\u{2465}', "\u{2785}"),
+    ('\u{2466}', "\u{2786}"),
+    ('\u{2467}', "\u{2787}"),
+    ('\u{2468}', "\u{2788}"),
+    ('\u{2469}', "\u{2789}"),
+    ('\u{2474}', "(l)"),
+    ('\u{2475}', "(2)"),
+    ('\u{2476}', "(3)"),
+    ('\u{2477}', "(4)"),
+    ('\u{2478}', "(5)"),
+    ('\u{2479}', "(6)"),
+    ('\u{247A}', "(7)"),
+    ('\u{247B}', "(8)"),
+    ('\u{247C}', "(9)"),
+    ('\u{247D}', "(lO)"),
+    ('\u{247E}', "(ll)"),
+    ('\u{247F}', "(l2)"),
+    ('\u{2480}', "(l3)"),
+    ('\u{2481}', "(l4)"),
+    ('\u{2482}', "(l5)"),
+    ('\u{2483}', "(l6)"),
+    ('\u{2484}', "(l7)"),
+    ('\u{2485}', "(l8)"),
+    ('\u{2486}', "(l9)"),
+    ('\u{2487}', "(2O)"),
+    ('\u{2488}', "l."),
+    ('\u{2489}', "2."),
+    ('\u{248A}', "3."),
+    ('\u{248B}', "4."),
+    ('\u{248C}', "5."),
+    ('\u{248D}', "6."),
+    ('\u{248E}', "7."),
+    ('\u{248F}', "8."),
+    ('\u{2490}', "9."),
+    ('\u{2491}', "lO."),
+    ('\u{2492}', "ll."),
+    ('\u{2493}', "l2."),
+    ('\u{2494}', "l3."),
+    ('\u{2495}', "l4."),
+    ('\u{2496}', "l5."),
+    ('\u{2497}', "l6."),
+    ('\u{2498}', "l7."),
+    ('\u{2499}', "l8."),
+    ('\u{249A}', "l9."),
+    ('\u{249B}', "2O."),
+    ('\u{249C}', "(a)"),
+    ('\u{249D}', "(b)"),
+    ('\u{249E}', "(c)"),
+    ('\u{249F}', "(d)"),
+    ('\u{24A0}', "(e)"),
+    ('\u{24A1}', "(f)"),
+    ('\u{24A2}', "(g)"),
+    ('\u{24A3}', "(h)"),
+    ('\u{24A4}', "(i)"),
+    ('\u{24A5}', "(j)"),
+    ('\u{24A6}', "(k)"),
+    ('\u{24A7}', "(l)"),
+    ('\u{24A8}', "(rn)"),
+    ('\u{24A9}', "(n)"),
+    ('\u{24AA}', "(o)"),
+    ('\u{24AB}', "(p)"),
+    ('\u{24AC}', "(q)"),
+    ('\u{24AD}', "(r)"),
+    ('\u{24AE}', "(s)"),
+    ('\u{24AF}', "(t)"),
+    ('\u{24B0}', "(u)"),
+    ('\u{24B1}', "(v)"),
+    ('\u{24B2}', "(w)"),
+    ('\u{24B3}', "(x)"),
+    ('\u{24B4}', "(y)"),
+    ('\u{24B5}', "(z)"),
+    ('\u{24B8}', "\u{A9}"),
+    ('\u{24C5}', "\u{2117}"),
+    ('\u{24C7}', "\u{AE}"),
+    ('\u{24DB}', "\u{24BE}"),
+    ('\u{24EA}', "\u{1F10D}"),
+    ('\u{2500}', "\u{30FC}"),
+    ('\u{2501}', "\u{30FC}"),
+    ('\u{2503}', "\u{2502}"),
+    ('\u{250F}', "\u{250C}"),
+    ('\u{2523}', "\u{251C}"),
+    ('\u{2571}', "/"),
+    ('\u{2573}', "X"),
+    ('\u{2588}', "\u{220E}"),
+    ('\u{2590}', "\u{258C}"),
+    ('\u{2594}', "\u{2C9}"),
+    ('\u{2597}', "\u{2596}"),
+    ('\u{259D}', "\u{2598}"),
+    ('\u{25A0}', "\u{220E}"),
+    ('\u{25B1}', "\u{23E5}"),
+    ('\u{25B3}', "\u{394}"),
+    ('\u{25B7}', "\u{22B3}"),
+    ('\u{25B8}', "\u{25B6}"),
+    ('\u{25BA}', "\u{25B6}"),
+    ('\u{25BD}', "\u{102BC}"),
+    ('\u{25C1}', "\u{22B2}"),
+    ('\u{25C7}', "\u{16DC}"),
+    ('\u{25CA}', "\u{16DC}"),
+    ('\u{25CB}', "\u{B0}"),
+    ('\u{25CE}', "\u{233E}"),
+    ('\u{25E0}', "\u{2312}"),
+    ('\u{25E6}', "\u{B0}"),
+    ('\u{2609}', "\u{298}"),
+    ('\u{2610}', "\u{25A1}"),
+    ('\u{2625}', "\u{1099E}"),
+    ('\u{2630}', "\u{2CB6}"),
+    ('\u{2638}', "\u{2388}"),
+    ('\u{264E}', "\u{224F}"),
+    ('\u{2662}', "\u{16DC}"),
+    ('\u{2669}', "\u{1D158}\u{1D165}"),
+    ('\u{266A}', "\u{1D158}\u{1D165}\u{1D16E}"),
+    ('\u{26AC}', "\u{970}"),
+    ('\u{2768}', "("),
+    ('\u{2769}', ")"),
+    ('\u{276E}', "<"),
+    ('\u{276F}', ">"),
+    ('\u{2772}', "("),
+    ('\u{2773}', ")"),
+    ('\u{2774}', "{"),
+    ('\u{2775}', "}"),
+    ('\u{2795}', "+"),
+    ('\u{2796}', "-"),
+    ('\u{2797}', "\u{F7}"),
+    ('\u{27C2}', "\u{A4D5}"),
+    ('\u{27C8}', "\u{5C}\u{1455}"),
+    ('\u{27C9}', "\u{1450}/"),
+    ('\u{27CB}', "/"),
+    ('\u{27CD}', "\u{5C}"),
+    ('\u{27D9}', "T"),
+    ('\u{27E8}', "\u{276C}"),
+    ('\u{27E9}', "\u{276D}"),
+    ('\u{292B}', "x"),
+    ('\u{292C}', "x"),
+    ('\u{2963}', "\u{16D0}\u{16DA}"),
+    ('\u{2965}', "\u{21C3}\u{21C2}"),
+    ('\u{296E}', "\u{16D0}\u{21C2}"),
+    ('\u{296F}', "\u{21C3}\u{16DA}"),
+    ('\u{2999}', "\u{2D42}"),
+    ('\u{29B0}', "\u{2349}"),
+    ('\u{29BE}', "\u{233E}"),
+    ('\u{29C4}', "\u{303C}"),
+    ('\u{29C5}', "\u{2342}"),
+    ('\u{29C7}', "\u{233B}"),
+    ('\u{29D6}', "\u{102C0}"),
+    ('\u{29D9}', "\u{299A}"),
+    ('\u{29F4}', ":\u{2192}"),
+    ('\u{29F5}', "\u{5C}"),
+    ('\u{29F6}', "/\u{304}"),
+    ('\u{29F8}', "/"),
+    ('\u{29F9}', "\u{5C}"),
+    ('\u{2A00}', "\u{298}"),
+    ('\u{2A01}', "\u{102A8}"),
+    ('\u{2A02}', "\u{2297}"),
+    ('\u{2A03}', "\u{228D}"),
+    ('\u{2A04}', "\u{228E}"),
+    ('\u{2A05}', "\u{2293}"),
+    ('\u{2A06}', "\u{2294}"),
+    ('\u{2A0C}', "\u{283}\u{283}\u{283}\u{283}"),
+    ('\u{2A1D}', "\u{16DE}"),
+    ('\u{2A20}', ">>"),
+    ('\u{2A21}', "\u{16DA}"),
+    ('\u{2A22}', "+\u{30A}"),
+    ('\u{2A23}', "+\u{302}"),
+    ('\u{2A24}', "+\u{303}"),
+    ('\u{2A25}', "+\u{323}"),
+    ('\u{2A26}', "+\u{330}"),
+    ('\u{2A27}', "+\u{2082}"),
+    ('\u{2A29}', "-\u{313}"),
+    ('\u{2A2A}', "-\u{323}"),
+    ('\u{2A2F}', "x"),
+    ('\u{2A30}', "x\u{307}"),
+    ('\u{2A3D}', "\u{2319}"),
+    ('\u{2A3E}', "\u{2A1F}"),
+    ('\u{2A3F}', "\u{2210}"),
+    ('\u{2A6A}', "~\u{307}"),
+    ('\u{2A6E}', "=\u{20F0}"),
+    ('\u{2A74}', "::="),
+    ('\u{2A75}', "=="),
+    ('\u{2A76}', "==="),
+    ('\u{2AA5}', "><"),
+    ('\u{2AAA}', "\u{15D5}"),
+    ('\u{2AAB}', "\u{15D2}"),
+    ('\u{2AD7}', "\u{1450}\u{1455}"),
+    ('\u{2ADC}', "\u{2ADD}\u{338}"),
+    ('\u{2AFB}', "///"),
+    ('\u{2AFD}', "//"),
+    ('\u{2BEC}', "\u{219E}"),
+    ('\u{2BED}', "\u{219F}"),
+    ('\u{2BEE}', "\u{21A0}"),
+    ('\u{2BEF}', "\u{21A1}"),
+    ('\u{2C67}', "H\u{329}"),
+    ('\u{2C69}', "K\u{329}"),
+    ('\u{2C84}', "\u{393}"),
+    ('\u{2C85}', "r"),
+    ('\u{2C86}', "\u{394}"),
+    ('\u{2C88}', "\u{A792}"),
+    ('\u{2C89}', "\u{A793}"),
+    ('\u{2C8E}', "H"),
+    ('\u{2C92}', "l"),
+    ('\u{2C94}', "K"),
+    ('\u{2C95}', "\u{138}"),
+    ('\u{2C96}', "\u{3BB}"),
+    ('\u{2C98}', "M"),
+    ('\u{2C9A}', "N"),
+    ('\u{2C9E}', "O"),
+    ('\u{2C9F}', "o"),
+    ('\u{2CA0}', "\u{3A0}"),
+    ('\u{2CA2}', "P"),
+    ('\u{2CA3}', "p"),
+    ('\u{2CA4}', "C"),
+    ('\u{2CA5}', "c"),
+    ('\u{2CA6}', "T"),
+    ('\u{2CA8}', "Y"),
+    ('\u{2CAA}', "\u{3A6}"),
+    ('\u{2CAB}', "\u{278}"),
+    ('\u{2CAC}', "X"),
+    ('\u{2CAD}', "\u{3C7}"),
+    ('\u{2CAE}', "\u{3A8}"),
+    ('\u{2CB1}', "\u{3C9}"),
+    ('\u{2CB4}', "<\u{B7}"),
+    ('\u{2CBA}', "-"),
+    ('\u{2CBC}', "\u{428}"),
+    ('\u{2CBD}', "\u{448}"),
+    ('\u{2CC6}', "/"),
+    ('\u{2CCA}', "9"),
+    ('\u{2CCC}', "3"),
+    ('\u{2CCD}', "\u{21D}"),
+    ('\u{2CD0}', "L"),
+    ('\u{2CD1}', "\u{29F}"),
+    ('\u{2CD2}', "6"),
+    ('\u{2CDC}', "\u{3EC}"),
+    ('\u{2CE4}', "\u{3D7}"),
+    ('\u{2CE9}', "\u{2627}"),
+    ('\u{2CF9}', "\u{5C}\u{5C}"),
+    ('\u{2D31}', "O\u{335}"),
+    ('\u{2D37}', "\u{245}"),
+    ('\u{2D38}', "V"),
+    ('\u{2D39}', "E"),
+    ('\u{2D3A}', "\u{18E}"),
+    ('\u{2D41}', "O\u{338}"),
+    ('\u{2D48}', "\u{B7}\u{B7}\u{B7}"),
+    ('\u{2D49}', "\u{1A9}"),
+    ('\u{2D4F}', "l"),
+    ('\u{2D51}', "!"),
+    ('\u{2D54}', "O"),
+    ('\u{2D55}', "Q"),
+    ('\u{2D59}', "\u{298}"),
+    ('\u{2D5D}', "X"),
+    ('\u{2D60}', "\u{394}"),
+    ('\u{2D63}', "\u{16EF}"),
+    ('\u{2DE8}', "\u{1DDF}"),
+    ('\u{2DEA}', "\u{30A}"),
+    ('\u{2DED}', "\u{368}"),
+    ('\u{2DEF}', "\u{36F}"),
+    ('\u{2DF6}', "\u{363}"),
+    ('\u{2DF7}', "\u{364}"),
+    ('\u{2E1A}', "-\u{308}"),
+    ('\u{2E1E}', "~\u{307}"),
+    ('\u{2E1F}', "~\u{323}"),
+    ('\u{2E26}', "\u{1455}"),
+    ('\u{2E27}', "\u{1450}"),
+    ('\u{2E28}', "(("),
+    ('\u{2E29}', "))"),
+    ('\u{2E2A}', "\u{2235}"),
+    ('\u{2E2B}', "\u{2234}"),
+    ('\u{2E2C}', "\u{2237}"),
+    ('\u{2E2E}', "\u{61F}"),
+    ('\u{2E30}', "\u{B0}"),
+    ('\u{2E31}', "\u{B7}"),
+    ('\u{2E32}', "\u{60C}"),
+    ('\u{2E35}', "\u{61B}"),
+    ('\u{2E39}', "\u{1E9F}"),
+    ('\u{2E3D}', "\u{2D42}"),
+    ('\u{2E3F}', "\u{B6}"),
+    ('\u{2E40}', "="),
+    ('\u{2E82}', "\u{4E5B}"),
+    ('\u{2E83}', "\u{4E5A}"),
+    ('\u{2E85}', "\u{4EBB}"),
+    ('\u{2E89}', "\u{5202}"),
+    ('\u{2E8B}', "\u{353E}"),
+    ('\u{2E8E}', "\u{5140}"),
+    ('\u{2E8F}', "\u{5C23}"),
+    ('\u{2E90}', "\u{5C22}"),
+    ('\u{2E92}', "\u{5DF3}"),
+    ('\u{2E93}', "\u{5E7A}"),
+    ('\u{2E94}', "\u{5F51}"),
+    ('\u{2E96}', "\u{5FC4}"),
+    ('\u{2E97}', "\u{38FA}"),
+    ('\u{2E98}', "\u{624C}"),
+    ('\u{2E99}', "\u{6535}"),
+    ('\u{2E9B}', "\u{65E1}"),
+    ('\u{2E9E}', "\u{6B7A}"),
+    ('\u{2E9F}', "\u{6BCD}"),
+    ('\u{2EA0}', "\u{6C11}"),
+    ('\u{2EA1}', "\u{6C35}"),
+    ('\u{2EA2}', "\u{6C3A}"),
+    ('\u{2EA3}', "\u{706C}"),
+    ('\u{2EA4}', "\u{722B}"),
+    ('\u{2EA6}', "\u{4E2C}"),
+    ('\u{2EA8}', "\u{72AD}"),
+    ('\u{2EAB}', "\u{7F52}"),
+    ('\u{2EAD}', "\u{793B}"),
+    ('\u{2EAF}', "\u{7CF9}"),
+    ('\u{2EB1}', "\u{7F53}"),
+    ('\u{2EB2}', "\u{7F52}"),
+    ('\u{2EB9}', "\u{8002}"),
+    ('\u{2EBA}', "\u{8080}"),
+    ('\u{2EBE}', "\u{8279}"),
+    ('\u{2EBF}', "\u{8279}"),
+    ('\u{2EC0}', "\u{8279}"),
+    ('\u{2EC1}', "\u{864E}"),
+    ('\u{2EC2}', "\u{8864}"),
+    ('\u{2EC3}', "\u{8980}"),
+    ('\u{2EC4}', "\u{897F}"),
+    ('\u{2EC5}', "\u{89C1}"),
+    ('\u{2EC8}', "\u{8BA0}"),
+    ('\u{2EC9}', "\u{8D1D}"),
+    ('\u{2ECB}', "\u{8F66}"),
+    ('\u{2ECC}', "\u{8FB6}"),
+    ('\u{2ECD}', "\u{8FB6}"),
+    ('\u{2ECF}', "\u{961D}"),
+    ('\u{2ED0}', "\u{9485}"),
+    ('\u{2ED1}', "\u{9577}"),
+    ('\u{2ED2}', "\u{9578}"),
+    ('\u{2ED3}', "\u{957F}"),
+    ('\u{2ED4}', "\u{95E8}"),
+    ('\u{2ED6}', "\u{961D}"),
+    ('\u{2ED8}', "\u{9752}"),
+    ('\u{2ED9}', "\u{97E6}"),
+    ('\u{2EDA}', "\u{9875}"),
+    ('\u{2EDB}', "\u{98CE}"),
+    ('\u{2EDC}', "\u{98DE}"),
+    ('\u{2EDD}', "\u{98DF}"),
+    ('\u{2EDF}', "\u{98E0}"),
+    ('\u{2EE0}', "\u{9963}"),
+    ('\u{2EE2}', "\u{9A6C}"),
+    ('\u{2EE4}', "\u{9B3C}"),
+    ('\u{2EE5}', "\u{9C7C}"),
+    ('\u{2EE8}', "\u{9EA6}"),
+    ('\u{2EE9}', "\u{9EC4}"),
+    ('\u{2EEB}', "\u{6589}"),
+    ('\u{2EEC}', "\u{9F50}"),
+    ('\u{2EED}', "\u{6B6F}"),
+    ('\u{2EEE}', "\u{9F7F}"),
+    ('\u{2EEF}', "\u{7ADC}"),
+    ('\u{2EF0}', "\u{9F99}"),
+    ('\u{2EF2}', "\u{4E80}"),
+    ('\u{2EF3}', "\u{9F9F}"),
+    ('\u{2F00}', "\u{30FC}"),
+    ('\u{2F01}', "\u{4E28}"),
+    ('\u{2F02}', "\u{5C}"),
+    ('\u{2F03}', "/"),
+    ('\u{2F04}', "\u{4E59}"),
+    ('\u{2F05}', "\u{4E85}"),
+    ('\u{2F06}', "\u{4E8C}"),
+    ('\u{2F07}', "\u{4EA0}"),
+    ('\u{2F08}', "\u{4EBA}"),
+    ('\u{2F09}', "\u{513F}"),
+    ('\u{2F0A}', "\u{5165}"),
+    ('\u{2F0B}', "\u{516B}"),
+    ('\u{2F0C}', "\u{5182}"),
+    ('\u{2F0D}', "\u{5196}"),
+    ('\u{2F0E}', "\u{51AB}"),
+    ('\u{2F0F}', "\u{51E0}"),
+    ('\u{2F10}', "\u{51F5}"),
+    ('\u{2F11}', "\u{5200}"),
+    ('\u{2F12}', "\u{529B}"),
+    ('\u{2F13}', "\u{52F9}"),
+    ('\u{2F14}', "\u{5315}"),
+    ('\u{2F15}', "\u{531A}"),
+    ('\u{2F16}', "\u{5338}"),
+    ('\u{2F17}', "\u{5341}"),
+    ('\u{2F18}', "\u{535C}"),
+    ('\u{2F19}', "\u{5369}"),
+    ('\u{2F1A}', "\u{5382}"),
+    ('\u{2F1B}', "\u{53B6}"),
+    ('\u{2F1C}', "\u{53C8}"),
+    ('\u{2F1D}', "\u{53E3}"),
+    ('\u{2F1E}', "\u{53E3}"),
+    ('\u{2F1F}', "\u{571F}"),
+    ('\u{2F20}', "\u{571F}"),
+    ('\u{2F21}', "\u{5902}"),
+    ('\u{2F22}', "\u{590A}"),
+    ('\u{2F23}', "\u{5915}"),
+    ('\u{2F24}', "\u{5927}"),
+    ('\u{2F25}', "\u{5973}"),
+    ('\u{2F26}', "\u{5B50}"),
+    ('\u{2F27}', "\u{5B80}"),
+    ('\u{2F28}', "\u{5BF8}"),
+    ('\u{2F29}', "\u{5C0F}"),
+    ('\u{2F2A}', "\u{5C22}"),
+    ('\u{2F2B}', "\u{5C38}"),
+    ('\u{2F2C}', "\u{5C6E}"),
+    ('\u{2F2D}', "\u{5C71}"),
+    ('\u{2F2E}', "\u{5DDB}"),
+    ('\u{2F2F}', "\u{5DE5}"),
+    ('\u{2F30}', "\u{5DF1}"),
+    ('\u{2F31}', "\u{5DFE}"),
+    ('\u{2F32}', "\u{5E72}"),
+    ('\u{2F33}', "\u{5E7A}"),
+    ('\u{2F34}', "\u{5E7F}"),
+    ('\u{2F35}', "\u{5EF4}"),
+    ('\u{2F36}', "\u{5EFE}"),
+    ('\u{2F37}', "\u{5F0B}"),
+    ('\u{2F38}', "\u{5F13}"),
+    ('\u{2F39}', "\u{5F50}"),
+    ('\u{2F3A}', "\u{5F61}"),
+    ('\u{2F3B}', "\u{5F73}"),
+    ('\u{2F3C}', "\u{5FC3}"),
+    ('\u{2F3D}', "\u{6208}"),
+    ('\u{2F3E}', "\u{6236}"),
+    ('\u{2F3F}', "\u{624B}"),
+    ('\u{2F40}', "\u{652F}"),
+    ('\u{2F41}', "\u{6534}"),
+    ('\u{2F42}', "\u{6587}"),
+    ('\u{2F43}', "\u{6597}"),
+    ('\u{2F44}', "\u{65A4}"),
+    ('\u{2F45}', "\u{65B9}"),
+    ('\u{2F46}', "\u{65E0}"),
+    ('\u{2F47}', "\u{65E5}"),
+    ('\u{2F48}', "\u{66F0}"),
+    ('\u{2F49}', "\u{6708}"),
+    ('\u{2F4A}', "\u{6728}"),
+    ('\u{2F4B}', "\u{6B20}"),
+    ('\u{2F4C}', "\u{6B62}"),
+    ('\u{2F4D}', "\u{6B79}"),
+    ('\u{2F4E}', "\u{6BB3}"),
+    ('\u{2F4F}', "\u{6BCB}"),
+    ('\u{2F50}', "\u{6BD4}"),
+    ('\u{2F51}', "\u{6BDB}"),
+    ('\u{2F52}', "\u{6C0F}"),
+    ('\u{2F53}', "\u{6C14}"),
+    ('\u{2F54}', "\u{6C34}"),
+    ('\u{2F55}', "\u{706B}"),
+    ('\u{2F56}', "\u{722A}"),
+    ('\u{2F57}', "\u{7236}"),
+    ('\u{2F58}', "\u{723B}"),
+    ('\u{2F59}', "\u{723F}"),
+    ('\u{2F5A}', "\u{7247}"),
+    ('\u{2F5B}', "\u{7259}"),
+    ('\u{2F5C}', "\u{725B}"),
+    ('\u{2F5D}', "\u{72AC}"),
+    ('\u{2F5E}', "\u{7384}"),
+    ('\u{2F5F}', "\u{7389}"),
+    ('\u{2F60}', "\u{74DC}"),
+    ('\u{2F61}', "\u{74E6}"),
+    ('\u{2F62}', "\u{7518}"),
+    ('\u{2F63}', "\u{751F}"),
+    ('\u{2F64}', "\u{7528}"),
+    ('\u{2F65}', "\u{7530}"),
+    ('\u{2F66}', "\u{758B}"),
+    ('\u{2F67}', "\u{7592}"),
+    ('\u{2F68}', "\u{7676}"),
+    ('\u{2F69}', "\u{767D}"),
+    ('\u{2F6A}', "\u{76AE}"),
+    ('\u{2F6B}', "\u{76BF}"),
+    ('\u{2F6C}', "\u{76EE}"),
+    ('\u{2F6D}', "\u{77DB}"),
+    ('\u{2F6E}', "\u{77E2}"),
+    ('\u{2F6F}', "\u{77F3}"),
+    ('\u{2F70}', "\u{793A}"),
+    ('\u{2F71}', "\u{79B8}"),
+    ('\u{2F72}', "\u{79BE}"),
+    ('\u{2F73}', "\u{7A74}"),
+    ('\u{2F74}', "\u{7ACB}"),
+    ('\u{2F75}', "\u{7AF9}"),
+    ('\u{2F76}', "\u{7C73}"),
+    ('\u{2F77}', "\u{7CF8}"),
+    ('\u{2F78}', "\u{7F36}"),
+    ('\u{2F79}', "\u{7F51}"),
+    ('\u{2F7A}', "\u{7F8A}"),
+    ('\u{2F7B}', "\u{7FBD}"),
+    ('\u{2F7C}', "\u{8001}"),
+    ('\u{2F7D}', "\u{800C}"),
+    ('\u{2F7E}', "\u{8012}"),
+    ('\u{2F7F}', "\u{8033}"),
+    ('\u{2F80}', "\u{807F}"),
+    ('\u{2F81}', "\u{8089}"),
+    ('\u{2F82}', "\u{81E3}"),
+    ('\u{2F83}', "\u{81EA}"),
+    ('\u{2F84}', "\u{81F3}"),
+    ('\u{2F85}', "\u{81FC}"),
+    ('\u{2F86}', "\u{820C}"),
+    ('\u{2F87}', "\u{821B}"),
+    ('\u{2F88}', "\u{821F}"),
+    ('\u{2F89}', "\u{826E}"),
+    ('\u{2F8A}', "\u{8272}"),
+    ('\u{2F8B}', "\u{8278}"),
+    ('\u{2F8C}', "\u{864D}"),
+    ('\u{2F8D}', "\u{866B}"),
+    ('\u{2F8E}', "\u{8840}"),
+    ('\u{2F8F}', "\u{884C}"),
+    ('\u{2F90}', "\u{8863}"),
+    ('\u{2F91}', "\u{897E}"),
+    ('\u{2F92}', "\u{898B}"),
+    ('\u{2F93}', "\u{89D2}"),
+    ('\u{2F94}', "\u{8A00}"),
+    ('\u{2F95}', "\u{8C37}"),
+    ('\u{2F96}', "\u{8C46}"),
+    ('\u{2F97}', "\u{8C55}"),
+    ('\u{2F98}', "\u{8C78}"),
+    ('\u{2F99}', "\u{8C9D}"),
+    ('\u{2F9A}', "\u{8D64}"),
+    ('\u{2F9B}', "\u{8D70}"),
+    ('\u{2F9C}', "\u{8DB3}"),
+    ('\u{2F9D}', "\u{8EAB}"),
+    ('\u{2F9E}', "\u{8ECA}"),
+    ('\u{2F9F}', "\u{8F9B}"),
+    ('\u{2FA0}', "\u{8FB0}"),
+    ('\u{2FA1}', "\u{8FB5}"),
+    ('\u{2FA2}', "\u{9091}"),
+    ('\u{2FA3}', "\u{9149}"),
+    ('\u{2FA4}', "\u{91C6}"),
+    ('\u{2FA5}', "\u{91CC}"),
+    ('\u{2FA6}', "\u{91D1}"),
+    ('\u{2FA7}', "\u{9577}"),
+    ('\u{2FA8}', "\u{9580}"),
+    ('\u{2FA9}', "\u{961C}"),
+    ('\u{2FAA}', "\u{96B6}"),
+    ('\u{2FAB}', "\u{96B9}"),
+    ('\u{2FAC}', "\u{96E8}"),
+    ('\u{2FAD}', "\u{9751}"),
+    ('\u{2FAE}', "\u{975E}"),
+    ('\u{2FAF}', "\u{9762}"),
+    ('\u{2FB0}', "\u{9769}"),
+    ('\u{2FB1}', "\u{97CB}"),
+    ('\u{2FB2}', "\u{97ED}"),
+    ('\u{2FB3}', "\u{97F3}"),
+    ('\u{2FB4}', "\u{9801}"),
+    ('\u{2FB5}', "\u{98A8}"),
+    ('\u{2FB6}', "\u{98DB}"),
+    ('\u{2FB7}', "\u{98DF}"),
+    ('\u{2FB8}', "\u{9996}"),
+    ('\u{2FB9}', "\u{9999}"),
+    ('\u{2FBA}', "\u{99AC}"),
+    ('\u{2FBB}', "\u{9AA8}"),
+    ('\u{2FBC}', "\u{9AD8}"),
+    ('\u{2FBD}', "\u{9ADF}"),
+    ('\u{2FBE}', "\u{9B25}"),
+    ('\u{2FBF}', "\u{9B2F}"),
+    ('\u{2FC0}', "\u{9B32}"),
+    ('\u{2FC1}', "\u{9B3C}"),
+    ('\u{2FC2}', "\u{9B5A}"),
+    ('\u{2FC3}', "\u{9CE5}"),
+    ('\u{2FC4}', "\u{9E75}"),
+    ('\u{2FC5}', "\u{9E7F}"),
+    ('\u{2FC6}', "\u{9EA5}"),
+    ('\u{2FC7}', "\u{9EBB}"),
+    ('\u{2FC8}', "\u{9EC3}"),
+    ('\u{2FC9}', "\u{9ECD}"),
+    ('\u{2FCA}', "\u{9ED1}"),
+    ('\u{2FCB}', "\u{9EF9}"),
+    ('\u{2FCC}', "\u{9EFD}"),
+    ('\u{2FCD}', "\u{9F0E}"),
+    ('\u{2FCE}', "\u{9F13}"),
+    ('\u{2FCF}', "\u{9F20}"),
+    ('\u{2FD0}', "\u{9F3B}"),
+    ('\u{2FD1}', "\u{9F4A}"),
+    ('\u{2FD2}', "\u{9F52}"),
+    ('\u{2FD3}', "\u{9F8D}"),
+    ('\u{2FD4}', "\u{9F9C}"),
+    ('\u{2FD5}', "\u{9FA0}"),
+    ('\u{3002}', "\u{2F3}"),
+    ('\u{3003}', "\u{27}\u{27}"),
+    ('\u{3007}', "O"),
+    ('\u{3008}', "\u{276C}"),
+    ('\u{3009}', "\u{276D}"),
+    ('\u{3012}', "\u{20B8}"),
+    ('\u{3014}', "("),
+    ('\u{3015}', ")"),
+    ('\u{301A}', "\u{27E6}"),
+    ('\u{301B}', "\u{27E7}"),
+    ('\u{302C}', "\u{309}"),
+    ('\u{302D}', "\u{325}"),
+    ('\u{3033}', "/"),
+    ('\u{3036}', "\u{20B8}"),
+    ('\u{3038}', "\u{5341}"),
+    ('\u{3039}', "\u{5344}"),
+    ('\u{303A}', "\u{5345}"),
+    ('\u{304C}', "\u{304B}\u{3099}"),
+    ('\u{304E}', "\u{304D}\u{3099}"),
+    ('\u{304F}', "\u{276C}"),
+    ('\u{3050}', "\u{276C}\u{3099}"),
+    ('\u{3052}', "\u{3051}\u{3099}"),
+    ('\u{3054}', "\u{3053}\u{3099}"),
+    ('\u{3056}', "\u{3055}\u{3099}"),
+    ('\u{3058}', "\u{3057}\u{3099}"),
+    ('\u{305A}', "\u{3059}\u{3099}"),
+    ('\u{305C}', "\u{305B}\u{3099}"),
+    ('\u{305E}', "\u{305D}\u{3099}"),
+    ('\u{3060}', "\u{305F}\u{3099}"),
+    ('\u{3062}', "\u{3061}\u{3099}"),
+    ('\u{3065}', "\u{3064}\u{3099}"),
+    ('\u{3067}', "\u{3066}\u{3099}"),
+    ('\u{3069}', "\u{3068}\u{3099}"),
+    ('\u{3070}', "\u{306F}\u{3099}"),
+    ('\u{3071}', "\u{306F}\u{30A}"),
+    ('\u{3073}', "\u{3072}\u{3099}"),
+    ('\u{3074}', "\u{3072}\u{30A}"),
+    ('\u{3076}', "\u{3075}\u{3099}"),
+    ('\u{3077}', "\u{3075}\u{30A}"),
+    ('\u{3079}', "\u{3078}\u{3099}"),
+    ('\u{307A}', "\u{3078}\u{30A}"),
+    ('\u{307C}', "\u{307B}\u{3099}"),
+    ('\u{307D}', "\u{307B}\u{30A}"),
+    ('\u{3094}', "\u{3046}\u{3099}"),
+    ('\u{309A}', "\u{30A}"),
+    ('\u{309B}', "\u{FF9E}"),
+    ('\u{309C}', "\u{FF9F}"),
+    ('\u{309E}', "\u{309D}\u{3099}"),
+    ('\u{30A0}', "="),
+    ('\u{30A4}', "\u{4EBB}"),
+    ('\u{30A8}', "\u{5DE5}"),
+    ('\u{30AB}', "\u{529B}"),
+    ('\u{30AC}', "\u{529B}\u{3099}"),
+    ('\u{30AE}', "\u{30AD}\u{3099}"),
+    ('\u{30B0}', "\u{30AF}\u{3099}"),
+    ('\u{30B2}', "\u{30B1}\u{3099}"),
+    ('\u{30B4}', "\u{30B3}\u{3099}"),
+    ('\u{30B6}', "\u{30B5}\u{3099}"),
+    ('\u{30B8}', "\u{30B7}\u{3099}"),
+    ('\u{30BA}', "\u{30B9}\u{3099}"),
+    ('\u{30BC}', "\u{30BB}\u{3099}"),
+    ('\u{30BE}', "\u{30BD}\u{3099}"),
+    ('\u{30BF}', "\u{5915}"),
+    ('\u{30C0}', "\u{5915}\u{3099}"),
+    ('\u{30C2}', "\u{30C1}\u{3099}"),
+    ('\u{30C5}', "\u{30C4}\u{3099}"),
+    ('\u{30C7}', "\u{30C6}\u{3099}"),
+    ('\u{30C8}', "\u{535C}"),
+    ('\u{30C9}', "\u{535C}\u{3099}"),
+    ('\u{30CB}', "\u{4E8C}"),
+    ('\u{30CE}', "/"),
+    ('\u{30CF}', "\u{516B}"),
+    ('\u{30D0}', "\u{516B}\u{3099}"),
+    ('\u{30D1}', "\u{516B}\u{30A}"),
+    ('\u{30D3}', "\u{30D2}\u{3099}"),
+    ('\u{30D4}', "\u{30D2}\u{30A}"),
+    ('\u{30D6}', "\u{30D5}\u{3099}"),
+    ('\u{30D7}', "\u{30D5}\u{30A}"),
+    ('\u{30D8}', "\u{3078}"),
+    ('\u{30D9}', "\u{3078}\u{3099}"),
+    ('\u{30DA}', "\u{3078}\u{30A}"),
+    ('\u{30DC}', "\u{30DB}\u{3099}"),
+    ('\u{30DD}', "\u{30DB}\u{30A}"),
+    ('\u{30ED}', "\u{53E3}"),
+    ('\u{30F4}', "\u{30A6}\u{3099}"),
+    ('\u{30F7}', "\u{30EF}\u{3099}"),
+    ('\u{30F8}', "\u{30F0}\u{3099}"),
+    ('\u{30F9}', "\u{30F1}\u{3099}"),
+    ('\u{30FA}', "\u{30F2}\u{3099}"),
+    ('\u{30FB}', "\u{B7}"),
+    ('\u{30FE}', "\u{30FD}\u{3099}"),
+    ('\u{3131}', "\u{1100}"),
+    ('\u{3132}', "\u{1100}\u{1100}"),
+    ('\u{3133}', "\u{1100}\u{1109}"),
+    ('\u{3134}', "\u{1102}"),
+    ('\u{3135}', "\u{1102}\u{110C}"),
+    ('\u{3136}', "\u{1102}\u{1112}"),
+    ('\u{3137}', "\u{1103}"),
+    ('\u{3138}', "\u{1103}\u{1103}"),
+    ('\u{3139}', "\u{1105}"),
+    ('\u{313A}', "\u{1105}\u{1100}"),
+    ('\u{313B}', "\u{1105}\u{1106}"),
+    ('\u{313C}', "\u{1105}\u{1107}"),
+    ('\u{313D}', "\u{1105}\u{1109}"),
+    ('\u{313E}', "\u{1105}\u{1110}"),
+    ('\u{313F}', "\u{1105}\u{1111}"),
+    ('\u{3140}', "\u{1105}\u{1112}"),
+    ('\u{3141}', "\u{1106}"),
+    ('\u{3142}', "\u{1107}"),
+    ('\u{3143}', "\u{1107}\u{1107}"),
+    ('\u{3144}', "\u{1107}\u{1109}"),
+    ('\u{3145}', "\u{1109}"),
+    ('\u{3146}', "\u{1109}\u{1109}"),
+    ('\u{3147}', "\u{110B}"),
+    ('\u{3148}', "\u{110C}"),
+    ('\u{3149}', "\u{110C}\u{110C}"),
+    ('\u{314A}', "\u{110E}"),
+    ('\u{314B}', "\u{110F}"),
+    ('\u{314C}', "\u{1110}"),
+    ('\u{314D}', "\u{1111}"),
+    ('\u{314E}', "\u{1112}"),
+    ('\u{314F}', "\u{1161}"),
+    ('\u{3150}', "\u{1161}\u{4E28}"),
+    ('\u{3151}', "\u{1163}"),
+    ('\u{3152}', "\u{1163}\u{4E28}"),
+    ('\u{3153}', "\u{1165}"),
+    ('\u{3154}', "\u{1165}\u{4E28}"),
+    ('\u{3155}', "\u{1167}"),
+    ('\u{3156}', "\u{1167}\u{4E28}"),
+    ('\u{3157}', "\u{1169}"),
+    ('\u{3158}', "\u{1169}\u{1161}"),
+    ('\u{3159}', "\u{1169}\u{1161}\u{4E28}"),
+    ('\u{315A}', "\u{1169}\u{4E28}"),
+    ('\u{315B}', "\u{116D}"),
+    ('\u{315C}', "\u{116E}"),
+    ('\u{315D}', "\u{116E}\u{1165}"),
+    ('\u{315E}', "\u{116E}\u{1165}\u{4E28}"),
+    ('\u{315F}', "\u{116E}\u{4E28}"),
+    ('\u{3160}', "\u{1172}"),
+    ('\u{3161}', "\u{30FC}"),
+    ('\u{3162}', "\u{30FC}\u{4E28}"),
+    ('\u{3163}', "\u{4E28}"),
+    ('\u{3164}', "\u{1160}"),
+    ('\u{3165}', "\u{1102}\u{1102}"),
+    ('\u{3166}', "\u{1102}\u{1103}"),
+    ('\u{3167}', "\u{1102}\u{1109}"),
+    ('\u{3168}', "\u{1102}\u{1140}"),
+    ('\u{3169}', "\u{1105}\u{1100}\u{1109}"),
+    ('\u{316A}', "\u{1105}\u{1103}"),
+    ('\u{316B}', "\u{1105}\u{1107}\u{1109}"),
+    ('\u{316C}', "\u{1105}\u{1140}"),
+    ('\u{316D}', "\u{1105}\u{1159}"),
+    ('\u{316E}', "\u{1106}\u{1107}"),
+    ('\u{316F}', "\u{1106}\u{1109}"),
+    ('\u{3170}', "\u{1106}\u{1140}"),
+    ('\u{3171}', "\u{1106}\u{110B}"),
+    ('\u{3172}', "\u{1107}\u{1100}"),
+    ('\u{3173}', "\u{1107}\u{1103}"),
+    ('\u{3174}', "\u{1107}\u{1109}\u{1100}"),
+    ('\u{3175}', "\u{1107}\u{1109}\u{1103}"),
+    ('\u{3176}', "\u{1107}\u{110C}"),
+    ('\u{3177}', "\u{1107}\u{1110}"),
+    ('\u{3178}', "\u{1107}\u{110B}"),
+    ('\u{3179}', "\u{1107}\u{1107}\u{110B}"),
+    ('\u{317A}', "\u{1109}\u{1100}"),
+    ('\u{317B}', "\u{1109}\u{1102}"),
+    ('\u{317C}', "\u{1109}\u{1103}"),
+    ('\u{317D}', "\u{1109}\u{1107}"),
+    ('\u{317E}', "\u{1109}\u{110C}"),
+    ('\u{317F}', "\u{1140}"),
+    ('\u{3180}', "\u{110B}\u{110B}"),
+    ('\u{3181}', "\u{114C}"),
+    ('\u{3182}', "\u{110B}\u{1109}"),
+    ('\u{3183}', "\u{110B}\u{1140}"),
+    ('\u{3184}', "\u{1111}\u{110B}"),
+    ('\u{3185}', "\u{1112}\u{1112}"),
+    ('\u{3186}', "\u{1159}"),
+    ('\u{3187}', "\u{116D}\u{1163}"),
+    ('\u{3188}', "\u{116D}\u{1163}\u{4E28}"),
+    ('\u{3189}', "\u{116D}\u{4E28}"),
+    ('\u{318A}', "\u{1172}\u{1167}"),
+    ('\u{318B}', "\u{1172}\u{1167}\u{4E28}"),
+    ('\u{318C}', "\u{1172}\u{4E28}"),
+    ('\u{318D}', "\u{119E}"),
+    ('\u{318E}', "\u{119E}\u{4E28}"),
+    ('\u{31D0}', "\u{30FC}"),
+    ('\u{31D1}', "\u{4E28}"),
+    ('\u{31D3}', "/"),
+    ('\u{31D4}', "\u{5C}"),
+    ('\u{31D6}', "\u{4E5B}"),
+    ('\u{31DA}', "\u{4E85}"),
+    ('\u{31DB}', "\u{276C}"),
+    ('\u{31DF}', "\u{4E5A}"),
+    ('\u{31E0}', "\u{4E59}"),
+    ('\u{3200}', "(\u{1100})"),
+    ('\u{3201}', "(\u{1102})"),
+    ('\u{3202}', "(\u{1103})"),
+    ('\u{3203}', "(\u{1105})"),
+    ('\u{3204}', "(\u{1106})"),
+    ('\u{3205}', "(\u{1107})"),
+    ('\u{3206}', "(\u{1109})"),
+    ('\u{3207}', "(\u{110B})"),
+    ('\u{3208}', "(\u{110C})"),
+    ('\u{3209}', "(\u{110E})"),
+    ('\u{320A}', "(\u{110F})"),
+    ('\u{320B}', "(\u{1110})"),
+    ('\u{320C}', "(\u{1111})"),
+    ('\u{320D}', "(\u{1112})"),
+    ('\u{320E}', "(\u{1100}\u{1161})"),
+    ('\u{320F}', "(\u{1102}\u{1161})"),
+    ('\u{3210}', "(\u{1103}\u{1161})"),
+    ('\u{3211}', "(\u{1105}\u{1161})"),
+    ('\u{3212}', "(\u{1106}\u{1161})"),
+    ('\u{3213}', "(\u{1107}\u{1161})"),
+    ('\u{3214}', "(\u{1109}\u{1161})"),
+    ('\u{3215}', "(\u{110B}\u{1161})"),
+    ('\u{3216}', "(\u{110C}\u{1161})"),
+    ('\u{3217}', "(\u{110E}\u{1161})"),
+    ('\u{3218}', "(\u{110F}\u{1161})"),
+    ('\u{3219}', "(\u{1110}\u{1161})"),
+    ('\u{321A}', "(\u{1111}\u{1161})"),
+    ('\u{321B}', "(\u{1112}\u{1161})"),
+    ('\u{321C}', "(\u{110C}\u{116E})"),
+    ('\u{321D}', "(\u{110B}\u{1169}\u{110C}\u{1165}\u{11AB})"),
+    ('\u{321E}', "(\u{110B}\u{1169}\u{1112}\u{116E})"),
+    ('\u{3220}', "(\u{30FC})"),
+    ('\u{3221}', "(\u{4E8C})"),
+    ('\u{3222}', "(\u{4E09})"),
+    ('\u{3223}', "(\u{56DB})"),
+    ('\u{3224}', "(\u{4E94})"),
+    ('\u{3225}', "(\u{516D})"),
+    ('\u{3226}', "(\u{4E03})"),
+    ('\u{3227}', "(\u{516B})"),
+    ('\u{3228}', "(\u{4E5D})"),
+    ('\u{3229}', "(\u{5341})"),
+    ('\u{322A}', "(\u{6708})"),
+    ('\u{322B}', "(\u{706B})"),
+    ('\u{322C}', "(\u{6C34})"),
+    ('\u{322D}', "(\u{6728})"),
+    ('\u{322E}', "(\u{91D1})"),
+    ('\u{322F}', "(\u{571F})"),
+    ('\u{3230}', "(\u{65E5})"),
+    ('\u{3231}', "(\u{682A})"),
+    ('\u{3232}', "(\u{6709})"),
+    ('\u{3233}', "(\u{793E})"),
+    ('\u{3234}', "(\u{540D})"),
+    ('\u{3235}', "(\u{7279})"),
+    ('\u{3236}', "(\u{8CA1})"),
+    ('\u{3237}', "(\u{795D})"),
+    ('\u{3238}', "(\u{52B4})"),
+    ('\u{3239}', "(\u{4EE3})"),
+    ('\u{323A}', "(\u{547C})"),
+    ('\u{323B}', "(\u{5B66})"),
+    ('\u{323C}', "(\u{76E3})"),
+    ('\u{323D}', "(\u{4F01})"),
+    ('\u{323E}', "(\u{8CC7})"),
+    ('\u{323F}', "(\u{5354})"),
+    ('\u{3240}', "(\u{796D})"),
+    ('\u{3241}', "(\u{4F11})"),
+    ('\u{3242}', "(\u{81EA})"),
+    ('\u{3243}', "(\u{81F3})"),
+    ('\u{32C0}', "l\u{6708}"),
+    ('\u{32C1}', "2\u{6708}"),
+    ('\u{32C2}', "3\u{6708}"),
+    ('\u{32C3}', "4\u{6708}"),
+    ('\u{32C4}', "5\u{6708}"),
+    ('\u{32C5}', "6\u{6708}"),
+    ('\u{32C6}', "7\u{6708}"),
+    ('\u{32C7}', "8\u{6708}"),
+    ('\u{32C8}', "9\u{6708}"),
+    ('\u{32C9}', "lO\u{6708}"),
+    ('\u{32CA}', "ll\u{6708}"),
+    ('\u{32CB}', "l2\u{6708}"),
+    ('\u{3358}', "O\u{70B9}"),
+    ('\u{3359}', "l\u{70B9}"),
+    ('\u{335A}', "2\u{70B9}"),
+    ('\u{335B}', "3\u{70B9}"),
+    ('\u{335C}', "4\u{70B9}"),
+    ('\u{335D}', "5\u{70B9}"),
+    ('\u{335E}', "6\u{70B9}"),
+    ('\u{335F}', "7\u{70B9}"),
+    ('\u{3360}', "8\u{70B9}"),
+    ('\u{3361}', "9\u{70B9}"),
+    ('\u{3362}', "lO\u{70B9}"),
+    ('\u{3363}', "ll\u{70B9}"),
+    ('\u{3364}', "l2\u{70B9}"),
+    ('\u{3365}', "l3\u{70B9}"),
+    ('\u{3366}', "l4\u{70B9}"),
+    ('\u{3367}', "l5\u{70B9}"),
+    ('\u{3368}', "l6\u{70B9}"),
+    ('\u{3369}', "l7\u{70B9}"),
+    ('\u{336A}', "l8\u{70B9}"),
+    ('\u{336B}', "l9\u{70B9}"),
+    ('\u{336C}', "2O\u{70B9}"),
+    ('\u{336D}', "2l\u{70B9}"),
+    ('\u{336E}', "22\u{70B9}"),
+    ('\u{336F}', "23\u{70B9}"),
+    ('\u{3370}', "24\u{70B9}"),
+    ('\u{33E0}', "l\u{65E5}"),
+    ('\u{33E1}', "2\u{65E5}"),
+    ('\u{33E2}', "3\u{65E5}"),
+    ('\u{33E3}', "4\u{65E5}"),
+    ('\u{33E4}', "5\u{65E5}"),
+    ('\u{33E5}', "6\u{65E5}"),
+    ('\u{33E6}', "7\u{65E5}"),
+    ('\u{33E7}', "8\u{65E5}"),
+    ('\u{33E8}', "9\u{65E5}"),
+    ('\u{33E9}', "lO\u{65E5}"),
+    ('\u{33EA}', "ll\u{65E5}"),
+    ('\u{33EB}', "l2\u{65E5}"),
+    ('\u{33EC}', "l3\u{65E5}"),
+    ('\u{33ED}', "l4\u{65E5}"),
+    ('\u{33EE}', "l5\u{65E5}"),
+    ('\u{33EF}', "l6\u{65E5}"),
+    ('\u{33F0}', "l7\u{65E5}"),
+    ('\u{33F1}', "l8\u{65E5}"),
+    ('\u{33F2}', "l9\u{65E5}"),
+    ('\u{33F3}', "2O\u{65E5}"),
+    ('\u{33F4}', "2l\u{65E5}"),
+    ('\u{33F5}', "22\u{65E5}"),
+    ('\u{33F6}', "23\u{65E5}"),
+    ('\u{33F7}', "24\u{65E5}"),
+    ('\u{33F8}', "25\u{65E5}"),
+    ('\u{33F9}', "26\u{65E5}"),
+    ('\u{33FA}', "27\u{65E5}"),
+    ('\u{33FB}', "28\u{65E5}"),
+    ('\u{33FC}', "29\u{65E5}"),
+    ('\u{33FD}', "3O\u{65E5}"),
+    ('\u{33FE}', "3l\u{65E5}"),
+    ('\u{39B3}', "\u{363D}"),
+    ('\u{439B}', "\u{3588}"),
+    ('\u{4420}', "\u{3B3B}"),
+    ('\u{4E00}', "\u{30FC}"),
+    ('\u{4E36}', "\u{5C}"),
+    ('\u{4E3F}', "/"),
+    ('\u{5002}', "\u{4F75}"),
+    ('\u{503C}', "\u{5024}"),
+    ('\u{555F}', "\u{5553}"),
+    ('\u{56D7}', "\u{53E3}"),
+    ('\u{586B}', "\u{5861}"),
+    ('\u{58EB}', "\u{571F}"),
+    ('\u{58FF}', "\u{58AB}"),
+    ('\u{5B00}', "\u{5AAF}"),
+    ('\u{5E32}', "\u{5E21}"),
+    ('\u{5E50}', "\u{3B3A}"),
+    ('\u{6238}', "\u{6236}"),
+    ('\u{6409}', "\u{3A41}"),
+    ('\u{6663}', "\u{403F}"),
+    ('\u{6669}', "\u{665A}"),
+    ('\u{66F6}', "\u{3ADA}"),
+    ('\u{6726}', "\u{4443}"),
+    ('\u{67FF}', "\u{676E}"),
+    ('\u{69E9}', "\u{3BA3}"),
+    ('\u{6A27}', "\u{699D}"),
+    ('\u{6F59}', "\u{6E88}"),
+    ('\u{784F}', "\u{7814}"),
+    ('\u{7D76}', "\u{7D55}"),
+    ('\u{80A6}', "\u{670C}"),
+    ('\u{80CA}', "\u{6710}"),
+    ('\u{80D0}', "\u{670F}"),
+    ('\u{80F6}', "\u{3B35}"),
+    ('\u{8101}', "\u{6713}"),
+    ('\u{8127}', "\u{6718}"),
+    ('\u{8141}', "\u{80FC}"),
+    ('\u{81A7}', "\u{6723}"),
+    ('\u{853F}', "\u{848D}"),
+    ('\u{8641}', "\u{8637}"),
+    ('\u{8A1E}', "\u{46B6}"),
+    ('\u{8A7D}', "\u{8A2E}"),
+    ('\u{8B8F}', "\u{8B86}"),
+    ('\u{8C63}', "\u{8C5C}"),
+    ('\u{8D86}', "\u{8D7F}"),
+    ('\u{8DFA}', "\u{8DE5}"),
+    ('\u{8E9B}', "\u{8E97}"),
+    ('\u{8F27}', "\u{8EFF}"),
+    ('\u{90DE}', "\u{90CE}"),
+    ('\u{93AE}', "\u{93AD}"),
+    ('\u{96B8}', "\u{96B7}"),
+    ('\u{9E43}', "\u{9E42}"),
+    ('\u{9ED2}', "\u{9ED1}"),
+    ('\u{9FC3}', "\u{4039}"),
+    ('\u{A494}', "\u{A2CD}"),
+    ('\u{A49C}', "\u{A0C0}"),
+    ('\u{A49E}', "\u{A04A}"),
+    ('\u{A4A7}', "\u{A458}"),
+    ('\u{A4A8}', "\u{A132}"),
+    ('\u{A4AC}', "\u{A050}"),
+    ('\u{A4B0}', "\u{A3C2}"),
+    ('\u{A4BA}', "\u{A3BF}"),
+    ('\u{A4BE}', "\u{A2B1}"),
+    ('\u{A4BF}', "\u{A259}"),
+    ('\u{A4C0}', "\u{A3AB}"),
+    ('\u{A4C2}', "\u{A3B5}"),
+    ('\u{A4D0}', "B"),
+    ('\u{A4D1}', "P"),
+    ('\u{A4D2}', "d"),
+    ('\u{A4D3}', "D"),
+    ('\u{A4D4}', "T"),
+    ('\u{A4D6}', "G"),
+    ('\u{A4D7}', "K"),
+    ('\u{A4D9}', "J"),
+    ('\u{A4DA}', "C"),
+    ('\u{A4DB}', "\u{186}"),
+    ('\u{A4DC}', "Z"),
+    ('\u{A4DD}', "F"),
+    ('\u{A4DE}', "\u{2132}"),
+    ('\u{A4DF}', "M"),
+    ('\u{A4E0}', "N"),
+    ('\u{A4E1}', "L"),
+    ('\u{A4E2}', "S"),
+    ('\u{A4E3}', "R"),
+    ('\u{A4E5}', "\u{245}"),
+    ('\u{A4E6}', "V"),
+    ('\u{A4E7}', "H"),
+    ('\u{A4EA}', "W"),
+    ('\u{A4EB}', "X"),
+    ('\u{A4EC}', "Y"),
+    ('\u{A4ED}', "\u{1660}"),
+    ('\u{A4EE}', "A"),
+    ('\u{A4EF}', "\u{2C6F}"),
+    ('\u{A4F0}', "E"),
+    ('\u{A4F1}', "\u{18E}"),
+    ('\u{A4F2}', "l"),
+    ('\u{A4F3}', "O"),
+    ('\u{A4F4}', "U"),
+    ('\u{A4F5}', "\u{548}"),
+    ('\u{A4F7}', "\u{15E1}"),
+    ('\u{A4F8}', "."),
+    ('\u{A4F9}', ","),
+    ('\u{A4FA}', ".."),
+    ('\u{A4FB}', ".,"),
+    ('\u{A4FD}', ":"),
+    ('\u{A4FE}', "-."),
+    ('\u{A4FF}', "="),
+    ('\u{A60E}', "."),
+    ('\u{A644}', "2"),
+    ('\u{A645}', "\u{1A8}"),
+    ('\u{A647}', "i"),
+    ('\u{A64D}', "\u{3C9}"),
+    ('\u{A650}', "\u{42A}l"),
+    ('\u{A651}', "\u{2C9}bi"),
+    ('\u{A668}', "\u{298}"),
+    ('\u{A66F}', "\u{20E9}"),
+    ('\u{A67C}', "\u{306}"),
+    ('\u{A67E}', "\u{2C7}"),
+    ('\u{A695}', "h\u{314}"),
+    ('\u{A698}', "OO"),
+    ('\u{A699}', "oo"),
+    ('\u{A69A}', "\u{102A8}"),
+    ('\u{A6A1}', "\u{418}"),
+    ('\u{A6B0}', "\u{16B9}"),
+    ('\u{A6B1}', "\u{2C75}"),
+    ('\u{A6CD}', "\u{2A1}"),
+    ('\u{A6CE}', "\u{245}"),
+    ('\u{A6DB}', "\u{3A0}"),
+    ('\u{A6DF}', "V"),
+    ('\u{A6EB}', "?"),
+    ('\u{A6EF}', "2"),
+    ('\u{A6F0}', "\u{302}"),
+    ('\u{A6F1}', "\u{304}"),
+    ('\u{A6F4}', "\u{A6F3}\u{A6F3}"),
+    ('\u{A714}', "\u{2EB}"),
+    ('\u{A716}', "\u{2EA}"),
+    ('\u{A728}', "T3"),
+    ('\u{A729}', "t\u{21D}"),
+    ('\u{A731}', "s"),
+    ('\u{A732}', "AA"),
+    ('\u{A733}', "aa"),
+    ('\u{A734}', "AO"),
+    ('\u{A735}', "ao"),
+    ('\u{A736}', "AU"),
+    ('\u{A737}', "au"),
+    ('\u{A738}', "AV"),
+    ('\u{A739}', "av"),
+    ('\u{A73A}', "AV"),
+    ('\u{A73B}', "av"),
+    ('\u{A73C}', "AY"),
+    ('\u{A73D}', "ay"),
+    ('\u{A740}', "K\u{335}"),
+    ('\u{A74A}', "O\u{335}"),
+    ('\u{A74B}', "o\u{335}"),
+    ('\u{A74E}', "OO"),
+    ('\u{A74F}', "oo"),
+    ('\u{A75A}', "2"),
+    ('\u{A761}', "w\u{326}"),
+    ('\u{A76A}', "3"),
+    ('\u{A76B}', "\u{21D}"),
+    ('\u{A76E}', "9"),
+    ('\u{A777}', "tf"),
+    ('\u{A778}', "&"),
+    ('\u{A77A}', "\u{A779}"),
+    ('\u{A789}', ":"),
+    ('\u{A78C}', "\u{27}"),
+    ('\u{A78F}', "\u{B7}"),
+    ('\u{A795}', "\u{A727}"),
+    ('\u{A798}', "F"),
+    ('\u{A799}', "f"),
+    ('\u{A79A}', "\u{10412}"),
+    ('\u{A79B}', "\u{1043A}"),
+    ('\u{A79D}', "\u{29A}"),
+    ('\u{A79E}', "\u{A4E4}"),
+    ('\u{A79F}', "u"),
+    ('\u{A7AB}', "3"),
+    ('\u{A7B1}', "\u{A4D5}"),
+    ('\u{A7B2}', "J"),
+    ('\u{A7B3}', "X"),
+    ('\u{A7B4}', "B"),
+    ('\u{A7B5}', "\u{DF}"),
+    ('\u{A7B6}', "\u{A64C}"),
+    ('\u{A7B7}', "\u{3C9}"),
+    ('\u{A7F7}', "\u{30FC}"),
+    ('\u{A830}', "\u{964}"),
+    ('\u{A960}', "\u{1103}\u{1106}"),
+    ('\u{A961}', "\u{1103}\u{1107}"),
+    ('\u{A962}', "\u{1103}\u{1109}"),
+    ('\u{A963}', "\u{1103}\u{110C}"),
+    ('\u{A964}', "\u{1105}\u{1100}"),
+    ('\u{A965}', "\u{1105}\u{1100}\u{1100}"),
+    ('\u{A966}', "\u{1105}\u{1103}"),
+    ('\u{A967}', "\u{1105}\u{1103}\u{1103}"),
+    ('\u{A968}', "\u{1105}\u{1106}"),
+    ('\u{A969}', "\u{1105}\u{1107}"),
+    ('\u{A96A}', "\u{1105}\u{1107}\u{1107}"),
+    ('\u{A96B}', "\u{1105}\u{1107}\u{110B}"),
+    ('\u{A96C}', "\u{1105}\u{1109}"),
+    ('\u{A96D}', "\u{1105}\u{110C}"),
+    ('\u{A96E}', "\u{1105}\u{110F}"),
+    ('\u{A96F}', "\u{1106}\u{1100}"),
+    ('\u{A970}', "\u{1106}\u{1103}"),
+    ('\u{A971}', "\u{1106}\u{1109}"),
+    ('\u{A972}', "\u{1107}\u{1109}\u{1110}"),
+    ('\u{A973}', "\u{1107}\u{110F}"),
+    ('\u{A974}', "\u{1107}\u{1112}"),
+    ('\u{A975}', "\u{1109}\u{1109}\u{1107}"),
+    ('\u{A976}', "\u{110B}\u{1105}"),
+    ('\u{A977}', "\u{110B}\u{1112}"),
+    ('\u{A978}', "\u{110C}\u{110C}\u{1112}"),
+    ('\u{A979}', "\u{1110}\u{1110}"),
+    ('\u{A97A}', "\u{1111}\u{1112}"),
+    ('\u{A97B}', "\u{1112}\u{1109}"),
+    ('\u{A97C}', "\u{1159}\u{1159}"),
+    ('\u{A992}', "\u{2C3F}"),
+    ('\u{A9A3}', "\u{A99D}"),
+    ('\u{A9C6}', "\u{A9D0}"),
+    ('\u{A9CF}', "\u{662}"),
+    ('\u{AA53}', "\u{AA01}"),
+    ('\u{AA56}', "\u{AA23}"),
+    ('\u{AB32}', "e"),
+    ('\u{AB35}', "f"),
+    ('\u{AB3D}', "o"),
+    ('\u{AB3E}', "o\u{338}"),
+    ('\u{AB3F}', "\u{254}\u{338}"),
+    ('\u{AB41}', "\u{1DD}o\u{338}"),
+    ('\u{AB42}', "\u{1DD}o\u{335}"),
+    ('\u{AB47}', "r"),
+    ('\u{AB48}', "r"),
+    ('\u{AB4D}', "\u{283}"),
+    ('\u{AB4E}', "u"),
+    ('\u{AB52}', "u"),
+    ('\u{AB53}', "\u{3C7}"),
+    ('\u{AB55}', "\u{3C7}"),
+    ('\u{AB5A}', "y"),
+    ('\u{AB60}', "\u{459}"),
+    ('\u{AB62}', "\u{254}e"),
+    ('\u{AB63}', "uo"),
+    ('\u{AB70}', "\u{1D05}"),
+    ('\u{AB71}', "\u{280}"),
+    ('\u{AB72}', "\u{1D1B}"),
+    ('\u{AB74}', "o\u{31B}"),
+    ('\u{AB75}', "i"),
+    ('\u{AB7A}', "\u{1D00}"),
+    ('\u{AB7B}', "\u{1D0A}"),
+    ('\u{AB7C}', "\u{1D07}"),
+    ('\u{AB7E}', "\u{242}"),
+    ('\u{AB80}', "\u{2C76}"),
+    ('\u{AB81}', "r"),
+    ('\u{AB83}', "w"),
+    ('\u{AB87}', "\u{28D}"),
+    ('\u{AB8B}', "\u{29C}"),
+    ('\u{AB8E}', "o\u{335}"),
+    ('\u{AB90}', "\u{262}"),
+    ('\u{AB93}', "z"),
+    ('\u{AB9B}', "\u{A793}"),
+    ('\u{AB9C}', "u\u{335}"),
+    ('\u{AB9F}', "\u{185}"),
+    ('\u{ABA2}', "\u{280}"),
+    ('\u{ABA9}', "v"),
+    ('\u{ABAA}', "s"),
+    ('\u{ABAE}', "\u{29F}"),
+    ('\u{ABAF}', "c"),
+    ('\u{ABB2}', "\u{1D18}"),
+    ('\u{ABB6}', "\u{138}"),
+    ('\u{ABBB}', "o\u{335}"),
+    ('\u{D7B0}', "\u{1169}\u{1167}"),
+    ('\u{D7B1}', "\u{1169}\u{1169}\u{4E28}"),
+    ('\u{D7B2}', "\u{116D}\u{1161}"),
+    ('\u{D7B3}', "\u{116D}\u{1161}\u{4E28}"),
+    ('\u{D7B4}', "\u{116D}\u{1165}"),
+    ('\u{D7B5}', "\u{116E}\u{1167}"),
+    ('\u{D7B6}', "\u{116E}\u{4E28}\u{4E28}"),
+    ('\u{D7B7}', "\u{1172}\u{1161}\u{4E28}"),
+    ('\u{D7B8}', "\u{1172}\u{1169}"),
+    ('\u{D7B9}', "\u{30FC}\u{1161}"),
+    ('\u{D7BA}', "\u{30FC}\u{1165}"),
+    ('\u{D7BB}', "\u{30FC}\u{1165}\u{4E28}"),
+    ('\u{D7BC}', "\u{30FC}\u{1169}"),
+    ('\u{D7BD}', "\u{4E28}\u{1163}\u{1169}"),
+    ('\u{D7BE}', "\u{4E28}\u{1163}\u{4E28}"),
+    ('\u{D7BF}', "\u{4E28}\u{1167}"),
+    ('\u{D7C0}', "\u{4E28}\u{1167}\u{4E28}"),
+    ('\u{D7C1}', "\u{4E28}\u{1169}\u{4E28}"),
+    ('\u{D7C2}', "\u{4E28}\u{116D}"),
+    ('\u{D7C3}', "\u{4E28}\u{1172}"),
+    ('\u{D7C4}', "\u{4E28}\u{4E28}"),
+    ('\u{D7C5}', "\u{119E}\u{1161}"),
+    ('\u{D7C6}', "\u{119E}\u{1165}\u{4E28}"),
+    ('\u{D7CB}', "\u{1102}\u{1105}"),
+    ('\u{D7CC}', "\u{1102}\u{110E}"),
+    ('\u{D7CD}', "\u{1103}\u{1103}"),
+    ('\u{D7CE}', "\u{1103}\u{1103}\u{1107}"),
+    ('\u{D7CF}', "\u{1103}\u{1107}"),
+    ('\u{D7D0}', "\u{1103}\u{1109}"),
+    ('\u{D7D1}', "\u{1103}\u{1109}\u{1100}"),
+    ('\u{D7D2}', "\u{1103}\u{110C}"),
+    ('\u{D7D3}', "\u{1103}\u{110E}"),
+    ('\u{D7D4}', "\u{1103}\u{1110}"),
+    ('\u{D7D5}', "\u{1105}\u{1100}\u{1100}"),
+    ('\u{D7D6}', "\u{1105}\u{1100}\u{1112}"),
+    ('\u{D7D7}', "\u{1105}\u{1105}\u{110F}"),
+    ('\u{D7D8}', "\u{1105}\u{1106}\u{1112}"),
+    ('\u{D7D9}', "\u{1105}\u{1107}\u{1103}"),
+    ('\u{D7DA}', "\u{1105}\u{1107}\u{1111}"),
+    ('\u{D7DB}', "\u{1105}\u{114C}"),
+    ('\u{D7DC}', "\u{1105}\u{1159}\u{1112}"),
+    ('\u{D7DD}', "\u{1105}\u{110B}"),
+    ('\u{D7DE}', "\u{1106}\u{1102}"),
+    ('\u{D7DF}', "\u{1106}\u{1102}\u{1102}"),
+    ('\u{D7E0}', "\u{1106}\u{1106}"),
+    ('\u{D7E1}', "\u{1106}\u{1107}\u{1109}"),
+    ('\u{D7E2}', "\u{1106}\u{110C}"),
+    ('\u{D7E3}', "\u{1107}\u{1103}"),
+    ('\u{D7E4}', "\u{1107}\u{1105}\u{1111}"),
+    ('\u{D7E5}', "\u{1107}\u{1106}"),
+    ('\u{D7E6}', "\u{1107}\u{1107}"),
+    ('\u{D7E7}', "\u{1107}\u{1109}\u{1103}"),
+    ('\u{D7E8}', "\u{1107}\u{110C}"),
+    ('\u{D7E9}', "\u{1107}\u{110E}"),
+    ('\u{D7EA}', "\u{1109}\u{1106}"),
+    ('\u{D7EB}', "\u{1109}\u{1107}\u{110B}"),
+    ('\u{D7EC}', "\u{1109}\u{1109}\u{1100}"),
+    ('\u{D7ED}', "\u{1109}\u{1109}\u{1103}"),
+    ('\u{D7EE}', "\u{1109}\u{1140}"),
+    ('\u{D7EF}', "\u{1109}\u{110C}"),
+    ('\u{D7F0}', "\u{1109}\u{110E}"),
+    ('\u{D7F1}', "\u{1109}\u{1110}"),
+    ('\u{D7F2}', "\u{1105}\u{1112}"),
+    ('\u{D7F3}', "\u{1140}\u{1107}"),
+    ('\u{D7F4}', "\u{1140}\u{1107}\u{110B}"),
+    ('\u{D7F5}', "\u{114C}\u{1106}"),
+    ('\u{D7F6}', "\u{114C}\u{1112}"),
+    ('\u{D7F7}', "\u{110C}\u{1107}"),
+    ('\u{D7F8}', "\u{110C}\u{1107}\u{1107}"),
+    ('\u{D7F9}', "\u{110C}\u{110C}"),
+    ('\u{D7FA}', "\u{1111}\u{1109}"),
+    ('\u{D7FB}', "\u{1111}\u{1110}"),
+    ('\u{F900}', "\u{8C48}"),
+    ('\u{F901}', "\u{66F4}"),
+    ('\u{F902}', "\u{8ECA}"),
+    ('\u{F903}', "\u{8CC8}"),
+    ('\u{F904}', "\u{6ED1}"),
+    ('\u{F905}', "\u{4E32}"),
+    ('\u{F906}', "\u{53E5}"),
+    ('\u{F907}', "\u{9F9C}"),
+    ('\u{F908}', "\u{9F9C}"),
+    ('\u{F909}', "\u{5951}"),
+    ('\u{F90A}', "\u{91D1}"),
+    ('\u{F90B}', "\u{5587}"),
+    ('\u{F90C}', "\u{5948}"),
+    ('\u{F90D}', "\u{61F6}"),
+    ('\u{F90E}', "\u{7669}"),
+    ('\u{F90F}', "\u{7F85}"),
+    ('\u{F910}', "\u{863F}"),
+    ('\u{F911}', "\u{87BA}"),
+    ('\u{F912}', "\u{88F8}"),
+    ('\u{F913}', "\u{908F}"),
+    ('\u{F914}', "\u{6A02}"),
+    ('\u{F915}', "\u{6D1B}"),
+    ('\u{F916}', "\u{70D9}"),
+    ('\u{F917}', "\u{73DE}"),
+    ('\u{F918}', "\u{843D}"),
+    ('\u{F919}', "\u{916A}"),
+    ('\u{F91A}', "\u{99F1}"),
+    ('\u{F91B}', "\u{4E82}"),
+    ('\u{F91C}', "\u{5375}"),
+    ('\u{F91D}', "\u{6B04}"),
+    ('\u{F91E}', "\u{721B}"),
+    ('\u{F91F}', "\u{862D}"),
+    ('\u{F920}', "\u{9E1E}"),
+    ('\u{F921}', "\u{5D50}"),
+    ('\u{F922}', "\u{6FEB}"),
+    ('\u{F923}', "\u{85CD}"),
+    ('\u{F924}', "\u{8964}"),
+    ('\u{F925}', "\u{62C9}"),
+    ('\u{F926}', "\u{81D8}"),
+    ('\u{F927}', "\u{881F}"),
+    ('\u{F928}', "\u{5ECA}"),
+    ('\u{F929}', "\u{6717}"),
+    ('\u{F92A}', "\u{6D6A}"),
+    ('\u{F92B}', "\u{72FC}"),
+    ('\u{F92C}', "\u{90CE}"),
+    ('\u{F92D}', "\u{4F86}"),
+    ('\u{F92E}', "\u{51B7}"),
+    ('\u{F92F}', "\u{52DE}"),
+    ('\u{F930}', "\u{64C4}"),
+    ('\u{F931}', "\u{6AD3}"),
+    ('\u{F932}', "\u{7210}"),
+    ('\u{F933}', "\u{76E7}"),
+    ('\u{F934}', "\u{8001}"),
+    ('\u{F935}', "\u{8606}"),
+    ('\u{F936}', "\u{865C}"),
+    ('\u{F937}', "\u{8DEF}"),
+    ('\u{F938}', "\u{9732}"),
+    ('\u{F939}', "\u{9B6F}"),
+    ('\u{F93A}', "\u{9DFA}"),
+    ('\u{F93B}', "\u{788C}"),
+    ('\u{F93C}', "\u{797F}"),
+    ('\u{F93D}', "\u{7DA0}"),
+    ('\u{F93E}', "\u{83C9}"),
+    ('\u{F93F}', "\u{9304}"),
+    ('\u{F940}', "\u{9E7F}"),
+    ('\u{F941}', "\u{8AD6}"),
+    ('\u{F942}', "\u{58DF}"),
+    ('\u{F943}', "\u{5F04}"),
+    ('\u{F944}', "\u{7C60}"),
+    ('\u{F945}', "\u{807E}"),
+    ('\u{F946}', "\u{7262}"),
+    ('\u{F947}', "\u{78CA}"),
+    ('\u{F948}', "\u{8CC2}"),
+    ('\u{F949}', "\u{96F7}"),
+    ('\u{F94A}', "\u{58D8}"),
+    ('\u{F94B}', "\u{5C62}"),
+    ('\u{F94C}', "\u{6A13}"),
+    ('\u{F94D}', "\u{6DDA}"),
+    ('\u{F94E}', "\u{6F0F}"),
+    ('\u{F94F}', "\u{7D2F}"),
+    ('\u{F950}', "\u{7E37}"),
+    ('\u{F951}', "\u{964B}"),
+    ('\u{F952}', "\u{52D2}"),
+    ('\u{F953}', "\u{808B}"),
+    ('\u{F954}', "\u{51DC}"),
+    ('\u{F955}', "\u{51CC}"),
+    ('\u{F956}', "\u{7A1C}"),
+    ('\u{F957}', "\u{7DBE}"),
+    ('\u{F958}', "\u{83F1}"),
+    ('\u{F959}', "\u{9675}"),
+    ('\u{F95A}', "\u{8B80}"),
+    ('\u{F95B}', "\u{62CF}"),
+    ('\u{F95C}', "\u{6A02}"),
+    ('\u{F95D}', "\u{8AFE}"),
+    ('\u{F95E}', "\u{4E39}"),
+    ('\u{F95F}', "\u{5BE7}"),
+    ('\u{F960}', "\u{6012}"),
+    ('\u{F961}', "\u{7387}"),
+    ('\u{F962}', "\u{7570}"),
+    ('\u{F963}', "\u{5317}"),
+    ('\u{F964}', "\u{78FB}"),
+    ('\u{F965}', "\u{4FBF}"),
+    ('\u{F966}', "\u{5FA9}"),
+    ('\u{F967}', "\u{4E0D}"),
+    ('\u{F968}', "\u{6CCC}"),
+    ('\u{F969}', "\u{6578}"),
+    ('\u{F96A}', "\u{7D22}"),
+    ('\u{F96B}', "\u{53C3}"),
+    ('\u{F96C}', "\u{585E}"),
+    ('\u{F96D}', "\u{7701}"),
+    ('\u{F96E}', "\u{8449}"),
+    ('\u{F96F}', "\u{8AAA}"),
+    ('\u{F970}', "\u{6BBA}"),
+    ('\u{F971}', "\u{8FB0}"),
+    ('\u{F972}', "\u{6C88}"),
+    ('\u{F973}', "\u{62FE}"),
+    ('\u{F974}', "\u{82E5}"),
+    ('\u{F975}', "\u{63A0}"),
+    ('\u{F976}', "\u{7565}"),
+    ('\u{F977}', "\u{4EAE}"),
+    ('\u{F978}', "\u{5169}"),
+    ('\u{F979}', "\u{51C9}"),
+    ('\u{F97A}', "\u{6881}"),
+    ('\u{F97B}', "\u{7CE7}"),
+    ('\u{F97C}', "\u{826F}"),
+    ('\u{F97D}', "\u{8AD2}"),
+    ('\u{F97E}', "\u{91CF}"),
+    ('\u{F97F}', "\u{52F5}"),
+    ('\u{F980}', "\u{5442}"),
+    ('\u{F981}', "\u{5973}"),
+    ('\u{F982}', "\u{5EEC}"),
+    ('\u{F983}', "\u{65C5}"),
+    ('\u{F984}', "\u{6FFE}"),
+    ('\u{F985}', "\u{792A}"),
+    ('\u{F986}', "\u{95AD}"),
+    ('\u{F987}', "\u{9A6A}"),
+    ('\u{F988}', "\u{9E97}"),
+    ('\u{F989}', "\u{9ECE}"),
+    ('\u{F98A}', "\u{529B}"),
+    ('\u{F98B}', "\u{66C6}"),
+    ('\u{F98C}', "\u{6B77}"),
+    ('\u{F98D}', "\u{8F62}"),
+    ('\u{F98E}', "\u{5E74}"),
+    ('\u{F98F}', "\u{6190}"),
+    ('\u{F990}', "\u{6200}"),
+    ('\u{F991}', "\u{649A}"),
+    ('\u{F992}', "\u{6F23}"),
+    ('\u{F993}', "\u{7149}"),
+    ('\u{F994}', "\u{7489}"),
+    ('\u{F995}', "\u{79CA}"),
+    ('\u{F996}', "\u{7DF4}"),
+    ('\u{F997}', "\u{806F}"),
+    ('\u{F998}', "\u{8F26}"),
+    ('\u{F999}', "\u{84EE}"),
+    ('\u{F99A}', "\u{9023}"),
+    ('\u{F99B}', "\u{934A}"),
+    ('\u{F99C}', "\u{5217}"),
+    ('\u{F99D}', "\u{52A3}"),
+    ('\u{F99E}', "\u{54BD}"),
+    ('\u{F99F}', "\u{70C8}"),
+    ('\u{F9A0}', "\u{88C2}"),
+    ('\u{F9A1}', "\u{8AAA}"),
+    ('\u{F9A2}', "\u{5EC9}"),
+    ('\u{F9A3}', "\u{5FF5}"),
+    ('\u{F9A4}', "\u{637B}"),
+    ('\u{F9A5}', "\u{6BAE}"),
+    ('\u{F9A6}', "\u{7C3E}"),
+    ('\u{F9A7}', "\u{7375}"),
+    ('\u{F9A8}', "\u{4EE4}"),
+    ('\u{F9A9}', "\u{56F9}"),
+    ('\u{F9AA}', "\u{5BE7}"),
+    ('\u{F9AB}', "\u{5DBA}"),
+    ('\u{F9AC}', "\u{601C}"),
+    ('\u{F9AD}', "\u{73B2}"),
+    ('\u{F9AE}', "\u{7469}"),
+    ('\u{F9AF}', "\u{7F9A}"),
+    ('\u{F9B0}', "\u{8046}"),
+    ('\u{F9B1}', "\u{9234}"),
+    ('\u{F9B2}', "\u{96F6}"),
+    ('\u{F9B3}', "\u{9748}"),
+    ('\u{F9B4}', "\u{9818}"),
+    ('\u{F9B5}', "\u{4F8B}"),
+    ('\u{F9B6}', "\u{79AE}"),
+    ('\u{F9B7}', "\u{91B4}"),
+    ('\u{F9B8}', "\u{96B7}"),
+    ('\u{F9B9}', "\u{60E1}"),
+    ('\u{F9BA}', "\u{4E86}"),
+    ('\u{F9BB}', "\u{50DA}"),
+    ('\u{F9BC}', "\u{5BEE}"),
+    ('\u{F9BD}', "\u{5C3F}"),
+    ('\u{F9BE}', "\u{6599}"),
+    ('\u{F9BF}', "\u{6A02}"),
+    ('\u{F9C0}', "\u{71CE}"),
+    ('\u{F9C1}', "\u{7642}"),
+    ('\u{F9C2}', "\u{84FC}"),
+    ('\u{F9C3}', "\u{907C}"),
+    ('\u{F9C4}', "\u{9F8D}"),
+    ('\u{F9C5}', "\u{6688}"),
+    ('\u{F9C6}', "\u{962E}"),
+    ('\u{F9C7}', "\u{5289}"),
+    ('\u{F9C8}', "\u{677B}"),
+    ('\u{F9C9}', "\u{67F3}"),
+    ('\u{F9CA}', "\u{6D41}"),
+    ('\u{F9CB}', "\u{6E9C}"),
+    ('\u{F9CC}', "\u{7409}"),
+    ('\u{F9CD}', "\u{7559}"),
+    ('\u{F9CE}', "\u{786B}"),
+    ('\u{F9CF}', "\u{7D10}"),
+    ('\u{F9D0}', "\u{985E}"),
+    ('\u{F9D1}', "\u{516D}"),
+    ('\u{F9D2}', "\u{622E}"),
+    ('\u{F9D3}', "\u{9678}"),
+    ('\u{F9D4}', "\u{502B}"),
+    ('\u{F9D5}', "\u{5D19}"),
+    ('\u{F9D6}', "\u{6DEA}"),
+    ('\u{F9D7}', "\u{8F2A}"),
+    ('\u{F9D8}', "\u{5F8B}"),
+    ('\u{F9D9}', "\u{6144}"),
+    ('\u{F9DA}', "\u{6817}"),
+    ('\u{F9DB}', "\u{7387}"),
+    ('\u{F9DC}', "\u{9686}"),
+    ('\u{F9DD}', "\u{5229}"),
+    ('\u{F9DE}', "\u{540F}"),
+    ('\u{F9DF}', "\u{5C65}"),
+    ('\u{F9E0}', "\u{6613}"),
+    ('\u{F9E1}', "\u{674E}"),
+    ('\u{F9E2}', "\u{68A8}"),
+    ('\u{F9E3}', "\u{6CE5}"),
+    ('\u{F9E4}', "\u{7406}"),
+    ('\u{F9E5}', "\u{75E2}"),
+    ('\u{F9E6}', "\u{7F79}"),
+    ('\u{F9E7}', "\u{88CF}"),
+    ('\u{F9E8}', "\u{88E1}"),
+    ('\u{F9E9}', "\u{91CC}"),
+    ('\u{F9EA}', "\u{96E2}"),
+    ('\u{F9EB}', "\u{533F}"),
+    ('\u{F9EC}', "\u{6EBA}"),
+    ('\u{F9ED}', "\u{541D}"),
+    ('\u{F9EE}', "\u{71D0}"),
+    ('\u{F9EF}', "\u{7498}"),
+    ('\u{F9F0}', "\u{85FA}"),
+    ('\u{F9F1}', "\u{96A3}"),
+    ('\u{F9F2}', "\u{9C57}"),
+    ('\u{F9F3}', "\u{9E9F}"),
+    ('\u{F9F4}', "\u{6797}"),
+    ('\u{F9F5}', "\u{6DCB}"),
+    ('\u{F9F6}', "\u{81E8}"),
+    ('\u{F9F7}', "\u{7ACB}"),
+    ('\u{F9F8}', "\u{7B20}"),
+    ('\u{F9F9}', "\u{7C92}"),
+    ('\u{F9FA}', "\u{72C0}"),
+    ('\u{F9FB}', "\u{7099}"),
+    ('\u{F9FC}', "\u{8B58}"),
+    ('\u{F9FD}', "\u{4EC0}"),
+    ('\u{F9FE}', "\u{8336}"),
+    ('\u{F9FF}', "\u{523A}"),
+    ('\u{FA00}', "\u{5207}"),
+    ('\u{FA01}', "\u{5EA6}"),
+    ('\u{FA02}', "\u{62D3}"),
+    ('\u{FA03}', "\u{7CD6}"),
+    ('\u{FA04}', "\u{5B85}"),
+    ('\u{FA05}', "\u{6D1E}"),
+    ('\u{FA06}', "\u{66B4}"),
+    ('\u{FA07}', "\u{8F3B}"),
+    ('\u{FA08}', "\u{884C}"),
+    ('\u{FA09}', "\u{964D}"),
+    ('\u{FA0A}', "\u{898B}"),
+    ('\u{FA0B}', "\u{5ED3}"),
+    ('\u{FA0C}', "\u{5140}"),
+    ('\u{FA0D}', "\u{55C0}"),
+    ('\u{FA10}', "\u{585A}"),
+    ('\u{FA12}', "\u{6674}"),
+    ('\u{FA15}', "\u{51DE}"),
+    ('\u{FA16}', "\u{732A}"),
+    ('\u{FA17}', "\u{76CA}"),
+    ('\u{FA18}', "\u{793C}"),
+    ('\u{FA19}', "\u{795E}"),
+    ('\u{FA1A}', "\u{7965}"),
+    ('\u{FA1B}', "\u{798F}"),
+    ('\u{FA1C}', "\u{9756}"),
+    ('\u{FA1D}', "\u{7CBE}"),
+    ('\u{FA1E}', "\u{7FBD}"),
+    ('\u{FA20}', "\u{8612}"),
+    ('\u{FA22}', "\u{8AF8}"),
+    ('\u{FA25}', "\u{9038}"),
+    ('\u{FA26}', "\u{90FD}"),
+    ('\u{FA2A}', "\u{98EF}"),
+    ('\u{FA2B}', "\u{98FC}"),
+    ('\u{FA2C}', "\u{9928}"),
+    ('\u{FA2D}', "\u{9DB4}"),
+    ('\u{FA2E}', "\u{90CE}"),
+    ('\u{FA2F}', "\u{96B7}"),
+    ('\u{FA30}', "\u{4FAE}"),
+    ('\u{FA31}', "\u{50E7}"),
+    ('\u{FA32}', "\u{514D}"),
+    ('\u{FA33}', "\u{52C9}"),
+    ('\u{FA34}', "\u{52E4}"),
+    ('\u{FA35}', "\u{5351}"),
+    ('\u{FA36}', "\u{559D}"),
+    ('\u{FA37}', "\u{5606}"),
+    ('\u{FA38}', "\u{5668}"),
+    ('\u{FA39}', "\u{5840}"),
+    ('\u{FA3A}', "\u{58A8}"),
+    ('\u{FA3B}', "\u{5C64}"),
+    ('\u{FA3C}', "\u{5C6E}"),
+    ('\u{FA3D}', "\u{6094}"),
+    ('\u{FA3E}', "\u{6168}"),
+    ('\u{FA3F}', "\u{618E}"),
+    ('\u{FA40}', "\u{61F2}"),
+    ('\u{FA41}', "\u{654F}"),
+    ('\u{FA42}', "\u{65E2}"),
+    ('\u{FA43}', "\u{6691}"),
+    ('\u{FA44}', "\u{6885}"),
+    ('\u{FA45}', "\u{6D77}"),
+    ('\u{FA46}', "\u{6E1A}"),
+    ('\u{FA47}', "\u{6F22}"),
+    ('\u{FA48}', "\u{716E}"),
+    ('\u{FA49}', "\u{722B}"),
+    ('\u{FA4A}', "\u{7422}"),
+    ('\u{FA4B}', "\u{7891}"),
+    ('\u{FA4C}', "\u{793E}"),
+    ('\u{FA4D}', "\u{7949}"),
+    ('\u{FA4E}', "\u{7948}"),
+    ('\u{FA4F}', "\u{7950}"),
+    ('\u{FA50}', "\u{7956}"),
+    ('\u{FA51}', "\u{795D}"),
+    ('\u{FA52}', "\u{798D}"),
+    ('\u{FA53}', "\u{798E}"),
+    ('\u{FA54}', "\u{7A40}"),
+    ('\u{FA55}', "\u{7A81}"),
+    ('\u{FA56}', "\u{7BC0}"),
+    ('\u{FA57}', "\u{7DF4}"),
+    ('\u{FA58}', "\u{7E09}"),
+    ('\u{FA59}', "\u{7E41}"),
+    ('\u{FA5A}', "\u{7F72}"),
+    ('\u{FA5B}', "\u{8005}"),
+    ('\u{FA5C}', "\u{81ED}"),
+    ('\u{FA5D}', "\u{8279}"),
+    ('\u{FA5E}', "\u{8279}"),
+    ('\u{FA5F}', "\u{8457}"),
+    ('\u{FA60}', "\u{8910}"),
+    ('\u{FA61}', "\u{8996}"),
+    ('\u{FA62}', "\u{8B01}"),
+    ('\u{FA63}', "\u{8B39}"),
+    ('\u{FA64}', "\u{8CD3}"),
+    ('\u{FA65}', "\u{8D08}"),
+    ('\u{FA66}', "\u{8FB6}"),
+    ('\u{FA67}', "\u{9038}"),
+    ('\u{FA68}', "\u{96E3}"),
+    ('\u{FA69}', "\u{97FF}"),
+    ('\u{FA6A}', "\u{983B}"),
+    ('\u{FA6B}', "\u{6075}"),
+    ('\u{FA6C}', "\u{242EE}"),
+    ('\u{FA6D}', "\u{8218}"),
+    ('\u{FA70}', "\u{4E26}"),
+    ('\u{FA71}', "\u{51B5}"),
+    ('\u{FA72}', "\u{5168}"),
+    ('\u{FA73}', "\u{4F80}"),
+    ('\u{FA74}', "\u{5145}"),
+    ('\u{FA75}', "\u{5180}"),
+    ('\u{FA76}', "\u{52C7}"),
+    ('\u{FA77}', "\u{52FA}"),
+    ('\u{FA78}', "\u{559D}"),
+    ('\u{FA79}', "\u{5555}"),
+    ('\u{FA7A}', "\u{5599}"),
+    ('\u{FA7B}', "\u{55E2}"),
+    ('\u{FA7C}', "\u{585A}"),
+    ('\u{FA7D}', "\u{58B3}"),
+    ('\u{FA7E}', "\u{5944}"),
+    ('\u{FA7F}', "\u{5954}"),
+    ('\u{FA80}', "\u{5A62}"),
+    ('\u{FA81}', "\u{5B28}"),
+    ('\u{FA82}', "\u{5ED2}"),
+    ('\u{FA83}', "\u{5ED9}"),
+    ('\u{FA84}', "\u{5F69}"),
+    ('\u{FA85}', "\u{5FAD}"),
+    ('\u{FA86}', "\u{60D8}"),
+    ('\u{FA87}', "\u{614E}"),
+    ('\u{FA88}', "\u{6108}"),
+    ('\u{FA89}', "\u{618E}"),
+    ('\u{FA8A}', "\u{6160}"),
+    ('\u{FA8B}', "\u{61F2}"),
+    ('\u{FA8C}', "\u{6234}"),
+    ('\u{FA8D}', "\u{63C4}"),
+    ('\u{FA8E}', "\u{641C}"),
+    ('\u{FA8F}', "\u{6452}"),
+    ('\u{FA90}', "\u{6556}"),
+    ('\u{FA91}', "\u{6674}"),
+    ('\u{FA92}', "\u{6717}"),
+    ('\u{FA93}', "\u{671B}"),
+    ('\u{FA94}', "\u{6756}"),
+    ('\u{FA95}', "\u{6B79}"),
+    ('\u{FA96}', "\u{6BBA}"),
+    ('\u{FA97}', "\u{6D41}"),
+    ('\u{FA98}', "\u{6EDB}"),
+    ('\u{FA99}', "\u{6ECB}"),
+    ('\u{FA9A}', "\u{6F22}"),
+    ('\u{FA9B}', "\u{701E}"),
+    ('\u{FA9C}', "\u{716E}"),
+    ('\u{FA9D}', "\u{77A7}"),
+    ('\u{FA9E}', "\u{7235}"),
+    ('\u{FA9F}', "\u{72AF}"),
+    ('\u{FAA0}', "\u{732A}"),
+    ('\u{FAA1}', "\u{7471}"),
+    ('\u{FAA2}', "\u{7506}"),
+    ('\u{FAA3}', "\u{753B}"),
+    ('\u{FAA4}', "\u{761D}"),
+    ('\u{FAA5}', "\u{761F}"),
+    ('\u{FAA6}', "\u{76CA}"),
+    ('\u{FAA7}', "\u{76DB}"),
+    ('\u{FAA8}', "\u{76F4}"),
+    ('\u{FAA9}', "\u{774A}"),
+    ('\u{FAAA}', "\u{7740}"),
+    ('\u{FAAB}', "\u{78CC}"),
+    ('\u{FAAC}', "\u{7AB1}"),
+    ('\u{FAAD}', "\u{7BC0}"),
+    ('\u{FAAE}', "\u{7C7B}"),
+    ('\u{FAAF}', "\u{7D5B}"),
+    ('\u{FAB0}', "\u{7DF4}"),
+    ('\u{FAB1}', "\u{7F3E}"),
+    ('\u{FAB2}', "\u{8005}"),
+    ('\u{FAB3}', "\u{8352}"),
+    ('\u{FAB4}', "\u{83EF}"),
+    ('\u{FAB5}', "\u{8779}"),
+    ('\u{FAB6}', "\u{8941}"),
+    ('\u{FAB7}', "\u{8986}"),
+    ('\u{FAB8}', "\u{8996}"),
+    ('\u{FAB9}', "\u{8ABF}"),
+    ('\u{FABA}', "\u{8AF8}"),
+    ('\u{FABB}', "\u{8ACB}"),
+    ('\u{FABC}', "\u{8B01}"),
+    ('\u{FABD}', "\u{8AFE}"),
+    ('\u{FABE}', "\u{8AED}"),
+    ('\u{FABF}', "\u{8B39}"),
+    ('\u{FAC0}', "\u{8B8A}"),
+    ('\u{FAC1}', "\u{8D08}"),
+    ('\u{FAC2}', "\u{8F38}"),
+    ('\u{FAC3}', "\u{9072}"),
+    ('\u{FAC4}', "\u{9199}"),
+    ('\u{FAC5}', "\u{9276}"),
+    ('\u{FAC6}', "\u{967C}"),
+    ('\u{FAC7}', "\u{96E3}"),
+    ('\u{FAC8}', "\u{9756}"),
+    ('\u{FAC9}', "\u{97DB}"),
+    ('\u{FACA}', "\u{97FF}"),
+    ('\u{FACB}', "\u{980B}"),
+    ('\u{FACC}', "\u{983B}"),
+    ('\u{FACD}', "\u{9B12}"),
+    ('\u{FACE}', "\u{9F9C}"),
+    ('\u{FACF}', "\u{2284A}"),
+    ('\u{FAD0}', "\u{22844}"),
+    ('\u{FAD1}', "\u{233D5}"),
+    ('\u{FAD2}', "\u{3B9D}"),
+    ('\u{FAD3}', "\u{4018}"),
+    ('\u{FAD4}', "\u{4039}"),
+    ('\u{FAD5}', "\u{25249}"),
+    ('\u{FAD6}', "\u{25CD0}"),
+    ('\u{FAD7}', "\u{27ED3}"),
+    ('\u{FAD8}', "\u{9F43}"),
+    ('\u{FAD9}', "\u{9F8E}"),
+    ('\u{FB00}', "ff"),
+    ('\u{FB01}', "fi"),
+    ('\u{FB02}', "fl"),
+    ('\u{FB03}', "ffi"),
+    ('\u{FB04}', "ffl"),
+    ('\u{FB06}', "st"),
+    ('\u{FB13}', "\u{574}\u{576}"),
+    ('\u{FB14}', "\u{574}\u{565}"),
+    ('\u{FB15}', "\u{574}\u{56B}"),
+    ('\u{FB16}', "\u{57E}\u{576}"),
+    ('\u{FB17}', "\u{574}\u{56D}"),
+    ('\u{FB1D}', "\u{27}\u{323}"),
+    ('\u{FB1F}', "\u{27}\u{27}\u{5B7}"),
+    ('\u{FB20}', "\u{5E2}"),
+    ('\u{FB21}', "\u{5D0}"),
+    ('\u{FB22}', "\u{5D3}"),
+    ('\u{FB23}', "\u{5D4}"),
+    ('\u{FB24}', "\u{5DB}"),
+    ('\u{FB25}', "\u{5DC}"),
+    ('\u{FB26}', "\u{5DD}"),
+    ('\u{FB27}', "\u{5E8}"),
+    ('\u{FB28}', "\u{5EA}"),
+    ('\u{FB29}', "-\u{307}"),
+    ('\u{FB2A}', "\u{5E9}\u{307}"),
+    ('\u{FB2B}', "\u{5E9}\u{307}"),
+    ('\u{FB2C}', "\u{5E9}\u{5BC}\u{307}"),
+    ('\u{FB2D}', "\u{5E9}\u{5BC}\u{307}"),
+    ('\u{FB2E}', "\u{5D0}\u{5B7}"),
+    ('\u{FB2F}', "\u{5D0}\u{5B8}"),
+    ('\u{FB30}', "\u{5D0}\u{5BC}"),
+    ('\u{FB31}', "\u{5D1}\u{5BC}"),
+    ('\u{FB32}', "\u{5D2}\u{5BC}"),
+    ('\u{FB33}', "\u{5D3}\u{5BC}"),
+    ('\u{FB34}', "\u{5D4}\u{5BC}"),
+    ('\u{FB35}', "l\u{5BC}"),
+    ('\u{FB36}', "\u{5D6}\u{5BC}"),
+    ('\u{FB38}', "v\u{5BC}"),
+    ('\u{FB39}', "\u{27}\u{5BC}"),
+    ('\u{FB3A}', "\u{5DA}\u{5BC}"),
+    ('\u{FB3B}', "\u{5DB}\u{5BC}"),
+    ('\u{FB3C}', "\u{5DC}\u{5BC}"),
+    ('\u{FB3E}', "\u{5DE}\u{5BC}"),
+    ('\u{FB40}', "\u{5E0}\u{5BC}"),
+    ('\u{FB41}', "o\u{5BC}"),
+    ('\u{FB43}', "\u{5E3}\u{5BC}"),
+    ('\u{FB44}', "\u{5E4}\u{5BC}"),
+    ('\u{FB46}', "\u{5E6}\u{5BC}"),
+    ('\u{FB47}', "\u{5E7}\u{5BC}"),
+    ('\u{FB48}', "\u{5E8}\u{5BC}"),
+    ('\u{FB49}', "\u{5E9}\u{5BC}"),
+    ('\u{FB4A}', "\u{5EA}\u{5BC}"),
+    ('\u{FB4B}', "l\u{307}"),
+    ('\u{FB4C}', "\u{5D1}\u{5BF}"),
+    ('\u{FB4D}', "\u{5DB}\u{5BF}"),
+    ('\u{FB4E}', "\u{5E4}\u{5BF}"),
+    ('\u{FB4F}', "\u{5D0}\u{5DC}"),
+    ('\u{FB50}', "\u{671}"),
+    ('\u{FB51}', "\u{671}"),
+    ('\u{FB52}', "\u{67B}"),
+    ('\u{FB53}', "\u{67B}"),
+    ('\u{FB54}', "\u{67B}"),
+    ('\u{FB55}', "\u{67B}"),
+    ('\u{FB56}', "\u{649}\u{6DB}"),
+    ('\u{FB57}', "\u{649}\u{6DB}"),
+    ('\u{FB58}', "\u{649}\u{6DB}"),
+    ('\u{FB59}', "\u{649}\u{6DB}"),
+    ('\u{FB5A}', "\u{680}"),
+    ('\u{FB5B}', "\u{680}"),
+    ('\u{FB5C}', "\u{680}"),
+    ('\u{FB5D}', "\u{680}"),
+    ('\u{FB5E}', "\u{67A}"),
+    ('\u{FB5F}', "\u{67A}"),
+    ('\u{FB60}', "\u{67A}"),
+    ('\u{FB61}', "\u{67A}"),
+    ('\u{FB62}', "\u{67F}"),
+    ('\u{FB63}', "\u{67F}"),
+    ('\u{FB64}', "\u{67F}"),
+    ('\u{FB65}', "\u{67F}"),
+    ('\u{FB66}', "\u{649}\u{615}"),
+    ('\u{FB67}', "\u{649}\u{615}"),
+    ('\u{FB68}', "\u{649}\u{615}"),
+    ('\u{FB69}', "\u{649}\u{615}"),
+    ('\u{FB6A}', "\u{6A1}\u{6DB}"),
+    ('\u{FB6B}', "\u{6A1}\u{6DB}"),
+    ('\u{FB6C}', "\u{6A1}\u{6DB}"),
+    ('\u{FB6D}', "\u{6A1}\u{6DB}"),
+    ('\u{FB6E}', "\u{6A6}"),
+    ('\u{FB6F}', "\u{6A6}"),
+    ('\u{FB70}', "\u{6A6}"),
+    ('\u{FB71}', "\u{6A6}"),
+    ('\u{FB72}', "\u{684}"),
+    ('\u{FB73}', "\u{684}"),
+    ('\u{FB74}', "\u{684}"),
+    ('\u{FB75}', "\u{684}"),
+    ('\u{FB76}', "\u{683}"),
+    ('\u{FB77}', "\u{683}"),
+    ('\u{FB78}', "\u{683}"),
+    ('\u{FB79}', "\u{683}"),
+    ('\u{FB7A}', "\u{686}"),
+    ('\u{FB7B}', "\u{686}"),
+    ('\u{FB7C}', "\u{686}"),
+    ('\u{FB7D}', "\u{686}"),
+    ('\u{FB7E}', "\u{687}"),
+    ('\u{FB7F}', "\u{687}"),
+    ('\u{FB80}', "\u{687}"),
+    ('\u{FB81}', "\u{687}"),
+    ('\u{FB82}', "\u{68D}"),
+    ('\u{FB83}', "\u{68D}"),
+    ('\u{FB84}', "\u{68C}"),
+    ('\u{FB85}', "\u{68C}"),
+    ('\u{FB86}', "\u{62F}\u{6DB}"),
+    ('\u{FB87}', "\u{62F}\u{6DB}"),
+    ('\u{FB88}', "\u{62F}\u{615}"),
+    ('\u{FB89}', "\u{62F}\u{615}"),
+    ('\u{FB8A}', "\u{631}\u{6DB}"),
+    ('\u{FB8B}', "\u{631}\u{6DB}"),
+    ('\u{FB8C}', "\u{631}\u{615}"),
+    ('\u{FB8D}', "\u{631}\u{615}"),
+    ('\u{FB8E}', "\u{643}"),
+    ('\u{FB8F}', "\u{643}"),
+    ('\u{FB90}', "\u{643}"),
+    ('\u{FB91}', "\u{643}"),
+    ('\u{FB92}', "\u{6AF}"),
+    ('\u{FB93}', "\u{6AF}"),
+    ('\u{FB94}', "\u{6AF}"),
+    ('\u{FB95}', "\u{6AF}"),
+    ('\u{FB96}', "\u{6B3}"),
+    ('\u{FB97}', "\u{6B3}"),
+    ('\u{FB98}', "\u{6B3}"),
+    ('\u{FB99}', "\u{6B3}"),
+    ('\u{FB9A}', "\u{6B1}"),
+    ('\u{FB9B}', "\u{6B1}"),
+    ('\u{FB9C}', "\u{6B1}"),
+    ('\u{FB9D}', "\u{6B1}"),
+    ('\u{FB9E}', "\u{649}"),
+    ('\u{FB9F}', "\u{649}"),
+    ('\u{FBA0}', "\u{649}\u{615}"),
+    ('\u{FBA1}', "\u{649}\u{615}"),
+    ('\u{FBA2}', "\u{649}\u{615}"),
+    ('\u{FBA3}', "\u{649}\u{615}"),
+    ('\u{FBA4}', "\u{6D5}\u{654}"),
+    ('\u{FBA5}', "\u{6D5}\u{654}"),
+    ('\u{FBA6}', "o"),
+    ('\u{FBA7}', "o"),
+    ('\u{FBA8}', "o"),
+    ('\u{FBA9}', "o"),
+    ('\u{FBAA}', "o"),
+    ('\u{FBAB}', "o"),
+    ('\u{FBAC}', "o"),
+    ('\u{FBAD}', "o"),
+    ('\u{FBAE}', "\u{649}"),
+    ('\u{FBAF}', "\u{649}"),
+    ('\u{FBB0}', "\u{6D2}\u{654}"),
+    ('\u{FBB1}', "\u{6D2}\u{654}"),
+    ('\u{FBD3}', "\u{643}\u{6DB}"),
+    ('\u{FBD4}', "\u{643}\u{6DB}"),
+    ('\u{FBD5}', "\u{643}\u{6DB}"),
+    ('\u{FBD6}', "\u{643}\u{6DB}"),
+    ('\u{FBD7}', "\u{648}\u{313}"),
+    ('\u{FBD8}', "\u{648}\u{313}"),
+    ('\u{FBD9}', "\u{648}\u{306}"),
+    ('\u{FBDA}', "\u{648}\u{306}"),
+    ('\u{FBDB}', "\u{648}\u{670}"),
+    ('\u{FBDC}', "\u{648}\u{670}"),
+    ('\u{FBDD}', "\u{648}\u{313}\u{674}"),
+    ('\u{FBDE}', "\u{648}\u{6DB}"),
+    ('\u{FBDF}', "\u{648}\u{6DB}"),
+    ('\u{FBE0}', "\u{6C5}"),
+    ('\u{FBE1}', "\u{6C5}"),
+    ('\u{FBE2}', "\u{648}\u{302}"),
+    ('\u{FBE3}', "\u{648}\u{302}"),
+    ('\u{FBE4}', "\u{67B}"),
+    ('\u{FBE5}', "\u{67B}"),
+    ('\u{FBE6}', "\u{67B}"),
+    ('\u{FBE7}', "\u{67B}"),
+    ('\u{FBE8}', "\u{649}"),
+    ('\u{FBE9}', "\u{649}"),
+    ('\u{FBEA}', "\u{649}\u{674}l"),
+    ('\u{FBEB}', "\u{649}\u{674}l"),
+    ('\u{FBEC}', "\u{649}\u{674}o"),
+    ('\u{FBED}', "\u{649}\u{674}o"),
+    ('\u{FBEE}', "\u{649}\u{674}\u{648}"),
+    ('\u{FBEF}', "\u{649}\u{674}\u{648}"),
+    ('\u{FBF0}', "\u{649}\u{674}\u{648}\u{313}"),
+    ('\u{FBF1}', "\u{649}\u{674}\u{648}\u{313}"),
+    ('\u{FBF2}', "\u{649}\u{674}\u{648}\u{306}"),
+    ('\u{FBF3}', "\u{649}\u{674}\u{648}\u{306}"),
+    ('\u{FBF4}', "\u{649}\u{674}\u{648}\u{670}"),
+    ('\u{FBF5}', "\u{649}\u{674}\u{648}\u{670}"),
+    ('\u{FBF6}', "\u{649}\u{674}\u{67B}"),
+    ('\u{FBF7}', "\u{649}\u{674}\u{67B}"),
+    ('\u{FBF8}', "\u{649}\u{674}\u{67B}"),
+    ('\u{FBF9}', "\u{649}\u{674}\u{649}"),
+    ('\u{FBFA}', "\u{649}\u{674}\u{649}"),
+    ('\u{FBFB}', "\u{649}\u{674}\u{649}"),
+    ('\u{FBFC}', "\u{649}"),
+    ('\u{FBFD}', "\u{649}"),
+    ('\u{FBFE}', "\u{649}"),
+    ('\u{FBFF}', "\u{649}"),
+    ('\u{FC00}', "\u{649}\u{674}\u{62C}"),
+    ('\u{FC01}', "\u{649}\u{674}\u{62D}"),
+    ('\u{FC02}', "\u{649}\u{674}\u{645}"),
+    ('\u{FC03}', "\u{649}\u{674}\u{649}"),
+    ('\u{FC04}', "\u{649}\u{674}\u{649}"),
+    ('\u{FC05}', "\u{628}\u{62C}"),
+    ('\u{FC06}', "\u{628}\u{62D}"),
+    ('\u{FC07}', "\u{628}\u{62E}"),
+    ('\u{FC08}', "\u{628}\u{645}"),
+    ('\u{FC09}', "\u{628}\u{649}"),
+    ('\u{FC0A}', "\u{628}\u{649}"),
+    ('\u{FC0B}', "\u{62A}\u{62C}"),
+    ('\u{FC0C}', "\u{62A}\u{62D}"),
+    ('\u{FC0D}', "\u{62A}\u{62E}"),
+    ('\u{FC0E}', "\u{62A}\u{645}"),
+    ('\u{FC0F}', "\u{62A}\u{649}"),
+    ('\u{FC10}', "\u{62A}\u{649}"),
+    ('\u{FC11}', "\u{649}\u{6DB}\u{62C}"),
+    ('\u{FC12}', "\u{649}\u{6DB}\u{645}"),
+    ('\u{FC13}', "\u{649}\u{6DB}\u{649}"),
+    ('\u{FC14}', "\u{649}\u{6DB}\u{649}"),
+    ('\u{FC15}', "\u{62C}\u{62D}"),
+    ('\u{FC16}', "\u{62C}\u{645}"),
+    ('\u{FC17}', "\u{62D}\u{62C}"),
+    ('\u{FC18}', "\u{62D}\u{645}"),
+    ('\u{FC19}', "\u{62E}\u{62C}"),
+    ('\u{FC1A}', "\u{62E}\u{62D}"),
+    ('\u{FC1B}', "\u{62E}\u{645}"),
+    ('\u{FC1C}', "\u{633}\u{62C}"),
+    ('\u{FC1D}', "\u{633}\u{62D}"),
+    ('\u{FC1E}', "\u{633}\u{62E}"),
+    ('\u{FC1F}', "\u{633}\u{645}"),
+    ('\u{FC20}', "\u{635}\u{62D}"),
+    ('\u{FC21}', "\u{635}\u{645}"),
+    ('\u{FC22}', "\u{636}\u{62C}"),
+    ('\u{FC23}', "\u{636}\u{62D}"),
+    ('\u{FC24}', "\u{636}\u{62E}"),
+    ('\u{FC25}', "\u{636}\u{645}"),
+    ('\u{FC26}', "\u{637}\u{62D}"),
+    ('\u{FC27}', "\u{637}\u{645}"),
+    ('\u{FC28}', "\u{638}\u{645}"),
+    ('\u{FC29}', "\u{639}\u{62C}"),
+    ('\u{FC2A}', "\u{639}\u{645}"),
+    ('\u{FC2B}', "\u{63A}\u{62C}"),
+    ('\u{FC2C}', "\u{63A}\u{645}"),
+    ('\u{FC2D}', "\u{641}\u{62C}"),
+    ('\u{FC2E}', "\u{641}\u{62D}"),
+    ('\u{FC2F}', "\u{641}\u{62E}"),
+    ('\u{FC30}', "\u{641}\u{645}"),
+    ('\u{FC31}', "\u{641}\u{649}"),
+    ('\u{FC32}', "\u{641}\u{649}"),
+    ('\u{FC33}', "\u{642}\u{62D}"),
+    ('\u{FC34}', "\u{642}\u{645}"),
+    ('\u{FC35}', "\u{642}\u{649}"),
+    ('\u{FC36}', "\u{642}\u{649}"),
+    ('\u{FC37}', "\u{643}l"),
+    ('\u{FC38}', "\u{643}\u{62C}"),
+    ('\u{FC39}', "\u{643}\u{62D}"),
+    ('\u{FC3A}', "\u{643}\u{62E}"),
+    ('\u{FC3B}', "\u{643}\u{644}"),
+    ('\u{FC3C}', "\u{643}\u{645}"),
+    ('\u{FC3D}', "\u{643}\u{649}"),
+    ('\u{FC3E}', "\u{643}\u{649}"),
+    ('\u{FC3F}', "\u{644}\u{62C}"),
+    ('\u{FC40}', "\u{644}\u{62D}"),
+    ('\u{FC41}', "\u{644}\u{62E}"),
+    ('\u{FC42}', "\u{644}\u{645}"),
+    ('\u{FC43}', "\u{644}\u{649}"),
+    ('\u{FC44}', "\u{644}\u{649}"),
+    ('\u{FC45}', "\u{645}\u{62C}"),
+    ('\u{FC46}', "\u{645}\u{62D}"),
+    ('\u{FC47}', "\u{645}\u{62E}"),
+    ('\u{FC48}', "\u{645}\u{645}"),
+    ('\u{FC49}', "\u{645}\u{649}"),
+    ('\u{FC4A}', "\u{645}\u{649}"),
+    ('\u{FC4B}', "\u{628}\u{62E}"),
+    ('\u{FC4C}', "\u{646}\u{62D}"),
+    ('\u{FC4D}', "\u{646}\u{62E}"),
+    ('\u{FC4E}', "\u{646}\u{645}"),
+    ('\u{FC4F}', "\u{646}\u{649}"),
+    ('\u{FC50}', "\u{646}\u{649}"),
+    ('\u{FC51}', "o\u{62C}"),
+    ('\u{FC52}', "o\u{645}"),
+    ('\u{FC53}', "o\u{649}"),
+    ('\u{FC54}', "o\u{649}"),
+    ('\u{FC55}', "\u{649}\u{62C}"),
+    ('\u{FC56}', "\u{649}\u{62D}"),
+    ('\u{FC57}', "\u{649}\u{62E}"),
+    ('\u{FC58}', "\u{649}\u{645}"),
+    ('\u{FC59}', "\u{649}\u{649}"),
+    ('\u{FC5A}', "\u{649}\u{649}"),
+    ('\u{FC5B}', "\u{630}\u{670}"),
+    ('\u{FC5C}', "\u{631}\u{670}"),
+    ('\u{FC5D}', "\u{649}\u{670}"),
+    ('\u{FC5E}', "\u{FE72}\u{651}"),
+    ('\u{FC5F}', "\u{FE74}\u{651}"),
+    ('\u{FC60}', "\u{FE76}\u{651}"),
+    ('\u{FC61}', "\u{FE78}\u{651}"),
+    ('\u{FC62}', "\u{FE7A}\u{651}"),
+    ('\u{FC63}', "\u{FE7C}\u{670}"),
+    ('\u{FC64}', "\u{649}\u{674}\u{631}"),
+    ('\u{FC65}', "\u{649}\u{674}\u{632}"),
+    ('\u{FC66}', "\u{649}\u{674}\u{645}"),
+    ('\u{FC67}', "\u{649}\u{674}\u{646}"),
+    ('\u{FC68}', "\u{649}\u{674}\u{649}"),
+    ('\u{FC69}', "\u{649}\u{674}\u{649}"),
+    ('\u{FC6A}', "\u{628}\u{631}"),
+    ('\u{FC6B}', "\u{628}\u{632}"),
+    ('\u{FC6C}', "\u{628}\u{645}"),
+    ('\u{FC6D}', "\u{628}\u{646}"),
+    ('\u{FC6E}', "\u{628}\u{649}"),
+    ('\u{FC6F}', "\u{628}\u{649}"),
+    ('\u{FC70}', "\u{62A}\u{631}"),
+    ('\u{FC71}', "\u{62A}\u{632}"),
+    ('\u{FC72}', "\u{62A}\u{645}"),
+    ('\u{FC73}', "\u{62A}\u{646}"),
+    ('\u{FC74}', "\u{62A}\u{649}"),
+    ('\u{FC75}', "\u{62A}\u{649}"),
+    ('\u{FC76}', "\u{649}\u{6DB}\u{631}"),
+    ('\u{FC77}', "\u{649}\u{6DB}\u{632}"),
+    ('\u{FC78}', "\u{649}\u{6DB}\u{645}"),
+    ('\u{FC79}', "\u{649}\u{6DB}\u{646}"),
+    ('\u{FC7A}', "\u{649}\u{6DB}\u{649}"),
+    ('\u{FC7B}', "\u{649}\u{6DB}\u{649}"),
+    ('\u{FC7C}', "\u{641}\u{649}"),
+    ('\u{FC7D}', "\u{641}\u{649}"),
+    ('\u{FC7E}', "\u{642}\u{649}"),
+    ('\u{FC7F}', "\u{642}\u{649}"),
+    ('\u{FC80}', "\u{643}l"),
+    ('\u{FC81}', "\u{643}\u{644}"),
+    ('\u{FC82}', "\u{643}\u{645}"),
+    ('\u{FC83}', "\u{643}\u{649}"),
+    ('\u{FC84}', "\u{643}\u{649}"),
+    ('\u{FC85}', "\u{644}\u{645}"),
+    ('\u{FC86}', "\u{644}\u{649}"),
+    ('\u{FC87}', "\u{644}\u{649}"),
+    ('\u{FC88}', "\u{645}l"),
+    ('\u{FC89}', "\u{645}\u{645}"),
+    ('\u{FC8A}', "\u{646}\u{631}"),
+    ('\u{FC8B}', "\u{646}\u{632}"),
+    ('\u{FC8C}', "\u{646}\u{645}"),
+    ('\u{FC8D}', "\u{646}\u{646}"),
+    ('\u{FC8E}', "\u{646}\u{649}"),
+    ('\u{FC8F}', "\u{646}\u{649}"),
+    ('\u{FC90}', "\u{649}\u{670}"),
+    ('\u{FC91}', "\u{649}\u{631}"),
+    ('\u{FC92}', "\u{649}\u{632}"),
+    ('\u{FC93}', "\u{649}\u{645}"),
+    ('\u{FC94}', "\u{649}\u{646}"),
+    ('\u{FC95}', "\u{649}\u{649}"),
+    ('\u{FC96}', "\u{649}\u{649}"),
+    ('\u{FC97}', "\u{649}\u{674}\u{62C}"),
+    ('\u{FC98}', "\u{649}\u{674}\u{62D}"),
+    ('\u{FC99}', "\u{649}\u{674}\u{62E}"),
+    ('\u{FC9A}', "\u{649}\u{674}\u{645}"),
+    ('\u{FC9B}', "\u{649}\u{674}o"),
+    ('\u{FC9C}', "\u{628}\u{62C}"),
+    ('\u{FC9D}', "\u{628}\u{62D}"),
+    ('\u{FC9E}', "\u{628}\u{62E}"),
+    ('\u{FC9F}', "\u{628}\u{645}"),
+    ('\u{FCA0}', "\u{628}o"),
+    ('\u{FCA1}', "\u{62A}\u{62C}"),
+    ('\u{FCA2}', "\u{62A}\u{62D}"),
+    ('\u{FCA3}', "\u{62A}\u{62E}"),
+    ('\u{FCA4}', "\u{62A}\u{645}"),
+    ('\u{FCA5}', "\u{62A}o"),
+    ('\u{FCA6}', "\u{649}\u{6DB}\u{645}"),
+    ('\u{FCA7}', "\u{62C}\u{62D}"),
+    ('\u{FCA8}', "\u{62C}\u{645}"),
+    ('\u{FCA9}', "\u{62D}\u{62C}"),
+    ('\u{FCAA}', "\u{62D}\u{645}"),
+    ('\u{FCAB}', "\u{62E}\u{62C}"),
+    ('\u{FCAC}', "\u{62E}\u{645}"),
+    ('\u{FCAD}', "\u{633}\u{62C}"),
+    ('\u{FCAE}', "\u{633}\u{62D}"),
+    ('\u{FCAF}', "\u{633}\u{62E}"),
+    ('\u{FCB0}', "\u{633}\u{645}"),
+    ('\u{FCB1}', "\u{635}\u{62D}"),
+    ('\u{FCB2}', "\u{635}\u{62E}"),
+    ('\u{FCB3}', "\u{635}\u{645}"),
+    ('\u{FCB4}', "\u{636}\u{62C}"),
+    ('\u{FCB5}', "\u{636}\u{62D}"),
+    ('\u{FCB6}', "\u{636}\u{62E}"),
+    ('\u{FCB7}', "\u{636}\u{645}"),
+    ('\u{FCB8}', "\u{637}\u{62D}"),
+    ('\u{FCB9}', "\u{638}\u{645}"),
+    ('\u{FCBA}', "\u{639}\u{62C}"),
+    ('\u{FCBB}', "\u{639}\u{645}"),
+    ('\u{FCBC}', "\u{63A}\u{62C}"),
+    ('\u{FCBD}', "\u{63A}\u{645}"),
+    ('\u{FCBE}', "\u{641}\u{62C}"),
+    ('\u{FCBF}', "\u{641}\u{62D}"),
+    ('\u{FCC0}', "\u{641}\u{62E}"),
+    ('\u{FCC1}', "\u{641}\u{645}"),
+    ('\u{FCC2}', "\u{642}\u{62D}"),
+    ('\u{FCC3}', "\u{642}\u{645}"),
+    ('\u{FCC4}', "\u{643}\u{62C}"),
+    ('\u{FCC5}', "\u{643}\u{62D}"),
+    ('\u{FCC6}', "\u{643}\u{62E}"),
+    ('\u{FCC7}', "\u{643}\u{644}"),
+    ('\u{FCC8}', "\u{643}\u{645}"),
+    ('\u{FCC9}', "\u{644}\u{62C}"),
+    ('\u{FCCA}', "\u{644}\u{62D}"),
+    ('\u{FCCB}', "\u{644}\u{62E}"),
+    ('\u{FCCC}', "\u{644}\u{645}"),
+    ('\u{FCCD}', "\u{644}o"),
+    ('\u{FCCE}', "\u{645}\u{62C}"),
+    ('\u{FCCF}', "\u{645}\u{62D}"),
+    ('\u{FCD0}', "\u{645}\u{62E}"),
+    ('\u{FCD1}', "\u{645}\u{645}"),
+    ('\u{FCD2}', "\u{628}\u{62E}"),
+    ('\u{FCD3}', "\u{646}\u{62D}"),
+    ('\u{FCD4}', "\u{646}\u{62E}"),
+    ('\u{FCD5}', "\u{646}\u{645}"),
+    ('\u{FCD6}', "\u{646}o"),
+    ('\u{FCD7}', "o\u{62C}"),
+    ('\u{FCD8}', "o\u{645}"),
+    ('\u{FCD9}', "o\u{670}"),
+    ('\u{FCDA}', "\u{649}\u{62C}"),
+    ('\u{FCDB}', "\u{649}\u{62D}"),
+    ('\u{FCDC}', "\u{649}\u{62E}"),
+    ('\u{FCDD}', "\u{649}\u{645}"),
+    ('\u{FCDE}', "\u{649}o"),
+    ('\u{FCDF}', "\u{649}\u{674}\u{645}"),
+    ('\u{FCE0}', "\u{649}\u{674}o"),
+    ('\u{FCE1}', "\u{628}\u{645}"),
+    ('\u{FCE2}', "\u{628}o"),
+    ('\u{FCE3}', "\u{62A}\u{645}"),
+    ('\u{FCE4}', "\u{62A}o"),
+    ('\u{FCE5}', "\u{649}\u{6DB}\u{645}"),
+    ('\u{FCE6}', "\u{649}\u{6DB}o"),
+    ('\u{FCE7}', "\u{633}\u{645}"),
+    ('\u{FCE8}', "\u{633}o"),
+    ('\u{FCE9}', "\u{633}\u{6DB}\u{645}"),
+    ('\u{FCEA}', "\u{633}\u{6DB}o"),
+    ('\u{FCEB}', "\u{643}\u{644}"),
+    ('\u{FCEC}', "\u{643}\u{645}"),
+    ('\u{FCED}', "\u{644}\u{645}"),
+    ('\u{FCEE}', "\u{646}\u{645}"),
+    ('\u{FCEF}', "\u{646}o"),
+    ('\u{FCF0}', "\u{649}\u{645}"),
+    ('\u{FCF1}', "\u{649}o"),
+    ('\u{FCF2}', "\u{FE77}\u{651}"),
+    ('\u{FCF3}', "\u{FE79}\u{651}"),
+    ('\u{FCF4}', "\u{FE7B}\u{651}"),
+    ('\u{FCF5}', "\u{637}\u{649}"),
+    ('\u{FCF6}', "\u{637}\u{649}"),
+    ('\u{FCF7}', "\u{639}\u{649}"),
+    ('\u{FCF8}', "\u{639}\u{649}"),
+    ('\u{FCF9}', "\u{63A}\u{649}"),
+    ('\u{FCFA}', "\u{63A}\u{649}"),
+    ('\u{FCFB}', "\u{633}\u{649}"),
+    ('\u{FCFC}', "\u{633}\u{649}"),
+    ('\u{FCFD}', "\u{633}\u{6DB}\u{649}"),
+    ('\u{FCFE}', "\u{633}\u{6DB}\u{649}"),
+    ('\u{FCFF}', "\u{62D}\u{649}"),
+    ('\u{FD00}', "\u{62D}\u{649}"),
+    ('\u{FD01}', "\u{62C}\u{649}"),
+    ('\u{FD02}', "\u{62C}\u{649}"),
+    ('\u{FD03}', "\u{62E}\u{649}"),
+    ('\u{FD04}', "\u{62E}\u{649}"),
+    ('\u{FD05}', "\u{635}\u{649}"),
+    ('\u{FD06}', "\u{635}\u{649}"),
+    ('\u{FD07}', "\u{636}\u{649}"),
+    ('\u{FD08}', "\u{636}\u{649}"),
+    ('\u{FD09}', "\u{633}\u{6DB}\u{62C}"),
+    ('\u{FD0A}', "\u{633}\u{6DB}\u{62D}"),
+    ('\u{FD0B}', "\u{633}\u{6DB}\u{62E}"),
+    ('\u{FD0C}', "\u{633}\u{6DB}\u{645}"),
+    ('\u{FD0D}', "\u{633}\u{6DB}\u{631}"),
+    ('\u{FD0E}', "\u{633}\u{631}"),
+    ('\u{FD0F}', "\u{635}\u{631}"),
+    ('\u{FD10}', "\u{636}\u{631}"),
+    ('\u{FD11}', "\u{637}\u{649}"),
+    ('\u{FD12}', "\u{637}\u{649}"),
+    ('\u{FD13}', "\u{639}\u{649}"),
+    ('\u{FD14}', "\u{639}\u{649}"),
+    ('\u{FD15}', "\u{63A}\u{649}"),
+    ('\u{FD16}', "\u{63A}\u{649}"),
+    ('\u{FD17}', "\u{633}\u{649}"),
+    ('\u{FD18}', "\u{633}\u{649}"),
+    ('\u{FD19}', "\u{633}\u{6DB}\u{649}"),
+    ('\u{FD1A}', "\u{633}\u{6DB}\u{649}"),
+    ('\u{FD1B}', "\u{62D}\u{649}"),
+    ('\u{FD1C}', "\u{62D}\u{649}"),
+    ('\u{FD1D}', "\u{62C}\u{649}"),
+    ('\u{FD1E}', "\u{62C}\u{649}"),
+    ('\u{FD1F}', "\u{62E}\u{649}"),
+    ('\u{FD20}', "\u{62E}\u{649}"),
+    ('\u{FD21}', "\u{635}\u{649}"),
+    ('\u{FD22}', "\u{635}\u{649}"),
+    ('\u{FD23}', "\u{636}\u{649}"),
+    ('\u{FD24}', "\u{636}\u{649}"),
+    ('\u{FD25}', "\u{633}\u{6DB}\u{62C}"),
+    ('\u{FD26}', "\u{633}\u{6DB}\u{62D}"),
+    ('\u{FD27}', "\u{633}\u{6DB}\u{62E}"),
+    ('\u{FD28}', "\u{633}\u{6DB}\u{645}"),
+    ('\u{FD29}', "\u{633}\u{6DB}\u{631}"),
+    ('\u{FD2A}', "\u{633}\u{631}"),
+    ('\u{FD2B}', "\u{635}\u{631}"),
+    ('\u{FD2C}', "\u{636}\u{631}"),
+    ('\u{FD2D}', "\u{633}\u{6DB}\u{62C}"),
+    ('\u{FD2E}', "\u{633}\u{6DB}\u{62D}"),
+    ('\u{FD2F}', "\u{633}\u{6DB}\u{62E}"),
+    ('\u{FD30}', "\u{633}\u{6DB}\u{645}"),
+    ('\u{FD31}', "\u{633}o"),
+    ('\u{FD32}', "\u{633}\u{6DB}o"),
+    ('\u{FD33}', "\u{637}\u{645}"),
+    ('\u{FD34}', "\u{633}\u{62C}"),
+    ('\u{FD35}', "\u{633}\u{62D}"),
+    ('\u{FD36}', "\u{633}\u{62E}"),
+    ('\u{FD37}', "\u{633}\u{6DB}\u{62C}"),
+    ('\u{FD38}', "\u{633}\u{6DB}\u{62D}"),
+    ('\u{FD39}', "\u{633}\u{6DB}\u{62E}"),
+    ('\u{FD3A}', "\u{637}\u{645}"),
+    ('\u{FD3B}', "\u{638}\u{645}"),
+    ('\u{FD3C}', "l\u{30B}"),
+    ('\u{FD3D}', "l\u{30B}"),
+    ('\u{FD3E}', "("),
+    ('\u{FD3F}', ")"),
+    ('\u{FD50}', "\u{62A}\u{62C}\u{645}"),
+    ('\u{FD51}', "\u{62A}\u{62D}\u{62C}"),
+    ('\u{FD52}', "\u{62A}\u{62D}\u{62C}"),
+    ('\u{FD53}', "\u{62A}\u{62D}\u{645}"),
+    ('\u{FD54}', "\u{62A}\u{62E}\u{645}"),
+    ('\u{FD55}', "\u{62A}\u{645}\u{62C}"),
+    ('\u{FD56}', "\u{62A}\u{645}\u{62D}"),
+    ('\u{FD57}', "\u{62A}\u{645}\u{62E}"),
+    ('\u{FD58}', "\u{62C}\u{645}\u{62D}"),
+    ('\u{FD59}', "\u{62C}\u{645}\u{62D}"),
+    ('\u{FD5A}', "\u{62D}\u{645}\u{649}"),
+    ('\u{FD5B}', "\u{62D}\u{645}\u{649}"),
+    ('\u{FD5C}', "\u{633}\u{62D}\u{62C}"),
+    ('\u{FD5D}', "\u{633}\u{62C}\u{62D}"),
+    ('\u{FD5E}', "\u{633}\u{62C}\u{649}"),
+    ('\u{FD5F}', "\u{633}\u{645}\u{62D}"),
+    ('\u{FD60}', "\u{633}\u{645}\u{62D}"),
+    ('\u{FD61}', "\u{633}\u{645}\u{62C}"),
+    ('\u{FD62}', "\u{633}\u{645}\u{645}"),
+    ('\u{FD63}', "\u{633}\u{645}\u{645}"),
+    ('\u{FD64}', "\u{635}\u{62D}\u{62D}"),
+    ('\u{FD65}', "\u{635}\u{62D}\u{62D}"),
+    ('\u{FD66}', "\u{635}\u{645}\u{645}"),
+    ('\u{FD67}', "\u{633}\u{6DB}\u{62D}\u{645}"),
+    ('\u{FD68}', "\u{633}\u{6DB}\u{62D}\u{645}"),
+    ('\u{FD69}', "\u{633}\u{6DB}\u{62C}\u{649}"),
+    ('\u{FD6A}', "\u{633}\u{6DB}\u{645}\u{62E}"),
+    ('\u{FD6B}', "\u{633}\u{6DB}\u{645}\u{62E}"),
+    ('\u{FD6C}', "\u{633}\u{6DB}\u{645}\u{645}"),
+    ('\u{FD6D}', "\u{633}\u{6DB}\u{645}\u{645}"),
+    ('\u{FD6E}', "\u{636}\u{62D}\u{649}"),
+    ('\u{FD6F}', "\u{636}\u{62E}\u{645}"),
+    ('\u{FD70}', "\u{636}\u{62E}\u{645}"),
+    ('\u{FD71}', "\u{637}\u{645}\u{62D}"),
+    ('\u{FD72}', "\u{637}\u{645}\u{62D}"),
+    ('\u{FD73}', "\u{637}\u{645}\u{645}"),
+    ('\u{FD74}', "\u{637}\u{645}\u{649}"),
+    ('\u{FD75}', "\u{639}\u{62C}\u{645}"),
+    ('\u{FD76}', "\u{639}\u{645}\u{645}"),
+    ('\u{FD77}', "\u{639}\u{645}\u{645}"),
+    ('\u{FD78}', "\u{639}\u{645}\u{649}"),
+    ('\u{FD79}', "\u{63A}\u{645}\u{645}"),
+    ('\u{FD7A}', "\u{63A}\u{645}\u{649}"),
+    ('\u{FD7B}', "\u{63A}\u{645}\u{649}"),
+    ('\u{FD7C}', "\u{641}\u{62E}\u{645}"),
+    ('\u{FD7D}', "\u{641}\u{62E}\u{645}"),
+    ('\u{FD7E}', "\u{642}\u{645}\u{62D}"),
+    ('\u{FD7F}', "\u{642}\u{645}\u{645}"),
+    ('\u{FD80}', "\u{644}\u{62D}\u{645}"),
+    ('\u{FD81}', "\u{644}\u{62D}\u{649}"),
+    ('\u{FD82}', "\u{644}\u{62D}\u{649}"),
+    ('\u{FD83}', "\u{644}\u{62C}\u{62C}"),
+    ('\u{FD84}', "\u{644}\u{62C}\u{62C}"),
+    ('\u{FD85}', "\u{644}\u{62E}\u{645}"),
+    ('\u{FD86}', "\u{644}\u{62E}\u{645}"),
+    ('\u{FD87}', "\u{644}\u{645}\u{62D}"),
+    ('\u{FD88}', "\u{644}\u{645}\u{62D}"),
+    ('\u{FD89}', "\u{645}\u{62D}\u{62C}"),
+    ('\u{FD8A}', "\u{645}\u{62D}\u{645}"),
+    ('\u{FD8B}', "\u{645}\u{62D}\u{649}"),
+    ('\u{FD8C}', "\u{645}\u{62C}\u{62D}"),
+    ('\u{FD8D}', "\u{645}\u{62C}\u{645}"),
+    ('\u{FD8E}', "\u{645}\u{62E}\u{62C}"),
+    ('\u{FD8F}', "\u{645}\u{62E}\u{645}"),
+    ('\u{FD92}', "\u{645}\u{62C}\u{62E}"),
+    ('\u{FD93}', "o\u{645}\u{62C}"),
+    ('\u{FD94}', "o\u{645}\u{645}"),
+    ('\u{FD95}', "\u{646}\u{62D}\u{645}"),
+    ('\u{FD96}', "\u{646}\u{62D}\u{649}"),
+    ('\u{FD97}', "\u{646}\u{62C}\u{645}"),
+    ('\u{FD98}', "\u{646}\u{62C}\u{645}"),
+    ('\u{FD99}', "\u{646}\u{62C}\u{649}"),
+    ('\u{FD9A}', "\u{646}\u{645}\u{649}"),
+    ('\u{FD9B}', "\u{646}\u{645}\u{649}"),
+    ('\u{FD9C}', "\u{649}\u{645}\u{645}"),
+    ('\u{FD9D}', "\u{649}\u{645}\u{645}"),
+    ('\u{FD9E}', "\u{628}\u{62E}\u{649}"),
+    ('\u{FD9F}', "\u{62A}\u{62C}\u{649}"),
+    ('\u{FDA0}', "\u{62A}\u{62C}\u{649}"),
+    ('\u{FDA1}', "\u{62A}\u{62E}\u{649}"),
+    ('\u{FDA2}', "\u{62A}\u{62E}\u{649}"),
+    ('\u{FDA3}', "\u{62A}\u{645}\u{649}"),
+    ('\u{FDA4}', "\u{62A}\u{645}\u{649}"),
+    ('\u{FDA5}', "\u{62C}\u{645}\u{649}"),
+    ('\u{FDA6}', "\u{62C}\u{62D}\u{649}"),
+    ('\u{FDA7}', "\u{62C}\u{645}\u{649}"),
+    ('\u{FDA8}', "\u{633}\u{62E}\u{649}"),
+    ('\u{FDA9}', "\u{635}\u{62D}\u{649}"),
+    ('\u{FDAA}', "\u{633}\u{6DB}\u{62D}\u{649}"),
+    ('\u{FDAB}', "\u{636}\u{62D}\u{649}"),
+    ('\u{FDAC}', "\u{644}\u{62C}\u{649}"),
+    ('\u{FDAD}', "\u{644}\u{645}\u{649}"),
+    ('\u{FDAE}', "\u{649}\u{62D}\u{649}"),
+    ('\u{FDAF}', "\u{649}\u{62C}\u{649}"),
+    ('\u{FDB0}', "\u{649}\u{645}\u{649}"),
+    ('\u{FDB1}', "\u{645}\u{645}\u{649}"),
+    ('\u{FDB2}', "\u{642}\u{645}\u{649}"),
+    ('\u{FDB3}', "\u{646}\u{62D}\u{649}"),
+    ('\u{FDB4}', "\u{642}\u{645}\u{62D}"),
+    ('\u{FDB5}', "\u{644}\u{62D}\u{645}"),
+    ('\u{FDB6}', "\u{639}\u{645}\u{649}"),
+    ('\u{FDB7}', "\u{643}\u{645}\u{649}"),
+    ('\u{FDB8}', "\u{646}\u{62C}\u{62D}"),
+    ('\u{FDB9}', "\u{645}\u{62E}\u{649}"),
+    ('\u{FDBA}', "\u{644}\u{62C}\u{645}"),
+    ('\u{FDBB}', "\u{643}\u{645}\u{645}"),
+    ('\u{FDBC}', "\u{644}\u{62C}\u{645}"),
+    ('\u{FDBD}', "\u{646}\u{62C}\u{62D}"),
+    ('\u{FDBE}', "\u{62C}\u{62D}\u{649}"),
+    ('\u{FDBF}', "\u{62D}\u{62C}\u{649}"),
+    ('\u{FDC0}', "\u{645}\u{62C}\u{649}"),
+    ('\u{FDC1}', "\u{641}\u{645}\u{649}"),
+    ('\u{FDC2}', "\u{628}\u{62D}\u{649}"),
+    ('\u{FDC3}', "\u{643}\u{645}\u{645}"),
+    ('\u{FDC4}', "\u{639}\u{62C}\u{645}"),
+    ('\u{FDC5}', "\u{635}\u{645}\u{645}"),
+    ('\u{FDC6}', "\u{633}\u{62E}\u{649}"),
+    ('\u{FDC7}', "\u{646}\u{62C}\u{649}"),
+    ('\u{FDF0}', "\u{635}\u{644}\u{649}"),
+    ('\u{FDF1}', "\u{642}\u{644}\u{649}"),
+    ('\u{FDF2}', "l\u{644}\u{644}\u{651}\u{670}o"),
+    ('\u{FDF3}', "l\u{643}\u{628}\u{631}"),
+    ('\u{FDF4}', "\u{645}\u{62D}\u{645}\u{62F}"),
+    ('\u{FDF5}', "\u{635}\u{644}\u{639}\u{645}"),
+    ('\u{FDF6}', "\u{631}\u{633}\u{648}\u{644}"),
+    ('\u{FDF7}', "\u{639}\u{644}\u{649}o"),
+    ('\u{FDF8}', "\u{648}\u{633}\u{644}\u{645}"),
+    ('\u{FDF9}', "\u{635}\u{644}\u{649}"),
+    ('\u{FDFA}', "\u{635}\u{644}\u{649} l\u{644}\u{644}o \u{639}\u{644}\u{649}o \u{648}\u{633}\u{644}\u{645}"),
+    ('\u{FDFB}', "\u{62C}\u{644} \u{62C}\u{644}l\u{644}o"),
+    ('\u{FDFC}', "\u{631}\u{649}l\u{644}"),
+    ('\u{FE19}', "\u{2D57}"),
+    ('\u{FE30}', ":"),
+    ('\u{FE31}', "\u{2502}"),
+    ('\u{FE34}', "\u{2307}"),
+    ('\u{FE35}', "\u{23DC}"),
+    ('\u{FE36}', "\u{23DD}"),
+    ('\u{FE37}', "\u{23DE}"),
+    ('\u{FE38}', "\u{23DF}"),
+    ('\u{FE39}', "\u{23E0}"),
+    ('\u{FE3A}', "\u{23E1}"),
+    ('\u{FE49}', "\u{2C9}"),
+    ('\u{FE4A}', "\u{2C9}"),
+    ('\u{FE4B}', "\u{2C9}"),
+    ('\u{FE4C}', "\u{2C9}"),
+    ('\u{FE4D}', "_"),
+    ('\u{FE4E}', "_"),
+    ('\u{FE4F}', "_"),
+    ('\u{FE58}', "-"),
+    ('\u{FE68}', "\u{5C}"),
+    ('\u{FE80}', "\u{621}"),
+    ('\u{FE81}', "\u{627}\u{653}"),
+    ('\u{FE82}', "\u{627}\u{653}"),
+    ('\u{FE83}', "l\u{674}"),
+    ('\u{FE84}', "l\u{674}"),
+    ('\u{FE85}', "\u{648}\u{674}"),
+    ('\u{FE86}', "\u{648}\u{674}"),
+    ('\u{FE87}', "l\u{655}"),
+    ('\u{FE88}', "l\u{655}"),
+    ('\u{FE89}', "\u{649}\u{674}"),
+    ('\u{FE8A}', "\u{649}\u{674}"),
+    ('\u{FE8B}', "\u{649}\u{674}"),
+    ('\u{FE8C}', "\u{649}\u{674}"),
+    ('\u{FE8D}', "l"),
+    ('\u{FE8E}', "l"),
+    ('\u{FE8F}', "\u{628}"),
+    ('\u{FE90}', "\u{628}"),
+    ('\u{FE91}', "\u{628}"),
+    ('\u{FE92}', "\u{628}"),
+    ('\u{FE93}', "\u{629}"),
+    ('\u{FE94}', "\u{629}"),
+    ('\u{FE95}', "\u{62A}"),
+    ('\u{FE96}', "\u{62A}"),
+    ('\u{FE97}', "\u{62A}"),
+    ('\u{FE98}', "\u{62A}"),
+    ('\u{FE99}', "\u{649}\u{6DB}"),
+    ('\u{FE9A}', "\u{649}\u{6DB}"),
+    ('\u{FE9B}', "\u{649}\u{6DB}"),
+    ('\u{FE9C}', "\u{649}\u{6DB}"),
+    ('\u{FE9D}', "\u{62C}"),
+    ('\u{FE9E}', "\u{62C}"),
+    ('\u{FE9F}', "\u{62C}"),
+    ('\u{FEA0}', "\u{62C}"),
+    ('\u{FEA1}', "\u{62D}"),
+    ('\u{FEA2}', "\u{62D}"),
+    ('\u{FEA3}', "\u{62D}"),
+    ('\u{FEA4}', "\u{62D}"),
+    ('\u{FEA5}', "\u{62E}"),
+    ('\u{FEA6}', "\u{62E}"),
+    ('\u{FEA7}', "\u{62E}"),
+    ('\u{FEA8}', "\u{62E}"),
+    ('\u{FEA9}', "\u{62F}"),
+    ('\u{FEAA}', "\u{62F}"),
+    ('\u{FEAB}', "\u{630}"),
+    ('\u{FEAC}', "\u{630}"),
+    ('\u{FEAD}', "\u{631}"),
+    ('\u{FEAE}', "\u{631}"),
+    ('\u{FEAF}', "\u{632}"),
+    ('\u{FEB0}', "\u{632}"),
+    ('\u{FEB1}', "\u{633}"),
+    ('\u{FEB2}', "\u{633}"),
+    ('\u{FEB3}', "\u{633}"),
+    ('\u{FEB4}', "\u{633}"),
+    ('\u{FEB5}', "\u{633}\u{6DB}"),
+    ('\u{FEB6}', "\u{633}\u{6DB}"),
+    ('\u{FEB7}', "\u{633}\u{6DB}"),
+    ('\u{FEB8}', "\u{633}\u{6DB}"),
+    ('\u{FEB9}', "\u{635}"),
+    ('\u{FEBA}', "\u{635}"),
+    ('\u{FEBB}', "\u{635}"),
+    ('\u{FEBC}', "\u{635}"),
+    ('\u{FEBD}', "\u{636}"),
+    ('\u{FEBE}', "\u{636}"),
+    ('\u{FEBF}', "\u{636}"),
+    ('\u{FEC0}', "\u{636}"),
+    ('\u{FEC1}', "\u{637}"),
+    ('\u{FEC2}', "\u{637}"),
+    ('\u{FEC3}', "\u{637}"),
+    ('\u{FEC4}', "\u{637}"),
+    ('\u{FEC5}', "\u{638}"),
+    ('\u{FEC6}', "\u{638}"),
+    ('\u{FEC7}', "\u{638}"),
+    ('\u{FEC8}', "\u{638}"),
+    ('\u{FEC9}', "\u{639}"),
+    ('\u{FECA}', "\u{639}"),
+    ('\u{FECB}', "\u{639}"),
+    ('\u{FECC}', "\u{639}"),
+    ('\u{FECD}', "\u{63A}"),
+    ('\u{FECE}', "\u{63A}"),
+    ('\u{FECF}', "\u{63A}"),
+    ('\u{FED0}', "\u{63A}"),
+    ('\u{FED1}', "\u{641}"),
+    ('\u{FED2}', "\u{641}"),
+    ('\u{FED3}', "\u{641}"),
+    ('\u{FED4}', "\u{641}"),
+    ('\u{FED5}', "\u{642}"),
+    ('\u{FED6}', "\u{642}"),
+    ('\u{FED7}', "\u{642}"),
+    ('\u{FED8}', "\u{642}"),
+    ('\u{FED9}', "\u{643}"),
+    ('\u{FEDA}', "\u{643}"),
+    ('\u{FEDB}', "\u{643}"),
+    ('\u{FEDC}', "\u{643}"),
+    ('\u{FEDD}', "\u{644}"),
+    ('\u{FEDE}', "\u{644}"),
+    ('\u{FEDF}', "\u{644}"),
+    ('\u{FEE0}', "\u{644}"),
+    ('\u{FEE1}', "\u{645}"),
+    ('\u{FEE2}', "\u{645}"),
+    ('\u{FEE3}', "\u{645}"),
+    ('\u{FEE4}', "\u{645}"),
+    ('\u{FEE5}', "\u{646}"),
+    ('\u{FEE6}', "\u{646}"),
+    ('\u{FEE7}', "\u{646}"),
+    ('\u{FEE8}', "\u{646}"),
+    ('\u{FEE9}', "o"),
+    ('\u{FEEA}', "o"),
+    ('\u{FEEB}', "o"),
+    ('\u{FEEC}', "o"),
+    ('\u{FEED}', "\u{648}"),
+    ('\u{FEEE}', "\u{648}"),
+    ('\u{FEEF}', "\u{649}"),
+    ('\u{FEF0}', "\u{649}"),
+    ('\u{FEF1}', "\u{649}"),
+    ('\u{FEF2}', "\u{649}"),
+    ('\u{FEF3}', "\u{649}"),
+    ('\u{FEF4}', "\u{649}"),
+    ('\u{FEF5}', "\u{644}\u{627}\u{653}"),
+    ('\u{FEF6}', "\u{644}\u{627}\u{653}"),
+    ('\u{FEF7}', "\u{644}l\u{674}"),
+    ('\u{FEF8}', "\u{644}l\u{674}"),
+    ('\u{FEF9}', "\u{644}l\u{655}"),
+    ('\u{FEFA}', "\u{644}l\u{655}"),
+    ('\u{FEFB}', "\u{644}l"),
+    ('\u{FEFC}', "\u{644}l"),
+    ('\u{FF01}', "!"),
+    ('\u{FF02}', "\u{27}\u{27}"),
+    ('\u{FF07}', "\u{27}"),
+    ('\u{FF0D}', "\u{30FC}"),
+    ('\u{FF1A}', ":"),
+    ('\u{FF21}', "A"),
+    ('\u{FF22}', "B"),
+    ('\u{FF23}', "C"),
+    ('\u{FF25}', "E"),
+    ('\u{FF28}', "H"),
+    ('\u{FF29}', "l"),
+    ('\u{FF2A}', "J"),
+    ('\u{FF2B}', "K"),
+    ('\u{FF2D}', "M"),
+    ('\u{FF2E}', "N"),
+    ('\u{FF2F}', "O"),
+    ('\u{FF30}', "P"),
+    ('\u{FF33}', "S"),
+    ('\u{FF34}', "T"),
+    ('\u{FF38}', "X"),
+    ('\u{FF39}', "Y"),
+    ('\u{FF3A}', "Z"),
+    ('\u{FF3B}', "("),
+    ('\u{FF3C}', "\u{5C}"),
+    ('\u{FF3D}', ")"),
+    ('\u{FF3E}', "\u{FE3F}"),
+    ('\u{FF40}', "\u{27}"),
+    ('\u{FF41}', "a"),
+    ('\u{FF43}', "c"),
+    ('\u{FF45}', "e"),
+    ('\u{FF47}', "g"),
+    ('\u{FF48}', "h"),
+    ('\u{FF49}', "i"),
+    ('\u{FF4A}', "j"),
+    ('\u{FF4C}', "l"),
+    ('\u{FF4F}', "o"),
+    ('\u{FF50}', "p"),
+    ('\u{FF53}', "s"),
+    ('\u{FF56}', "v"),
+    ('\u{FF58}', "x"),
+    ('\u{FF59}', "y"),
+    ('\u{FF5C}', "\u{2502}"),
+    ('\u{FF5E}', "\u{301C}"),
+    ('\u{FF65}', "\u{B7}"),
+    ('\u{FFE3}', "\u{2C9}"),
+    ('\u{FFE8}', "l"),
+    ('\u{FFED}', "\u{25AA}"),
+    ('\u{10101}', "\u{B7}"),
+    ('\u{1018E}', "N\u{30A}"),
+    ('\u{10196}', "X\u{335}"),
+    ('\u{10197}', "V\u{335}"),
+    ('\u{10198}', "l\u{335}l\u{335}S\u{335}"),
+    ('\u{10199}', "l\u{335}l\u{335}"),
+    ('\u{101A0}', "\u{2CE8}"),
+    ('\u{10282}', "B"),
+    ('\u{10285}', "\u{394}"),
+    ('\u{10286}', "E"),
+    ('\u{10287}', "F"),
+    ('\u{1028A}', "l"),
+    ('\u{1028D}', "\u{245}"),
+    ('\u{10290}', "X"),
+    ('\u{10292}', "O"),
+    ('\u{10294}', "\u{16DC}"),
+    ('\u{10295}', "P"),
+    ('\u{10296}', "S"),
+    ('\u{10297}', "T"),
+    ('\u{1029B}', "+"),
+    ('\u{102A0}', "A"),
+    ('\u{102A1}', "B"),
+    ('\u{102A2}', "C"),
+    ('\u{102A3}', "\u{394}"),
+    ('\u{102A5}', "F"),
+    ('\u{102AB}', "O"),
+    ('\u{102AD}', "\u{3D8}"),
+    ('\u{102B0}', "M"),
+    ('\u{102B1}', "T"),
+    ('\u{102B2}', "Y"),
+    ('\u{102B3}', "\u{3A6}"),
+    ('\u{102B4}', "X"),
+    ('\u{102B5}', "\u{3A8}"),
+    ('\u{102B6}', "\u{3A9}"),
+    ('\u{102B8}', "\u{2D40}"),
+    ('\u{102CF}', "H"),
+    ('\u{102E1}', "\u{62F}"),
+    ('\u{102E4}', "\u{648}"),
+    ('\u{102E8}', "\u{637}"),
+    ('\u{102F2}', "\u{635}"),
+    ('\u{102F5}', "Z"),
+    ('\u{10301}', "B"),
+    ('\u{10302}', "C"),
+    ('\u{10309}', "l"),
+    ('\u{10311}', "M"),
+    ('\u{10312}', "\u{3D8}"),
+    ('\u{10315}', "T"),
+    ('\u{10317}', "X"),
+    ('\u{1031A}', "8"),
+    ('\u{1031F}', "*"),
+    ('\u{10320}', "l"),
+    ('\u{10322}', "X"),
+    ('\u{103D1}', "\u{10382}"),
+    ('\u{103D3}', "\u{10393}"),
+    ('\u{10401}', "\u{190}"),
+    ('\u{10404}', "O"),
+    ('\u{10411}', "\u{A4F6}"),
+    ('\u{10415}', "C"),
+    ('\u{1041B}', "L"),
+    ('\u{1041F}', "\u{2C70}"),
+    ('\u{10420}', "S"),
+    ('\u{10423}', "\u{186}"),
+    ('\u{10425}', "\u{418}"),
+    ('\u{10429}', "\u{A793}"),
+    ('\u{1042A}', "\u{29A}"),
+    ('\u{1042C}', "o"),
+    ('\u{1043D}', "c"),
+    ('\u{1043F}', "\u{277}"),
+    ('\u{10442}', "\u{25E}"),
+    ('\u{10443}', "\u{29F}"),
+    ('\u{10448}', "s"),
+    ('\u{1044B}', "\u{254}"),
+    ('\u{1044D}', "\u{1D0E}"),
+    ('\u{104A0}', "\u{10486}"),
+    ('\u{104B0}', "\u{245}"),
+    ('\u{104B4}', "R"),
+    ('\u{104BC}', "\u{4C3}"),
+    ('\u{104C2}', "O"),
+    ('\u{104C3}', "\u{298}"),
+    ('\u{104C4}', "\u{DE}"),
+    ('\u{104CD}', "\u{40B}"),
+    ('\u{104CE}', "U"),
+    ('\u{104D0}', "\u{16E6}"),
+    ('\u{104D1}', "\u{3A8}"),
+    ('\u{104D2}', "7"),
+    ('\u{104D8}', "\u{28C}"),
+    ('\u{104DB}', "\u{3BB}"),
+    ('\u{104EA}', "o"),
+    ('\u{104EB}', "\u{A669}"),
+    ('\u{104F6}', "u"),
+    ('\u{104F9}', "\u{3C8}"),
+    ('\u{10513}', "N"),
+    ('\u{10516}', "O"),
+    ('\u{10518}', "K"),
+    ('\u{1051C}', "C"),
+    ('\u{1051D}', "V"),
+    ('\u{10525}', "F"),
+    ('\u{10526}', "L"),
+    ('\u{10527}', "X"),
+    ('\u{10A3A}', "\u{323}"),
+    ('\u{10A50}', "."),
+    ('\u{10A57}', "\u{10A56}\u{10A56}"),
+    ('\u{10CFA}', "\u{10CA5}"),
+    ('\u{10CFC}', "\u{10C82}"),
+    ('\u{1109A}', "\u{11099}\u{110BA}"),
+    ('\u{1109C}', "\u{1109B}\u{110BA}"),
+    ('\u{110AB}', "\u{110A5}\u{110BA}"),
+    ('\u{110BB}', "\u{970}"),
+    ('\u{1112E}', "\u{11131}\u{11127}"),
+    ('\u{1112F}', "\u{11132}\u{11127}"),
+    ('\u{111C7}', "\u{970}"),
+    ('\u{111CA}', "\u{323}"),
+    ('\u{111CB}', "\u{93A}"),
+    ('\u{111DB}', "\u{A8FC}"),
+    ('\u{111DC}', "\u{A8FB}"),
+    ('\u{111DE}', "\u{2248}"),
+    ('\u{11300}', "\u{30A}"),
+    ('\u{1134B}', "\u{11347}\u{1133E}"),
+    ('\u{1134C}', "\u{11347}\u{11357}"),
+    ('\u{11413}', "\u{11434}\u{11442}\u{11412}"),
+    ('\u{11419}', "\u{11434}\u{11442}\u{11418}"),
+    ('\u{11424}', "\u{11434}\u{11442}\u{11423}"),
+    ('\u{1142A}', "\u{11434}\u{11442}\u{11429}"),
+    ('\u{1142D}', "\u{11434}\u{11442}\u{1142C}"),
+    ('\u{1142F}', "\u{11434}\u{11442}\u{1142E}"),
+    ('\u{1144C}', "\u{1144B}\u{1144B}"),
+    ('\u{11492}', "\u{998}"),
+    ('\u{11494}', "\u{99A}"),
+    ('\u{11496}', "\u{99C}"),
+    ('\u{11498}', "\u{99E}"),
+    ('\u{11499}', "\u{99F}"),
+    ('\u{1149B}', "\u{9A1}"),
+    ('\u{1149D}', "\u{9B2}"),
+    ('\u{1149E}', "\u{9A4}"),
+    ('\u{1149F}', "\u{9A5}"),
+    ('\u{114A0}', "\u{9A6}"),
+    ('\u{114A1}', "\u{9A7}"),
+    ('\u{114A2}', "\u{9A8}"),
+    ('\u{114A3}', "\u{9AA}"),
+    ('\u{114A7}', "\u{9AE}"),
+    ('\u{114A8}', "\u{9AF}"),
+    ('\u{114A9}', "\u{9AC}"),
+    ('\u{114AA}', "\u{9A3}"),
+    ('\u{114AB}', "\u{9B0}"),
+    ('\u{114AD}', "\u{9B7}"),
+    ('\u{114AE}', "\u{9B8}"),
+    ('\u{114B0}', "\u{9BE}"),
+    ('\u{114B1}', "\u{9BF}"),
+    ('\u{114B9}', "\u{9C7}"),
+    ('\u{114BB}', "\u{9C7}\u{114BA}"),
+    ('\u{114BC}', "\u{9C7}\u{9BE}"),
+    ('\u{114BD}', "\u{9D7}"),
+    ('\u{114BE}', "\u{9C7}\u{9D7}"),
+    ('\u{114BF}', "\u{306}\u{307}"),
+    ('\u{114C1}', "\u{983}"),
+    ('\u{114C2}', "\u{9CD}"),
+    ('\u{114C3}', "\u{323}"),
+    ('\u{114C4}', "\u{9BD}"),
+    ('\u{114C5}', "w\u{307}"),
+    ('\u{114D0}', "O"),
+    ('\u{114D1}', "\u{9E7}"),
+    ('\u{114D2}', "\u{9E8}"),
+    ('\u{114D6}', "\u{9EC}"),
+    ('\u{115BA}', "\u{115B8}\u{115AF}"),
+    ('\u{115BB}', "\u{115B9}\u{115AF}"),
+    ('\u{115D8}', "\u{11582}"),
+    ('\u{115D9}', "\u{11582}"),
+    ('\u{115DA}', "\u{11583}"),
+    ('\u{115DB}', "\u{11584}"),
+    ('\u{115DC}', "\u{115B2}"),
+    ('\u{115DD}', "\u{115B3}"),
+    ('\u{11642}', "\u{11641}\u{11641}"),
+    ('\u{11700}', "rn"),
+    ('\u{11706}', "v"),
+    ('\u{1170A}', "w"),
+    ('\u{1170E}', "w"),
+    ('\u{1170F}', "w"),
+    ('\u{118A0}', "V"),
+    ('\u{118A2}', "F"),
+    ('\u{118A3}', "L"),
+    ('\u{118A4}', "Y"),
+    ('\u{118A6}', "E"),
+    ('\u{118A8}', "\u{2207}"),
+    ('\u{118A9}', "Z"),
+    ('\u{118AC}', "9"),
+    ('\u{118AE}', "E"),
+    ('\u{118AF}', "4"),
+    ('\u{118B2}', "L"),
+    ('\u{118B5}', "O"),
+    ('\u{118B7}', "\u{16DC}"),
+    ('\u{118B8}', "U"),
+    ('\u{118BB}', "5"),
+    ('\u{118BC}', "T"),
+    ('\u{118C0}', "v"),
+    ('\u{118C1}', "s"),
+    ('\u{118C2}', "F"),
+    ('\u{118C3}', "i"),
+    ('\u{118C4}', "z"),
+    ('\u{118C6}', "7"),
+    ('\u{118C8}', "o"),
+    ('\u{118CA}', "3"),
+    ('\u{118CC}', "9"),
+    ('\u{118CE}', "\u{A793}"),
+    ('\u{118D5}', "6"),
+    ('\u{118D6}', "9"),
+    ('\u{118D7}', "o"),
+    ('\u{118D8}', "u"),
+    ('\u{118DC}', "y"),
+    ('\u{118E0}', "O"),
+    ('\u{118E3}', "rn"),
+    ('\u{118E4}', "\u{669}"),
+    ('\u{118E5}', "Z"),
+    ('\u{118E6}', "W"),
+    ('\u{118E9}', "C"),
+    ('\u{118EC}', "X"),
+    ('\u{118EF}', "W"),
+    ('\u{118F2}', "C"),
+    ('\u{11938}', "\u{11935}\u{11930}"),
+    ('\u{11AE6}', "\u{11AE5}\u{11AEF}"),
+    ('\u{11AE7}', "\u{11AE5}\u{11AF0}"),
+    ('\u{11AE8}', "\u{11AE5}\u{11AE5}"),
+    ('\u{11AE9}', "\u{11AE5}\u{11AE5}\u{11AEF}"),
+    ('\u{11AEA}', "\u{11AE5}\u{11AE5}\u{11AF0}"),
+    ('\u{11AEC}', "\u{11AEB}\u{11AEF}"),
+    ('\u{11AED}', "\u{11AEB}\u{11AEB}"),
+    ('\u{11AEE}', "\u{11AEB}\u{11AEB}\u{11AEF}"),
+    ('\u{11AF4}', "\u{11AF3}\u{11AEF}"),
+    ('\u{11AF5}', "\u{11AF3}\u{11AF0}"),
+    ('\u{11AF6}', "\u{11AF3}\u{11AF3}"),
+    ('\u{11AF7}', "\u{11AF3}\u{11AF3}\u{11AEF}"),
+    ('\u{11AF8}', "\u{11AF3}\u{11AF3}\u{11AF0}"),
+    ('\u{11C42}', "\u{11C41}\u{11C41}"),
+    ('\u{11CB2}', "\u{11CAA}"),
+    ('\u{12038}', "\u{1039A}"),
+    ('\u{132F9}', "\u{1099E}"),
+    ('\u{16F07}', "\u{393}"),
+    ('\u{16F08}', "V"),
+    ('\u{16F0A}', "T"),
+    ('\u{16F16}', "L"),
+    ('\u{16F1A}', "\u{394}"),
+    ('\u{16F1C}', "\u{A658}"),
+    ('\u{16F26}', "\u{A4F6}"),
+    ('\u{16F28}', "l"),
+    ('\u{16F2D}', "\u{190}"),
+    ('\u{16F35}', "R"),
+    ('\u{16F3A}', "S"),
+    ('\u{16F3B}', "3"),
+    ('\u{16F3D}', "\u{245}"),
+    ('\u{16F3F}', ">"),
+    ('\u{16F40}', "A"),
+    ('\u{16F42}', "U"),
+    ('\u{16F43}', "Y"),
+    ('\u{16F51}', "\u{27}"),
+    ('\u{16F52}', "\u{27}"),
+    ('\u{1D114}', "{"),
+    ('\u{1D15E}', "\u{1D157}\u{1D165}"),
+    ('\u{1D15F}', "\u{1D158}\u{1D165}"),
+    ('\u{1D160}', "\u{1D158}\u{1D165}\u{1D16E}"),
+    ('\u{1D161}', "\u{1D158}\u{1D165}\u{1D16F}"),
+    ('\u{1D162}', "\u{1D158}\u{1D165}\u{1D170}"),
+    ('\u{1D163}', "\u{1D158}\u{1D165}\u{1D171}"),
+    ('\u{1D164}', "\u{1D158}\u{1D165}\u{1D172}"),
+    ('\u{1D16D}', "."),
+    ('\u{1D1BB}', "\u{1D1B9}\u{1D165}"),
+    ('\u{1D1BC}', "\u{1D1BA}\u{1D165}"),
+    ('\u{1D1BD}', "\u{1D1B9}\u{1D165}\u{1D16E}"),
+    ('\u{1D1BE}', "\u{1D1BA}\u{1D165}\u{1D16E}"),
+    ('\u{1D1BF}', "\u{1D1B9}\u{1D165}\u{1D16F}"),
+    ('\u{1D1C0}', "\u{1D1BA}\u{1D165}\u{1D16F}"),
+    ('\u{1D202}', "\u{4FE}"),
+    ('\u{1D206}', "3"),
+    ('\u{1D20B}', "\u{418}"),
+    ('\u{1D20D}', "V"),
+    ('\u{1D20F}', "\u{5C}"),
+    ('\u{1D212}', "7"),
+    ('\u{1D213}', "F"),
+    ('\u{1D214}', "\u{102BC}"),
+    ('\u{1D215}', "\u{A4F6}"),
+    ('\u{1D216}', "R"),
+    ('\u{1D217}', "\u{2C6F}"),
+    ('\u{1D21A}', "O\u{335}"),
+    ('\u{1D21B}', "\u{2144}"),
+    ('\u{1D21C}', "\u{A4D5}"),
+    ('\u{1D221}', "\u{190}"),
+    ('\u{1D222}', "\u{460}"),
+    ('\u{1D22A}', "L"),
+    ('\u{1D22B}', "\u{A4F6}"),
+    ('\u{1D230}', "\u{A7FB}"),
+    ('\u{1D236}', "<"),
+    ('\u{1D237}', ">"),
+    ('\u{1D238}', "\u{228F}"),
+    ('\u{1D239}', "\u{2290}"),
+    ('\u{1D23A}', "/"),
+    ('\u{1D23B}', "\u{5C}"),
+    ('\u{1D23F}', "\u{16CB}"),
+    ('\u{1D245}', "\u{548}"),
+    ('\u{1D400}', "A"),
+    ('\u{1D401}', "B"),
+    ('\u{1D402}', "C"),
+    ('\u{1D403}', "D"),
+    ('\u{1D404}', "E"),
+    ('\u{1D405}', "F"),
+    ('\u{1D406}', "G"),
+    ('\u{1D407}', "H"),
+    ('\u{1D408}', "l"),
+    ('\u{1D409}', "J"),
+    ('\u{1D40A}', "K"),
+    ('\u{1D40B}', "L"),
+    ('\u{1D40C}', "M"),
+    ('\u{1D40D}', "N"),
+    ('\u{1D40E}', "O"),
+    ('\u{1D40F}', "P"),
+    ('\u{1D410}', "Q"),
+    ('\u{1D411}', "R"),
+    ('\u{1D412}', "S"),
+    ('\u{1D413}', "T"),
+    ('\u{1D414}', "U"),
+    ('\u{1D415}', "V"),
+    ('\u{1D416}', "W"),
+    ('\u{1D417}', "X"),
+    ('\u{1D418}', "Y"),
+    ('\u{1D419}', "Z"),
+    ('\u{1D41A}', "a"),
+    ('\u{1D41B}', "b"),
+    ('\u{1D41C}', "c"),
+    ('\u{1D41D}', "d"),
+    ('\u{1D41E}', "e"),
+    ('\u{1D41F}', "f"),
+    ('\u{1D420}', "g"),
+    ('\u{1D421}', "h"),
+    ('\u{1D422}', "i"),
+    ('\u{1D423}', "j"),
+    ('\u{1D424}', "k"),
+    ('\u{1D425}', "l"),
+    ('\u{1D426}', "rn"),
+    ('\u{1D427}', "n"),
+    ('\u{1D428}', "o"),
+    ('\u{1D429}', "p"),
+    ('\u{1D42A}', "q"),
+    ('\u{1D42B}', "r"),
+    ('\u{1D42C}', "s"),
+    ('\u{1D42D}', "t"),
+    ('\u{1D42E}', "u"),
+    ('\u{1D42F}', "v"),
+    ('\u{1D430}', "w"),
+    ('\u{1D431}', "x"),
+    ('\u{1D432}', "y"),
+    ('\u{1D433}', "z"),
+    ('\u{1D434}', "A"),
+    ('\u{1D435}', "B"),
+    ('\u{1D436}', "C"),
+    ('\u{1D437}', "D"),
+    ('\u{1D438}', "E"),
+    ('\u{1D439}', "F"),
+    ('\u{1D43A}', "G"),
+    ('\u{1D43B}', "H"),
+    ('\u{1D43C}', "l"),
+    ('\u{1D43D}', "J"),
+    ('\u{1D43E}', "K"),
+    ('\u{1D43F}', "L"),
+    ('\u{1D440}', "M"),
+    ('\u{1D441}', "N"),
+    ('\u{1D442}', "O"),
+    ('\u{1D443}', "P"),
+    ('\u{1D444}', "Q"),
+    ('\u{1D445}', "R"),
+    ('\u{1D446}', "S"),
+    ('\u{1D447}', "T"),
+    ('\u{1D448}', "U"),
+    ('\u{1D449}', "V"),
+    ('\u{1D44A}', "W"),
+    ('\u{1D44B}', "X"),
+    ('\u{1D44C}', "Y"),
+    ('\u{1D44D}', "Z"),
+    ('\u{1D44E}', "a"),
+    ('\u{1D44F}', "b"),
+    ('\u{1D450}', "c"),
+    ('\u{1D451}', "d"),
+    ('\u{1D452}', "e"),
+    ('\u{1D453}', "f"),
+    ('\u{1D454}', "g"),
+    ('\u{1D456}', "i"),
+    ('\u{1D457}', "j"),
+    ('\u{1D458}', "k"),
+    ('\u{1D459}', "l"),
+    ('\u{1D45A}', "rn"),
+    ('\u{1D45B}', "n"),
+    ('\u{1D45C}', "o"),
+    ('\u{1D45D}', "p"),
+    ('\u{1D45E}', "q"),
+    ('\u{1D45F}', "r"),
+    ('\u{1D460}', "s"),
+    ('\u{1D461}', "t"),
+    ('\u{1D462}', "u"),
+    ('\u{1D463}', "v"),
+    ('\u{1D464}', "w"),
+    ('\u{1D465}', "x"),
+    ('\u{1D466}', "y"),
+    ('\u{1D467}', "z"),
+    ('\u{1D468}', "A"),
+    ('\u{1D469}', "B"),
+    ('\u{1D46A}', "C"),
+    ('\u{1D46B}', "D"),
+    ('\u{1D46C}', "E"),
+    ('\u{1D46D}', "F"),
+    ('\u{1D46E}', "G"),
+    ('\u{1D46F}', "H"),
+    ('\u{1D470}', "l"),
+    ('\u{1D471}', "J"),
+    ('\u{1D472}', "K"),
+    ('\u{1D473}', "L"),
+    ('\u{1D474}', "M"),
+    ('\u{1D475}', "N"),
+    ('\u{1D476}', "O"),
+    ('\u{1D477}', "P"),
+    ('\u{1D478}', "Q"),
+    ('\u{1D479}', "R"),
+    ('\u{1D47A}', "S"),
+    ('\u{1D47B}', "T"),
+    ('\u{1D47C}', "U"),
+    ('\u{1D47D}', "V"),
+    ('\u{1D47E}', "W"),
+    ('\u{1D47F}', "X"),
+    ('\u{1D480}', "Y"),
+    ('\u{1D481}', "Z"),
+    ('\u{1D482}', "a"),
+    ('\u{1D483}', "b"),
+    ('\u{1D484}', "c"),
+    ('\u{1D485}', "d"),
+    ('\u{1D486}', "e"),
+    ('\u{1D487}', "f"),
+    ('\u{1D488}', "g"),
+    ('\u{1D489}', "h"),
+    ('\u{1D48A}', "i"),
+    ('\u{1D48B}', "j"),
+    ('\u{1D48C}', "k"),
+    ('\u{1D48D}', "l"),
+    ('\u{1D48E}', "rn"),
+    ('\u{1D48F}', "n"),
+    ('\u{1D490}', "o"),
+    ('\u{1D491}', "p"),
+    ('\u{1D492}', "q"),
+    ('\u{1D493}', "r"),
+    ('\u{1D494}', "s"),
+    ('\u{1D495}', "t"),
+    ('\u{1D496}', "u"),
+    ('\u{1D497}', "v"),
+    ('\u{1D498}', "w"),
+    ('\u{1D499}', "x"),
+    ('\u{1D49A}', "y"),
+    ('\u{1D49B}', "z"),
+    ('\u{1D49C}', "A"),
+    ('\u{1D49E}', "C"),
+    ('\u{1D49F}', "D"),
+    ('\u{1D4A2}', "G"),
+    ('\u{1D4A5}', "J"),
+    ('\u{1D4A6}', "K"),
+    ('\u{1D4A9}', "N"),
+    ('\u{1D4AA}', "O"),
+    ('\u{1D4AB}', "P"),
+    ('\u{1D4AC}', "Q"),
+    ('\u{1D4AE}', "S"),
+    ('\u{1D4AF}', "T"),
+    ('\u{1D4B0}', "U"),
+    ('\u{1D4B1}', "V"),
+    ('\u{1D4B2}', "W"),
+    ('\u{1D4B3}', "X"),
+    ('\u{1D4B4}', "Y"),
+    ('\u{1D4B5}', "Z"),
+    ('\u{1D4B6}', "a"),
+    ('\u{1D4B7}', "b"),
+    ('\u{1D4B8}', "c"),
+    ('\u{1D4B9}', "d"),
+    ('\u{1D4BB}', "f"),
+    ('\u{1D4BD}', "h"),
+    ('\u{1D4BE}', "i"),
+    ('\u{1D4BF}', "j"),
+    ('\u{1D4C0}', "k"),
+    ('\u{1D4C1}', "l"),
+    ('\u{1D4C2}', "rn"),
+    ('\u{1D4C3}', "n"),
+    ('\u{1D4C5}', "p"),
+    ('\u{1D4C6}', "q"),
+    ('\u{1D4C7}', "r"),
+    ('\u{1D4C8}', "s"),
+    ('\u{1D4C9}', "t"),
+    ('\u{1D4CA}', "u"),
+    ('\u{1D4CB}', "v"),
+    ('\u{1D4CC}', "w"),
+    ('\u{1D4CD}', "x"),
+    ('\u{1D4CE}', "y"),
+    ('\u{1D4CF}', "z"),
+    ('\u{1D4D0}', "A"),
+    ('\u{1D4D1}', "B"),
+    ('\u{1D4D2}', "C"),
+    ('\u{1D4D3}', "D"),
+    ('\u{1D4D4}', "E"),
+    ('\u{1D4D5}', "F"),
+    ('\u{1D4D6}', "G"),
+    ('\u{1D4D7}', "H"),
+    ('\u{1D4D8}', "l"),
+    ('\u{1D4D9}', "J"),
+    ('\u{1D4DA}', "K"),
+    ('\u{1D4DB}', "L"),
+    ('\u{1D4DC}', "M"),
+    ('\u{1D4DD}', "N"),
+    ('\u{1D4DE}', "O"),
+    ('\u{1D4DF}', "P"),
+    ('\u{1D4E0}', "Q"),
+    ('\u{1D4E1}', "R"),
+    ('\u{1D4E2}', "S"),
+    ('\u{1D4E3}', "T"),
+    ('\u{1D4E4}', "U"),
+    ('\u{1D4E5}', "V"),
+    ('\u{1D4E6}', "W"),
+    ('\u{1D4E7}', "X"),
+    ('\u{1D4E8}', "Y"),
+    ('\u{1D4E9}', "Z"),
+    ('\u{1D4EA}', "a"),
+    ('\u{1D4EB}', "b"),
+    ('\u{1D4EC}', "c"),
+    ('\u{1D4ED}', "d"),
+    ('\u{1D4EE}', "e"),
+    ('\u{1D4EF}', "f"),
+    ('\u{1D4F0}', "g"),
+    ('\u{1D4F1}', "h"),
+    ('\u{1D4F2}', "i"),
+    ('\u{1D4F3}', "j"),
+    ('\u{1D4F4}', "k"),
+    ('\u{1D4F5}', "l"),
+    ('\u{1D4F6}', "rn"),
+    ('\u{1D4F7}', "n"),
+    ('\u{1D4F8}', "o"),
+    ('\u{1D4F9}', "p"),
+    ('\u{1D4FA}', "q"),
+    ('\u{1D4FB}', "r"),
+    ('\u{1D4FC}', "s"),
+    ('\u{1D4FD}', "t"),
+    ('\u{1D4FE}', "u"),
+    ('\u{1D4FF}', "v"),
+    ('\u{1D500}', "w"),
+    ('\u{1D501}', "x"),
+    ('\u{1D502}', "y"),
+    ('\u{1D503}', "z"),
+    ('\u{1D504}', "A"),
+    ('\u{1D505}', "B"),
+    ('\u{1D507}', "D"),
+    ('\u{1D508}', "E"),
+    ('\u{1D509}', "F"),
+    ('\u{1D50A}', "G"),
+    ('\u{1D50D}', "J"),
+    ('\u{1D50E}', "K"),
+    ('\u{1D50F}', "L"),
+    ('\u{1D510}', "M"),
+    ('\u{1D511}', "N"),
+    ('\u{1D512}', "O"),
+    ('\u{1D513}', "P"),
+    ('\u{1D514}', "Q"),
+    ('\u{1D516}', "S"),
+    ('\u{1D517}', "T"),
+    ('\u{1D518}', "U"),
+    ('\u{1D519}', "V"),
+    ('\u{1D51A}', "W"),
+    ('\u{1D51B}', "X"),
+    ('\u{1D51C}', "Y"),
+    ('\u{1D51E}', "a"),
+    ('\u{1D51F}', "b"),
+    ('\u{1D520}', "c"),
+    ('\u{1D521}', "d"),
+    ('\u{1D522}', "e"),
+    ('\u{1D523}', "f"),
+    ('\u{1D524}', "g"),
+    ('\u{1D525}', "h"),
+    ('\u{1D526}', "i"),
+    ('\u{1D527}', "j"),
+    ('\u{1D528}', "k"),
+    ('\u{1D529}', "l"),
+    ('\u{1D52A}', "rn"),
+    ('\u{1D52B}', "n"),
+    ('\u{1D52C}', "o"),
+    ('\u{1D52D}', "p"),
+    ('\u{1D52E}', "q"),
+    ('\u{1D52F}', "r"),
+    ('\u{1D530}', "s"),
+    ('\u{1D531}', "t"),
+    ('\u{1D532}', "u"),
+    ('\u{1D533}', "v"),
+    ('\u{1D534}', "w"),
+    ('\u{1D535}', "x"),
+    ('\u{1D536}', "y"),
+    ('\u{1D537}', "z"),
+    ('\u{1D538}', "A"),
+    ('\u{1D539}', "B"),
+    ('\u{1D53B}', "D"),
+    ('\u{1D53C}', "E"),
+    ('\u{1D53D}', "F"),
+    ('\u{1D53E}', "G"),
+    ('\u{1D540}', "l"),
+    ('\u{1D541}', "J"),
+    ('\u{1D542}', "K"),
+    ('\u{1D543}', "L"),
+    ('\u{1D544}', "M"),
+    ('\u{1D546}', "O"),
+    ('\u{1D54A}', "S"),
+    ('\u{1D54B}', "T"),
+    ('\u{1D54C}', "U"),
+    ('\u{1D54D}', "V"),
+    ('\u{1D54E}', "W"),
+    ('\u{1D54F}', "X"),
+    ('\u{1D550}', "Y"),
+    ('\u{1D552}', "a"),
+    ('\u{1D553}', "b"),
+    ('\u{1D554}', "c"),
+    ('\u{1D555}', "d"),
+    ('\u{1D556}', "e"),
+    ('\u{1D557}', "f"),
+    ('\u{1D558}', "g"),
+    ('\u{1D559}', "h"),
+    ('\u{1D55A}', "i"),
+    ('\u{1D55B}', "j"),
+    ('\u{1D55C}', "k"),
+    ('\u{1D55D}', "l"),
+    ('\u{1D55E}', "rn"),
+    ('\u{1D55F}', "n"),
+    ('\u{1D560}', "o"),
+    ('\u{1D561}', "p"),
+    ('\u{1D562}', "q"),
+    ('\u{1D563}', "r"),
+    ('\u{1D564}', "s"),
+    ('\u{1D565}', "t"),
+    ('\u{1D566}', "u"),
+    ('\u{1D567}', "v"),
+    ('\u{1D568}', "w"),
+    ('\u{1D569}', "x"),
+    ('\u{1D56A}', "y"),
+    ('\u{1D56B}', "z"),
+    ('\u{1D56C}', "A"),
+    ('\u{1D56D}', "B"),
+    ('\u{1D56E}', "C"),
+    ('\u{1D56F}', "D"),
+    ('\u{1D570}', "E"),
+    ('\u{1D571}', "F"),
+    ('\u{1D572}', "G"),
+    ('\u{1D573}', "H"),
+    ('\u{1D574}', "l"),
+    ('\u{1D575}', "J"),
+    ('\u{1D576}', "K"),
+    ('\u{1D577}', "L"),
+    ('\u{1D578}', "M"),
+    ('\u{1D579}', "N"),
+    ('\u{1D57A}', "O"),
+    ('\u{1D57B}', "P"),
+    ('\u{1D57C}', "Q"),
+    ('\u{1D57D}', "R"),
+    ('\u{1D57E}', "S"),
+    ('\u{1D57F}', "T"),
+    ('\u{1D580}', "U"),
+    ('\u{1D581}', "V"),
+    ('\u{1D582}', "W"),
+    ('\u{1D583}', "X"),
+    ('\u{1D584}', "Y"),
+    ('\u{1D585}', "Z"),
+    ('\u{1D586}', "a"),
+    ('\u{1D587}', "b"),
+    ('\u{1D588}', "c"),
+    ('\u{1D589}', "d"),
+    ('\u{1D58A}', "e"),
+    ('\u{1D58B}', "f"),
+    ('\u{1D58C}', "g"),
+    ('\u{1D58D}', "h"),
+    ('\u{1D58E}', "i"),
+    ('\u{1D58F}', "j"),
+    ('\u{1D590}', "k"),
+    ('\u{1D591}', "l"),
+    ('\u{1D592}', "rn"),
+    ('\u{1D593}', "n"),
+    ('\u{1D594}', "o"),
+    ('\u{1D595}', "p"),
+    ('\u{1D596}', "q"),
+    ('\u{1D597}', "r"),
+    ('\u{1D598}', "s"),
+    ('\u{1D599}', "t"),
+    ('\u{1D59A}', "u"),
+    ('\u{1D59B}', "v"),
+    ('\u{1D59C}', "w"),
+    ('\u{1D59D}', "x"),
+    ('\u{1D59E}', "y"),
+    ('\u{1D59F}', "z"),
+    ('\u{1D5A0}', "A"),
+    ('\u{1D5A1}', "B"),
+    ('\u{1D5A2}', "C"),
+    ('\u{1D5A3}', "D"),
+    ('\u{1D5A4}', "E"),
+    ('\u{1D5A5}', "F"),
+    ('\u{1D5A6}', "G"),
+    ('\u{1D5A7}', "H"),
+    ('\u{1D5A8}', "l"),
+    ('\u{1D5A9}', "J"),
+    ('\u{1D5AA}', "K"),
+    ('\u{1D5AB}', "L"),
+    ('\u{1D5AC}', "M"),
+    ('\u{1D5AD}', "N"),
+    ('\u{1D5AE}', "O"),
+    ('\u{1D5AF}', "P"),
+    ('\u{1D5B0}', "Q"),
+    ('\u{1D5B1}', "R"),
+    ('\u{1D5B2}', "S"),
+    ('\u{1D5B3}', "T"),
+    ('\u{1D5B4}', "U"),
+    ('\u{1D5B5}', "V"),
+    ('\u{1D5B6}', "W"),
+    ('\u{1D5B7}', "X"),
+    ('\u{1D5B8}', "Y"),
+    ('\u{1D5B9}', "Z"),
+    ('\u{1D5BA}', "a"),
+    ('\u{1D5BB}', "b"),
+    ('\u{1D5BC}', "c"),
+    ('\u{1D5BD}', "d"),
+    ('\u{1D5BE}', "e"),
+    ('\u{1D5BF}', "f"),
+    ('\u{1D5C0}', "g"),
+    ('\u{1D5C1}', "h"),
+    ('\u{1D5C2}', "i"),
+    ('\u{1D5C3}', "j"),
+    ('\u{1D5C4}', "k"),
+    ('\u{1D5C5}', "l"),
+    ('\u{1D5C6}', "rn"),
+    ('\u{1D5C7}', "n"),
+    ('\u{1D5C8}', "o"),
+    ('\u{1D5C9}', "p"),
+    ('\u{1D5CA}', "q"),
+    ('\u{1D5CB}', "r"),
+    ('\u{1D5CC}', "s"),
+    ('\u{1D5CD}', "t"),
+    ('\u{1D5CE}', "u"),
+    ('\u{1D5CF}', "v"),
+    ('\u{1D5D0}', "w"),
+    ('\u{1D5D1}', "x"),
+    ('\u{1D5D2}', "y"),
+    ('\u{1D5D3}', "z"),
+    ('\u{1D5D4}', "A"),
+    ('\u{1D5D5}', "B"),
+    ('\u{1D5D6}', "C"),
+    ('\u{1D5D7}', "D"),
+    ('\u{1D5D8}', "E"),
+    ('\u{1D5D9}', "F"),
+    ('\u{1D5DA}', "G"),
+    ('\u{1D5DB}', "H"),
+    ('\u{1D5DC}', "l"),
+    ('\u{1D5DD}', "J"),
+    ('\u{1D5DE}', "K"),
+    ('\u{1D5DF}', "L"),
+    ('\u{1D5E0}', "M"),
+    ('\u{1D5E1}', "N"),
+    ('\u{1D5E2}', "O"),
+    ('\u{1D5E3}', "P"),
+    ('\u{1D5E4}', "Q"),
+    ('\u{1D5E5}', "R"),
+    ('\u{1D5E6}', "S"),
+    ('\u{1D5E7}', "T"),
+    ('\u{1D5E8}', "U"),
+    ('\u{1D5E9}', "V"),
+    ('\u{1D5EA}', "W"),
+    ('\u{1D5EB}', "X"),
+    ('\u{1D5EC}', "Y"),
+    ('\u{1D5ED}', "Z"),
+    ('\u{1D5EE}', "a"),
+    ('\u{1D5EF}', "b"),
+    ('\u{1D5F0}', "c"),
+    ('\u{1D5F1}', "d"),
+    ('\u{1D5F2}', "e"),
+    ('\u{1D5F3}', "f"),
+    ('\u{1D5F4}', "g"),
+    ('\u{1D5F5}', "h"),
+    ('\u{1D5F6}', "i"),
+    ('\u{1D5F7}', "j"),
+    ('\u{1D5F8}', "k"),
+    ('\u{1D5F9}', "l"),
+    ('\u{1D5FA}', "rn"),
+    ('\u{1D5FB}', "n"),
+    ('\u{1D5FC}', "o"),
+    ('\u{1D5FD}', "p"),
+    ('\u{1D5FE}', "q"),
+    ('\u{1D5FF}', "r"),
+    ('\u{1D600}', "s"),
+    ('\u{1D601}', "t"),
+    ('\u{1D602}', "u"),
+    ('\u{1D603}', "v"),
+    ('\u{1D604}', "w"),
+    ('\u{1D605}', "x"),
+    ('\u{1D606}', "y"),
+    ('\u{1D607}', "z"),
+    ('\u{1D608}', "A"),
+    ('\u{1D609}', "B"),
+    ('\u{1D60A}', "C"),
+    ('\u{1D60B}', "D"),
+    ('\u{1D60C}', "E"),
+    ('\u{1D60D}', "F"),
+    ('\u{1D60E}', "G"),
+    ('\u{1D60F}', "H"),
+    ('\u{1D610}', "l"),
+    ('\u{1D611}', "J"),
+    ('\u{1D612}', "K"),
+    ('\u{1D613}', "L"),
+    ('\u{1D614}', "M"),
+    ('\u{1D615}', "N"),
+    ('\u{1D616}', "O"),
+    ('\u{1D617}', "P"),
+    ('\u{1D618}', "Q"),
+    ('\u{1D619}', "R"),
+    ('\u{1D61A}', "S"),
+    ('\u{1D61B}', "T"),
+    ('\u{1D61C}', "U"),
+    ('\u{1D61D}', "V"),
+    ('\u{1D61E}', "W"),
+    ('\u{1D61F}', "X"),
+    ('\u{1D620}', "Y"),
+    ('\u{1D621}', "Z"),
+    ('\u{1D622}', "a"),
+    ('\u{1D623}', "b"),
+    ('\u{1D624}', "c"),
+    ('\u{1D625}', "d"),
+    ('\u{1D626}', "e"),
+    ('\u{1D627}', "f"),
+    ('\u{1D628}', "g"),
+    ('\u{1D629}', "h"),
+    ('\u{1D62A}', "i"),
+    ('\u{1D62B}', "j"),
+    ('\u{1D62C}', "k"),
+    ('\u{1D62D}', "l"),
+    ('\u{1D62E}', "rn"),
+    ('\u{1D62F}', "n"),
+    ('\u{1D630}', "o"),
+    ('\u{1D631}', "p"),
+    ('\u{1D632}', "q"),
+    ('\u{1D633}', "r"),
+    ('\u{1D634}', "s"),
+    ('\u{1D635}', "t"),
+    ('\u{1D636}', "u"),
+    ('\u{1D637}', "v"),
+    ('\u{1D638}', "w"),
+    ('\u{1D639}', "x"),
+    ('\u{1D63A}', "y"),
+    ('\u{1D63B}', "z"),
+    ('\u{1D63C}', "A"),
+    ('\u{1D63D}', "B"),
+    ('\u{1D63E}', "C"),
+    ('\u{1D63F}', "D"),
+    ('\u{1D640}', "E"),
+    ('\u{1D641}', "F"),
+    ('\u{1D642}', "G"),
+    ('\u{1D643}', "H"),
+    ('\u{1D644}', "l"),
+    ('\u{1D645}', "J"),
+    ('\u{1D646}', "K"),
+    ('\u{1D647}', "L"),
+    ('\u{1D648}', "M"),
+    ('\u{1D649}', "N"),
+    ('\u{1D64A}', "O"),
+    ('\u{1D64B}', "P"),
+    ('\u{1D64C}', "Q"),
+    ('\u{1D64D}', "R"),
+    ('\u{1D64E}', "S"),
+    ('\u{1D64F}', "T"),
+    ('\u{1D650}', "U"),
+    ('\u{1D651}', "V"),
+    ('\u{1D652}', "W"),
+    ('\u{1D653}', "X"),
+    ('\u{1D654}', "Y"),
+    ('\u{1D655}', "Z"),
+    ('\u{1D656}', "a"),
+    ('\u{1D657}', "b"),
+    ('\u{1D658}', "c"),
+    ('\u{1D659}', "d"),
+    ('\u{1D65A}', "e"),
+    ('\u{1D65B}', "f"),
+    ('\u{1D65C}', "g"),
+    ('\u{1D65D}', "h"),
+    ('\u{1D65E}', "i"),
+    ('\u{1D65F}', "j"),
+    ('\u{1D660}', "k"),
+    ('\u{1D661}', "l"),
+    ('\u{1D662}', "rn"),
+    ('\u{1D663}', "n"),
+    ('\u{1D664}', "o"),
+    ('\u{1D665}', "p"),
+    ('\u{1D666}', "q"),
+    ('\u{1D667}', "r"),
+    ('\u{1D668}', "s"),
+    ('\u{1D669}', "t"),
+    ('\u{1D66A}', "u"),
+    ('\u{1D66B}', "v"),
+    ('\u{1D66C}', "w"),
+    ('\u{1D66D}', "x"),
+    ('\u{1D66E}', "y"),
+    ('\u{1D66F}', "z"),
+    ('\u{1D670}', "A"),
+    ('\u{1D671}', "B"),
+    ('\u{1D672}', "C"),
+    ('\u{1D673}', "D"),
+    ('\u{1D674}', "E"),
+    ('\u{1D675}', "F"),
+    ('\u{1D676}', "G"),
+    ('\u{1D677}', "H"),
+    ('\u{1D678}', "l"),
+    ('\u{1D679}', "J"),
+    ('\u{1D67A}', "K"),
+    ('\u{1D67B}', "L"),
+    ('\u{1D67C}', "M"),
+    ('\u{1D67D}', "N"),
+    ('\u{1D67E}', "O"),
+    ('\u{1D67F}', "P"),
+    ('\u{1D680}', "Q"),
+    ('\u{1D681}', "R"),
+    ('\u{1D682}', "S"),
+    ('\u{1D683}', "T"),
+    ('\u{1D684}', "U"),
+    ('\u{1D685}', "V"),
+    ('\u{1D686}', "W"),
+    ('\u{1D687}', "X"),
+    ('\u{1D688}', "Y"),
+    ('\u{1D689}', "Z"),
+    ('\u{1D68A}', "a"),
+    ('\u{1D68B}', "b"),
+    ('\u{1D68C}', "c"),
+    ('\u{1D68D}', "d"),
+    ('\u{1D68E}', "e"),
+    ('\u{1D68F}', "f"),
+    ('\u{1D690}', "g"),
+    ('\u{1D691}', "h"),
+    ('\u{1D692}', "i"),
+    ('\u{1D693}', "j"),
+    ('\u{1D694}', "k"),
+    ('\u{1D695}', "l"),
+    ('\u{1D696}', "rn"),
+    ('\u{1D697}', "n"),
+    ('\u{1D698}', "o"),
+    ('\u{1D699}', "p"),
+    ('\u{1D69A}', "q"),
+    ('\u{1D69B}', "r"),
+    ('\u{1D69C}', "s"),
+    ('\u{1D69D}', "t"),
+    ('\u{1D69E}', "u"),
+    ('\u{1D69F}', "v"),
+    ('\u{1D6A0}', "w"),
+    ('\u{1D6A1}', "x"),
+    ('\u{1D6A2}', "y"),
+    ('\u{1D6A3}', "z"),
+    ('\u{1D6A4}', "i"),
+    ('\u{1D6A5}', "\u{237}"),
+    ('\u{1D6A8}', "A"),
+    ('\u{1D6A9}', "B"),
+    ('\u{1D6AA}', "\u{393}"),
+    ('\u{1D6AB}', "\u{394}"),
+    ('\u{1D6AC}', "E"),
+    ('\u{1D6AD}', "Z"),
+    ('\u{1D6AE}', "H"),
+    ('\u{1D6AF}', "O\u{335}"),
+    ('\u{1D6B0}', "l"),
+    ('\u{1D6B1}', "K"),
+    ('\u{1D6B2}', "\u{245}"),
+    ('\u{1D6B3}', "M"),
+    ('\u{1D6B4}', "N"),
+    ('\u{1D6B5}', "\u{39E}"),
+    ('\u{1D6B6}', "O"),
+    ('\u{1D6B7}', "\u{3A0}"),
+    ('\u{1D6B8}', "P"),
+    ('\u{1D6B9}', "O\u{335}"),
+    ('\u{1D6BA}', "\u{1A9}"),
+    ('\u{1D6BB}', "T"),
+    ('\u{1D6BC}', "Y"),
+    ('\u{1D6BD}', "\u{3A6}"),
+    ('\u{1D6BE}', "X"),
+    ('\u{1D6BF}', "\u{3A8}"),
+    ('\u{1D6C0}', "\u{3A9}"),
+    ('\u{1D6C1}', "\u{2207}"),
+    ('\u{1D6C2}', "a"),
+    ('\u{1D6C3}', "\u{DF}"),
+    ('\u{1D6C4}', "y"),
+    ('\u{1D6C5}', "\u{1E9F}"),
+    ('\u{1D6C6}', "\u{A793}"),
+    ('\u{1D6C7}', "\u{3B6}"),
+    ('\u{1D6C8}', "n\u{329}"),
+    ('\u{1D6C9}', "O\u{335}"),
+    ('\u{1D6CA}', "i"),
+    ('\u{1D6CB}', "\u{138}"),
+    ('\u{1D6CC}', "\u{3BB}"),
+    ('\u{1D6CD}', "\u{3BC}"),
+    ('\u{1D6CE}', "v"),
+    ('\u{1D6CF}', "\u{3BE}"),
+    ('\u{1D6D0}', "o"),
+    ('\u{1D6D1}', "\u{3C0}"),
+    ('\u{1D6D2}', "p"),
+    ('\u{1D6D3}', "\u{3C2}"),
+    ('\u{1D6D4}', "o"),
+    ('\u{1D6D5}', "\u{1D1B}"),
+    ('\u{1D6D6}', "u"),
+    ('\u{1D6D7}', "\u{278}"),
+    ('\u{1D6D8}', "\u{3C7}"),
+    ('\u{1D6D9}', "\u{3C8}"),
+    ('\u{1D6DA}', "\u{3C9}"),
+    ('\u{1D6DB}', "\u{2202}"),
+    ('\u{1D6DC}', "\u{A793}"),
+    ('\u{1D6DD}', "O\u{335}"),
+    ('\u{1D6DE}', "\u{138}"),
+    ('\u{1D6DF}', "\u{278}"),
+    ('\u{1D6E0}', "p"),
+    ('\u{1D6E1}', "\u{3C0}"),
+    ('\u{1D6E2}', "A"),
+    ('\u{1D6E3}', "B"),
+    ('\u{1D6E4}', "\u{393}"),
+    ('\u{1D6E5}', "\u{394}"),
+    ('\u{1D6E6}', "E"),
+    ('\u{1D6E7}', "Z"),
+    ('\u{1D6E8}', "H"),
+    ('\u{1D6E9}', "O\u{335}"),
+    ('\u{1D6EA}', "l"),
+    ('\u{1D6EB}', "K"),
+    ('\u{1D6EC}', "\u{245}"),
+    ('\u{1D6ED}', "M"),
+    ('\u{1D6EE}', "N"),
+    ('\u{1D6EF}', "\u{39E}"),
+    ('\u{1D6F0}', "O"),
+    ('\u{1D6F1}', "\u{3A0}"),
+    ('\u{1D6F2}', "P"),
+    ('\u{1D6F3}', "O\u{335}"),
+    ('\u{1D6F4}', "\u{1A9}"),
+    ('\u{1D6F5}', "T"),
+    ('\u{1D6F6}', "Y"),
+    ('\u{1D6F7}', "\u{3A6}"),
+    ('\u{1D6F8}', "X"),
+    ('\u{1D6F9}', "\u{3A8}"),
+    ('\u{1D6FA}', "\u{3A9}"),
+    ('\u{1D6FB}', "\u{2207}"),
+    ('\u{1D6FC}', "a"),
+    ('\u{1D6FD}', "\u{DF}"),
+    ('\u{1D6FE}', "y"),
+    ('\u{1D6FF}', "\u{1E9F}"),
+    ('\u{1D700}', "\u{A793}"),
+    ('\u{1D701}', "\u{3B6}"),
+    ('\u{1D702}', "n\u{329}"),
+    ('\u{1D703}', "O\u{335}"),
+    ('\u{1D704}', "i"),
+    ('\u{1D705}', "\u{138}"),
+    ('\u{1D706}', "\u{3BB}"),
+    ('\u{1D707}', "\u{3BC}"),
+    ('\u{1D708}', "v"),
+    ('\u{1D709}', "\u{3BE}"),
+    ('\u{1D70A}', "o"),
+    ('\u{1D70B}', "\u{3C0}"),
+    ('\u{1D70C}', "p"),
+    ('\u{1D70D}', "\u{3C2}"),
+    ('\u{1D70E}', "o"),
+    ('\u{1D70F}', "\u{1D1B}"),
+    ('\u{1D710}', "u"),
+    ('\u{1D711}', "\u{278}"),
+    ('\u{1D712}', "\u{3C7}"),
+    ('\u{1D713}', "\u{3C8}"),
+    ('\u{1D714}', "\u{3C9}"),
+    ('\u{1D715}', "\u{2202}"),
+    ('\u{1D716}', "\u{A793}"),
+    ('\u{1D717}', "O\u{335}"),
+    ('\u{1D718}', "\u{138}"),
+    ('\u{1D719}', "\u{278}"),
+    ('\u{1D71A}', "p"),
+    ('\u{1D71B}', "\u{3C0}"),
+    ('\u{1D71C}', "A"),
+    ('\u{1D71D}', "B"),
+    ('\u{1D71E}', "\u{393}"),
+    ('\u{1D71F}', "\u{394}"),
+    ('\u{1D720}', "E"),
+    ('\u{1D721}', "Z"),
+    ('\u{1D722}', "H"),
+    ('\u{1D723}', "O\u{335}"),
+    ('\u{1D724}', "l"),
+    ('\u{1D725}', "K"),
+    ('\u{1D726}', "\u{245}"),
+    ('\u{1D727}', "M"),
+    ('\u{1D728}', "N"),
+    ('\u{1D729}', "\u{39E}"),
+    ('\u{1D72A}', "O"),
+    ('\u{1D72B}', "\u{3A0}"),
+    ('\u{1D72C}', "P"),
+    ('\u{1D72D}', "O\u{335}"),
+    ('\u{1D72E}', "\u{1A9}"),
+    ('\u{1D72F}', "T"),
+    ('\u{1D730}', "Y"),
+    ('\u{1D731}', "\u{3A6}"),
+    ('\u{1D732}', "X"),
+    ('\u{1D733}', "\u{3A8}"),
+    ('\u{1D734}', "\u{3A9}"),
+    ('\u{1D735}', "\u{2207}"),
+    ('\u{1D736}', "a"),
+    ('\u{1D737}', "\u{DF}"),
+    ('\u{1D738}', "y"),
+    ('\u{1D739}', "\u{1E9F}"),
+    ('\u{1D73A}', "\u{A793}"),
+    ('\u{1D73B}', "\u{3B6}"),
+    ('\u{1D73C}', "n\u{329}"),
+    ('\u{1D73D}', "O\u{335}"),
+    ('\u{1D73E}', "i"),
+    ('\u{1D73F}', "\u{138}"),
+    ('\u{1D740}', "\u{3BB}"),
+    ('\u{1D741}', "\u{3BC}"),
+    ('\u{1D742}', "v"),
+    ('\u{1D743}', "\u{3BE}"),
+    ('\u{1D744}', "o"),
+    ('\u{1D745}', "\u{3C0}"),
+    ('\u{1D746}', "p"),
+    ('\u{1D747}', "\u{3C2}"),
+    ('\u{1D748}', "o"),
+    ('\u{1D749}', "\u{1D1B}"),
+    ('\u{1D74A}', "u"),
+    ('\u{1D74B}', "\u{278}"),
+    ('\u{1D74C}', "\u{3C7}"),
+    ('\u{1D74D}', "\u{3C8}"),
+    ('\u{1D74E}', "\u{3C9}"),
+    ('\u{1D74F}', "\u{2202}"),
+    ('\u{1D750}', "\u{A793}"),
+    ('\u{1D751}', "O\u{335}"),
+    ('\u{1D752}', "\u{138}"),
+    ('\u{1D753}', "\u{278}"),
+    ('\u{1D754}', "p"),
+    ('\u{1D755}', "\u{3C0}"),
+    ('\u{1D756}', "A"),
+    ('\u{1D757}', "B"),
+    ('\u{1D758}', "\u{393}"),
+    ('\u{1D759}', "\u{394}"),
+    ('\u{1D75A}', "E"),
+    ('\u{1D75B}', "Z"),
+    ('\u{1D75C}', "H"),
+    ('\u{1D75D}', "O\u{335}"),
+    ('\u{1D75E}', "l"),
+    ('\u{1D75F}', "K"),
+    ('\u{1D760}', "\u{245}"),
+    ('\u{1D761}', "M"),
+    ('\u{1D762}', "N"),
+    ('\u{1D763}', "\u{39E}"),
+    ('\u{1D764}', "O"),
+    ('\u{1D765}', "\u{3A0}"),
+    ('\u{1D766}', "P"),
+    ('\u{1D767}', "O\u{335}"),
+    ('\u{1D768}', "\u{1A9}"),
+    ('\u{1D769}', "T"),
+    ('\u{1D76A}', "Y"),
+    ('\u{1D76B}', "\u{3A6}"),
+    ('\u{1D76C}', "X"),
+    ('\u{1D76D}', "\u{3A8}"),
+    ('\u{1D76E}', "\u{3A9}"),
+    ('\u{1D76F}', "\u{2207}"),
+    ('\u{1D770}', "a"),
+    ('\u{1D771}', "\u{DF}"),
+    ('\u{1D772}', "y"),
+    ('\u{1D773}', "\u{1E9F}"),
+    ('\u{1D774}', "\u{A793}"),
+    ('\u{1D775}', "\u{3B6}"),
+    ('\u{1D776}', "n\u{329}"),
+    ('\u{1D777}', "O\u{335}"),
+    ('\u{1D778}', "i"),
+    ('\u{1D779}', "\u{138}"),
+    ('\u{1D77A}', "\u{3BB}"),
+    ('\u{1D77B}', "\u{3BC}"),
+    ('\u{1D77C}', "v"),
+    ('\u{1D77D}', "\u{3BE}"),
+    ('\u{1D77E}', "o"),
+    ('\u{1D77F}', "\u{3C0}"),
+    ('\u{1D780}', "p"),
+    ('\u{1D781}', "\u{3C2}"),
+    ('\u{1D782}', "o"),
+    ('\u{1D783}', "\u{1D1B}"),
+    ('\u{1D784}', "u"),
+    ('\u{1D785}', "\u{278}"),
+    ('\u{1D786}', "\u{3C7}"),
+    ('\u{1D787}', "\u{3C8}"),
+    ('\u{1D788}', "\u{3C9}"),
+    ('\u{1D789}', "\u{2202}"),
+    ('\u{1D78A}', "\u{A793}"),
+    ('\u{1D78B}', "O\u{335}"),
+    ('\u{1D78C}', "\u{138}"),
+    ('\u{1D78D}', "\u{278}"),
+    ('\u{1D78E}', "p"),
+    ('\u{1D78F}', "\u{3C0}"),
+    ('\u{1D790}', "A"),
+    ('\u{1D791}', "B"),
+    ('\u{1D792}', "\u{393}"),
+    ('\u{1D793}', "\u{394}"),
+    ('\u{1D794}', "E"),
+    ('\u{1D795}', "Z"),
+    ('\u{1D796}', "H"),
+    ('\u{1D797}', "O\u{335}"),
+    ('\u{1D798}', "l"),
+    ('\u{1D799}', "K"),
+    ('\u{1D79A}', "\u{245}"),
+    ('\u{1D79B}', "M"),
+    ('\u{1D79C}', "N"),
+    ('\u{1D79D}', "\u{39E}"),
+    ('\u{1D79E}', "O"),
+    ('\u{1D79F}', "\u{3A0}"),
+    ('\u{1D7A0}', "P"),
+    ('\u{1D7A1}', "O\u{335}"),
+    ('\u{1D7A2}', "\u{1A9}"),
+    ('\u{1D7A3}', "T"),
+    ('\u{1D7A4}', "Y"),
+    ('\u{1D7A5}', "\u{3A6}"),
+    ('\u{1D7A6}', "X"),
+    ('\u{1D7A7}', "\u{3A8}"),
+    ('\u{1D7A8}', "\u{3A9}"),
+    ('\u{1D7A9}', "\u{2207}"),
+    ('\u{1D7AA}', "a"),
+    ('\u{1D7AB}', "\u{DF}"),
+    ('\u{1D7AC}', "y"),
+    ('\u{1D7AD}', "\u{1E9F}"),
+    ('\u{1D7AE}', "\u{A793}"),
+    ('\u{1D7AF}', "\u{3B6}"),
+    ('\u{1D7B0}', "n\u{329}"),
+    ('\u{1D7B1}', "O\u{335}"),
+    ('\u{1D7B2}', "i"),
+    ('\u{1D7B3}', "\u{138}"),
+    ('\u{1D7B4}', "\u{3BB}"),
+    ('\u{1D7B5}', "\u{3BC}"),
+    ('\u{1D7B6}', "v"),
+    ('\u{1D7B7}', "\u{3BE}"),
+    ('\u{1D7B8}', "o"),
+    ('\u{1D7B9}', "\u{3C0}"),
+    ('\u{1D7BA}', "p"),
+    ('\u{1D7BB}', "\u{3C2}"),
+    ('\u{1D7BC}', "o"),
+    ('\u{1D7BD}', "\u{1D1B}"),
+    ('\u{1D7BE}', "u"),
+    ('\u{1D7BF}', "\u{278}"),
+    ('\u{1D7C0}', "\u{3C7}"),
+    ('\u{1D7C1}', "\u{3C8}"),
+    ('\u{1D7C2}', "\u{3C9}"),
+    ('\u{1D7C3}', "\u{2202}"),
+    ('\u{1D7C4}', "\u{A793}"),
+    ('\u{1D7C5}', "O\u{335}"),
+    ('\u{1D7C6}', "\u{138}"),
+    ('\u{1D7C7}', "\u{278}"),
+    ('\u{1D7C8}', "p"),
+    ('\u{1D7C9}', "\u{3C0}"),
+    ('\u{1D7CA}', "F"),
+    ('\u{1D7CB}', "\u{3DD}"),
+    ('\u{1D7CE}', "O"),
+    ('\u{1D7CF}', "l"),
+    ('\u{1D7D0}', "2"),
+    ('\u{1D7D1}', "3"),
+    ('\u{1D7D2}', "4"),
+    ('\u{1D7D3}', "5"),
+    ('\u{1D7D4}', "6"),
+    ('\u{1D7D5}', "7"),
+    ('\u{1D7D6}', "8"),
+    ('\u{1D7D7}', "9"),
+    ('\u{1D7D8}', "O"),
+    ('\u{1D7D9}', "l"),
+    ('\u{1D7DA}', "2"),
+    ('\u{1D7DB}', "3"),
+    ('\u{1D7DC}', "4"),
+    ('\u{1D7DD}', "5"),
+    ('\u{1D7DE}', "6"),
+    ('\u{1D7DF}', "7"),
+    ('\u{1D7E0}', "8"),
+    ('\u{1D7E1}', "9"),
+    ('\u{1D7E2}', "O"),
+    ('\u{1D7E3}', "l"),
+    ('\u{1D7E4}', "2"),
+    ('\u{1D7E5}', "3"),
+    ('\u{1D7E6}', "4"),
+    ('\u{1D7E7}', "5"),
+    ('\u{1D7E8}', "6"),
+    ('\u{1D7E9}', "7"),
+    ('\u{1D7EA}', "8"),
+    ('\u{1D7EB}', "9"),
+    ('\u{1D7EC}', "O"),
+    ('\u{1D7ED}', "l"),
+    ('\u{1D7EE}', "2"),
+    ('\u{1D7EF}', "3"),
+    ('\u{1D7F0}', "4"),
+    ('\u{1D7F1}', "5"),
+    ('\u{1D7F2}', "6"),
+    ('\u{1D7F3}', "7"),
+    ('\u{1D7F4}', "8"),
+    ('\u{1D7F5}', "9"),
+    ('\u{1D7F6}', "O"),
+    ('\u{1D7F7}', "l"),
+    ('\u{1D7F8}', "2"),
+    ('\u{1D7F9}', "3"),
+    ('\u{1D7FA}', "4"),
+    ('\u{1D7FB}', "5"),
+    ('\u{1D7FC}', "6"),
+    ('\u{1D7FD}', "7"),
+    ('\u{1D7FE}', "8"),
+    ('\u{1D7FF}', "9"),
+    ('\u{1E8C7}', "l"),
+    ('\u{1E8C8}', "\u{2220}"),
+    ('\u{1E8C9}', "\u{663}"),
+    ('\u{1E8CB}', "8"),
+    ('\u{1E8CC}', "\u{2202}"),
+    ('\u{1E8CD}', "\u{2202}\u{335}"),
+    ('\u{1EE00}', "l"),
+    ('\u{1EE01}', "\u{628}"),
+    ('\u{1EE02}', "\u{62C}"),
+    ('\u{1EE03}', "\u{62F}"),
+    ('\u{1EE05}', "\u{648}"),
+    ('\u{1EE06}', "\u{632}"),
+    ('\u{1EE07}', "\u{62D}"),
+    ('\u{1EE08}', "\u{637}"),
+    ('\u{1EE09}', "\u{649}"),
+    ('\u{1EE0A}', "\u{643}"),
+    ('\u{1EE0B}', "\u{644}"),
+    ('\u{1EE0C}', "\u{645}"),
+    ('\u{1EE0D}', "\u{646}"),
+    ('\u{1EE0E}', "\u{633}"),
+    ('\u{1EE0F}', "\u{639}"),
+    ('\u{1EE10}', "\u{641}"),
+    ('\u{1EE11}', "\u{635}"),
+    ('\u{1EE12}', "\u{642}"),
+    ('\u{1EE13}', "\u{631}"),
+    ('\u{1EE14}', "\u{633}\u{6DB}"),
+    ('\u{1EE15}', "\u{62A}"),
+    ('\u{1EE16}', "\u{649}\u{6DB}"),
+    ('\u{1EE17}', "\u{62E}"),
+    ('\u{1EE18}', "\u{630}"),
+    ('\u{1EE19}', "\u{636}"),
+    ('\u{1EE1A}', "\u{638}"),
+    ('\u{1EE1B}', "\u{63A}"),
+    ('\u{1EE1C}', "\u{649}"),
+    ('\u{1EE1D}', "\u{649}"),
+    ('\u{1EE1E}', "\u{6A1}"),
+    ('\u{1EE1F}', "\u{6A1}"),
+    ('\u{1EE21}', "\u{628}"),
+    ('\u{1EE22}', "\u{62C}"),
+    ('\u{1EE24}', "o"),
+    ('\u{1EE27}', "\u{62D}"),
+    ('\u{1EE29}', "\u{649}"),
+    ('\u{1EE2A}', "\u{643}"),
+    ('\u{1EE2B}', "\u{644}"),
+    ('\u{1EE2C}', "\u{645}"),
+    ('\u{1EE2D}', "\u{646}"),
+    ('\u{1EE2E}', "\u{633}"),
+    ('\u{1EE2F}', "\u{639}"),
+    ('\u{1EE30}', "\u{641}"),
+    ('\u{1EE31}', "\u{635}"),
+    ('\u{1EE32}', "\u{642}"),
+    ('\u{1EE34}', "\u{633}\u{6DB}"),
+    ('\u{1EE35}', "\u{62A}"),
+    ('\u{1EE36}', "\u{649}\u{6DB}"),
+    ('\u{1EE37}', "\u{62E}"),
+    ('\u{1EE39}', "\u{636}"),
+    ('\u{1EE3B}', "\u{63A}"),
+    ('\u{1EE42}', "\u{62C}"),
+    ('\u{1EE47}', "\u{62D}"),
+    ('\u{1EE49}', "\u{649}"),
+    ('\u{1EE4B}', "\u{644}"),
+    ('\u{1EE4D}', "\u{646}"),
+    ('\u{1EE4E}', "\u{633}"),
+    ('\u{1EE4F}', "\u{639}"),
+    ('\u{1EE51}', "\u{635}"),
+    ('\u{1EE52}', "\u{642}"),
+    ('\u{1EE54}', "\u{633}\u{6DB}"),
+    ('\u{1EE57}', "\u{62E}"),
+    ('\u{1EE59}', "\u{636}"),
+    ('\u{1EE5B}', "\u{63A}"),
+    ('\u{1EE5D}', "\u{649}"),
+    ('\u{1EE5F}', "\u{6A1}"),
+    ('\u{1EE61}', "\u{628}"),
+    ('\u{1EE62}', "\u{62C}"),
+    ('\u{1EE64}', "o"),
+    ('\u{1EE67}', "\u{62D}"),
+    ('\u{1EE68}', "\u{637}"),
+    ('\u{1EE69}', "\u{649}"),
+    ('\u{1EE6A}', "\u{643}"),
+    ('\u{1EE6C}', "\u{645}"),
+    ('\u{1EE6D}', "\u{646}"),
+    ('\u{1EE6E}', "\u{633}"),
+    ('\u{1EE6F}', "\u{639}"),
+    ('\u{1EE70}', "\u{641}"),
+    ('\u{1EE71}', "\u{635}"),
+    ('\u{1EE72}', "\u{642}"),
+    ('\u{1EE74}', "\u{633}\u{6DB}"),
+    ('\u{1EE75}', "\u{62A}"),
+    ('\u{1EE76}', "\u{649}\u{6DB}"),
+    ('\u{1EE77}', "\u{62E}"),
+    ('\u{1EE79}', "\u{636}"),
+    ('\u{1EE7A}', "\u{638}"),
+    ('\u{1EE7B}', "\u{63A}"),
+    ('\u{1EE7C}', "\u{649}"),
+    ('\u{1EE7E}', "\u{6A1}"),
+    ('\u{1EE80}', "l"),
+    ('\u{1EE81}', "\u{628}"),
+    ('\u{1EE82}', "\u{62C}"),
+    ('\u{1EE83}', "\u{62F}"),
+    ('\u{1EE84}', "o"),
+    ('\u{1EE85}', "\u{648}"),
+    ('\u{1EE86}', "\u{632}"),
+    ('\u{1EE87}', "\u{62D}"),
+    ('\u{1EE88}', "\u{637}"),
+    ('\u{1EE89}', "\u{649}"),
+    ('\u{1EE8B}', "\u{644}"),
+    ('\u{1EE8C}', "\u{645}"),
+    ('\u{1EE8D}', "\u{646}"),
+    ('\u{1EE8E}', "\u{633}"),
+    ('\u{1EE8F}', "\u{639}"),
+    ('\u{1EE90}', "\u{641}"),
+    ('\u{1EE91}', "\u{635}"),
+    ('\u{1EE92}', "\u{642}"),
+    ('\u{1EE93}', "\u{631}"),
+    ('\u{1EE94}', "\u{633}\u{6DB}"),
+    ('\u{1EE95}', "\u{62A}"),
+    ('\u{1EE96}', "\u{649}\u{6DB}"),
+    ('\u{1EE97}', "\u{62E}"),
+    ('\u{1EE98}', "\u{630}"),
+    ('\u{1EE99}', "\u{636}"),
+    ('\u{1EE9A}', "\u{638}"),
+    ('\u{1EE9B}', "\u{63A}"),
+    ('\u{1EEA1}', "\u{628}"),
+    ('\u{1EEA2}', "\u{62C}"),
+    ('\u{1EEA3}', "\u{62F}"),
+    ('\u{1EEA5}', "\u{648}"),
+    ('\u{1EEA6}', "\u{632}"),
+    ('\u{1EEA7}', "\u{62D}"),
+    ('\u{1EEA8}', "\u{637}"),
+    ('\u{1EEA9}', "\u{649}"),
+    ('\u{1EEAB}', "\u{644}"),
+    ('\u{1EEAC}', "\u{645}"),
+    ('\u{1EEAD}', "\u{646}"),
+    ('\u{1EEAE}', "\u{633}"),
+    ('\u{1EEAF}', "\u{639}"),
+    ('\u{1EEB0}', "\u{641}"),
+    ('\u{1EEB1}', "\u{635}"),
+    ('\u{1EEB2}', "\u{642}"),
+    ('\u{1EEB3}', "\u{631}"),
+    ('\u{1EEB4}', "\u{633}\u{6DB}"),
+    ('\u{1EEB5}', "\u{62A}"),
+    ('\u{1EEB6}', "\u{649}\u{6DB}"),
+    ('\u{1EEB7}', "\u{62E}"),
+    ('\u{1EEB8}', "\u{630}"),
+    ('\u{1EEB9}', "\u{636}"),
+    ('\u{1EEBA}', "\u{638}"),
+    ('\u{1EEBB}', "\u{63A}"),
+    ('\u{1F100}', "O."),
+    ('\u{1F101}', "O,"),
+    ('\u{1F102}', "l,"),
+    ('\u{1F103}', "2,"),
+    ('\u{1F104}', "3,"),
+    ('\u{1F105}', "4,"),
+    ('\u{1F106}', "5,"),
+    ('\u{1F107}', "6,"),
+    ('\u{1F108}', "7,"),
+    ('\u{1F109}', "8,"),
+    ('\u{1F10A}', "9,"),
+    ('\u{1F10F}', "$\u{20E0}"),
+    ('\u{1F110}', "(A)"),
+    ('\u{1F111}', "(B)"),
+    ('\u{1F112}', "(C)"),
+    ('\u{1F113}', "(D)"),
+    ('\u{1F114}', "(E)"),
+    ('\u{1F115}', "(F)"),
+    ('\u{1F116}', "(G)"),
+    ('\u{1F117}', "(H)"),
+    ('\u{1F118}', "(l)"),
+    ('\u{1F119}', "(J)"),
+    ('\u{1F11A}', "(K)"),
+    ('\u{1F11B}', "(L)"),
+    ('\u{1F11C}', "(M)"),
+    ('\u{1F11D}', "(N)"),
+    ('\u{1F11E}', "(O)"),
+    ('\u{1F11F}', "(P)"),
+    ('\u{1F120}', "(Q)"),
+    ('\u{1F121}', "(R)"),
+    ('\u{1F122}', "(S)"),
+    ('\u{1F123}', "(T)"),
+    ('\u{1F124}', "(U)"),
+    ('\u{1F125}', "(V)"),
+    ('\u{1F126}', "(W)"),
+    ('\u{1F127}', "(X)"),
+    ('\u{1F128}', "(Y)"),
+    ('\u{1F129}', "(Z)"),
+    ('\u{1F12A}', "(S)"),
+    ('\u{1F16D}', "\u{33C4}\u{9}\u{20DD}"),
+    ('\u{1F16E}', "C\u{20E0}"),
+    ('\u{1F240}', "(\u{672C})"),
+    ('\u{1F241}', "(\u{4E09})"),
+    ('\u{1F242}', "(\u{4E8C})"),
+    ('\u{1F243}', "(\u{5B89})"),
+    ('\u{1F244}', "(\u{70B9})"),
+    ('\u{1F245}', "(\u{6253})"),
+    ('\u{1F246}', "(\u{76D7})"),
+    ('\u{1F247}', "(\u{52DD})"),
+    ('\u{1F248}', "(\u{6557})"),
+    ('\u{1F312}', "\u{263D}"),
+    ('\u{1F318}', "\u{263E}"),
+    ('\u{1F319}', "\u{263D}"),
+    ('\u{1F700}', "QE"),
+    ('\u{1F701}', "\u{A658}"),
+    ('\u{1F702}', "\u{394}"),
+    ('\u{1F704}', "\u{102BC}"),
+    ('\u{1F707}', "AR"),
+    ('\u{1F708}', "V\u{1DE4}"),
+    ('\u{1F70A}', "\u{2629}"),
+    ('\u{1F714}', "O\u{335}"),
+    ('\u{1F728}', "\u{102A8}"),
+    ('\u{1F73A}', "\u{29DF}"),
+    ('\u{1F74C}', "C"),
+    ('\u{1F754}', "\u{16DC}"),
+    ('\u{1F755}', "\u{22A1}"),
+    ('\u{1F75C}', "sss"),
+    ('\u{1F75E}', "\u{224F}"),
+    ('\u{1F768}', "T"),
+    ('\u{1F76B}', "MB"),
+    ('\u{1F76C}', "VB"),
+    ('\u{1F771}', "\u{22A0}"),
+    ('\u{1FBF0}', "O"),
+    ('\u{1FBF1}', "l"),
+    ('\u{1FBF2}', "2"),
+    ('\u{1FBF3}', "3"),
+    ('\u{1FBF4}', "4"),
+    ('\u{1FBF5}', "5"),
+    ('\u{1FBF6}', "6"),
+    ('\u{1FBF7}', "7"),
+    ('\u{1FBF8}', "8"),
+    ('\u{1FBF9}', "9"),
+    ('\u{21FE8}', "\u{276C}"),
+    ('\u{2F800}', "\u{4E3D}"),
+    ('\u{2F801}', "\u{4E38}"),
+    ('\u{2F802}', "\u{4E41}"),
+    ('\u{2F803}', "\u{20122}"),
+    ('\u{2F804}', "\u{4F60}"),
+    ('\u{2F805}', "\u{4FAE}"),
+    ('\u{2F806}', "\u{4FBB}"),
+    ('\u{2F807}', "\u{4F75}"),
+    ('\u{2F808}', "\u{507A}"),
+    ('\u{2F809}', "\u{5099}"),
+    ('\u{2F80A}', "\u{50E7}"),
+    ('\u{2F80B}', "\u{50CF}"),
+    ('\u{2F80C}', "\u{349E}"),
+    ('\u{2F80D}', "\u{2063A}"),
+    ('\u{2F80E}', "\u{514D}"),
+    ('\u{2F80F}', "\u{5154}"),
+    ('\u{2F810}', "\u{5164}"),
+    ('\u{2F811}', "\u{5177}"),
+    ('\u{2F812}', "\u{2051C}"),
+    ('\u{2F813}', "\u{34B9}"),
+    ('\u{2F814}', "\u{5167}"),
+    ('\u{2F815}', "\u{518D}"),
+    ('\u{2F816}', "\u{2054B}"),
+    ('\u{2F817}', "\u{5197}"),
+    ('\u{2F818}', "\u{51A4}"),
+    ('\u{2F819}', "\u{4ECC}"),
+    ('\u{2F81A}', "\u{51AC}"),
+    ('\u{2F81B}', "\u{51B5}"),
+    ('\u{2F81C}', "\u{291DF}"),
+    ('\u{2F81D}', "\u{51F5}"),
+    ('\u{2F81E}', "\u{5203}"),
+    ('\u{2F81F}', "\u{34DF}"),
+    ('\u{2F820}', "\u{523B}"),
+    ('\u{2F821}', "\u{5246}"),
+    ('\u{2F822}', "\u{5272}"),
+    ('\u{2F823}', "\u{5277}"),
+    ('\u{2F824}', "\u{3515}"),
+    ('\u{2F825}', "\u{52C7}"),
+    ('\u{2F826}', "\u{52C9}"),
+    ('\u{2F827}', "\u{52E4}"),
+    ('\u{2F828}', "\u{52FA}"),
+    ('\u{2F829}', "\u{5305}"),
+    ('\u{2F82A}', "\u{5306}"),
+    ('\u{2F82B}', "\u{5317}"),
+    ('\u{2F82C}', "\u{5349}"),
+    ('\u{2F82D}', "\u{5351}"),
+    ('\u{2F82E}', "\u{535A}"),
+    ('\u{2F82F}', "\u{5373}"),
+    ('\u{2F830}', "\u{537D}"),
+    ('\u{2F831}', "\u{537F}"),
+    ('\u{2F832}', "\u{537F}"),
+    ('\u{2F833}', "\u{537F}"),
+    ('\u{2F834}', "\u{20A2C}"),
+    ('\u{2F835}', "\u{7070}"),
+    ('\u{2F836}', "\u{53CA}"),
+    ('\u{2F837}', "\u{53DF}"),
+    ('\u{2F838}', "\u{20B63}"),
+    ('\u{2F839}', "\u{53EB}"),
+    ('\u{2F83A}', "\u{53F1}"),
+    ('\u{2F83B}', "\u{5406}"),
+    ('\u{2F83C}', "\u{549E}"),
+    ('\u{2F83D}', "\u{5438}"),
+    ('\u{2F83E}', "\u{5448}"),
+    ('\u{2F83F}', "\u{5468}"),
+    ('\u{2F840}', "\u{54A2}"),
+    ('\u{2F841}', "\u{54F6}"),
+    ('\u{2F842}', "\u{5510}"),
+    ('\u{2F843}', "\u{5553}"),
+    ('\u{2F844}', "\u{5563}"),
+    ('\u{2F845}', "\u{5584}"),
+    ('\u{2F846}', "\u{5584}"),
+    ('\u{2F847}', "\u{5599}"),
+    ('\u{2F848}', "\u{55AB}"),
+    ('\u{2F849}', "\u{55B3}"),
+    ('\u{2F84A}', "\u{55C2}"),
+    ('\u{2F84B}', "\u{5716}"),
+    ('\u{2F84C}', "\u{5606}"),
+    ('\u{2F84D}', "\u{5717}"),
+    ('\u{2F84E}', "\u{5651}"),
+    ('\u{2F84F}', "\u{5674}"),
+    ('\u{2F850}', "\u{5207}"),
+    ('\u{2F851}', "\u{58EE}"),
+    ('\u{2F852}', "\u{57CE}"),
+    ('\u{2F853}', "\u{57F4}"),
+    ('\u{2F854}', "\u{580D}"),
+    ('\u{2F855}', "\u{578B}"),
+    ('\u{2F856}', "\u{5832}"),
+    ('\u{2F857}', "\u{5831}"),
+    ('\u{2F858}', "\u{58AC}"),
+    ('\u{2F859}', "\u{214E4}"),
+    ('\u{2F85A}', "\u{58F2}"),
+    ('\u{2F85B}', "\u{58F7}"),
+    ('\u{2F85C}', "\u{5906}"),
+    ('\u{2F85D}', "\u{591A}"),
+    ('\u{2F85E}', "\u{5922}"),
+    ('\u{2F85F}', "\u{5962}"),
+    ('\u{2F860}', "\u{216A8}"),
+    ('\u{2F861}', "\u{216EA}"),
+    ('\u{2F862}', "\u{59EC}"),
+    ('\u{2F863}', "\u{5A1B}"),
+    ('\u{2F864}', "\u{5A27}"),
+    ('\u{2F865}', "\u{59D8}"),
+    ('\u{2F866}', "\u{5A66}"),
+    ('\u{2F867}', "\u{36EE}"),
+    ('\u{2F868}', "\u{36FC}"),
+    ('\u{2F869}', "\u{5B08}"),
+    ('\u{2F86A}', "\u{5B3E}"),
+    ('\u{2F86B}', "\u{5B3E}"),
+    ('\u{2F86C}', "\u{219C8}"),
+    ('\u{2F86D}', "\u{5BC3}"),
+    ('\u{2F86E}', "\u{5BD8}"),
+    ('\u{2F86F}', "\u{5BE7}"),
+    ('\u{2F870}', "\u{5BF3}"),
+    ('\u{2F871}', "\u{21B18}"),
+    ('\u{2F872}', "\u{5BFF}"),
+    ('\u{2F873}', "\u{5C06}"),
+    ('\u{2F874}', "\u{5F53}"),
+    ('\u{2F875}', "\u{5C22}"),
+    ('\u{2F876}', "\u{3781}"),
+    ('\u{2F877}', "\u{5C60}"),
+    ('\u{2F878}', "\u{5C6E}"),
+    ('\u{2F879}', "\u{5CC0}"),
+    ('\u{2F87A}', "\u{5C8D}"),
+    ('\u{2F87B}', "\u{21DE4}"),
+    ('\u{2F87C}', "\u{5D43}"),
+    ('\u{2F87D}', "\u{21DE6}"),
+    ('\u{2F87E}', "\u{5D6E}"),
+    ('\u{2F87F}', "\u{5D6B}"),
+    ('\u{2F880}', "\u{5D7C}"),
+    ('\u{2F881}', "\u{5DE1}"),
+    ('\u{2F882}', "\u{5DE2}"),
+    ('\u{2F883}', "\u{382F}"),
+    ('\u{2F884}', "\u{5DFD}"),
+    ('\u{2F885}', "\u{5E28}"),
+    ('\u{2F886}', "\u{5E3D}"),
+    ('\u{2F887}', "\u{5E69}"),
+    ('\u{2F888}', "\u{3862}"),
+    ('\u{2F889}', "\u{22183}"),
+    ('\u{2F88A}', "\u{387C}"),
+    ('\u{2F88B}', "\u{5EB0}"),
+    ('\u{2F88C}', "\u{5EB3}"),
+    ('\u{2F88D}', "\u{5EB6}"),
+    ('\u{2F88E}', "\u{5ECA}"),
+    ('\u{2F88F}', "\u{2A392}"),
+    ('\u{2F890}', "\u{5EFE}"),
+    ('\u{2F891}', "\u{22331}"),
+    ('\u{2F892}', "\u{22331}"),
+    ('\u{2F893}', "\u{8201}"),
+    ('\u{2F894}', "\u{5F22}"),
+    ('\u{2F895}', "\u{5F22}"),
+    ('\u{2F896}', "\u{38C7}"),
+    ('\u{2F897}', "\u{232B8}"),
+    ('\u{2F898}', "\u{261DA}"),
+    ('\u{2F899}', "\u{5F62}"),
+    ('\u{2F89A}', "\u{5F6B}"),
+    ('\u{2F89B}', "\u{38E3}"),
+    ('\u{2F89C}', "\u{5F9A}"),
+    ('\u{2F89D}', "\u{5FCD}"),
+    ('\u{2F89E}', "\u{5FD7}"),
+    ('\u{2F89F}', "\u{5FF9}"),
+    ('\u{2F8A0}', "\u{6081}"),
+    ('\u{2F8A1}', "\u{393A}"),
+    ('\u{2F8A2}', "\u{391C}"),
+    ('\u{2F8A3}', "\u{6094}"),
+    ('\u{2F8A4}', "\u{226D4}"),
+    ('\u{2F8A5}', "\u{60C7}"),
+    ('\u{2F8A6}', "\u{6148}"),
+    ('\u{2F8A7}', "\u{614C}"),
+    ('\u{2F8A8}', "\u{614E}"),
+    ('\u{2F8A9}', "\u{614C}"),
+    ('\u{2F8AA}', "\u{617A}"),
+    ('\u{2F8AB}', "\u{618E}"),
+    ('\u{2F8AC}', "\u{61B2}"),
+    ('\u{2F8AD}', "\u{61A4}"),
+    ('\u{2F8AE}', "\u{61AF}"),
+    ('\u{2F8AF}', "\u{61DE}"),
+    ('\u{2F8B0}', "\u{61F2}"),
+    ('\u{2F8B1}', "\u{61F6}"),
+    ('\u{2F8B2}', "\u{6210}"),
+    ('\u{2F8B3}', "\u{621B}"),
+    ('\u{2F8B4}', "\u{625D}"),
+    ('\u{2F8B5}', "\u{62B1}"),
+    ('\u{2F8B6}', "\u{62D4}"),
+    ('\u{2F8B7}', "\u{6350}"),
+    ('\u{2F8B8}', "\u{22B0C}"),
+    ('\u{2F8B9}', "\u{633D}"),
+    ('\u{2F8BA}', "\u{62FC}"),
+    ('\u{2F8BB}', "\u{6368}"),
+    ('\u{2F8BC}', "\u{6383}"),
+    ('\u{2F8BD}', "\u{63E4}"),
+    ('\u{2F8BE}', "\u{22BF1}"),
+    ('\u{2F8BF}', "\u{6422}"),
+    ('\u{2F8C0}', "\u{63C5}"),
+    ('\u{2F8C1}', "\u{63A9}"),
+    ('\u{2F8C2}', "\u{3A2E}"),
+    ('\u{2F8C3}', "\u{6469}"),
+    ('\u{2F8C4}', "\u{647E}"),
+    ('\u{2F8C5}', "\u{649D}"),
+    ('\u{2F8C6}', "\u{6477}"),
+    ('\u{2F8C7}', "\u{3A6C}"),
+    ('\u{2F8C8}', "\u{654F}"),
+    ('\u{2F8C9}', "\u{656C}"),
+    ('\u{2F8CA}', "\u{2300A}"),
+    ('\u{2F8CB}', "\u{65E3}"),
+    ('\u{2F8CC}', "\u{66F8}"),
+    ('\u{2F8CD}', "\u{6649}"),
+    ('\u{2F8CE}', "\u{3B19}"),
+    ('\u{2F8CF}', "\u{6691}"),
+    ('\u{2F8D0}', "\u{3B08}"),
+    ('\u{2F8D1}', "\u{3AE4}"),
+    ('\u{2F8D2}', "\u{5192}"),
+    ('\u{2F8D3}', "\u{5195}"),
+    ('\u{2F8D4}', "\u{6700}"),
+    ('\u{2F8D5}', "\u{669C}"),
+    ('\u{2F8D6}', "\u{80AD}"),
+    ('\u{2F8D7}', "\u{43D9}"),
+    ('\u{2F8D8}', "\u{6717}"),
+    ('\u{2F8D9}', "\u{671B}"),
+    ('\u{2F8DA}', "\u{6721}"),
+    ('\u{2F8DB}', "\u{675E}"),
+    ('\u{2F8DC}', "\u{6753}"),
+    ('\u{2F8DD}', "\u{233C3}"),
+    ('\u{2F8DE}', "\u{3B49}"),
+    ('\u{2F8DF}', "\u{67FA}"),
+    ('\u{2F8E0}', "\u{6785}"),
+    ('\u{2F8E1}', "\u{6852}"),
+    ('\u{2F8E2}', "\u{6885}"),
+    ('\u{2F8E3}', "\u{2346D}"),
+    ('\u{2F8E4}', "\u{688E}"),
+    ('\u{2F8E5}', "\u{681F}"),
+    ('\u{2F8E6}', "\u{6914}"),
+    ('\u{2F8E7}', "\u{3B9D}"),
+    ('\u{2F8E8}', "\u{6942}"),
+    ('\u{2F8E9}', "\u{69A3}"),
+    ('\u{2F8EA}', "\u{69EA}"),
+    ('\u{2F8EB}', "\u{6AA8}"),
+    ('\u{2F8EC}', "\u{236A3}"),
+    ('\u{2F8ED}', "\u{6ADB}"),
+    ('\u{2F8EE}', "\u{3C18}"),
+    ('\u{2F8EF}', "\u{6B21}"),
+    ('\u{2F8F0}', "\u{238A7}"),
+    ('\u{2F8F1}', "\u{6B54}"),
+    ('\u{2F8F2}', "\u{3C4E}"),
+    ('\u{2F8F3}', "\u{6B72}"),
+    ('\u{2F8F4}', "\u{6B9F}"),
+    ('\u{2F8F5}', "\u{6BBA}"),
+    ('\u{2F8F6}', "\u{6BBB}"),
+    ('\u{2F8F7}', "\u{23A8D}"),
+    ('\u{2F8F8}', "\u{21D0B}"),
+    ('\u{2F8F9}', "\u{23AFA}"),
+    ('\u{2F8FA}', "\u{6C4E}"),
+    ('\u{2F8FB}', "\u{23CBC}"),
+    ('\u{2F8FC}', "\u{6CBF}"),
+    ('\u{2F8FD}', "\u{6CCD}"),
+    ('\u{2F8FE}', "\u{6C67}"),
+    ('\u{2F8FF}', "\u{6D16}"),
+    ('\u{2F900}', "\u{6D3E}"),
+    ('\u{2F901}', "\u{6D77}"),
+    ('\u{2F902}', "\u{6D41}"),
+    ('\u{2F903}', "\u{6D69}"),
+    ('\u{2F904}', "\u{6D78}"),
+    ('\u{2F905}', "\u{6D85}"),
+    ('\u{2F906}', "\u{23D1E}"),
+    ('\u{2F907}', "\u{6D34}"),
+    ('\u{2F908}', "\u{6E2F}"),
+    ('\u{2F909}', "\u{6E6E}"),
+    ('\u{2F90A}', "\u{3D33}"),
+    ('\u{2F90B}', "\u{6ECB}"),
+    ('\u{2F90C}', "\u{6EC7}"),
+    ('\u{2F90D}', "\u{23ED1}"),
+    ('\u{2F90E}', "\u{6DF9}"),
+    ('\u{2F90F}', "\u{6F6E}"),
+    ('\u{2F910}', "\u{23F5E}"),
+    ('\u{2F911}', "\u{23F8E}"),
+    ('\u{2F912}', "\u{6FC6}"),
+    ('\u{2F913}', "\u{7039}"),
+    ('\u{2F914}', "\u{701E}"),
+    ('\u{2F915}', "\u{701B}"),
+    ('\u{2F916}', "\u{3D96}"),
+    ('\u{2F917}', "\u{704A}"),
+    ('\u{2F918}', "\u{707D}"),
+    ('\u{2F919}', "\u{7077}"),
+    ('\u{2F91A}', "\u{70AD}"),
+    ('\u{2F91B}', "\u{20525}"),
+    ('\u{2F91C}', "\u{7145}"),
+    ('\u{2F91D}', "\u{24263}"),
+    ('\u{2F91E}', "\u{719C}"),
+    ('\u{2F91F}', "\u{243AB}"),
+    ('\u{2F920}', "\u{7228}"),
+    ('\u{2F921}', "\u{7235}"),
+    ('\u{2F922}', "\u{7250}"),
+    ('\u{2F923}', "\u{24608}"),
+    ('\u{2F924}', "\u{7280}"),
+    ('\u{2F925}', "\u{7295}"),
+    ('\u{2F926}', "\u{24735}"),
+    ('\u{2F927}', "\u{24814}"),
+    ('\u{2F928}', "\u{737A}"),
+    ('\u{2F929}', "\u{738B}"),
+    ('\u{2F92A}', "\u{3EAC}"),
+    ('\u{2F92B}', "\u{73A5}"),
+    ('\u{2F92C}', "\u{3EB8}"),
+    ('\u{2F92D}', "\u{3EB8}"),
+    ('\u{2F92E}', "\u{7447}"),
+    ('\u{2F92F}', "\u{745C}"),
+    ('\u{2F930}', "\u{7471}"),
+    ('\u{2F931}', "\u{7485}"),
+    ('\u{2F932}', "\u{74CA}"),
+    ('\u{2F933}', "\u{3F1B}"),
+    ('\u{2F934}', "\u{7524}"),
+    ('\u{2F935}', "\u{24C36}"),
+    ('\u{2F936}', "\u{753E}"),
+    ('\u{2F937}', "\u{24C92}"),
+    ('\u{2F938}', "\u{7570}"),
+    ('\u{2F939}', "\u{2219F}"),
+    ('\u{2F93A}', "\u{7610}"),
+    ('\u{2F93B}', "\u{24FA1}"),
+    ('\u{2F93C}', "\u{24FB8}"),
+    ('\u{2F93D}', "\u{25044}"),
+    ('\u{2F93E}', "\u{3FFC}"),
+    ('\u{2F93F}', "\u{4008}"),
+    ('\u{2F940}', "\u{76F4}"),
+    ('\u{2F941}', "\u{250F3}"),
+    ('\u{2F942}', "\u{250F2}"),
+    ('\u{2F943}', "\u{25119}"),
+    ('\u{2F944}', "\u{25133}"),
+    ('\u{2F945}', "\u{771E}"),
+    ('\u{2F946}', "\u{771F}"),
+    ('\u{2F947}', "\u{771F}"),
+    ('\u{2F948}', "\u{774A}"),
+    ('\u{2F949}', "\u{4039}"),
+    ('\u{2F94A}', "\u{778B}"),
+    ('\u{2F94B}', "\u{4046}"),
+    ('\u{2F94C}', "\u{4096}"),
+    ('\u{2F94D}', "\u{2541D}"),
+    ('\u{2F94E}', "\u{784E}"),
+    ('\u{2F94F}', "\u{788C}"),
+    ('\u{2F950}', "\u{78CC}"),
+    ('\u{2F951}', "\u{40E3}"),
+    ('\u{2F952}', "\u{25626}"),
+    ('\u{2F953}', "\u{7956}"),
+    ('\u{2F954}', "\u{2569A}"),
+    ('\u{2F955}', "\u{256C5}"),
+    ('\u{2F956}', "\u{798F}"),
+    ('\u{2F957}', "\u{79EB}"),
+    ('\u{2F958}', "\u{412F}"),
+    ('\u{2F959}', "\u{7A40}"),
+    ('\u{2F95A}', "\u{7A4A}"),
+    ('\u{2F95B}', "\u{7A4F}"),
+    ('\u{2F95C}', "\u{2597C}"),
+    ('\u{2F95D}', "\u{25AA7}"),
+    ('\u{2F95E}', "\u{25AA7}"),
+    ('\u{2F95F}', "\u{7AEE}"),
+    ('\u{2F960}', "\u{4202}"),
+    ('\u{2F961}', "\u{25BAB}"),
+    ('\u{2F962}', "\u{7BC6}"),
+    ('\u{2F963}', "\u{7BC9}"),
+    ('\u{2F964}', "\u{4227}"),
+    ('\u{2F965}', "\u{25C80}"),
+    ('\u{2F966}', "\u{7CD2}"),
+    ('\u{2F967}', "\u{42A0}"),
+    ('\u{2F968}', "\u{7CE8}"),
+    ('\u{2F969}', "\u{7CE3}"),
+    ('\u{2F96A}', "\u{7D00}"),
+    ('\u{2F96B}', "\u{25F86}"),
+    ('\u{2F96C}', "\u{7D63}"),
+    ('\u{2F96D}', "\u{4301}"),
+    ('\u{2F96E}', "\u{7DC7}"),
+    ('\u{2F96F}', "\u{7E02}"),
+    ('\u{2F970}', "\u{7E45}"),
+    ('\u{2F971}', "\u{4334}"),
+    ('\u{2F972}', "\u{26228}"),
+    ('\u{2F973}', "\u{26247}"),
+    ('\u{2F974}', "\u{4359}"),
+    ('\u{2F975}', "\u{262D9}"),
+    ('\u{2F976}', "\u{7F7A}"),
+    ('\u{2F977}', "\u{2633E}"),
+    ('\u{2F978}', "\u{7F95}"),
+    ('\u{2F979}', "\u{7FFA}"),
+    ('\u{2F97A}', "\u{8005}"),
+    ('\u{2F97B}', "\u{264DA}"),
+    ('\u{2F97C}', "\u{26523}"),
+    ('\u{2F97D}', "\u{8060}"),
+    ('\u{2F97E}', "\u{265A8}"),
+    ('\u{2F97F}', "\u{8070}"),
+    ('\u{2F980}', "\u{2335F}"),
+    ('\u{2F981}', "\u{43D5}"),
+    ('\u{2F982}', "\u{80B2}"),
+    ('\u{2F983}', "\u{8103}"),
+    ('\u{2F984}', "\u{440B}"),
+    ('\u{2F985}', "\u{813E}"),
+    ('\u{2F986}', "\u{5AB5}"),
+    ('\u{2F987}', "\u{267A7}"),
+    ('\u{2F988}', "\u{267B5}"),
+    ('\u{2F989}', "\u{23393}"),
+    ('\u{2F98A}', "\u{2339C}"),
+    ('\u{2F98B}', "\u{8201}"),
+    ('\u{2F98C}', "\u{8204}"),
+    ('\u{2F98D}', "\u{8F9E}"),
+    ('\u{2F98E}', "\u{446B}"),
+    ('\u{2F98F}', "\u{8291}"),
+    ('\u{2F990}', "\u{828B}"),
+    ('\u{2F991}', "\u{829D}"),
+    ('\u{2F992}', "\u{52B3}"),
+    ('\u{2F993}', "\u{82B1}"),
+    ('\u{2F994}', "\u{82B3}"),
+    ('\u{2F995}', "\u{82BD}"),
+    ('\u{2F996}', "\u{82E6}"),
+    ('\u{2F997}', "\u{26B3C}"),
+    ('\u{2F998}', "\u{82E5}"),
+    ('\u{2F999}', "\u{831D}"),
+    ('\u{2F99A}', "\u{8363}"),
+    ('\u{2F99B}', "\u{83AD}"),
+    ('\u{2F99C}', "\u{8323}"),
+    ('\u{2F99D}', "\u{83BD}"),
+    ('\u{2F99E}', "\u{83E7}"),
+    ('\u{2F99F}', "\u{8457}"),
+    ('\u{2F9A0}', "\u{8353}"),
+    ('\u{2F9A1}', "\u{83CA}"),
+    ('\u{2F9A2}', "\u{83CC}"),
+    ('\u{2F9A3}', "\u{83DC}"),
+    ('\u{2F9A4}', "\u{26C36}"),
+    ('\u{2F9A5}', "\u{26D6B}"),
+    ('\u{2F9A6}', "\u{26CD5}"),
+    ('\u{2F9A7}', "\u{452B}"),
+    ('\u{2F9A8}', "\u{84F1}"),
+    ('\u{2F9A9}', "\u{84F3}"),
+    ('\u{2F9AA}', "\u{8516}"),
+    ('\u{2F9AB}', "\u{273CA}"),
+    ('\u{2F9AC}', "\u{8564}"),
+    ('\u{2F9AD}', "\u{26F2C}"),
+    ('\u{2F9AE}', "\u{455D}"),
+    ('\u{2F9AF}', "\u{4561}"),
+    ('\u{2F9B0}', "\u{26FB1}"),
+    ('\u{2F9B1}', "\u{270D2}"),
+    ('\u{2F9B2}', "\u{456B}"),
+    ('\u{2F9B3}', "\u{8650}"),
+    ('\u{2F9B4}', "\u{865C}"),
+    ('\u{2F9B5}', "\u{8667}"),
+    ('\u{2F9B6}', "\u{8669}"),
+    ('\u{2F9B7}', "\u{86A9}"),
+    ('\u{2F9B8}', "\u{8688}"),
+    ('\u{2F9B9}', "\u{870E}"),
+    ('\u{2F9BA}', "\u{86E2}"),
+    ('\u{2F9BB}', "\u{8779}"),
+    ('\u{2F9BC}', "\u{8728}"),
+    ('\u{2F9BD}', "\u{876B}"),
+    ('\u{2F9BE}', "\u{8786}"),
+    ('\u{2F9BF}', "\u{45D7}"),
+    ('\u{2F9C0}', "\u{87E1}"),
+    ('\u{2F9C1}', "\u{8801}"),
+    ('\u{2F9C2}', "\u{45F9}"),
+    ('\u{2F9C3}', "\u{8860}"),
+    ('\u{2F9C4}', "\u{8863}"),
+    ('\u{2F9C5}', "\u{27667}"),
+    ('\u{2F9C6}', "\u{88D7}"),
+    ('\u{2F9C7}', "\u{88DE}"),
+    ('\u{2F9C8}', "\u{4635}"),
+    ('\u{2F9C9}', "\u{88FA}"),
+    ('\u{2F9CA}', "\u{34BB}"),
+    ('\u{2F9CB}', "\u{278AE}"),
+    ('\u{2F9CC}', "\u{27966}"),
+    ('\u{2F9CD}', "\u{46BE}"),
+    ('\u{2F9CE}', "\u{46C7}"),
+    ('\u{2F9CF}', "\u{8AA0}"),
+    ('\u{2F9D0}', "\u{8AED}"),
+    ('\u{2F9D1}', "\u{8B8A}"),
+    ('\u{2F9D2}', "\u{8C55}"),
+    ('\u{2F9D3}', "\u{27CA8}"),
+    ('\u{2F9D4}', "\u{8CAB}"),
+    ('\u{2F9D5}', "\u{8CC1}"),
+    ('\u{2F9D6}', "\u{8D1B}"),
+    ('\u{2F9D7}', "\u{8D77}"),
+    ('\u{2F9D8}', "\u{27F2F}"),
+    ('\u{2F9D9}', "\u{20804}"),
+    ('\u{2F9DA}', "\u{8DCB}"),
+    ('\u{2F9DB}', "\u{8DBC}"),
+    ('\u{2F9DC}', "\u{8DF0}"),
+    ('\u{2F9DD}', "\u{208DE}"),
+    ('\u{2F9DE}', "\u{8ED4}"),
+    ('\u{2F9DF}', "\u{8F38}"),
+    ('\u{2F9E0}', "\u{285D2}"),
+    ('\u{2F9E1}', "\u{285ED}"),
+    ('\u{2F9E2}', "\u{9094}"),
+    ('\u{2F9E3}', "\u{90F1}"),
+    ('\u{2F9E4}', "\u{9111}"),
+    ('\u{2F9E5}', "\u{2872E}"),
+    ('\u{2F9E6}', "\u{911B}"),
+    ('\u{2F9E7}', "\u{9238}"),
+    ('\u{2F9E8}', "\u{92D7}"),
+    ('\u{2F9E9}', "\u{92D8}"),
+    ('\u{2F9EA}', "\u{927C}"),
+    ('\u{2F9EB}', "\u{93F9}"),
+    ('\u{2F9EC}', "\u{9415}"),
+    ('\u{2F9ED}', "\u{28BFA}"),
+    ('\u{2F9EE}', "\u{958B}"),
+    ('\u{2F9EF}', "\u{4995}"),
+    ('\u{2F9F0}', "\u{95B7}"),
+    ('\u{2F9F1}', "\u{28D77}"),
+    ('\u{2F9F2}', "\u{49E6}"),
+    ('\u{2F9F3}', "\u{96C3}"),
+    ('\u{2F9F4}', "\u{5DB2}"),
+    ('\u{2F9F5}', "\u{9723}"),
+    ('\u{2F9F6}', "\u{29145}"),
+    ('\u{2F9F7}', "\u{2921A}"),
+    ('\u{2F9F8}', "\u{4A6E}"),
+    ('\u{2F9F9}', "\u{4A76}"),
+    ('\u{2F9FA}', "\u{97E0}"),
+    ('\u{2F9FB}', "\u{2940A}"),
+    ('\u{2F9FC}', "\u{4AB2}"),
+    ('\u{2F9FD}', "\u{29496}"),
+    ('\u{2F9FE}', "\u{980B}"),
+    ('\u{2F9FF}', "\u{980B}"),
+    ('\u{2FA00}', "\u{9829}"),
+    ('\u{2FA01}', "\u{295B6}"),
+    ('\u{2FA02}', "\u{98E2}"),
+    ('\u{2FA03}', "\u{4B33}"),
+    ('\u{2FA04}', "\u{9929}"),
+    ('\u{2FA05}', "\u{99A7}"),
+    ('\u{2FA06}', "\u{99C2}"),
+    ('\u{2FA07}', "\u{99FE}"),
+    ('\u{2FA08}', "\u{4BCE}"),
+    ('\u{2FA09}', "\u{29B30}"),
+    ('\u{2FA0A}', "\u{9B12}"),
+    ('\u{2FA0B}', "\u{9C40}"),
+    ('\u{2FA0C}', "\u{9CFD}"),
+    ('\u{2FA0D}', "\u{4CCE}"),
+    ('\u{2FA0E}', "\u{4CED}"),
+    ('\u{2FA0F}', "\u{9D67}"),
+    ('\u{2FA10}', "\u{2A0CE}"),
+    ('\u{2FA11}', "\u{4CF8}"),
+    ('\u{2FA12}', "\u{2A105}"),
+    ('\u{2FA13}', "\u{2A20E}"),
+    ('\u{2FA14}', "\u{2A291}"),
+    ('\u{2FA15}', "\u{9EBB}"),
+    ('\u{2FA16}', "\u{4D56}"),
+    ('\u{2FA17}', "\u{9EF9}"),
+    ('\u{2FA18}', "\u{9EFE}"),
+    ('\u{2FA19}', "\u{9F05}"),
+    ('\u{2FA1A}', "\u{9F0F}"),
+    ('\u{2FA1B}', "\u{9F16}"),
+    ('\u{2FA1C}', "\u{9F3B}"),
+    ('\u{2FA1D}', "\u{2A600}"),
+];
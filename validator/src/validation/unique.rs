@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Validates that all the items are distinct, returning the duplicates otherwise as the index of
+/// the duplicate and the index of the first occurrence of its value.
+/// The derive passes the items of the collection, or the keys given by `unique_by`, as a slice.
+pub fn validate_unique<T>(items: &[T]) -> Result<(), Vec<(usize, usize)>>
+where
+    T: Hash + Eq,
+{
+    let mut first_occurrences = HashMap::with_capacity(items.len());
+    let mut duplicates = Vec::new();
+    for (index, item) in items.iter().enumerate() {
+        if let Some(first_index) = first_occurrences.get(item) {
+            duplicates.push((index, *first_index));
+        } else {
+            first_occurrences.insert(item, index);
+        }
+    }
+
+    if duplicates.is_empty() {
+        Ok(())
+    } else {
+        Err(duplicates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_unique;
+
+    #[test]
+    fn test_validate_unique() {
+        assert_eq!(validate_unique::<u8>(&[]), Ok(()));
+        assert_eq!(validate_unique(&[1, 2, 3]), Ok(()));
+        assert_eq!(
+            validate_unique(&["a", "b", "a", "c", "b", "a"]),
+            Err(vec![(2, 0), (4, 1), (5, 0)])
+        );
+    }
+
+    #[test]
+    fn test_validate_unique_by_key() {
+        struct LineItem {
+            sku: String,
+        }
+
+        let items = [
+            LineItem { sku: "A-1".to_string() },
+            LineItem { sku: "B-2".to_string() },
+            LineItem { sku: "A-1".to_string() },
+        ];
+        let skus = items.iter().map(|i| &i.sku).collect::<Vec<_>>();
+        assert_eq!(validate_unique(&skus), Err(vec![(2, 0)]));
+    }
+}
@@ -3,26 +3,37 @@ use darling::util::{Override, WithOriginal};
 use darling::FromDeriveInput;
 use proc_macro_error3::{abort, proc_macro_error};
 use quote::{quote, ToTokens};
-use syn::{parse_macro_input, DeriveInput, Field, GenericParam, Path, PathArguments};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, DeriveInput, Expr, Field, GenericParam, Path, PathArguments};
 
-use tokens::cards::credit_card_tokens;
+use tokens::blank::{non_empty_tokens, not_blank_tokens, trimmed_tokens};
+use tokens::cards::{card_cvv_tokens, card_expiry_tokens, credit_card_tokens};
 use tokens::contains::contains_tokens;
 use tokens::custom::custom_tokens;
 use tokens::does_not_contain::does_not_contain_tokens;
 use tokens::email::email_tokens;
-use tokens::ip::ip_tokens;
+use tokens::ip::{cidr_tokens, ip_tokens, socket_addr_tokens};
 use tokens::length::length_tokens;
 use tokens::must_match::must_match_tokens;
 use tokens::nested::nested_tokens;
 use tokens::non_control_character::non_control_char_tokens;
+use tokens::normalized::normalized_tokens;
+use tokens::one_of::{none_of_tokens, one_of_tokens};
+use tokens::password::password_tokens;
 use tokens::range::range_tokens;
 use tokens::regex::regex_tokens;
 use tokens::required::required_tokens;
 use tokens::schema::schema_tokens;
-use tokens::url::url_tokens;
+use tokens::sorted::sorted_tokens;
+use tokens::unicode_security::{
+    no_bidi_controls_tokens, no_confusable_with_tokens, no_invisible_tokens, single_script_tokens,
+};
+use tokens::unique::{unique_by_tokens, unique_tokens};
+use tokens::url::{public_url_tokens, url_tokens};
 use types::*;
 use utils::{quote_use_stmts, CrateName};
 
+mod sanitize;
 mod tokens;
 mod types;
 mod utils;
@@ -74,6 +85,36 @@ impl ToTokens for ValidateField {
             quote!()
         };
 
+        // Card expiry validation
+        let card_expiry = if let Some(card_expiry) = self.card_expiry.clone() {
+            wrapper_closure(card_expiry_tokens(
+                &self.crate_name,
+                match card_expiry {
+                    Override::Inherit => CardExpiry::default(),
+                    Override::Explicit(c) => c,
+                },
+                &actual_field,
+                &field_name_str,
+            ))
+        } else {
+            quote!()
+        };
+
+        // Card security code validation
+        let card_cvv = if let Some(card_cvv) = self.card_cvv.clone() {
+            wrapper_closure(card_cvv_tokens(
+                &self.crate_name,
+                match card_cvv {
+                    Override::Inherit => CardCvv::default(),
+                    Override::Explicit(c) => c,
+                },
+                &actual_field,
+                &field_name_str,
+            ))
+        } else {
+            quote!()
+        };
+
         // Url validation
         let url = if let Some(url) = self.url.clone() {
             wrapper_closure(url_tokens(
@@ -89,6 +130,21 @@ impl ToTokens for ValidateField {
             quote!()
         };
 
+        // Public url validation
+        let public_url = if let Some(public_url) = self.public_url.clone() {
+            wrapper_closure(public_url_tokens(
+                &self.crate_name,
+                match public_url {
+                    Override::Inherit => PublicUrl::default(),
+                    Override::Explicit(p) => p,
+                },
+                &actual_field,
+                &field_name_str,
+            ))
+        } else {
+            quote!()
+        };
+
         // Ip address validation
         let ip = if let Some(ip) = self.ip.clone() {
             wrapper_closure(ip_tokens(
@@ -104,6 +160,36 @@ impl ToTokens for ValidateField {
             quote!()
         };
 
+        // CIDR notation validation
+        let cidr = if let Some(cidr) = self.cidr.clone() {
+            wrapper_closure(cidr_tokens(
+                &self.crate_name,
+                match cidr {
+                    Override::Inherit => Cidr::default(),
+                    Override::Explicit(c) => c,
+                },
+                &actual_field,
+                &field_name_str,
+            ))
+        } else {
+            quote!()
+        };
+
+        // Socket address validation
+        let socket_addr = if let Some(socket_addr) = self.socket_addr.clone() {
+            wrapper_closure(socket_addr_tokens(
+                &self.crate_name,
+                match socket_addr {
+                    Override::Inherit => SocketAddr::default(),
+                    Override::Explicit(s) => s,
+                },
+                &actual_field,
+                &field_name_str,
+            ))
+        } else {
+            quote!()
+        };
+
         // Non control character validation
         let ncc = if let Some(ncc) = self.non_control_character.clone() {
             wrapper_closure(non_control_char_tokens(
@@ -119,6 +205,138 @@ impl ToTokens for ValidateField {
             quote!()
         };
 
+        // Not blank validation
+        let not_blank = if let Some(not_blank) = self.not_blank.clone() {
+            wrapper_closure(not_blank_tokens(
+                &self.crate_name,
+                match not_blank {
+                    Override::Inherit => NotBlank::default(),
+                    Override::Explicit(v) => v,
+                },
+                &actual_field,
+                &field_name_str,
+            ))
+        } else {
+            quote!()
+        };
+
+        // Non empty validation
+        let non_empty = if let Some(non_empty) = self.non_empty.clone() {
+            wrapper_closure(non_empty_tokens(
+                &self.crate_name,
+                match non_empty {
+                    Override::Inherit => NonEmpty::default(),
+                    Override::Explicit(v) => v,
+                },
+                &actual_field,
+                &field_name_str,
+            ))
+        } else {
+            quote!()
+        };
+
+        // Trimmed validation
+        let trimmed = if let Some(trimmed) = self.trimmed.clone() {
+            wrapper_closure(trimmed_tokens(
+                &self.crate_name,
+                match trimmed {
+                    Override::Inherit => Trimmed::default(),
+                    Override::Explicit(v) => v,
+                },
+                &actual_field,
+                &field_name_str,
+            ))
+        } else {
+            quote!()
+        };
+
+        // Normalization validation
+        let normalized = if let Some(normalized) = self.normalized.clone() {
+            wrapper_closure(normalized_tokens(
+                &self.crate_name,
+                match normalized {
+                    Override::Inherit => Normalized::default(),
+                    Override::Explicit(n) => n,
+                },
+                &actual_field,
+                &field_name_str,
+            ))
+        } else {
+            quote!()
+        };
+
+        // Single script validation
+        let single_script = if let Some(single_script) = self.single_script.clone() {
+            wrapper_closure(single_script_tokens(
+                &self.crate_name,
+                match single_script {
+                    Override::Inherit => SingleScript::default(),
+                    Override::Explicit(s) => s,
+                },
+                &actual_field,
+                &field_name_str,
+            ))
+        } else {
+            quote!()
+        };
+
+        // Confusable validation
+        let no_confusable_with = if let Some(no_confusable_with) = self.no_confusable_with.clone() {
+            wrapper_closure(no_confusable_with_tokens(
+                &self.crate_name,
+                no_confusable_with,
+                &actual_field,
+                &field_name_str,
+            ))
+        } else {
+            quote!()
+        };
+
+        // Bidi controls validation
+        let no_bidi_controls = if let Some(no_bidi_controls) = self.no_bidi_controls.clone() {
+            wrapper_closure(no_bidi_controls_tokens(
+                &self.crate_name,
+                match no_bidi_controls {
+                    Override::Inherit => NoBidiControls::default(),
+                    Override::Explicit(n) => n,
+                },
+                &actual_field,
+                &field_name_str,
+            ))
+        } else {
+            quote!()
+        };
+
+        // Invisible characters validation
+        let no_invisible = if let Some(no_invisible) = self.no_invisible.clone() {
+            wrapper_closure(no_invisible_tokens(
+                &self.crate_name,
+                match no_invisible {
+                    Override::Inherit => NoInvisible::default(),
+                    Override::Explicit(n) => n,
+                },
+                &actual_field,
+                &field_name_str,
+            ))
+        } else {
+            quote!()
+        };
+
+        // Password validation
+        let password = if let Some(password) = self.password.clone() {
+            wrapper_closure(password_tokens(
+                &self.crate_name,
+                match password {
+                    Override::Inherit => Password::default(),
+                    Override::Explicit(p) => p,
+                },
+                &actual_field,
+                &field_name_str,
+            ))
+        } else {
+            quote!()
+        };
+
         // Range validation
         let range = if let Some(range) = self.range.clone() {
             wrapper_closure(range_tokens(&self.crate_name, range, &actual_field, &field_name_str))
@@ -165,6 +383,25 @@ impl ToTokens for ValidateField {
             quote!()
         };
 
+        // One of validation
+        let one_of = if let Some(one_of) = self.one_of.clone() {
+            wrapper_closure(one_of_tokens(&self.crate_name, one_of, &actual_field, &field_name_str))
+        } else {
+            quote!()
+        };
+
+        // None of validation
+        let none_of = if let Some(none_of) = self.none_of.clone() {
+            wrapper_closure(none_of_tokens(
+                &self.crate_name,
+                none_of,
+                &actual_field,
+                &field_name_str,
+            ))
+        } else {
+            quote!()
+        };
+
         // Must match validation
         let must_match = if let Some(must_match) = self.must_match.clone() {
             // TODO: handle option for other
@@ -219,21 +456,88 @@ impl ToTokens for ValidateField {
             quote!()
         };
 
+        // Collection validations, which add their errors to the same list as the nested ones
+        let unique = if let Some(unique) = self.unique.clone() {
+            wrapper_closure(unique_tokens(
+                &self.crate_name,
+                match unique {
+                    Override::Inherit => Unique::default(),
+                    Override::Explicit(u) => u,
+                },
+                &actual_field,
+                &field_name_str,
+            ))
+        } else {
+            quote!()
+        };
+
+        let unique_by = if let Some(unique_by) = self.unique_by.clone() {
+            wrapper_closure(unique_by_tokens(
+                &self.crate_name,
+                unique_by,
+                &actual_field,
+                &field_name_str,
+            ))
+        } else {
+            quote!()
+        };
+
+        let sorted = if let Some(sorted) = self.sorted.clone() {
+            wrapper_closure(sorted_tokens(
+                &self.crate_name,
+                match sorted {
+                    Override::Inherit => Sorted::default(),
+                    Override::Explicit(s) => s,
+                },
+                &actual_field,
+                &field_name_str,
+            ))
+        } else {
+            quote!()
+        };
+
+        // The values are redacted once all the validations of the field added their errors
+        let redact = if self.sensitive == Some(true) {
+            quote!(errors.redact_field_values(#field_name_str);)
+        } else {
+            quote!()
+        };
+
         tokens.extend(quote! {
             #length
             #email
             #card
+            #card_expiry
+            #card_cvv
             #url
+            #public_url
             #ip
+            #cidr
+            #socket_addr
             #ncc
+            #not_blank
+            #non_empty
+            #trimmed
+            #normalized
+            #single_script
+            #no_confusable_with
+            #no_bidi_controls
+            #no_invisible
+            #password
             #range
             #required
             #contains
             #does_not_contain
+            #one_of
+            #none_of
             #must_match
             #regex
             #custom
             #nested
+            #unique
+            #unique_by
+            #sorted
+            #redact
         });
     }
 }
@@ -252,6 +556,8 @@ struct ValidationData {
     context: Option<Path>,
     mutable: Option<bool>,
     nest_all_fields: Option<bool>,
+    /// The fields whose values are redacted from the errors, like with `#[validate(sensitive)]`.
+    redact: Option<syn::ExprArray>,
     /// The name of the crate to use for the generated code,
     /// defaults to `validator`.
     #[darling(rename = "crate", default)]
@@ -284,6 +590,31 @@ impl ValidationData {
             for f in &fields.fields {
                 f.parsed.validate(&self.ident, &original_fields, &f.original);
             }
+
+            for redacted in self.redact.iter().flat_map(|r| r.elems.iter()) {
+                let Some(field) = (match redacted {
+                    Expr::Path(p) => p.path.get_ident().map(|i| i.to_string()),
+                    _ => None,
+                }) else {
+                    abort!(
+                        redacted.span(), "Invalid attribute #[validate(redact = [...])] on struct `{}`:", self.ident;
+                        note = "`redact` only accepts field names";
+                        help = "Try formating the argument like `redact = [password, card_number]`"
+                    )
+                };
+
+                if !fields
+                    .fields
+                    .iter()
+                    .any(|f| f.parsed.ident.as_ref().is_some_and(|i| *i == field))
+                {
+                    abort!(
+                        redacted.span(), "Invalid attribute #[validate(redact = [...])] on struct `{}`:", self.ident;
+                        note = "The field `{}` doesn't exist in the struct `{}`", field, self.ident;
+                        help = "Add the field `{}` to the struct", field
+                    )
+                }
+            }
         }
 
         Ok(self)
@@ -342,6 +673,27 @@ pub fn derive_validation(input: proc_macro::TokenStream) -> proc_macro::TokenStr
         }
     }
 
+    let redacted_fields = validation_data
+        .redact
+        .iter()
+        .flat_map(|r| r.elems.iter())
+        .map(|e| e.to_token_stream().to_string())
+        .collect::<Vec<_>>();
+    for f in &mut validation_fields {
+        if f.ident.as_ref().is_some_and(|i| redacted_fields.contains(&i.to_string())) {
+            f.sensitive = Some(true);
+        }
+    }
+    let sensitive_fields = validation_fields
+        .iter()
+        .filter(|f| f.sensitive == Some(true))
+        .filter_map(|f| f.ident.as_ref().map(|i| i.to_string()))
+        .collect::<Vec<_>>();
+    for must_match in validation_fields.iter_mut().filter_map(|f| f.must_match.as_mut()) {
+        let other = must_match.other.to_token_stream().to_string();
+        must_match.other_sensitive = sensitive_fields.contains(&other);
+    }
+
     // generate `use` statements for all used validator traits
     let use_statements = quote_use_stmts(&crate_name, &validation_fields);
 
@@ -422,3 +774,10 @@ pub fn derive_validation(input: proc_macro::TokenStream) -> proc_macro::TokenStr
     )
     .into()
 }
+
+#[proc_macro_error]
+#[proc_macro_derive(Sanitize, attributes(sanitizer))]
+pub fn derive_sanitize(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: DeriveInput = parse_macro_input!(input);
+    sanitize::derive_sanitize(&input).into()
+}
@@ -0,0 +1,145 @@
+use darling::ast::Data;
+use darling::{FromDeriveInput, FromField, FromMeta};
+use proc_macro_error3::abort;
+use quote::{format_ident, quote};
+use syn::Path;
+
+use crate::utils::CrateName;
+
+// The string sanitizers in the order they are applied, whatever the order of the attributes,
+// and their `validator::Sanitizer` variant. The whitespace is handled before the control
+// characters are stripped, so that newlines and tabs still separate words.
+static STRING_SANITIZERS: [(&str, &str); 6] = [
+    ("nfc", "Nfc"),
+    ("collapse_whitespace", "CollapseWhitespace"),
+    ("trim", "Trim"),
+    ("strip_control", "StripControl"),
+    ("lowercase", "Lowercase"),
+    ("uppercase", "Uppercase"),
+];
+
+#[derive(Debug, Clone, FromMeta)]
+pub struct SanitizeCustom {
+    pub function: Path,
+}
+
+#[derive(Debug, FromField)]
+#[darling(attributes(sanitizer))]
+pub struct SanitizeField {
+    pub ident: Option<syn::Ident>,
+    pub trim: Option<bool>,
+    pub lowercase: Option<bool>,
+    pub uppercase: Option<bool>,
+    pub collapse_whitespace: Option<bool>,
+    pub strip_control: Option<bool>,
+    pub nfc: Option<bool>,
+    #[darling(multiple)]
+    pub custom: Vec<SanitizeCustom>,
+    pub nested: Option<bool>,
+}
+
+impl SanitizeField {
+    fn is_set(&self, sanitizer: &str) -> bool {
+        let flag = match sanitizer {
+            "strip_control" => self.strip_control,
+            "nfc" => self.nfc,
+            "collapse_whitespace" => self.collapse_whitespace,
+            "trim" => self.trim,
+            "lowercase" => self.lowercase,
+            "uppercase" => self.uppercase,
+            _ => unreachable!("Unknown sanitizer `{}`", sanitizer),
+        };
+        flag.unwrap_or(false)
+    }
+
+    fn to_tokens(&self, crate_name: &CrateName) -> proc_macro2::TokenStream {
+        let field_name = self.ident.clone().expect("Field is not a named field");
+
+        if self.lowercase == Some(true) && self.uppercase == Some(true) {
+            abort!(
+                field_name.span(), "Invalid attribute #[sanitizer(...)] on field `{}`:", field_name;
+                note = "Both `lowercase` and `uppercase` have been set";
+                help = "Use only one of them"
+            )
+        }
+
+        if self.nfc == Some(true) && !cfg!(feature = "unicode_normalization") {
+            abort!(
+                field_name.span(), "Invalid attribute #[sanitizer(...)] on field `{}`:", field_name;
+                note = "`nfc` requires the `unicode_normalization` feature of `validator`";
+                help = "Add `unicode_normalization` to the features of `validator` in your Cargo.toml"
+            )
+        }
+
+        let sanitizers = STRING_SANITIZERS
+            .iter()
+            .filter(|(name, _)| self.is_set(name))
+            .map(|(_, variant)| {
+                let variant = format_ident!("{}", variant);
+                quote!(#crate_name::Sanitizer::#variant.apply(s);)
+            })
+            .collect::<Vec<_>>();
+        let strings = if sanitizers.is_empty() {
+            quote!()
+        } else {
+            quote!(
+                #crate_name::SanitizeStrings::sanitize_strings(&mut self.#field_name, &mut |s| {
+                    #(#sanitizers)*
+                });
+            )
+        };
+
+        let nested = if self.nested.unwrap_or(false) {
+            quote!(#crate_name::Sanitize::sanitize(&mut self.#field_name);)
+        } else {
+            quote!()
+        };
+
+        // Custom functions run last so that they get the sanitized value
+        let custom = self.custom.iter().map(|c| {
+            let function = &c.function;
+            quote!(#function(&mut self.#field_name);)
+        });
+
+        quote! {
+            #strings
+            #nested
+            #(#custom)*
+        }
+    }
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(sanitizer), supports(struct_named))]
+pub struct SanitizeData {
+    ident: syn::Ident,
+    generics: syn::Generics,
+    data: Data<(), SanitizeField>,
+    /// The name of the crate to use for the generated code,
+    /// defaults to `validator`.
+    #[darling(rename = "crate", default)]
+    crate_name: CrateName,
+}
+
+pub fn derive_sanitize(input: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    let sanitize_data = match SanitizeData::from_derive_input(input) {
+        Ok(data) => data,
+        Err(e) => return e.write_errors(),
+    };
+
+    let crate_name = sanitize_data.crate_name;
+    let fields = sanitize_data.data.take_struct().unwrap().fields;
+
+    let field_tokens = fields.iter().map(|f| f.to_tokens(&crate_name));
+
+    let ident = sanitize_data.ident;
+    let (imp, ty, whr) = sanitize_data.generics.split_for_impl();
+
+    quote!(
+        impl #imp #crate_name::Sanitize for #ident #ty #whr {
+            fn sanitize(&mut self) {
+                #(#field_tokens)*
+            }
+        }
+    )
+}
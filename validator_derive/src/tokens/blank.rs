@@ -0,0 +1,61 @@
+use quote::quote;
+
+use crate::types::{NonEmpty, NotBlank, Trimmed};
+use crate::utils::{quote_code, quote_message, CrateName};
+
+pub fn not_blank_tokens(
+    crate_name: &CrateName,
+    not_blank: NotBlank,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let message = quote_message(not_blank.message);
+    let code = quote_code(crate_name, not_blank.code, "not_blank");
+
+    quote! {
+        if !#field_name.validate_not_blank() {
+            #code
+            #message
+            err.add_param(::std::borrow::Cow::from("value"), &#field_name);
+            errors.add(#field_name_str, err);
+        }
+    }
+}
+
+pub fn non_empty_tokens(
+    crate_name: &CrateName,
+    non_empty: NonEmpty,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let message = quote_message(non_empty.message);
+    let code = quote_code(crate_name, non_empty.code, "non_empty");
+
+    quote! {
+        if !#field_name.validate_non_empty() {
+            #code
+            #message
+            err.add_param(::std::borrow::Cow::from("value"), &#field_name);
+            errors.add(#field_name_str, err);
+        }
+    }
+}
+
+pub fn trimmed_tokens(
+    crate_name: &CrateName,
+    trimmed: Trimmed,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let message = quote_message(trimmed.message);
+    let code = quote_code(crate_name, trimmed.code, "trimmed");
+
+    quote! {
+        if !#field_name.validate_trimmed() {
+            #code
+            #message
+            err.add_param(::std::borrow::Cow::from("value"), &#field_name);
+            errors.add(#field_name_str, err);
+        }
+    }
+}
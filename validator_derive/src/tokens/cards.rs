@@ -1,23 +1,127 @@
+use proc_macro2::Span;
 use quote::quote;
+use syn::Ident;
 
-use crate::types::Card;
+use crate::types::{Card, CardCvv, CardExpiry};
 use crate::utils::{quote_code, quote_message, CrateName};
 
+/// The names accepted in `brands` and the matching `CardBrand` variants
+pub static CARD_BRANDS: [(&str, &str); 9] = [
+    ("amex", "Amex"),
+    ("diners_club", "DinersClub"),
+    ("discover", "Discover"),
+    ("jcb", "Jcb"),
+    ("maestro", "Maestro"),
+    ("mastercard", "Mastercard"),
+    ("mir", "Mir"),
+    ("unionpay", "UnionPay"),
+    ("visa", "Visa"),
+];
+
+pub fn card_brand_variant(name: &str) -> Option<&'static str> {
+    CARD_BRANDS.iter().find(|(n, _)| *n == name).map(|(_, variant)| *variant)
+}
+
 pub fn credit_card_tokens(
     crate_name: &CrateName,
     credit_card: Card,
     field_name: &proc_macro2::TokenStream,
     field_name_str: &str,
 ) -> proc_macro2::TokenStream {
+    let (brands, brands_err) = if let Some(b) = credit_card.brands {
+        let variants = b.iter().map(|brand| {
+            let variant =
+                card_brand_variant(&brand.value()).expect("brand is checked in `validate`");
+            let variant = Ident::new(variant, Span::call_site());
+            quote!(#crate_name::CardBrand::#variant)
+        });
+        (
+            quote!(&[#(#variants),*]),
+            quote!(err.add_param(::std::borrow::Cow::from("brands"), &[#(#b),*]);),
+        )
+    } else {
+        (quote!(&[]), quote!())
+    };
+
     let message = quote_message(credit_card.message);
-    let code = quote_code(crate_name, credit_card.code, "credit_card");
+    let code = if let Some(c) = credit_card.code {
+        quote!(
+            let mut err = #crate_name::ValidationError::new(#c);
+        )
+    } else {
+        quote!(
+            let mut err = #crate_name::ValidationError::new(card_err.code());
+        )
+    };
 
     quote! {
-        if !#field_name.validate_credit_card() {
+        if let ::std::result::Result::Err(card_err) = #field_name.validate_credit_card_with_brands(#brands) {
             #code
             #message
+            if let ::std::option::Option::Some(brand) = #field_name.credit_card_brand() {
+                err.add_param(::std::borrow::Cow::from("brand"), &brand);
+            }
+            #brands_err
             err.add_param(::std::borrow::Cow::from("value"), &#field_name);
             errors.add(#field_name_str, err);
         }
     }
 }
+
+pub fn card_expiry_tokens(
+    crate_name: &CrateName,
+    card_expiry: CardExpiry,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let clock = if let Some(c) = card_expiry.clock {
+        quote!(&#c)
+    } else {
+        quote!(&#crate_name::SystemClock)
+    };
+
+    let message = quote_message(card_expiry.message);
+    let code = quote_code(crate_name, card_expiry.code, "card_expiry");
+
+    quote! {
+        if !#field_name.validate_card_expiry(#clock) {
+            #code
+            #message
+            err.add_param(::std::borrow::Cow::from("value"), &#field_name);
+            errors.add(#field_name_str, err);
+        }
+    }
+}
+
+pub fn card_cvv_tokens(
+    crate_name: &CrateName,
+    card_cvv: CardCvv,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let (brand, brand_err) = if let Some(b) = card_cvv.brand_field {
+        (
+            quote!(self.#b.credit_card_brand()),
+            quote!(
+                if let ::std::option::Option::Some(brand) = self.#b.credit_card_brand() {
+                    err.add_param(::std::borrow::Cow::from("brand"), &brand);
+                }
+            ),
+        )
+    } else {
+        (quote!(::std::option::Option::None), quote!())
+    };
+
+    let message = quote_message(card_cvv.message);
+    let code = quote_code(crate_name, card_cvv.code, "card_cvv");
+
+    // The value of the security code is never added to the params
+    quote! {
+        if !#field_name.validate_card_cvv(#brand) {
+            #code
+            #message
+            #brand_err
+            errors.add(#field_name_str, err);
+        }
+    }
+}
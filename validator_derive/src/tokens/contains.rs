@@ -1,28 +1,112 @@
 use quote::quote;
+use syn::{Expr, LitStr, Path};
 
+use crate::tokens::normalized::quote_text_comparison;
 use crate::types::Contains;
 use crate::utils::{quote_code, quote_message, CrateName};
 
+/// The static `PATTERNS` compiling the patterns once when `patterns`, `patterns_from` or
+/// `whole_word` is used, `None` otherwise
+pub fn quote_pattern_set(
+    crate_name: &CrateName,
+    pattern: Option<&String>,
+    patterns: Option<&Vec<LitStr>>,
+    patterns_from: Option<&Path>,
+    whole_word: Option<bool>,
+    normalize: Option<&LitStr>,
+    case_insensitive: Option<bool>,
+) -> Option<proc_macro2::TokenStream> {
+    if patterns.is_none() && patterns_from.is_none() && whole_word.is_none() {
+        return None;
+    }
+
+    let source = if let Some(patterns) = patterns {
+        quote!([#(#patterns),*])
+    } else if let Some(path) = patterns_from {
+        quote!(#path.iter())
+    } else {
+        quote!([#pattern])
+    };
+    let comparison = quote_text_comparison(crate_name, normalize, case_insensitive)
+        .unwrap_or_else(|| quote!(#crate_name::TextComparison::default()));
+    let whole_word = whole_word.unwrap_or(false);
+
+    Some(quote!(
+        static PATTERNS: ::std::sync::LazyLock<#crate_name::PatternSet> =
+            ::std::sync::LazyLock::new(|| #crate_name::PatternSet::new(#source, #comparison, #whole_word));
+    ))
+}
+
+/// The params describing what was searched: `needle` for a single pattern or element and
+/// `patterns` for a literal list
+pub fn quote_needle_params(
+    pattern: Option<&String>,
+    needle: Option<&Expr>,
+    patterns: Option<&Vec<LitStr>>,
+) -> proc_macro2::TokenStream {
+    if let Some(p) = pattern {
+        quote!(err.add_param(::std::borrow::Cow::from("needle"), &#p);)
+    } else if let Some(n) = needle {
+        quote!(err.add_param(::std::borrow::Cow::from("needle"), &#n);)
+    } else if let Some(patterns) = patterns {
+        quote!(err.add_param(::std::borrow::Cow::from("patterns"), &[#(#patterns),*]);)
+    } else {
+        quote!()
+    }
+}
+
 pub fn contains_tokens(
     crate_name: &CrateName,
     contains: Contains,
     field_name: &proc_macro2::TokenStream,
     field_name_str: &str,
 ) -> proc_macro2::TokenStream {
-    let p = contains.pattern;
-    let (needle, needle_err) =
-        (quote!(#p), quote!(err.add_param(::std::borrow::Cow::from("needle"), &#p);));
+    let needle_err = quote_needle_params(
+        contains.pattern.as_ref(),
+        contains.needle.as_ref(),
+        contains.patterns.as_ref(),
+    );
 
     let message = quote_message(contains.message);
     let code = quote_code(crate_name, contains.code, "contains");
 
+    let pattern_set = quote_pattern_set(
+        crate_name,
+        contains.pattern.as_ref(),
+        contains.patterns.as_ref(),
+        contains.patterns_from.as_ref(),
+        contains.whole_word,
+        contains.normalize.as_ref(),
+        contains.case_insensitive,
+    );
+    let comparison =
+        quote_text_comparison(crate_name, contains.normalize.as_ref(), contains.case_insensitive);
+    // A `needle` is compared with the elements, a `pattern` is a substring
+    let p = match contains.needle {
+        Some(n) => quote!(&#n),
+        None => {
+            let p = contains.pattern;
+            quote!(#p)
+        }
+    };
+    let is_valid = if pattern_set.is_some() {
+        quote!(#crate_name::validate_contains_any(&#field_name, &PATTERNS))
+    } else if let Some(comparison) = comparison {
+        quote!(#crate_name::validate_contains_with(&#field_name, #p, &#comparison))
+    } else {
+        quote!(#field_name.validate_contains(#p))
+    };
+
     quote! {
-        if !#field_name.validate_contains(#needle) {
-            #code
-            #message
-            #needle_err
-            err.add_param(::std::borrow::Cow::from("value"), &#field_name);
-            errors.add(#field_name_str, err);
+        {
+            #pattern_set
+            if !#is_valid {
+                #code
+                #message
+                #needle_err
+                err.add_param(::std::borrow::Cow::from("value"), &#field_name);
+                errors.add(#field_name_str, err);
+            }
         }
     }
 }
@@ -1,5 +1,7 @@
 use quote::quote;
 
+use crate::tokens::contains::{quote_needle_params, quote_pattern_set};
+use crate::tokens::normalized::quote_text_comparison;
 use crate::types::DoesNotContain;
 use crate::utils::{quote_code, quote_message, CrateName};
 
@@ -9,16 +11,65 @@ pub fn does_not_contain_tokens(
     field_name: &proc_macro2::TokenStream,
     field_name_str: &str,
 ) -> proc_macro2::TokenStream {
-    let p = does_not_contain.pattern;
-
-    let (needle, needle_err) =
-        (quote!(#p), quote!(err.add_param(::std::borrow::Cow::from("needle"), &#p);));
+    let needle_err = quote_needle_params(
+        does_not_contain.pattern.as_ref(),
+        does_not_contain.needle.as_ref(),
+        does_not_contain.patterns.as_ref(),
+    );
 
     let message = quote_message(does_not_contain.message);
     let code = quote_code(crate_name, does_not_contain.code, "does_not_contain");
 
+    let pattern_set = quote_pattern_set(
+        crate_name,
+        does_not_contain.pattern.as_ref(),
+        does_not_contain.patterns.as_ref(),
+        does_not_contain.patterns_from.as_ref(),
+        does_not_contain.whole_word,
+        does_not_contain.normalize.as_ref(),
+        does_not_contain.case_insensitive,
+    );
+
+    // The pattern found is added as a param when searching for several patterns
+    if pattern_set.is_some() {
+        return quote! {
+            {
+                #pattern_set
+                if let ::std::result::Result::Err(pattern) =
+                    #crate_name::validate_does_not_contain_any(&#field_name, &PATTERNS)
+                {
+                    #code
+                    #message
+                    #needle_err
+                    err.add_param(::std::borrow::Cow::from("pattern"), &pattern);
+                    err.add_param(::std::borrow::Cow::from("value"), &#field_name);
+                    errors.add(#field_name_str, err);
+                }
+            }
+        };
+    }
+
+    let comparison = quote_text_comparison(
+        crate_name,
+        does_not_contain.normalize.as_ref(),
+        does_not_contain.case_insensitive,
+    );
+    // A `needle` is compared with the elements, a `pattern` is a substring
+    let p = match does_not_contain.needle {
+        Some(n) => quote!(&#n),
+        None => {
+            let p = does_not_contain.pattern;
+            quote!(#p)
+        }
+    };
+    let is_valid = if let Some(comparison) = comparison {
+        quote!(#crate_name::validate_does_not_contain_with(&#field_name, #p, &#comparison))
+    } else {
+        quote!(#field_name.validate_does_not_contain(#p))
+    };
+
     quote! {
-        if !#field_name.validate_does_not_contain(#needle) {
+        if !#is_valid {
             #code
             #message
             #needle_err
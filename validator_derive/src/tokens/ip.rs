@@ -1,14 +1,64 @@
+use std::net::IpAddr;
+
 use quote::quote;
 
-use crate::types::Ip;
+use crate::types::{Cidr, Ip, SocketAddr};
 use crate::utils::{quote_code, quote_message, CrateName};
 
+/// Parses a network in CIDR notation so that invalid networks are caught at compile time
+pub fn parse_cidr(cidr: &str) -> Option<(IpAddr, u8)> {
+    let (address, prefix) = cidr.split_once('/')?;
+    if prefix.is_empty() || !prefix.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let address = address.parse::<IpAddr>().ok()?;
+    let prefix = prefix.parse::<u8>().ok()?;
+    let max = if address.is_ipv4() { 32 } else { 128 };
+
+    (prefix <= max).then_some((address, prefix))
+}
+
+fn quote_cidrs(crate_name: &CrateName, cidrs: &[syn::LitStr]) -> proc_macro2::TokenStream {
+    let cidrs = cidrs.iter().map(|c| {
+        let (address, prefix) = parse_cidr(&c.value()).expect("CIDR is checked in `validate`");
+        let address = match address {
+            IpAddr::V4(v4) => {
+                let [a, b, c, d] = v4.octets();
+                quote!(::std::net::IpAddr::V4(::std::net::Ipv4Addr::new(#a, #b, #c, #d)))
+            }
+            IpAddr::V6(v6) => {
+                let segments = v6.segments();
+                quote!(::std::net::IpAddr::V6(::std::net::Ipv6Addr::new(#(#segments),*)))
+            }
+        };
+        quote!(#crate_name::IpCidr::new(#address, #prefix))
+    });
+
+    quote!(&[#(#cidrs),*])
+}
+
+fn quote_version(
+    crate_name: &CrateName,
+    v4: Option<bool>,
+    v6: Option<bool>,
+) -> proc_macro2::TokenStream {
+    match (v4.unwrap_or(false), v6.unwrap_or(false)) {
+        (true, false) => quote!(Some(#crate_name::IpVersion::V4)),
+        (false, true) => quote!(Some(#crate_name::IpVersion::V6)),
+        _ => quote!(None),
+    }
+}
+
 pub fn ip_tokens(
     crate_name: &CrateName,
     ip: Ip,
     field_name: &proc_macro2::TokenStream,
     field_name_str: &str,
 ) -> proc_macro2::TokenStream {
+    if ip.has_rules() {
+        return ip_rules_tokens(crate_name, ip, field_name, field_name_str);
+    }
+
     let message = quote_message(ip.message);
     let code = quote_code(crate_name, ip.code, "ip");
 
@@ -44,3 +94,95 @@ pub fn ip_tokens(
         }
     }
 }
+
+fn ip_rules_tokens(
+    crate_name: &CrateName,
+    ip: Ip,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let version = quote_version(crate_name, ip.v4, ip.v6);
+    let public = ip.public.unwrap_or(false);
+    let private = ip.private.unwrap_or(false);
+    let not_loopback = ip.not_loopback.unwrap_or(false);
+
+    let (in_cidr, in_cidr_err) = if let Some(c) = ip.in_cidr {
+        let cidrs = quote_cidrs(crate_name, &c);
+        (
+            quote!(Some(#cidrs)),
+            quote!(err.add_param(::std::borrow::Cow::from("in_cidr"), &[#(#c),*]);),
+        )
+    } else {
+        (quote!(None), quote!())
+    };
+    let (not_in_cidr, not_in_cidr_err) = if let Some(c) = ip.not_in_cidr {
+        let cidrs = quote_cidrs(crate_name, &c);
+        (cidrs, quote!(err.add_param(::std::borrow::Cow::from("not_in_cidr"), &[#(#c),*]);))
+    } else {
+        (quote!(&[]), quote!())
+    };
+
+    let message = quote_message(ip.message);
+    let code = quote_code(crate_name, ip.code, "ip");
+
+    quote! {
+        if !#field_name.validate_ip_with_rules(&#crate_name::IpRules {
+            version: #version,
+            public: #public,
+            private: #private,
+            not_loopback: #not_loopback,
+            in_cidr: #in_cidr,
+            not_in_cidr: #not_in_cidr,
+        }) {
+            #code
+            #message
+            #in_cidr_err
+            #not_in_cidr_err
+            err.add_param(::std::borrow::Cow::from("value"), &#field_name);
+            errors.add(#field_name_str, err);
+        }
+    }
+}
+
+pub fn cidr_tokens(
+    crate_name: &CrateName,
+    cidr: Cidr,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let version = quote_version(crate_name, cidr.v4, cidr.v6);
+    let strict = cidr.strict.unwrap_or(false);
+
+    let message = quote_message(cidr.message);
+    let code = quote_code(crate_name, cidr.code, "cidr");
+
+    quote! {
+        if !#field_name.validate_cidr(#version, #strict) {
+            #code
+            #message
+            err.add_param(::std::borrow::Cow::from("value"), &#field_name);
+            errors.add(#field_name_str, err);
+        }
+    }
+}
+
+pub fn socket_addr_tokens(
+    crate_name: &CrateName,
+    socket_addr: SocketAddr,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let allow_hostname = socket_addr.allow_hostname.unwrap_or(false);
+
+    let message = quote_message(socket_addr.message);
+    let code = quote_code(crate_name, socket_addr.code, "socket_addr");
+
+    quote! {
+        if !#field_name.validate_socket_addr(#allow_hostname) {
+            #code
+            #message
+            err.add_param(::std::borrow::Cow::from("value"), &#field_name);
+            errors.add(#field_name_str, err);
+        }
+    }
+}
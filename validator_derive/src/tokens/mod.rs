@@ -1,3 +1,4 @@
+pub mod blank;
 pub mod cards;
 pub mod contains;
 pub mod custom;
@@ -8,8 +9,14 @@ pub mod length;
 pub mod must_match;
 pub mod nested;
 pub mod non_control_character;
+pub mod normalized;
+pub mod one_of;
+pub mod password;
 pub mod range;
 pub mod regex;
 pub mod required;
 pub mod schema;
+pub mod sorted;
+pub mod unicode_security;
+pub mod unique;
 pub mod url;
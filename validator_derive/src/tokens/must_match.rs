@@ -1,5 +1,6 @@
 use quote::quote;
 
+use crate::tokens::normalized::quote_text_comparison;
 use crate::types::MustMatch;
 use crate::utils::{quote_code, quote_message, CrateName};
 
@@ -10,14 +11,30 @@ pub fn must_match_tokens(
     field_name_str: &str,
 ) -> proc_macro2::TokenStream {
     let o = must_match.other;
-    let (other, other_err) =
-        (quote!(self.#o), quote!(err.add_param(::std::borrow::Cow::from("other"), &self.#o);));
+    let other = quote!(self.#o);
+    // The value of a sensitive field doesn't leak through the errors of the fields matching it
+    let other_err = if must_match.other_sensitive {
+        quote!(err.add_param(::std::borrow::Cow::from("other"), &#crate_name::REDACTED);)
+    } else {
+        quote!(err.add_param(::std::borrow::Cow::from("other"), &#other);)
+    };
 
     let message = quote_message(must_match.message);
     let code = quote_code(crate_name, must_match.code, "must_match");
 
+    let comparison = quote_text_comparison(
+        crate_name,
+        must_match.normalize.as_ref(),
+        must_match.case_insensitive,
+    );
+    let is_valid = if let Some(comparison) = comparison {
+        quote!(#crate_name::validate_must_match_with(&#field_name, &#other, &#comparison))
+    } else {
+        quote!(#crate_name::validate_must_match(&#field_name, &#other))
+    };
+
     quote! {
-        if !#crate_name::validate_must_match(&#field_name, &#other) {
+        if !#is_valid {
             #code
             #message
             #other_err
@@ -5,8 +5,10 @@ pub fn nested_tokens(
     field_name_str: &str,
 ) -> proc_macro2::TokenStream {
     quote! {
-        if let std::collections::hash_map::Entry::Vacant(entry) = errors.0.entry(::std::borrow::Cow::Borrowed(#field_name_str)) {
-            errors.merge_self(#field_name_str, (&#field_name).validate());
+        if !errors.0.contains_key(#field_name_str) {
+            if let ::std::result::Result::Err(kind) = (&#field_name).validate_nested() {
+                errors.merge_field(#field_name_str, kind);
+            }
         }
     }
 }
@@ -0,0 +1,71 @@
+use quote::{format_ident, quote};
+
+use crate::types::Normalized;
+use crate::utils::{quote_code, quote_message, CrateName};
+
+/// The normalization forms accepted by the derive and their `validator::NormalizationForm` variant
+pub static NORMALIZATION_FORMS: [(&str, &str); 2] = [("NFC", "Nfc"), ("NFKC", "Nfkc")];
+
+pub fn normalization_form_variant(name: &str) -> Option<&'static str> {
+    NORMALIZATION_FORMS.iter().find(|(n, _)| *n == name).map(|(_, variant)| *variant)
+}
+
+fn quote_normalization_form(
+    crate_name: &CrateName,
+    form: &syn::LitStr,
+) -> proc_macro2::TokenStream {
+    let variant = format_ident!(
+        "{}",
+        normalization_form_variant(&form.value()).expect("Normalization forms are validated")
+    );
+    quote!(#crate_name::NormalizationForm::#variant)
+}
+
+/// The `validator::TextComparison` used by `must_match`, `contains` and `does_not_contain`
+/// if any of their `normalize` or `case_insensitive` options is set
+pub fn quote_text_comparison(
+    crate_name: &CrateName,
+    normalize: Option<&syn::LitStr>,
+    case_insensitive: Option<bool>,
+) -> Option<proc_macro2::TokenStream> {
+    if normalize.is_none() && case_insensitive.is_none() {
+        return None;
+    }
+
+    let normalization = normalize.map(|form| {
+        let form = quote_normalization_form(crate_name, form);
+        quote!(.normalization(#form))
+    });
+    let case_insensitive = case_insensitive.unwrap_or(false);
+
+    Some(quote!(
+        #crate_name::TextComparison::default()
+            #normalization
+            .case_insensitive(#case_insensitive)
+    ))
+}
+
+pub fn normalized_tokens(
+    crate_name: &CrateName,
+    normalized: Normalized,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let form =
+        normalized.form.unwrap_or_else(|| syn::LitStr::new("NFC", proc_macro2::Span::call_site()));
+    let form_name = form.value();
+    let form = quote_normalization_form(crate_name, &form);
+
+    let message = quote_message(normalized.message);
+    let code = quote_code(crate_name, normalized.code, "normalized");
+
+    quote! {
+        if !#field_name.validate_normalized(#form) {
+            #code
+            #message
+            err.add_param(::std::borrow::Cow::from("form"), &#form_name);
+            err.add_param(::std::borrow::Cow::from("value"), &#field_name);
+            errors.add(#field_name_str, err);
+        }
+    }
+}
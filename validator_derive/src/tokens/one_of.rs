@@ -0,0 +1,118 @@
+use quote::{format_ident, quote};
+use syn::{ExprArray, LitStr, Path};
+
+use crate::tokens::normalized::quote_text_comparison;
+use crate::types::{NoneOf, OneOf};
+use crate::utils::{quote_code, quote_message, CrateName};
+
+/// The check of `one_of` or `none_of`, depending on `validator`.
+///
+/// Literal lists are given as a slice of references so that their type is inferred from the
+/// field, which makes `values = [80, 443]` work on a `u16`.
+fn quote_is_valid(
+    crate_name: &CrateName,
+    validator: &str,
+    values: Option<&ExprArray>,
+    values_from: Option<&Path>,
+    normalize: Option<&LitStr>,
+    case_insensitive: Option<bool>,
+    field_name: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let list = match (values, values_from) {
+        (Some(values), _) => {
+            let values = values.elems.iter();
+            quote!([#(&#values),*])
+        }
+        (None, Some(path)) => quote!(#path.iter()),
+        (None, None) => unreachable!("`values` or `values_from` is validated to be set"),
+    };
+
+    if let Some(comparison) = quote_text_comparison(crate_name, normalize, case_insensitive) {
+        let function = format_ident!("validate_{}_with", validator);
+        quote!(#crate_name::#function(&#field_name, #list, &#comparison))
+    } else if values.is_some() {
+        let method = format_ident!("validate_{}", validator);
+        quote!(#field_name.#method(&#list))
+    } else {
+        let method = format_ident!("validate_{}_in", validator);
+        quote!(#field_name.#method(#list))
+    }
+}
+
+pub fn one_of_tokens(
+    crate_name: &CrateName,
+    one_of: OneOf,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let is_valid = quote_is_valid(
+        crate_name,
+        "one_of",
+        one_of.values.as_ref(),
+        one_of.values_from.as_ref(),
+        one_of.normalize.as_ref(),
+        one_of.case_insensitive,
+        field_name,
+    );
+    // The allowed values are always added since messages will most likely list them
+    let values_err = if let Some(values) = &one_of.values {
+        let values = values.elems.iter();
+        quote!(err.add_param(::std::borrow::Cow::from("values"), &[#(#values),*]);)
+    } else {
+        let path = &one_of.values_from;
+        quote!(
+            err.add_param(
+                ::std::borrow::Cow::from("values"),
+                &#path.iter().collect::<::std::vec::Vec<_>>(),
+            );
+        )
+    };
+
+    let message = quote_message(one_of.message);
+    let code = quote_code(crate_name, one_of.code, "one_of");
+
+    quote! {
+        if !#is_valid {
+            #code
+            #message
+            #values_err
+            err.add_param(::std::borrow::Cow::from("value"), &#field_name);
+            errors.add(#field_name_str, err);
+        }
+    }
+}
+
+pub fn none_of_tokens(
+    crate_name: &CrateName,
+    none_of: NoneOf,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let is_valid = quote_is_valid(
+        crate_name,
+        "none_of",
+        none_of.values.as_ref(),
+        none_of.values_from.as_ref(),
+        none_of.normalize.as_ref(),
+        none_of.case_insensitive,
+        field_name,
+    );
+    // Like `does_not_contain`, a list coming from a path can be long so it isn't added
+    let values_err = none_of.values.as_ref().map(|values| {
+        let values = values.elems.iter();
+        quote!(err.add_param(::std::borrow::Cow::from("values"), &[#(#values),*]);)
+    });
+
+    let message = quote_message(none_of.message);
+    let code = quote_code(crate_name, none_of.code, "none_of");
+
+    quote! {
+        if !#is_valid {
+            #code
+            #message
+            #values_err
+            err.add_param(::std::borrow::Cow::from("value"), &#field_name);
+            errors.add(#field_name_str, err);
+        }
+    }
+}
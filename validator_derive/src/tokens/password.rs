@@ -0,0 +1,73 @@
+use quote::quote;
+
+use crate::types::Password;
+use crate::utils::{quote_message, CrateName};
+
+pub fn password_tokens(
+    crate_name: &CrateName,
+    password: Password,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let min_score = match password.min_score {
+        Some(s) => quote!(::std::option::Option::Some(#s)),
+        None => quote!(::std::option::Option::None),
+    };
+    let min_length = match password.min_length {
+        Some(l) => quote!(::std::option::Option::Some(#l)),
+        None => quote!(::std::option::Option::None),
+    };
+    let require_classes = match password.require_classes {
+        Some(c) => quote!(::std::option::Option::Some(#c)),
+        None => quote!(::std::option::Option::None),
+    };
+    let blocklist = match password.blocklist {
+        Some(b) => quote!(::std::option::Option::Some(&#b as &dyn #crate_name::PasswordBlocklist)),
+        None => quote!(::std::option::Option::None),
+    };
+
+    let forbidden = password.forbid_fields.map(|f| f.elems.into_iter().collect::<Vec<_>>());
+    let forbidden = match forbidden {
+        Some(f) => quote!(
+            [#(self.#f.as_user_input()),*]
+                .into_iter()
+                .flatten()
+                .collect::<::std::vec::Vec<&str>>()
+        ),
+        None => quote!(::std::vec::Vec::<&str>::new()),
+    };
+
+    let message = quote_message(password.message);
+    let code = if let Some(c) = password.code {
+        quote!(
+            let mut err = #crate_name::ValidationError::new(#c);
+        )
+    } else {
+        quote!(
+            let mut err = #crate_name::ValidationError::new(__validator_password_err.kind.code());
+        )
+    };
+
+    // The value of the password is never added to the params. The locals are prefixed so that
+    // they don't shadow the `Option` fields bound by name
+    quote! {
+        let __validator_forbidden = #forbidden;
+        let __validator_password_rules = #crate_name::PasswordRules {
+            min_score: #min_score,
+            min_length: #min_length,
+            require_classes: #require_classes,
+            forbidden: &__validator_forbidden,
+            blocklist: #blocklist,
+        };
+        if let ::std::result::Result::Err(__validator_password_err) = #field_name.validate_password(&__validator_password_rules) {
+            #code
+            #message
+            err.add_param(::std::borrow::Cow::from("score"), &__validator_password_err.strength.score);
+            err.add_param(
+                ::std::borrow::Cow::from("suggestions"),
+                &__validator_password_err.strength.suggestions,
+            );
+            errors.add(#field_name_str, err);
+        }
+    }
+}
@@ -13,8 +13,22 @@ pub fn required_tokens(
     let message = quote_message(required.message);
     let code = quote_code(crate_name, required.code, "required");
 
+    // `Some("")` is treated like `None`, which also works for empty collections
+    let is_valid = if required.empty_as_none.unwrap_or(false) {
+        quote!(
+            match &self.#field_name {
+                ::std::option::Option::Some(value) => {
+                    #crate_name::ValidateNonEmpty::validate_non_empty(value)
+                }
+                ::std::option::Option::None => false,
+            }
+        )
+    } else {
+        quote!(self.#field_name.validate_required())
+    };
+
     quote! {
-        if !self.#field_name.validate_required() {
+        if !#is_valid {
             #code
             #message
             err.add_param(::std::borrow::Cow::from("value"), &self.#field_name);
@@ -0,0 +1,40 @@
+use quote::quote;
+
+use crate::types::Sorted;
+use crate::utils::{quote_code, quote_message, CrateName};
+
+pub fn sorted_tokens(
+    crate_name: &CrateName,
+    sorted: Sorted,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let (order, order_name) = if sorted.desc.unwrap_or(false) {
+        (quote!(#crate_name::SortOrder::Descending), "desc")
+    } else {
+        (quote!(#crate_name::SortOrder::Ascending), "asc")
+    };
+    let strict = sorted.strict.unwrap_or(false);
+
+    let message = quote_message(sorted.message);
+    let code = quote_code(crate_name, sorted.code, "sorted");
+
+    quote! {
+        {
+            let items = #field_name.iter().collect::<::std::vec::Vec<_>>();
+            if let ::std::result::Result::Err(out_of_order) =
+                #crate_name::validate_sorted(&items, #order, #strict)
+            {
+                for index in out_of_order {
+                    #code
+                    #message
+                    err.add_param(::std::borrow::Cow::from("order"), &#order_name);
+                    err.add_param(::std::borrow::Cow::from("strict"), &#strict);
+                    err.add_param(::std::borrow::Cow::from("previous_index"), &(index - 1));
+                    err.add_param(::std::borrow::Cow::from("value"), &items[index]);
+                    errors.add_item(#field_name_str, index, "_item", err);
+                }
+            }
+        }
+    }
+}
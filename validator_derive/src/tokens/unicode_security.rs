@@ -0,0 +1,102 @@
+use quote::quote;
+
+use crate::types::{NoBidiControls, NoConfusableWith, NoInvisible, SingleScript};
+use crate::utils::{quote_code, quote_message, CrateName};
+
+pub fn single_script_tokens(
+    crate_name: &CrateName,
+    single_script: SingleScript,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let message = quote_message(single_script.message);
+    let code = quote_code(crate_name, single_script.code, "single_script");
+
+    quote! {
+        if let ::std::result::Result::Err(__validator_positions) = #field_name.validate_single_script() {
+            #code
+            #message
+            err.add_param(::std::borrow::Cow::from("positions"), &__validator_positions);
+            err.add_param(::std::borrow::Cow::from("value"), &#field_name);
+            errors.add(#field_name_str, err);
+        }
+    }
+}
+
+pub fn no_confusable_with_tokens(
+    crate_name: &CrateName,
+    no_confusable_with: NoConfusableWith,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let values = no_confusable_with.values.unwrap_or_default();
+    // The locals are prefixed so that they don't shadow the fields bound by the `Option` wrapper
+    let others = if let Some(f) = no_confusable_with.field {
+        quote!(
+            let __validator_other = self.#f.as_unicode_security_str();
+            let __validator_others = __validator_other
+                .as_deref()
+                .into_iter()
+                .chain([#(#values),*])
+                .collect::<::std::vec::Vec<&str>>();
+        )
+    } else {
+        quote!(
+            let __validator_others = [#(#values),*];
+        )
+    };
+
+    let message = quote_message(no_confusable_with.message);
+    let code = quote_code(crate_name, no_confusable_with.code, "no_confusable_with");
+
+    quote! {
+        #others
+        if let ::std::result::Result::Err(__validator_positions) = #field_name.validate_no_confusable_with(&__validator_others) {
+            #code
+            #message
+            err.add_param(::std::borrow::Cow::from("positions"), &__validator_positions);
+            err.add_param(::std::borrow::Cow::from("value"), &#field_name);
+            errors.add(#field_name_str, err);
+        }
+    }
+}
+
+pub fn no_bidi_controls_tokens(
+    crate_name: &CrateName,
+    no_bidi_controls: NoBidiControls,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let message = quote_message(no_bidi_controls.message);
+    let code = quote_code(crate_name, no_bidi_controls.code, "no_bidi_controls");
+
+    quote! {
+        if let ::std::result::Result::Err(__validator_positions) = #field_name.validate_no_bidi_controls() {
+            #code
+            #message
+            err.add_param(::std::borrow::Cow::from("positions"), &__validator_positions);
+            err.add_param(::std::borrow::Cow::from("value"), &#field_name);
+            errors.add(#field_name_str, err);
+        }
+    }
+}
+
+pub fn no_invisible_tokens(
+    crate_name: &CrateName,
+    no_invisible: NoInvisible,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let message = quote_message(no_invisible.message);
+    let code = quote_code(crate_name, no_invisible.code, "no_invisible");
+
+    quote! {
+        if let ::std::result::Result::Err(__validator_positions) = #field_name.validate_no_invisible() {
+            #code
+            #message
+            err.add_param(::std::borrow::Cow::from("positions"), &__validator_positions);
+            err.add_param(::std::borrow::Cow::from("value"), &#field_name);
+            errors.add(#field_name_str, err);
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use quote::quote;
+
+use crate::types::{Unique, UniqueBy};
+use crate::utils::{quote_code, quote_message, CrateName};
+
+pub fn unique_tokens(
+    crate_name: &CrateName,
+    unique: Unique,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let message = quote_message(unique.message);
+    let code = quote_code(crate_name, unique.code, "unique");
+
+    quote! {
+        {
+            let items = #field_name.iter().collect::<::std::vec::Vec<_>>();
+            if let ::std::result::Result::Err(duplicates) = #crate_name::validate_unique(&items) {
+                for (index, first_index) in duplicates {
+                    #code
+                    #message
+                    err.add_param(::std::borrow::Cow::from("first_index"), &first_index);
+                    err.add_param(::std::borrow::Cow::from("value"), &items[index]);
+                    errors.add_item(#field_name_str, index, "_item", err);
+                }
+            }
+        }
+    }
+}
+
+pub fn unique_by_tokens(
+    crate_name: &CrateName,
+    unique_by: UniqueBy,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let by = unique_by.field.get_ident().expect("`unique_by` is validated to be an ident");
+    let by_str = by.to_string();
+
+    let message = quote_message(unique_by.message);
+    let code = quote_code(crate_name, unique_by.code, "unique_by");
+
+    quote! {
+        {
+            let keys = #field_name.iter().map(|item| &item.#by).collect::<::std::vec::Vec<_>>();
+            if let ::std::result::Result::Err(duplicates) = #crate_name::validate_unique(&keys) {
+                for (index, first_index) in duplicates {
+                    #code
+                    #message
+                    err.add_param(::std::borrow::Cow::from("first_index"), &first_index);
+                    err.add_param(::std::borrow::Cow::from("value"), &keys[index]);
+                    errors.add_item(#field_name_str, index, #by_str, err);
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use quote::quote;
 
-use crate::types::Url;
+use crate::types::{PublicUrl, Url};
 use crate::utils::{quote_code, quote_message, CrateName};
 
 pub fn url_tokens(
@@ -9,6 +9,10 @@ pub fn url_tokens(
     field_name: &proc_macro2::TokenStream,
     field_name_str: &str,
 ) -> proc_macro2::TokenStream {
+    if url.has_policy() {
+        return url_policy_tokens(crate_name, url, field_name, field_name_str);
+    }
+
     let message = quote_message(url.message);
     let code = quote_code(crate_name, url.code, "url");
 
@@ -21,3 +25,124 @@ pub fn url_tokens(
         }
     }
 }
+
+fn url_policy_tokens(
+    crate_name: &CrateName,
+    url: Url,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    // Clippy complains about a struct update when all the fields are set
+    let defaults = if url.schemes.is_some()
+        && url.require_host.is_some()
+        && url.allow_credentials.is_some()
+        && url.max_length.is_some()
+        && url.allow_fragments.is_some()
+        && url.allowed_hosts.is_some()
+    {
+        quote!()
+    } else {
+        quote!(..::std::default::Default::default())
+    };
+    let (schemes, schemes_err) = if let Some(s) = url.schemes {
+        (
+            quote!(schemes: Some(&[#(#s),*]),),
+            quote!(err.add_param(::std::borrow::Cow::from("schemes"), &[#(#s),*]);),
+        )
+    } else {
+        (quote!(), quote!())
+    };
+    let (allowed_hosts, allowed_hosts_err) = if let Some(h) = url.allowed_hosts {
+        (
+            quote!(allowed_hosts: Some(&[#(#h),*]),),
+            quote!(err.add_param(::std::borrow::Cow::from("allowed_hosts"), &[#(#h),*]);),
+        )
+    } else {
+        (quote!(), quote!())
+    };
+    let (max_length, max_length_err) = if let Some(m) = url.max_length {
+        (
+            quote!(max_length: Some(#m),),
+            quote!(err.add_param(::std::borrow::Cow::from("max_length"), &#m);),
+        )
+    } else {
+        (quote!(), quote!())
+    };
+    let require_host = url.require_host.map(|r| quote!(require_host: #r,));
+    let allow_credentials = url.allow_credentials.map(|a| quote!(allow_credentials: #a,));
+    let allow_fragments = url.allow_fragments.map(|a| quote!(allow_fragments: #a,));
+
+    let message = quote_message(url.message);
+    // A custom code replaces the code of every violation
+    let (violation, code) = if let Some(c) = url.code {
+        (
+            quote!(_),
+            quote!(
+                let mut err = #crate_name::ValidationError::new(#c);
+            ),
+        )
+    } else {
+        (
+            quote!(violation),
+            quote!(
+                let mut err = #crate_name::ValidationError::new(violation.code());
+            ),
+        )
+    };
+
+    quote! {
+        if let ::std::result::Result::Err(#violation) = #field_name.validate_url_with_policy(&#crate_name::UrlPolicy {
+            #schemes
+            #require_host
+            #allow_credentials
+            #max_length
+            #allow_fragments
+            #allowed_hosts
+            #defaults
+        }) {
+            #code
+            #message
+            #schemes_err
+            #allowed_hosts_err
+            #max_length_err
+            err.add_param(::std::borrow::Cow::from("value"), &#field_name);
+            errors.add(#field_name_str, err);
+        }
+    }
+}
+
+pub fn public_url_tokens(
+    crate_name: &CrateName,
+    public_url: PublicUrl,
+    field_name: &proc_macro2::TokenStream,
+    field_name_str: &str,
+) -> proc_macro2::TokenStream {
+    let validation = if let Some(resolver) = public_url.resolver {
+        quote!(validate_url_public_with_resolver(&#resolver))
+    } else {
+        quote!(validate_url_public())
+    };
+
+    let message = quote_message(public_url.message);
+    let code = if let Some(c) = public_url.code {
+        quote!(
+            let mut err = #crate_name::ValidationError::new(#c);
+        )
+    } else {
+        quote!(
+            let mut err = #crate_name::ValidationError::new(violation.code());
+        )
+    };
+
+    quote! {
+        if let ::std::result::Result::Err(violation) = #field_name.#validation {
+            #code
+            #message
+            if let #crate_name::PublicUrlViolation::PrivateAddress(address) = violation {
+                err.add_param(::std::borrow::Cow::from("address"), &address);
+            }
+            err.add_param(::std::borrow::Cow::from("value"), &#field_name);
+            errors.add(#field_name_str, err);
+        }
+    }
+}
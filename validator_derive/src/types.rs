@@ -4,10 +4,13 @@ use darling::util::Override;
 use darling::{FromField, FromMeta};
 
 use proc_macro_error3::abort;
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::spanned::Spanned;
 use syn::{Expr, Field, Ident, Path};
 
+use crate::tokens::cards::{card_brand_variant, CARD_BRANDS};
+use crate::tokens::ip::parse_cidr;
+use crate::tokens::normalized::{normalization_form_variant, NORMALIZATION_FORMS};
 use crate::utils::{get_attr, CrateName};
 
 static OPTIONS_TYPE: [&str; 3] = ["Option|", "std|option|Option|", "core|option|Option|"];
@@ -52,21 +55,41 @@ pub struct ValidateField {
     pub ty: syn::Type,
     // pub attrs: Vec<syn::Attribute>,
     pub credit_card: Option<Override<Card>>,
+    pub card_expiry: Option<Override<CardExpiry>>,
+    pub card_cvv: Option<Override<CardCvv>>,
     pub contains: Option<Contains>,
     pub does_not_contain: Option<DoesNotContain>,
     pub email: Option<Override<Email>>,
     pub ip: Option<Override<Ip>>,
+    pub cidr: Option<Override<Cidr>>,
+    pub socket_addr: Option<Override<SocketAddr>>,
     pub length: Option<Length>,
     pub must_match: Option<MustMatch>,
     pub non_control_character: Option<Override<NonControlCharacter>>,
+    pub not_blank: Option<Override<NotBlank>>,
+    pub non_empty: Option<Override<NonEmpty>>,
+    pub trimmed: Option<Override<Trimmed>>,
+    pub normalized: Option<Override<Normalized>>,
+    pub one_of: Option<OneOf>,
+    pub none_of: Option<NoneOf>,
+    pub password: Option<Override<Password>>,
+    pub single_script: Option<Override<SingleScript>>,
+    pub no_confusable_with: Option<NoConfusableWith>,
+    pub no_bidi_controls: Option<Override<NoBidiControls>>,
+    pub no_invisible: Option<Override<NoInvisible>>,
     pub range: Option<Range>,
     pub required: Option<Override<Required>>,
     pub url: Option<Override<Url>>,
+    pub public_url: Option<Override<PublicUrl>>,
     pub regex: Option<Regex>,
+    pub unique: Option<Override<Unique>>,
+    pub unique_by: Option<UniqueBy>,
+    pub sorted: Option<Override<Sorted>>,
     #[darling(multiple)]
     pub custom: Vec<Custom>,
     pub skip: Option<bool>,
     pub nested: Option<bool>,
+    pub sensitive: Option<bool>,
     /// Placeholder for the crate name, filled in by the [`ValidationData`](crate::ValidationData) value.
     #[darling(skip)]
     pub crate_name: CrateName,
@@ -133,6 +156,239 @@ impl ValidateField {
             }
         }
 
+        if let Some(Override::Explicit(card)) = &self.credit_card {
+            for brand in card.brands.iter().flatten() {
+                if card_brand_variant(&brand.value()).is_none() {
+                    abort!(
+                        brand.span(), "Invalid attribute #[validate(credit_card(...))] on field `{}`:", field_name;
+                        note = "`{}` is not a known card brand", brand.value();
+                        help = "The known brands are {}", CARD_BRANDS.map(|(name, _)| format!("`{}`", name)).join(", ")
+                    )
+                }
+            }
+        }
+
+        if let Some(Override::Explicit(CardCvv { brand_field: Some(brand_field), .. })) =
+            &self.card_cvv
+        {
+            let other_field = brand_field
+                .get_ident()
+                .expect("Cannot get ident from `brand_field` field value")
+                .to_string();
+
+            if !all_fields.iter().any(|f| f.ident.clone().unwrap() == other_field) {
+                abort!(
+                    brand_field.span(), "Invalid attribute for #[validate(card_cvv(...))] on field `{}`:", field_name;
+                    note =  "The `brand_field` field doesn't exist in the struct `{}`", struct_ident;
+                    help = "Add the field `{}` to the struct", other_field
+                )
+            }
+        }
+
+        let pattern_sources = [
+            self.contains.as_ref().map(|c| {
+                let sources = [
+                    c.pattern.is_some(),
+                    c.needle.is_some(),
+                    c.patterns.is_some(),
+                    c.patterns_from.is_some(),
+                ];
+                let text_options =
+                    [c.normalize.is_some(), c.case_insensitive.is_some(), c.whole_word.is_some()];
+                ("contains", sources, c.needle.is_some() && text_options.contains(&true))
+            }),
+            self.does_not_contain.as_ref().map(|d| {
+                let sources = [
+                    d.pattern.is_some(),
+                    d.needle.is_some(),
+                    d.patterns.is_some(),
+                    d.patterns_from.is_some(),
+                ];
+                let text_options =
+                    [d.normalize.is_some(), d.case_insensitive.is_some(), d.whole_word.is_some()];
+                ("does_not_contain", sources, d.needle.is_some() && text_options.contains(&true))
+            }),
+        ];
+        for (validator, sources, needle_with_text_options) in pattern_sources.into_iter().flatten()
+        {
+            if sources.into_iter().filter(|s| *s).count() != 1 {
+                abort!(
+                    get_attr(field_attrs, validator).unwrap(), "Invalid attribute #[validate({}(...))] on field `{}`:", validator, field_name;
+                    note = "Validator `{}` requires exactly one of `pattern`, `needle`, `patterns` or `patterns_from`", validator;
+                    help = "Use `pattern` for a substring, `needle` for an element and `patterns` or `patterns_from` for a list of substrings"
+                )
+            }
+
+            if needle_with_text_options {
+                abort!(
+                    get_attr(field_attrs, validator).unwrap(), "Invalid attribute #[validate({}(...))] on field `{}`:", validator, field_name;
+                    note = "`needle` can't be used with `normalize`, `case_insensitive` or `whole_word`";
+                    help = "Use `pattern` to search for a substring in a string"
+                )
+            }
+        }
+
+        let normalization_forms = [
+            self.normalized.as_ref().and_then(|n| match n {
+                Override::Explicit(n) => n.form.as_ref().map(|f| (f, "normalized")),
+                Override::Inherit => None,
+            }),
+            self.must_match.as_ref().and_then(|m| m.normalize.as_ref().map(|f| (f, "must_match"))),
+            self.contains.as_ref().and_then(|c| c.normalize.as_ref().map(|f| (f, "contains"))),
+            self.does_not_contain
+                .as_ref()
+                .and_then(|d| d.normalize.as_ref().map(|f| (f, "does_not_contain"))),
+            self.one_of.as_ref().and_then(|o| o.normalize.as_ref().map(|f| (f, "one_of"))),
+            self.none_of.as_ref().and_then(|n| n.normalize.as_ref().map(|f| (f, "none_of"))),
+        ];
+        for (form, validator) in normalization_forms.into_iter().flatten() {
+            if normalization_form_variant(&form.value()).is_none() {
+                abort!(
+                    form.span(), "Invalid attribute #[validate({}(...))] on field `{}`:", validator, field_name;
+                    note = "`{}` is not a supported normalization form", form.value();
+                    help = "The supported forms are {}", NORMALIZATION_FORMS.map(|(name, _)| format!("`{}`", name)).join(", ")
+                )
+            }
+        }
+
+        let value_lists = [
+            self.one_of.as_ref().map(|o| {
+                ("one_of", o.values.as_ref(), o.values_from.is_some(), o.case_insensitive)
+            }),
+            self.none_of.as_ref().map(|n| {
+                ("none_of", n.values.as_ref(), n.values_from.is_some(), n.case_insensitive)
+            }),
+        ];
+        for (validator, values, has_values_from, case_insensitive) in
+            value_lists.into_iter().flatten()
+        {
+            if values.is_some() == has_values_from {
+                abort!(
+                    get_attr(field_attrs, validator).unwrap(), "Invalid attribute #[validate({}(...))] on field `{}`:", validator, field_name;
+                    note = "Validator `{}` requires exactly one of `values` or `values_from`", validator;
+                    help = "Use `values` for a literal list and `values_from` for the path to a static slice or set"
+                )
+            }
+
+            let Some(values) = values else { continue };
+            if values.elems.is_empty() {
+                abort!(
+                    values.span(), "Invalid attribute #[validate({}(...))] on field `{}`:", validator, field_name;
+                    note = "The list of `values` is empty";
+                    help = "Add at least one value to the list"
+                )
+            }
+
+            // Strings are compared as they will be at runtime, the other values as they are written
+            let mut seen = Vec::new();
+            for value in &values.elems {
+                let key = match value {
+                    Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(s), .. }) => {
+                        if case_insensitive == Some(true) {
+                            s.value().to_lowercase()
+                        } else {
+                            s.value()
+                        }
+                    }
+                    _ => value.to_token_stream().to_string(),
+                };
+                if seen.contains(&key) {
+                    abort!(
+                        value.span(), "Invalid attribute #[validate({}(...))] on field `{}`:", validator, field_name;
+                        note = "`{}` is in the list of `values` more than once", value.to_token_stream();
+                        help = "Remove the duplicate value"
+                    )
+                }
+                seen.push(key);
+            }
+        }
+
+        if let Some(unique_by) = &self.unique_by {
+            if unique_by.field.get_ident().is_none() {
+                abort!(
+                    unique_by.field.span(), "Invalid attribute #[validate(unique_by(...))] on field `{}`:", field_name;
+                    note = "`unique_by` only accepts the name of a field of the items";
+                    help = "Try formating the argument like `unique_by = \"sku\"`"
+                )
+            }
+        }
+
+        if let Some(no_confusable_with) = &self.no_confusable_with {
+            if no_confusable_with.field.is_none() && no_confusable_with.values.is_none() {
+                abort!(
+                    get_attr(field_attrs, "no_confusable_with").unwrap(), "Invalid attribute #[validate(no_confusable_with(...))] on field `{}`:", field_name;
+                    note = "Validator `no_confusable_with` requires at least 1 argument";
+                    help = "Add the argument `field` or `values`"
+                )
+            }
+
+            if let Some(other) = &no_confusable_with.field {
+                let other_field = other
+                    .get_ident()
+                    .expect("Cannot get ident from `field` field value")
+                    .to_string();
+
+                if !all_fields.iter().any(|f| f.ident.clone().unwrap() == other_field) {
+                    abort!(
+                        other.span(), "Invalid attribute for #[validate(no_confusable_with(...))] on field `{}`:", field_name;
+                        note =  "The `field` field doesn't exist in the struct `{}`", struct_ident;
+                        help = "Add the field `{}` to the struct", other_field
+                    )
+                }
+            }
+        }
+
+        if let Some(Override::Explicit(password)) = &self.password {
+            if password.min_score.is_some_and(|s| s > 4) {
+                abort!(
+                    get_attr(field_attrs, "password").unwrap(), "Invalid attribute #[validate(password(...))] on field `{}`:", field_name;
+                    note = "`min_score` goes from 0 to 4";
+                    help = "Use a `min_score` of 4 at most"
+                )
+            }
+
+            for forbid_field in password.forbid_fields.iter().flat_map(|f| f.elems.iter()) {
+                let Some(other_field) = (match forbid_field {
+                    Expr::Path(p) => p.path.get_ident().map(|i| i.to_string()),
+                    _ => None,
+                }) else {
+                    abort!(
+                        forbid_field.span(), "Invalid attribute for #[validate(password(...))] on field `{}`:", field_name;
+                        note = "`forbid_fields` only accepts field names";
+                        help = "Try formating the argument like `forbid_fields = [email, username]`"
+                    )
+                };
+
+                if !all_fields.iter().any(|f| f.ident.clone().unwrap() == other_field) {
+                    abort!(
+                        forbid_field.span(), "Invalid attribute for #[validate(password(...))] on field `{}`:", field_name;
+                        note =  "The `forbid_fields` field `{}` doesn't exist in the struct `{}`", other_field, struct_ident;
+                        help = "Add the field `{}` to the struct", other_field
+                    )
+                }
+            }
+        }
+
+        if let Some(Override::Explicit(ip)) = &self.ip {
+            if ip.public == Some(true) && ip.private == Some(true) {
+                abort!(
+                    get_attr(field_attrs, "ip").unwrap(), "Invalid attribute #[validate(ip(...))] on field `{}`:", field_name;
+                    note = "Both `public` and `private` have been set";
+                    help = "An address can't be both public and private, use only one of them"
+                )
+            }
+
+            for cidr in ip.in_cidr.iter().chain(ip.not_in_cidr.iter()).flatten() {
+                if parse_cidr(&cidr.value()).is_none() {
+                    abort!(
+                        cidr.span(), "Invalid attribute #[validate(ip(...))] on field `{}`:", field_name;
+                        note = "`{}` is not a network in CIDR notation", cidr.value();
+                        help = "Networks are written like `10.0.0.0/8` or `2001:db8::/32`"
+                    )
+                }
+            }
+        }
+
         if let Some(range) = &self.range {
             // Check if validator has no arguments
             if range.min.is_none()
@@ -227,20 +483,67 @@ impl ValidateField {
 //
 #[derive(Debug, Clone, FromMeta, Default)]
 pub struct Card {
+    pub brands: Option<Vec<syn::LitStr>>,
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
+#[derive(Debug, Clone, FromMeta, Default)]
+pub struct CardExpiry {
+    pub clock: Option<Expr>,
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
+#[derive(Debug, Clone, FromMeta, Default)]
+pub struct CardCvv {
+    pub brand_field: Option<Path>,
     pub message: Option<String>,
     pub code: Option<String>,
 }
 
 #[derive(Debug, Clone, FromMeta)]
 pub struct Contains {
-    pub pattern: String,
+    pub pattern: Option<String>,
+    pub needle: Option<Expr>,
+    pub patterns: Option<Vec<syn::LitStr>>,
+    pub patterns_from: Option<Path>,
+    pub whole_word: Option<bool>,
+    pub normalize: Option<syn::LitStr>,
+    pub case_insensitive: Option<bool>,
     pub message: Option<String>,
     pub code: Option<String>,
 }
 
 #[derive(Debug, Clone, FromMeta)]
 pub struct DoesNotContain {
-    pub pattern: String,
+    pub pattern: Option<String>,
+    pub needle: Option<Expr>,
+    pub patterns: Option<Vec<syn::LitStr>>,
+    pub patterns_from: Option<Path>,
+    pub whole_word: Option<bool>,
+    pub normalize: Option<syn::LitStr>,
+    pub case_insensitive: Option<bool>,
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
+#[derive(Debug, Clone, FromMeta)]
+pub struct OneOf {
+    pub values: Option<syn::ExprArray>,
+    pub values_from: Option<Path>,
+    pub normalize: Option<syn::LitStr>,
+    pub case_insensitive: Option<bool>,
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
+#[derive(Debug, Clone, FromMeta)]
+pub struct NoneOf {
+    pub values: Option<syn::ExprArray>,
+    pub values_from: Option<Path>,
+    pub normalize: Option<syn::LitStr>,
+    pub case_insensitive: Option<bool>,
     pub message: Option<String>,
     pub code: Option<String>,
 }
@@ -255,6 +558,38 @@ pub struct Email {
 pub struct Ip {
     pub v4: Option<bool>,
     pub v6: Option<bool>,
+    pub public: Option<bool>,
+    pub private: Option<bool>,
+    pub not_loopback: Option<bool>,
+    pub in_cidr: Option<Vec<syn::LitStr>>,
+    pub not_in_cidr: Option<Vec<syn::LitStr>>,
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
+impl Ip {
+    /// Whether any of the arguments needing `IpRules` have been set
+    pub fn has_rules(&self) -> bool {
+        self.public.is_some()
+            || self.private.is_some()
+            || self.not_loopback.is_some()
+            || self.in_cidr.is_some()
+            || self.not_in_cidr.is_some()
+    }
+}
+
+#[derive(Debug, Clone, FromMeta, Default)]
+pub struct Cidr {
+    pub v4: Option<bool>,
+    pub v6: Option<bool>,
+    pub strict: Option<bool>,
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
+#[derive(Debug, Clone, FromMeta, Default)]
+pub struct SocketAddr {
+    pub allow_hostname: Option<bool>,
     pub message: Option<String>,
     pub code: Option<String>,
 }
@@ -271,8 +606,13 @@ pub struct Length {
 #[derive(Debug, Clone, FromMeta)]
 pub struct MustMatch {
     pub other: Path,
+    pub normalize: Option<syn::LitStr>,
+    pub case_insensitive: Option<bool>,
     pub message: Option<String>,
     pub code: Option<String>,
+    /// Whether the `other` field is sensitive, filled in once all the fields are known
+    #[darling(skip)]
+    pub other_sensitive: bool,
 }
 
 #[derive(Debug, Clone, FromMeta, Default)]
@@ -281,6 +621,50 @@ pub struct NonControlCharacter {
     pub code: Option<String>,
 }
 
+#[derive(Debug, Clone, FromMeta, Default)]
+pub struct Normalized {
+    pub form: Option<syn::LitStr>,
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
+#[derive(Debug, Clone, FromMeta, Default)]
+pub struct SingleScript {
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
+#[derive(Debug, Clone, FromMeta)]
+pub struct NoConfusableWith {
+    pub field: Option<Path>,
+    pub values: Option<Vec<syn::LitStr>>,
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
+#[derive(Debug, Clone, FromMeta, Default)]
+pub struct NoBidiControls {
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
+#[derive(Debug, Clone, FromMeta, Default)]
+pub struct NoInvisible {
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
+#[derive(Debug, Clone, FromMeta, Default)]
+pub struct Password {
+    pub min_score: Option<u8>,
+    pub min_length: Option<usize>,
+    pub require_classes: Option<usize>,
+    pub forbid_fields: Option<syn::ExprArray>,
+    pub blocklist: Option<Expr>,
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
 #[derive(Debug, Clone, FromMeta)]
 pub struct Range {
     pub min: Option<Expr>,
@@ -291,14 +675,58 @@ pub struct Range {
     pub code: Option<String>,
 }
 
+#[derive(Debug, Clone, FromMeta, Default)]
+pub struct NotBlank {
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
+#[derive(Debug, Clone, FromMeta, Default)]
+pub struct NonEmpty {
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
+#[derive(Debug, Clone, FromMeta, Default)]
+pub struct Trimmed {
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
 #[derive(Debug, Clone, FromMeta, Default)]
 pub struct Required {
+    pub empty_as_none: Option<bool>,
     pub message: Option<String>,
     pub code: Option<String>,
 }
 
 #[derive(Debug, Clone, FromMeta, Default)]
 pub struct Url {
+    pub schemes: Option<Vec<syn::LitStr>>,
+    pub require_host: Option<bool>,
+    pub allow_credentials: Option<bool>,
+    pub max_length: Option<Expr>,
+    pub allow_fragments: Option<bool>,
+    pub allowed_hosts: Option<Vec<syn::LitStr>>,
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
+impl Url {
+    /// Whether any of the arguments needing a `UrlPolicy` have been set
+    pub fn has_policy(&self) -> bool {
+        self.schemes.is_some()
+            || self.require_host.is_some()
+            || self.allow_credentials.is_some()
+            || self.max_length.is_some()
+            || self.allow_fragments.is_some()
+            || self.allowed_hosts.is_some()
+    }
+}
+
+#[derive(Debug, Clone, FromMeta, Default)]
+pub struct PublicUrl {
+    pub resolver: Option<Expr>,
     pub message: Option<String>,
     pub code: Option<String>,
 }
@@ -310,6 +738,35 @@ pub struct Regex {
     pub code: Option<String>,
 }
 
+#[derive(Debug, Clone, FromMeta, Default)]
+pub struct Unique {
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
+#[derive(Debug, Clone, FromMeta)]
+#[darling(from_expr = UniqueBy::from_field_expr)]
+pub struct UniqueBy {
+    pub field: Path,
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
+impl UniqueBy {
+    /// Handles the short form `unique_by = "sku"`, or `unique_by = sku`
+    fn from_field_expr(expr: &Expr) -> darling::Result<Self> {
+        Ok(UniqueBy { field: Path::from_expr(expr)?, message: None, code: None })
+    }
+}
+
+#[derive(Debug, Clone, FromMeta, Default)]
+pub struct Sorted {
+    pub desc: Option<bool>,
+    pub strict: Option<bool>,
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
 #[derive(Debug, Clone, FromMeta)]
 pub struct Custom {
     pub function: darling::Result<Path>,
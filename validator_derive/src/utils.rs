@@ -1,6 +1,9 @@
 use quote::{quote, ToTokens};
 use syn::{Attribute, Path};
 
+use darling::util::Override;
+
+use crate::types::{CardCvv, Password};
 use crate::ValidateField;
 
 #[derive(Debug, Clone)]
@@ -67,14 +70,24 @@ pub fn quote_use_stmts(
     let mut length = quote!();
     let mut email = quote!();
     let mut card = quote!();
+    let mut card_expiry = quote!();
+    let mut card_cvv = quote!();
     let mut url = quote!();
     let mut ip = quote!();
+    let mut cidr = quote!();
+    let mut socket_addr = quote!();
     let mut ncc = quote!();
+    let mut blank = quote!();
+    let mut normalized = quote!();
+    let mut unicode_security = quote!();
+    let mut password = quote!();
     let mut range = quote!();
     let mut required = quote!();
     let mut contains = quote!();
     let mut does_not_contain = quote!();
+    let mut one_of = quote!();
     let mut regex = quote!();
+    let mut nested = quote!();
 
     for f in fields {
         if f.length.is_some() {
@@ -95,7 +108,24 @@ pub fn quote_use_stmts(
             );
         }
 
-        if f.url.is_some() {
+        if f.card_expiry.is_some() {
+            card_expiry = quote!(
+                use #crate_name::ValidateCardExpiry;
+            );
+        }
+
+        if let Some(c) = &f.card_cvv {
+            card_cvv = quote!(
+                use #crate_name::ValidateCardCvv;
+            );
+            if matches!(c, Override::Explicit(CardCvv { brand_field: Some(_), .. })) {
+                card = quote!(
+                    use #crate_name::ValidateCreditCard;
+                );
+            }
+        }
+
+        if f.url.is_some() || f.public_url.is_some() {
             url = quote!(
                 use #crate_name::ValidateUrl;
             );
@@ -107,12 +137,58 @@ pub fn quote_use_stmts(
             );
         }
 
+        if f.cidr.is_some() {
+            cidr = quote!(
+                use #crate_name::ValidateCidr;
+            );
+        }
+
+        if f.socket_addr.is_some() {
+            socket_addr = quote!(
+                use #crate_name::ValidateSocketAddr;
+            );
+        }
+
         if f.non_control_character.is_some() {
             ncc = quote!(
                 use #crate_name::ValidateNonControlCharacter;
             );
         }
 
+        if f.not_blank.is_some() || f.non_empty.is_some() || f.trimmed.is_some() {
+            blank = quote!(
+                use #crate_name::{ValidateNonEmpty, ValidateNotBlank, ValidateTrimmed};
+            );
+        }
+
+        if f.normalized.is_some() {
+            normalized = quote!(
+                use #crate_name::ValidateNormalized;
+            );
+        }
+
+        if f.single_script.is_some()
+            || f.no_confusable_with.is_some()
+            || f.no_bidi_controls.is_some()
+            || f.no_invisible.is_some()
+        {
+            unicode_security = quote!(
+                use #crate_name::ValidateUnicodeSecurity;
+            );
+        }
+
+        if let Some(p) = &f.password {
+            password = quote!(
+                use #crate_name::ValidatePassword;
+            );
+            if matches!(p, Override::Explicit(Password { forbid_fields: Some(_), .. })) {
+                password = quote!(
+                    #password
+                    use #crate_name::PasswordUserInput;
+                );
+            }
+        }
+
         if f.range.is_some() {
             range = quote!(
                 use #crate_name::ValidateRange;
@@ -137,25 +213,47 @@ pub fn quote_use_stmts(
             );
         }
 
+        if f.one_of.is_some() || f.none_of.is_some() {
+            one_of = quote!(
+                use #crate_name::ValidateOneOf;
+            );
+        }
+
         if f.regex.is_some() {
             regex = quote!(
                 use #crate_name::ValidateRegex;
             );
         }
+
+        if f.nested == Some(true) {
+            nested = quote!(
+                use #crate_name::ValidateNested;
+            );
+        }
     }
 
     quote!(
         #length
         #email
         #card
+        #card_expiry
+        #card_cvv
         #url
         #ip
+        #cidr
+        #socket_addr
         #ncc
+        #blank
+        #normalized
+        #unicode_security
+        #password
         #range
         #required
         #contains
         #does_not_contain
+        #one_of
         #regex
+        #nested
     )
 }
 
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use validator::Validate;
+
+#[test]
+fn can_validate_blank_rules_ok() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(not_blank, trimmed)]
+        name: String,
+        #[validate(non_empty)]
+        tags: Vec<String>,
+        #[validate(not_blank, non_empty, trimmed)]
+        nickname: Option<String>,
+    }
+
+    let s = TestStruct { name: "Vincent".to_string(), tags: vec!["a".to_string()], nickname: None };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn blank_string_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(length(min = 1), not_blank)]
+        name: String,
+        #[validate(not_blank)]
+        nickname: Option<String>,
+    }
+
+    let s = TestStruct { name: "   ".to_string(), nickname: Some("\t".to_string()) };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["name"].len(), 1);
+    assert_eq!(errs["name"][0].code, "not_blank");
+    assert_eq!(errs["name"][0].params["value"], "   ");
+    assert_eq!(errs["nickname"][0].code, "not_blank");
+}
+
+#[test]
+fn empty_values_fail_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(non_empty)]
+        name: String,
+        #[validate(non_empty)]
+        tags: Vec<String>,
+        #[validate(non_empty)]
+        labels: Option<HashMap<String, String>>,
+    }
+
+    let s = TestStruct { name: String::new(), tags: vec![], labels: Some(HashMap::new()) };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 3);
+    assert_eq!(errs["name"][0].code, "non_empty");
+    assert_eq!(errs["tags"][0].code, "non_empty");
+    assert_eq!(errs["labels"][0].code, "non_empty");
+}
+
+#[test]
+fn untrimmed_string_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(trimmed)]
+        email: String,
+    }
+
+    let s = TestStruct { email: "bob@example.com\n".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["email"][0].code, "trimmed");
+    assert_eq!(errs["email"][0].params["value"], "bob@example.com\n");
+}
+
+#[test]
+fn can_specify_code_and_message_for_blank_rules() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(not_blank(code = "oops"), trimmed(message = "no spaces around"))]
+        val: String,
+    }
+    let s = TestStruct { val: " ".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 2);
+    assert_eq!(errs["val"][0].code, "oops");
+    assert_eq!(errs["val"][1].clone().message.unwrap(), "no spaces around");
+}
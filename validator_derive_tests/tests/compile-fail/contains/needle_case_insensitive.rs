@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(contains(needle = 443, case_insensitive))]
+    ports: Vec<u16>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(credit_card(brands = ["visa", "bob"]))]
+    s: String,
+}
+
+fn main() {}
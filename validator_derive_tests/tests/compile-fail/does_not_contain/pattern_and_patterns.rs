@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(does_not_contain(pattern = "admin", patterns = ["root"]))]
+    username: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(ip(in_cidr = ["10.0.0.0/33"]))]
+    s: String,
+}
+
+fn main() {}
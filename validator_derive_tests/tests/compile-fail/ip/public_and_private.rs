@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(ip(public, private))]
+    s: String,
+}
+
+fn main() {}
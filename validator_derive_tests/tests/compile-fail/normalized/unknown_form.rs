@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(normalized(form = "NFX"))]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(none_of(values = ["admin", "root", "Admin"], case_insensitive))]
+    username: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(one_of(values = []))]
+    status: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    email: String,
+    #[validate(password(forbid_fields = [email, username]))]
+    password: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use validator::Validate;
+
+#[derive(Validate)]
+#[validate(redact = [password, card_number])]
+struct Test {
+    #[validate(length(min = 8))]
+    password: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validator::Sanitize;
+
+#[derive(Sanitize)]
+struct Test {
+    #[sanitizer(lowercase, uppercase)]
+    country: String,
+}
+
+fn main() {}
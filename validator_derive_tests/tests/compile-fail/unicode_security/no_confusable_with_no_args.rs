@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(no_confusable_with())]
+    username: String,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use validator::Validate;
+
+struct LineItem {
+    sku: String,
+}
+
+#[derive(Validate)]
+struct Test {
+    #[validate(unique_by = "item::sku")]
+    items: Vec<LineItem>,
+}
+
+fn main() {}
@@ -66,3 +66,84 @@ fn can_specify_message_for_contains() {
     assert_eq!(errs["val"].len(), 1);
     assert_eq!(errs["val"][0].clone().message.unwrap(), "oops");
 }
+
+#[test]
+fn can_validate_contains_case_insensitive() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(contains(pattern = "STRASSE", case_insensitive))]
+        val: String,
+        #[validate(contains(pattern = "caf\u{00E9}", normalize = "NFC"))]
+        val2: Option<String>,
+    }
+
+    let s = TestStruct {
+        val: "Hauptstraße 1".to_string(),
+        val2: Some("le cafe\u{0301} noir".to_string()),
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { val: "Hauptweg 1".to_string(), val2: None };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "contains");
+    assert_eq!(errs["val"][0].params["needle"], "STRASSE");
+}
+
+#[test]
+fn can_validate_contains_patterns() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(contains(patterns = ["street", "avenue"], case_insensitive, whole_word))]
+        val: String,
+    }
+
+    assert!(TestStruct { val: "1 Main Street".to_string() }.validate().is_ok());
+
+    let s = TestStruct { val: "1 Mainstreet".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "contains");
+    assert_eq!(errs["val"][0].params["patterns"], serde_json::json!(["street", "avenue"]));
+}
+
+#[test]
+fn can_validate_contains_elements() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(contains(pattern = "user"))]
+        roles: Vec<String>,
+        #[validate(contains(needle = 443))]
+        ports: Vec<u16>,
+        #[validate(contains(needle = 'a'))]
+        letters: std::collections::BTreeSet<char>,
+    }
+
+    let s = TestStruct {
+        roles: vec!["admin".to_string(), "user".to_string()],
+        ports: vec![80, 443],
+        letters: ['a', 'b'].into(),
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        roles: vec!["superuser".to_string()],
+        ports: vec![80, 8443],
+        letters: ['b'].into(),
+    };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 3);
+    assert_eq!(errs["roles"][0].code, "contains");
+    assert_eq!(errs["roles"][0].params["needle"], "user");
+    assert_eq!(errs["ports"][0].code, "contains");
+    assert_eq!(errs["ports"][0].params["needle"], 443);
+    assert_eq!(errs["ports"][0].params["value"], serde_json::json!([80, 8443]));
+    assert_eq!(errs["letters"][0].params["needle"], "a");
+}
@@ -85,8 +85,8 @@ fn can_validate_custom_impl_for_credit_card() {
     }
 
     impl validator::ValidateCreditCard for CustomCreditCard {
-        fn as_credit_card_string(&self) -> Cow<'_, str> {
-            Cow::from(format!("{}{}{}", &self.bin, &self.ian, &self.check,))
+        fn as_credit_card_string(&self) -> Option<Cow<'_, str>> {
+            Some(Cow::from(format!("{}{}{}", &self.bin, &self.ian, &self.check,)))
         }
     }
 
@@ -99,3 +99,91 @@ fn can_validate_custom_impl_for_credit_card() {
     assert!(valid.validate().is_ok());
     assert!(invalid.validate().is_err());
 }
+
+#[test]
+fn can_restrict_credit_card_brands() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(credit_card(brands = ["visa", "mastercard"]))]
+        val: String,
+    }
+
+    let s = TestStruct { val: "4111111111111111".to_string() };
+    assert!(s.validate().is_ok());
+    let s = TestStruct { val: "5555555555554444".to_string() };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { val: "378282246310005".to_string() };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "credit_card_brand");
+    assert_eq!(errs["val"][0].params["brand"], "amex");
+    assert_eq!(errs["val"][0].params["brands"], serde_json::json!(["visa", "mastercard"]));
+
+    let s = TestStruct { val: "4111111111111112".to_string() };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "credit_card");
+    assert_eq!(errs["val"][0].params["brand"], "visa");
+}
+
+#[test]
+fn can_validate_card_expiry() {
+    use validator::YearMonth;
+
+    const NOW: YearMonth = YearMonth { year: 2026, month: 10 };
+
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(card_expiry(clock = NOW))]
+        val: String,
+        #[validate(card_expiry)]
+        far_future: Option<String>,
+    }
+
+    let s = TestStruct { val: "10/26".to_string(), far_future: Some("12/99".to_string()) };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { val: "09/26".to_string(), far_future: Some("01/20".to_string()) };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "card_expiry");
+    assert_eq!(errs["val"][0].params["value"], "09/26");
+    assert_eq!(errs["far_future"][0].code, "card_expiry");
+}
+
+#[test]
+fn can_validate_card_cvv() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(credit_card)]
+        number: Option<String>,
+        #[validate(card_cvv(brand_field = number))]
+        cvv: String,
+        #[validate(card_cvv)]
+        other_cvv: String,
+    }
+
+    let s = TestStruct {
+        number: Some("378282246310005".to_string()),
+        cvv: "1234".to_string(),
+        other_cvv: "1234".to_string(),
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        number: Some("378282246310005".to_string()),
+        cvv: "123".to_string(),
+        other_cvv: "12".to_string(),
+    };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["cvv"][0].code, "card_cvv");
+    assert_eq!(errs["cvv"][0].params["brand"], "amex");
+    assert!(!errs["cvv"][0].params.contains_key("value"));
+    assert_eq!(errs["other_cvv"][0].code, "card_cvv");
+
+    let s = TestStruct { number: None, cvv: "123".to_string(), other_cvv: "123".to_string() };
+    assert!(s.validate().is_ok());
+}
@@ -1,5 +1,6 @@
-use std::{borrow::Cow, collections::HashMap};
+use std::borrow::Cow;
 
+use validator::indexmap::IndexMap;
 use validator::{Validate, ValidationError, ValidationErrors, ValidationErrorsKind};
 
 fn valid_custom_fn(_: &String) -> Result<(), ValidationError> {
@@ -170,7 +171,7 @@ fn custom_fn_on_optional_types_work() {
     let error_kind = ValidationErrorsKind::Field(vec![{ error }]);
     assert_eq!(
         t.validate(),
-        Err(ValidationErrors(HashMap::from_iter([
+        Err(ValidationErrors(IndexMap::from_iter([
             (Cow::Borrowed("plain"), error_kind.clone()),
             (Cow::Borrowed("option"), error_kind.clone()),
             (Cow::Borrowed("option_option"), error_kind),
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use validator::{Validate, ValidationErrors, ValidationErrorsKind};
+
+#[derive(Debug, Deserialize, Validate)]
+struct Item {
+    #[validate(length(min = 1))]
+    name: String,
+    #[validate(range(min = 0.0))]
+    price: f64,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+struct Order {
+    #[validate(email)]
+    mail: String,
+    #[validate(range(min = 18))]
+    age: u32,
+    note: Option<String>,
+    #[validate(nested)]
+    items: Vec<Item>,
+}
+
+fn field_code(errors: &ValidationErrors, field: &str) -> String {
+    errors.field_errors()[field][0].code.to_string()
+}
+
+fn item_errors(errors: &ValidationErrors, index: usize) -> &ValidationErrors {
+    match &errors.errors()["items"] {
+        ValidationErrorsKind::List(items) => &items[&index],
+        kind => panic!("Unexpected errors {:?}", kind),
+    }
+}
+
+#[test]
+fn can_deserialize_and_validate() {
+    let order: Order = validator::serde::from_str(
+        r#"{"mail": "bob@bob.com", "age": 20, "items": [{"name": "pen", "price": 1.5}]}"#,
+    )
+    .unwrap();
+    assert_eq!(order.age, 20);
+    assert_eq!(order.note, None);
+    assert_eq!(order.items[0].name, "pen");
+}
+
+#[test]
+fn validation_errors_are_returned() {
+    let err = validator::serde::from_str::<Order>(
+        r#"{"mail": "bob", "age": 20, "items": [{"name": "", "price": 1.5}]}"#,
+    )
+    .unwrap_err();
+    assert_eq!(field_code(&err, "mail"), "email");
+    assert_eq!(field_code(item_errors(&err, 0), "name"), "length");
+}
+
+#[test]
+fn type_and_missing_errors_are_merged_with_validation_errors() {
+    let err = validator::serde::from_str::<Order>(
+        r#"{"mail": "bob", "age": "abc", "items": [{"name": "pen", "price": -1}, {"price": "free"}]}"#,
+    )
+    .unwrap_err();
+
+    assert_eq!(field_code(&err, "mail"), "email");
+    let age = &err.field_errors()["age"][0];
+    assert_eq!(age.code, "type");
+    assert_eq!(age.params["value"], "abc");
+    assert_eq!(age.message.as_deref(), Some("invalid type: string \"abc\", expected u32"));
+
+    assert_eq!(field_code(item_errors(&err, 0), "price"), "range");
+    let item = item_errors(&err, 1);
+    assert_eq!(field_code(item, "name"), "missing");
+    assert_eq!(item.field_errors()["name"].len(), 1);
+    assert_eq!(field_code(item, "price"), "type");
+}
+
+#[test]
+fn invalid_items_are_reported_by_index() {
+    let value = serde_json::json!({"mail": "bob@bob.com", "age": 20, "items": [{"name": "pen", "price": 1}, 3]});
+    let err = validator::serde::from_value::<Order>(value).unwrap_err();
+    assert_eq!(field_code(item_errors(&err, 1), "_item"), "type");
+}
+
+#[test]
+fn unknown_fields_are_reported() {
+    #[derive(Debug, Deserialize, Validate)]
+    #[serde(deny_unknown_fields)]
+    struct Strict {
+        #[validate(length(min = 2))]
+        name: String,
+    }
+
+    let err = validator::serde::from_str::<Strict>(r#"{"name": "a", "nmae": "b"}"#).unwrap_err();
+    assert_eq!(field_code(&err, "nmae"), "unknown");
+    assert_eq!(field_code(&err, "name"), "length");
+}
+
+#[test]
+fn can_deserialize_maps_with_numeric_keys() {
+    #[derive(Debug, Deserialize, Validate)]
+    struct Prices {
+        #[validate(length(max = 2))]
+        by_quantity: HashMap<u32, f64>,
+    }
+
+    let prices: Prices =
+        validator::serde::from_str(r#"{"by_quantity": {"1": 2.0, "10": 1.5}}"#).unwrap();
+    assert_eq!(prices.by_quantity[&10], 1.5);
+}
+
+#[test]
+fn root_and_syntax_errors_are_in_all() {
+    let err = validator::serde::from_str::<Order>("[1, 2]").unwrap_err();
+    assert_eq!(field_code(&err, "__all__"), "type");
+
+    let err = validator::serde::from_str::<Order>(r#"{"mail": "#).unwrap_err();
+    assert_eq!(field_code(&err, "__all__"), "syntax");
+}
+
+#[test]
+fn errors_can_be_located_in_source() {
+    let source = r#"{
+    "mail": "bob@bob.com",
+    "age": "twenty",
+    "items": [
+        {"name": "pen", "price": -1}
+    ]
+}"#;
+    let err = validator::serde::from_str::<Order>(source).unwrap_err();
+    let located = err.locate_in_json(source);
+    let found = located
+        .iter()
+        .map(|e| (e.path.to_string(), e.error.code.as_ref(), e.span.unwrap().line))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        found,
+        vec![("age".to_string(), "type", 3), ("items[0].price".to_string(), "range", 5)]
+    );
+
+    let report = err.json_report(source).to_string();
+    assert!(report.contains(
+        "5 |         {\"name\": \"pen\", \"price\": -1}\n  |                                  ^^\n"
+    ));
+}
@@ -91,3 +91,78 @@ fn can_specify_message_for_does_not_contain() {
     assert_eq!(errs["val"].len(), 1);
     assert_eq!(errs["val"][0].clone().message.unwrap(), "oops");
 }
+
+#[test]
+fn can_validate_does_not_contain_normalized() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(does_not_contain(pattern = "admin", normalize = "NFKC", case_insensitive))]
+        val: String,
+    }
+
+    assert!(TestStruct { val: "keats".to_string() }.validate().is_ok());
+
+    let s = TestStruct { val: "ＡＤＭＩＮ".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "does_not_contain");
+}
+
+static RESERVED: [&str; 3] = ["admin", "root", "support"];
+
+#[test]
+fn can_validate_does_not_contain_patterns() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(does_not_contain(patterns = ["admin", "root"], case_insensitive))]
+        val: String,
+        #[validate(does_not_contain(patterns_from = "RESERVED", whole_word))]
+        val2: Option<String>,
+    }
+
+    let s = TestStruct { val: "keats".to_string(), val2: Some("supportive".to_string()) };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { val: "SuperAdmin".to_string(), val2: Some("the root user".to_string()) };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "does_not_contain");
+    assert_eq!(errs["val"][0].params["pattern"], "admin");
+    assert_eq!(errs["val"][0].params["patterns"], serde_json::json!(["admin", "root"]));
+    assert_eq!(errs["val2"][0].code, "does_not_contain");
+    assert_eq!(errs["val2"][0].params["pattern"], "root");
+    assert!(!errs["val2"][0].params.contains_key("patterns"));
+}
+
+const DEFAULT_PORT: u16 = 80;
+
+#[test]
+fn can_validate_does_not_contain_elements() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(does_not_contain(pattern = "internal"))]
+        tags: std::collections::HashSet<String>,
+        #[validate(does_not_contain(needle = DEFAULT_PORT))]
+        ports: [u16; 2],
+    }
+
+    let s = TestStruct { tags: ["public".to_string()].into(), ports: [443, 8080] };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        tags: ["public".to_string(), "internal".to_string()].into(),
+        ports: [80, 443],
+    };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["tags"][0].code, "does_not_contain");
+    assert_eq!(errs["tags"][0].params["needle"], "internal");
+    assert_eq!(errs["ports"][0].code, "does_not_contain");
+    assert_eq!(errs["ports"][0].params["needle"], 80);
+}
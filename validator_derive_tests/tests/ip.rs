@@ -1,5 +1,5 @@
 use serde::Serialize;
-use std::fmt;
+use std::net::{AddrParseError, IpAddr, Ipv4Addr, Ipv6Addr};
 use validator::Validate;
 #[test]
 fn can_validate_ipv4() {
@@ -170,12 +170,132 @@ fn can_validate_custom_impl_for_ip() {
         val: CustomIp,
     }
 
-    impl fmt::Display for CustomIp {
-        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            write!(f, "{}.{}.{}.{}", self.a, self.b, self.c, self.d)
+    impl validator::ValidateIp for CustomIp {
+        fn as_ip_addr(&self) -> Option<Result<IpAddr, AddrParseError>> {
+            Some(Ok(IpAddr::V4(Ipv4Addr::new(self.a, self.b, self.c, self.d))))
         }
     }
 
     let valid = TestStruct { val: CustomIp { a: 192, b: 168, c: 1, d: 1 } };
     assert!(valid.validate().is_ok());
 }
+
+#[test]
+fn can_validate_native_ip_types() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(ip(v4))]
+        v4: Ipv4Addr,
+        #[validate(ip(v4))]
+        any: IpAddr,
+        #[validate(ip(not_loopback))]
+        v6: Option<Ipv6Addr>,
+    }
+
+    let s = TestStruct {
+        v4: Ipv4Addr::new(192, 168, 1, 1),
+        any: IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1)),
+        v6: None,
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        v4: Ipv4Addr::new(192, 168, 1, 1),
+        any: IpAddr::V6(Ipv6Addr::LOCALHOST),
+        v6: Some(Ipv6Addr::LOCALHOST),
+    };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs["any"][0].code, "ip");
+    assert_eq!(errs["any"][0].params["value"], "::1");
+    assert_eq!(errs["v6"][0].code, "ip");
+}
+
+#[test]
+fn can_validate_ip_network_classes() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(ip(public))]
+        public: String,
+        #[validate(ip(v4, private))]
+        private: String,
+    }
+
+    let s = TestStruct { public: "8.8.8.8".to_string(), private: "10.0.0.1".to_string() };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { public: "169.254.169.254".to_string(), private: "fd00::1".to_string() };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["public"][0].code, "ip");
+    assert_eq!(errs["private"][0].code, "ip");
+}
+
+#[test]
+fn can_validate_ip_in_cidr() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(ip(in_cidr = ["10.0.0.0/8", "2001:db8::/32"], not_in_cidr = ["10.1.0.0/16"]))]
+        val: IpAddr,
+    }
+
+    let s = TestStruct { val: "10.2.3.4".parse().unwrap() };
+    assert!(s.validate().is_ok());
+    let s = TestStruct { val: "2001:db8::1".parse().unwrap() };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { val: "10.1.3.4".parse().unwrap() };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "ip");
+    assert_eq!(
+        errs["val"][0].params["in_cidr"],
+        serde_json::json!(["10.0.0.0/8", "2001:db8::/32"])
+    );
+    assert_eq!(errs["val"][0].params["not_in_cidr"], serde_json::json!(["10.1.0.0/16"]));
+    assert_eq!(errs["val"][0].params["value"], "10.1.3.4");
+}
+
+#[test]
+fn can_validate_cidr() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(cidr)]
+        any: String,
+        #[validate(cidr(v4, strict, code = "subnet"))]
+        v4: Option<String>,
+    }
+
+    let s = TestStruct { any: "2001:db8::1/32".to_string(), v4: Some("10.0.0.0/8".to_string()) };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { any: "10.0.0.1".to_string(), v4: Some("10.0.0.1/8".to_string()) };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["any"][0].code, "cidr");
+    assert_eq!(errs["v4"][0].code, "subnet");
+    assert_eq!(errs["v4"][0].params["value"], "10.0.0.1/8");
+}
+
+#[test]
+fn can_validate_socket_addr() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(socket_addr)]
+        listen: String,
+        #[validate(socket_addr(allow_hostname))]
+        upstream: String,
+    }
+
+    let s =
+        TestStruct { listen: "[::1]:8080".to_string(), upstream: "db.internal:5432".to_string() };
+    assert!(s.validate().is_ok());
+
+    let s =
+        TestStruct { listen: "db.internal:5432".to_string(), upstream: "db.internal".to_string() };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["listen"][0].code, "socket_addr");
+    assert_eq!(errs["upstream"][0].code, "socket_addr");
+}
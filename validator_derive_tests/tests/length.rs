@@ -168,11 +168,10 @@ fn can_validate_array_for_length() {
     assert_eq!(errs["val"][0].params["max"], 10);
 }
 
-#[cfg(feature = "indexmap")]
 #[test]
 fn can_validate_set_ref_for_length() {
-    use indexmap::{indexset, IndexSet};
     use serde_json::Value;
+    use validator::indexmap::{indexset, IndexSet};
 
     #[derive(Debug, Validate)]
     struct TestStruct<'a> {
@@ -232,3 +231,22 @@ fn can_validate_custom_impl_for_length() {
     assert!(ok.validate().is_ok());
     assert!(equals_ok.validate().is_ok());
 }
+
+#[test]
+fn value_that_cant_be_serialized_is_left_out_of_params() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        // Maps with non-string keys can't be serialized to JSON
+        #[validate(length(max = 1))]
+        cells: std::collections::HashMap<(u8, u8), char>,
+    }
+
+    let s = TestStruct { cells: [((0, 0), 'a'), ((0, 1), 'b')].into_iter().collect() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["cells"][0].code, "length");
+    assert_eq!(errs["cells"][0].params["max"], 1);
+    assert!(!errs["cells"][0].params.contains_key("value"));
+}
@@ -0,0 +1,67 @@
+use std::borrow::Cow;
+
+use validator::{Validate, ValidationError};
+
+fn validate_reserved(username: &str) -> Result<(), ValidationError> {
+    if username == "admin" {
+        return Err(ValidationError::new("reserved"));
+    }
+    Ok(())
+}
+
+#[derive(Validate)]
+struct Signup {
+    #[validate(
+        length(min = 3, max = 20),
+        custom(function = "validate_reserved", message = "{value} is reserved")
+    )]
+    username: String,
+    #[validate(length(min = 8, message = "must be at least {min} characters, got {value}"))]
+    nickname: String,
+    #[validate(range(min = 18))]
+    age: u8,
+    #[validate(email)]
+    mail: String,
+}
+
+// The catalog is global so everything is checked in a single test
+#[test]
+fn can_display_messages_from_templates_and_catalog() {
+    let signup = Signup {
+        username: "admin".to_string(),
+        nickname: "bob".to_string(),
+        age: 16,
+        mail: "bob".to_string(),
+    };
+    let err = signup.validate().unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "username: admin is reserved\n\
+         nickname: must be at least 8 characters, got bob\n\
+         age: must be at least 18\n\
+         mail: must be a valid email address"
+    );
+
+    validator::set_message_catalog(|error: &ValidationError| match error.code.as_ref() {
+        "range" => Some(Cow::Borrowed("doit être au moins {min}")),
+        "email" => Some(Cow::Borrowed("adresse invalide : {value}")),
+        _ => None,
+    });
+    let err = signup.validate().unwrap_err();
+    assert_eq!(err.errors_at("age")[0].to_string(), "doit être au moins 18");
+    assert_eq!(err.errors_at("mail")[0].to_string(), "adresse invalide : bob");
+    // Custom messages and codes without a template in the catalog are unchanged
+    assert_eq!(err.errors_at("username")[0].to_string(), "admin is reserved");
+    assert_eq!(
+        ValidationError::new("length").with_param("max", 2).to_string(),
+        "length must be at most 2"
+    );
+    assert_eq!(
+        ValidationError::new("unknown_code").to_string(),
+        "Validation error: unknown_code [{}]"
+    );
+
+    validator::reset_message_catalog();
+    assert_eq!(err.errors_at("age")[0].to_string(), "must be at least 18");
+}
@@ -71,3 +71,24 @@ fn can_specify_message_for_must_match() {
     assert_eq!(errs["val"].len(), 1);
     assert_eq!(errs["val"][0].clone().message.unwrap(), "oops");
 }
+
+#[test]
+fn can_match_after_normalization_and_case_folding() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(must_match(other = "val2", normalize = "NFC", case_insensitive))]
+        val: String,
+        val2: Option<String>,
+    }
+
+    let s = TestStruct { val: "Cafe\u{0301}".to_string(), val2: Some("CAF\u{00C9}".to_string()) };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { val: "Cafe".to_string(), val2: Some("CAF\u{00C9}".to_string()) };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "must_match");
+    assert_eq!(errs["val"][0].params["other"], "CAF\u{00C9}");
+}
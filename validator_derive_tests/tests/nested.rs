@@ -3,8 +3,10 @@ use std::{
     borrow::Cow,
     collections::{HashMap, HashSet},
 };
+use validator::indexmap::IndexMap;
 use validator::{
-    Validate, ValidateLength, ValidationError, ValidationErrors, ValidationErrorsKind,
+    Validate, ValidateLength, ValidateNested, ValidationError, ValidationErrors,
+    ValidationErrorsKind,
 };
 
 #[test]
@@ -71,9 +73,9 @@ fn fails_nested_validation_multiple_members() {
     }]);
     assert_eq!(
         root.validate(),
-        Err(ValidationErrors(HashMap::from_iter([(
+        Err(ValidationErrors(IndexMap::from_iter([(
             Cow::Borrowed("a"),
-            ValidationErrorsKind::Struct(Box::new(ValidationErrors(HashMap::from_iter([
+            ValidationErrorsKind::Struct(Box::new(ValidationErrors(IndexMap::from_iter([
                 (Cow::Borrowed("value1"), error_kind.clone()),
                 (Cow::Borrowed("value2"), error_kind),
             ]))))
@@ -559,20 +561,27 @@ fn test_can_validate_map_fields() {
     let errs = err.errors();
     assert_eq!(errs.len(), 1);
     assert!(errs.contains_key("child"));
-    if let ValidationErrorsKind::List(ref errs) = errs["child"] {
-        assert!(errs.contains_key(&0));
-        unwrap_map(&errs[&0], |errs| {
+    if let ValidationErrorsKind::Struct(ref errs) = errs["child"] {
+        unwrap_map(errs, |errs| {
             assert_eq!(errs.len(), 1);
-            assert!(errs.contains_key("value"));
-            if let ValidationErrorsKind::Field(ref errs) = errs["value"] {
-                assert_eq!(errs.len(), 1);
-                assert_eq!(errs[0].code, "length");
+            assert!(errs.contains_key("0"));
+            if let ValidationErrorsKind::Struct(ref errs) = errs["0"] {
+                unwrap_map(errs, |errs| {
+                    assert_eq!(errs.len(), 1);
+                    assert!(errs.contains_key("value"));
+                    if let ValidationErrorsKind::Field(ref errs) = errs["value"] {
+                        assert_eq!(errs.len(), 1);
+                        assert_eq!(errs[0].code, "length");
+                    } else {
+                        panic!("Expected field validation errors");
+                    }
+                });
             } else {
-                panic!("Expected field validation errors");
+                panic!("Expected struct validation errors");
             }
         });
     } else {
-        panic!("Expected list validation errors");
+        panic!("Expected struct validation errors");
     }
 
     // A valid struct should not fail
@@ -606,20 +615,27 @@ fn test_can_validate_ref_map_fields() {
     let errs = err.errors();
     assert_eq!(errs.len(), 1);
     assert!(errs.contains_key("child"));
-    if let ValidationErrorsKind::List(ref errs) = errs["child"] {
-        assert!(errs.contains_key(&0));
-        unwrap_map(&errs[&0], |errs| {
+    if let ValidationErrorsKind::Struct(ref errs) = errs["child"] {
+        unwrap_map(errs, |errs| {
             assert_eq!(errs.len(), 1);
-            assert!(errs.contains_key("value"));
-            if let ValidationErrorsKind::Field(ref errs) = errs["value"] {
-                assert_eq!(errs.len(), 1);
-                assert_eq!(errs[0].code, "length");
+            assert!(errs.contains_key("0"));
+            if let ValidationErrorsKind::Struct(ref errs) = errs["0"] {
+                unwrap_map(errs, |errs| {
+                    assert_eq!(errs.len(), 1);
+                    assert!(errs.contains_key("value"));
+                    if let ValidationErrorsKind::Field(ref errs) = errs["value"] {
+                        assert_eq!(errs.len(), 1);
+                        assert_eq!(errs[0].code, "length");
+                    } else {
+                        panic!("Expected field validation errors");
+                    }
+                });
             } else {
-                panic!("Expected field validation errors");
+                panic!("Expected struct validation errors");
             }
         });
     } else {
-        panic!("Expected list validation errors");
+        panic!("Expected struct validation errors");
     }
 
     // A valid struct should not fail
@@ -653,20 +669,27 @@ fn test_can_validate_option_map_fields() {
     let errs = err.errors();
     assert_eq!(errs.len(), 1);
     assert!(errs.contains_key("child"));
-    if let ValidationErrorsKind::List(ref errs) = errs["child"] {
-        assert!(errs.contains_key(&0));
-        unwrap_map(&errs[&0], |errs| {
+    if let ValidationErrorsKind::Struct(ref errs) = errs["child"] {
+        unwrap_map(errs, |errs| {
             assert_eq!(errs.len(), 1);
-            assert!(errs.contains_key("value"));
-            if let ValidationErrorsKind::Field(ref errs) = errs["value"] {
-                assert_eq!(errs.len(), 1);
-                assert_eq!(errs[0].code, "length");
+            assert!(errs.contains_key("0"));
+            if let ValidationErrorsKind::Struct(ref errs) = errs["0"] {
+                unwrap_map(errs, |errs| {
+                    assert_eq!(errs.len(), 1);
+                    assert!(errs.contains_key("value"));
+                    if let ValidationErrorsKind::Field(ref errs) = errs["value"] {
+                        assert_eq!(errs.len(), 1);
+                        assert_eq!(errs[0].code, "length");
+                    } else {
+                        panic!("Expected field validation errors");
+                    }
+                });
             } else {
-                panic!("Expected field validation errors");
+                panic!("Expected struct validation errors");
             }
         });
     } else {
-        panic!("Expected list validation errors");
+        panic!("Expected struct validation errors");
     }
 
     // A valid struct should not fail
@@ -905,8 +928,194 @@ fn test_field_validations_evaluated_after_nested_validations_fails() {
 #[allow(dead_code)]
 fn unwrap_map<F>(errors: &ValidationErrors, f: F)
 where
-    F: FnOnce(HashMap<Cow<'static, str>, ValidationErrorsKind>),
+    F: FnOnce(IndexMap<Cow<'static, str>, ValidationErrorsKind>),
 {
     let errors = errors.clone();
     f(errors.errors().clone());
 }
+
+#[test]
+fn test_can_iterate_over_nested_errors() {
+    #[derive(Validate)]
+    struct Root {
+        #[validate(length(min = 1))]
+        name: String,
+        #[validate(nested)]
+        address: Address,
+    }
+
+    #[derive(Validate)]
+    struct Address {
+        #[validate(nested)]
+        lines: Vec<Line>,
+    }
+
+    #[derive(Validate, Serialize)]
+    struct Line {
+        #[validate(length(min = 1))]
+        value: String,
+    }
+
+    let instance = Root {
+        name: String::new(),
+        address: Address {
+            lines: vec![Line { value: "1 Main Street".to_string() }, Line { value: String::new() }],
+        },
+    };
+    let err = instance.validate().unwrap_err();
+
+    let mut paths = err
+        .iter_flat()
+        .map(|(path, err)| (path.to_string(), path.to_json_pointer(), err.code.to_string()))
+        .collect::<Vec<_>>();
+    paths.sort();
+    assert_eq!(
+        paths,
+        vec![
+            (
+                "address.lines[1].value".to_string(),
+                "/address/lines/1/value".to_string(),
+                "length".to_string()
+            ),
+            ("name".to_string(), "/name".to_string(), "length".to_string()),
+        ]
+    );
+
+    let rebuilt: ValidationErrors = err.clone().into_iter().collect();
+    assert_eq!(rebuilt, err);
+}
+
+#[test]
+fn test_can_query_nested_errors_by_path() {
+    #[derive(Validate)]
+    struct Order {
+        #[validate(nested)]
+        items: Vec<Item>,
+    }
+
+    #[derive(Validate, Serialize)]
+    struct Item {
+        #[validate(length(min = 1))]
+        sku: String,
+        #[validate(range(min = 1))]
+        quantity: u32,
+    }
+
+    let instance = Order {
+        items: vec![
+            Item { sku: "A1".to_string(), quantity: 1 },
+            Item { sku: "A2".to_string(), quantity: 0 },
+            Item { sku: String::new(), quantity: 0 },
+        ],
+    };
+    let mut err = instance.validate().unwrap_err();
+
+    assert!(err.contains("items[2].sku"));
+    assert!(!err.contains("items[1].sku"));
+    assert!(!err.contains("items[0]"));
+    assert_eq!(err.errors_at("items[1].quantity")[0].code, "range");
+
+    // Quantities are fixed later on
+    err.retain(|path, _| !path.to_string().ends_with(".quantity"));
+    assert!(!err.contains("items[1]"));
+    assert!(err.remove("items[2].sku").is_some());
+    assert!(err.is_empty());
+}
+
+#[test]
+fn test_can_merge_errors_of_several_validations() {
+    #[derive(Validate)]
+    struct Order {
+        #[validate(length(min = 1))]
+        name: String,
+        #[validate(nested)]
+        items: Vec<Item>,
+    }
+
+    #[derive(Validate, Serialize)]
+    struct Item {
+        #[validate(length(min = 1))]
+        sku: String,
+    }
+
+    let instance = Order {
+        name: String::new(),
+        items: vec![Item { sku: "A1".to_string() }, Item { sku: String::new() }],
+    };
+    let mut err = instance.validate().unwrap_err();
+
+    // A check done outside of the derive, like a lookup in a database
+    let mut manual = ValidationErrors::new();
+    manual.add("name", ValidationError::new("taken"));
+    manual.add_item("items", 0, "sku", ValidationError::new("unknown_sku"));
+    manual.add_item("items", 1, "sku", ValidationError::new("unknown_sku"));
+    err.merge_from(manual);
+
+    let codes = |path| err.errors_at(path).iter().map(|e| e.code.to_string()).collect::<Vec<_>>();
+    assert_eq!(codes("name"), ["length", "taken"]);
+    assert_eq!(codes("items[0].sku"), ["unknown_sku"]);
+    assert_eq!(codes("items[1].sku"), ["length", "unknown_sku"]);
+}
+
+#[test]
+fn test_can_validate_top_level_collections() {
+    #[derive(Validate, Serialize)]
+    struct Item {
+        #[validate(length(min = 1))]
+        sku: String,
+    }
+
+    let items = vec![Item { sku: "A1".to_string() }, Item { sku: String::new() }];
+    let err = items.validate_nested().unwrap_err();
+
+    let ValidationErrorsKind::List(ref list) = err else {
+        panic!("Expected list validation errors");
+    };
+    assert_eq!(list.keys().collect::<Vec<_>>(), [&1]);
+    assert_eq!(
+        serde_json::to_value(&err).unwrap(),
+        serde_json::json!({"1": {"sku": [{
+            "code": "length",
+            "message": null,
+            "params": {"min": 1, "value": ""}
+        }]}})
+    );
+    assert_eq!(err.to_string(), "[1].sku: length must be at least 1");
+
+    let map = HashMap::from([("a", Item { sku: "A1".to_string() })]);
+    assert!(map.validate_nested().is_ok());
+
+    // The errors of the values of a map are reported under their key
+    let map = HashMap::from([
+        ("d", Item { sku: String::new() }),
+        ("b", Item { sku: "B1".to_string() }),
+        ("a.b", Item { sku: "A1".to_string() }),
+        ("c.d", Item { sku: String::new() }),
+    ]);
+    let err = map.validate_nested().unwrap_err();
+    let paths = err.iter_flat().map(|(path, _)| path.to_string()).collect::<Vec<_>>();
+    assert_eq!(paths, [r#"["c.d"].sku"#, "d.sku"]);
+}
+
+#[test]
+fn test_can_validate_nested_collection_fields() {
+    #[derive(Validate)]
+    struct Grid {
+        #[validate(nested)]
+        rows: Vec<Vec<Cell>>,
+    }
+
+    #[derive(Validate, Serialize)]
+    struct Cell {
+        #[validate(range(max = 9))]
+        value: u8,
+    }
+
+    let grid =
+        Grid { rows: vec![vec![Cell { value: 1 }], vec![Cell { value: 2 }, Cell { value: 10 }]] };
+    let err = grid.validate().unwrap_err();
+
+    let paths = err.iter_flat().map(|(path, e)| (path.to_string(), e.code.to_string()));
+    assert_eq!(paths.collect::<Vec<_>>(), [("rows[1][1].value".to_string(), "range".to_string())]);
+    assert!(!serde_json::to_string(&err).unwrap().contains("_tmp_validator"));
+}
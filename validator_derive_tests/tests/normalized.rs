@@ -0,0 +1,70 @@
+use validator::Validate;
+
+#[test]
+fn can_validate_normalized_ok() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(normalized)]
+        val: String,
+        #[validate(normalized(form = "NFKC"))]
+        val2: Option<String>,
+    }
+
+    let s = TestStruct { val: "caf\u{00E9}".to_string(), val2: Some("fine".to_string()) };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn not_normalized_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(normalized(form = "NFC"))]
+        val: String,
+        #[validate(normalized(form = "NFKC"))]
+        val2: String,
+    }
+
+    let s = TestStruct { val: "cafe\u{0301}".to_string(), val2: "\u{FB01}ne".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "normalized");
+    assert_eq!(errs["val"][0].params["form"], "NFC");
+    assert_eq!(errs["val"][0].params["value"], "cafe\u{0301}");
+    assert_eq!(errs["val2"][0].params["form"], "NFKC");
+}
+
+#[test]
+fn can_specify_code_for_normalized() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(normalized(code = "oops"))]
+        val: String,
+    }
+    let s = TestStruct { val: "cafe\u{0301}".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "oops");
+}
+
+#[test]
+fn can_specify_message_for_normalized() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(normalized(message = "oops"))]
+        val: String,
+    }
+    let s = TestStruct { val: "cafe\u{0301}".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].clone().message.unwrap(), "oops");
+}
@@ -0,0 +1,139 @@
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use validator::Validate;
+
+#[test]
+fn can_validate_one_of_ok() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(one_of(values = ["draft", "published", "archived"]))]
+        status: String,
+        #[validate(one_of(values = [80, 443]))]
+        port: u16,
+        #[validate(one_of(values = ['y', 'n']))]
+        answer: Option<char>,
+    }
+
+    let s = TestStruct { status: "draft".to_string(), port: 443, answer: None };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn value_not_in_list_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(one_of(values = ["draft", "published", "archived"]))]
+        status: String,
+        #[validate(one_of(values = [80, 443]))]
+        port: u16,
+    }
+
+    let s = TestStruct { status: "Draft".to_string(), port: 8080 };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["status"].len(), 1);
+    assert_eq!(errs["status"][0].code, "one_of");
+    assert_eq!(
+        errs["status"][0].params["values"],
+        serde_json::json!(["draft", "published", "archived"])
+    );
+    assert_eq!(errs["status"][0].params["value"], "Draft");
+    assert_eq!(errs["port"][0].code, "one_of");
+    assert_eq!(errs["port"][0].params["values"], serde_json::json!([80, 443]));
+}
+
+#[test]
+fn can_validate_one_of_case_insensitive() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(one_of(values = ["draft", "published"], case_insensitive))]
+        status: Option<String>,
+    }
+
+    assert!(TestStruct { status: Some("DRAFT".to_string()) }.validate().is_ok());
+    assert!(TestStruct { status: None }.validate().is_ok());
+
+    let err = TestStruct { status: Some("archived".to_string()) }.validate().unwrap_err();
+    assert_eq!(err.field_errors()["status"][0].code, "one_of");
+}
+
+static STATUSES: [&str; 2] = ["draft", "published"];
+static RESERVED: LazyLock<HashSet<String>> =
+    LazyLock::new(|| ["admin".to_string(), "root".to_string()].into());
+
+#[test]
+fn can_validate_values_from() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(one_of(values_from = STATUSES))]
+        status: String,
+        #[validate(none_of(values_from = RESERVED, case_insensitive))]
+        username: String,
+    }
+
+    let s = TestStruct { status: "draft".to_string(), username: "keats".to_string() };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { status: "archived".to_string(), username: "Admin".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["status"][0].code, "one_of");
+    assert_eq!(errs["status"][0].params["values"], serde_json::json!(["draft", "published"]));
+    assert_eq!(errs["username"][0].code, "none_of");
+    assert!(!errs["username"][0].params.contains_key("values"));
+}
+
+#[test]
+fn value_in_denylist_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(none_of(values = [0, -1]))]
+        offset: i64,
+    }
+
+    assert!(TestStruct { offset: 3 }.validate().is_ok());
+
+    let err = TestStruct { offset: -1 }.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["offset"][0].code, "none_of");
+    assert_eq!(errs["offset"][0].params["values"], serde_json::json!([0, -1]));
+    assert_eq!(errs["offset"][0].params["value"], -1);
+}
+
+#[test]
+fn can_specify_code_for_one_of() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(one_of(values = ["a"], code = "oops"))]
+        val: String,
+    }
+    let s = TestStruct { val: "b".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "oops");
+}
+
+#[test]
+fn can_specify_message_for_none_of() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(none_of(values = ["a"], message = "oops"))]
+        val: String,
+    }
+    let s = TestStruct { val: "a".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].clone().message.unwrap(), "oops");
+}
@@ -0,0 +1,88 @@
+use validator::{Validate, ValidationError};
+
+fn always_fails(_: &str) -> Result<(), ValidationError> {
+    Err(ValidationError::new("fails"))
+}
+
+#[derive(Debug, Validate)]
+struct Child {
+    #[validate(length(min = 2))]
+    zeta: String,
+    #[validate(length(min = 2))]
+    alpha: String,
+}
+
+#[derive(Debug, Validate)]
+#[validate(schema(function = "schema_fails", skip_on_field_errors = false))]
+struct TestStruct {
+    #[validate(range(min = 1))]
+    zone: u32,
+    #[validate(email)]
+    mail: String,
+    #[validate(nested)]
+    child: Child,
+    #[validate(custom(function = "always_fails"))]
+    banner: String,
+    #[validate(length(min = 2, max = 4))]
+    code: String,
+}
+
+fn schema_fails(_: &TestStruct) -> Result<(), ValidationError> {
+    Err(ValidationError::new("schema"))
+}
+
+fn invalid() -> TestStruct {
+    TestStruct {
+        zone: 0,
+        mail: "bob".to_string(),
+        child: Child { zeta: "z".to_string(), alpha: "a".to_string() },
+        banner: String::new(),
+        code: "a".to_string(),
+    }
+}
+
+#[test]
+fn errors_are_in_declaration_order() {
+    let err = invalid().validate().unwrap_err();
+
+    let paths = err.iter_flat().map(|(path, _)| path.to_string()).collect::<Vec<_>>();
+    assert_eq!(paths, ["zone", "mail", "child.zeta", "child.alpha", "banner", "code", "__all__"]);
+
+    let keys = err.errors().keys().map(|k| k.as_ref()).collect::<Vec<_>>();
+    assert_eq!(keys, ["zone", "mail", "child", "banner", "code", "__all__"]);
+
+    // Same order in every run
+    let display = err.to_string();
+    let lines = display.lines().map(|l| l.split(':').next().unwrap()).collect::<Vec<_>>();
+    assert_eq!(lines, ["zone", "mail", "child.zeta", "child.alpha", "banner", "code", "__all__"]);
+    for _ in 0..10 {
+        assert_eq!(invalid().validate().unwrap_err().to_string(), display);
+    }
+}
+
+#[test]
+fn serialization_is_in_declaration_order() {
+    let err = invalid().validate().unwrap_err();
+    let json = serde_json::to_string(&err).unwrap();
+    let positions = ["zone\":[", "mail\":[", "child\":{", "banner\":[", "code\":[", "__all__\":["]
+        .map(|key| json.find(key).unwrap());
+    assert!(positions.windows(2).all(|w| w[0] < w[1]), "{}", json);
+
+    // Params are in the order they were added
+    let code = &err.field_errors()["code"][0];
+    let params = code.params.keys().map(|k| k.as_ref()).collect::<Vec<_>>();
+    assert_eq!(params, ["min", "max", "value"]);
+}
+
+#[test]
+fn can_sort_errors_alphabetically() {
+    let mut err = invalid().validate().unwrap_err();
+    err.sort_alphabetically();
+
+    let paths = err.iter_flat().map(|(path, _)| path.to_string()).collect::<Vec<_>>();
+    assert_eq!(paths, ["__all__", "banner", "child.alpha", "child.zeta", "code", "mail", "zone"]);
+
+    let code = &err.field_errors()["code"][0];
+    let params = code.params.keys().map(|k| k.as_ref()).collect::<Vec<_>>();
+    assert_eq!(params, ["max", "min", "value"]);
+}
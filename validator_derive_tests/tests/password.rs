@@ -0,0 +1,179 @@
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use validator::Validate;
+
+static BREACHED: LazyLock<HashSet<String>> =
+    LazyLock::new(|| HashSet::from(["Tr0ub4dor&3".to_string()]));
+
+#[test]
+fn can_validate_strong_password() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(password(min_score = 3, min_length = 12, require_classes = 3))]
+        val: String,
+    }
+
+    let s = TestStruct { val: "kX9#mQ2$vL7!".to_string() };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn weak_password_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(password(min_score = 3))]
+        val: String,
+    }
+
+    let s = TestStruct { val: "Password1234".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "password_strength");
+    assert_eq!(errs["val"][0].params["score"], 1);
+    assert!(errs["val"][0].params["suggestions"]
+        .as_array()
+        .unwrap()
+        .contains(&"avoid_common_passwords".into()));
+    assert!(!errs["val"][0].params.contains_key("value"));
+}
+
+#[test]
+fn password_rules_have_their_own_code() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(password(min_length = 12, require_classes = 3))]
+        short: String,
+        #[validate(password(min_length = 12, require_classes = 3))]
+        classes: String,
+    }
+
+    let s = TestStruct { short: "kX9#mQ2".to_string(), classes: "kxamqbvlpwoe".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["short"][0].code, "password_length");
+    assert_eq!(errs["classes"][0].code, "password_classes");
+}
+
+#[test]
+fn password_containing_other_fields_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        email: String,
+        username: Option<String>,
+        #[validate(password(forbid_fields = [email, username]))]
+        val: String,
+    }
+
+    let s = TestStruct {
+        email: "vincent@example.com".to_string(),
+        username: Some("keats".to_string()),
+        val: "kX9#Vincent-2$vL".to_string(),
+    };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "password_user_input");
+
+    let s = TestStruct {
+        email: "vincent@example.com".to_string(),
+        username: Some("keats".to_string()),
+        val: "kX9#KEATS-2$vL".to_string(),
+    };
+    let err = s.validate().unwrap_err();
+    assert_eq!(err.field_errors()["val"][0].code, "password_user_input");
+
+    let s = TestStruct {
+        email: "vincent@example.com".to_string(),
+        username: None,
+        val: "kX9#mQ2$vL7!".to_string(),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn blocklisted_password_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(password(blocklist = *BREACHED))]
+        val: String,
+        #[validate(password(blocklist = ["hunter2", "letmein"]))]
+        other: String,
+    }
+
+    let s = TestStruct { val: "Tr0ub4dor&3".to_string(), other: "hunter2".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "password_blocklisted");
+    assert_eq!(errs["other"][0].code, "password_blocklisted");
+}
+
+#[test]
+fn can_validate_option_password() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(password(min_length = 12))]
+        val: Option<String>,
+    }
+
+    assert!(TestStruct { val: None }.validate().is_ok());
+    assert!(TestStruct { val: Some("short".to_string()) }.validate().is_err());
+}
+
+#[test]
+fn can_specify_code_for_password() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(password(min_score = 3, code = "oops"))]
+        val: String,
+    }
+    let s = TestStruct { val: "password".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "oops");
+}
+
+#[test]
+fn can_specify_message_for_password() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(password(min_score = 3, message = "oops"))]
+        val: String,
+    }
+    let s = TestStruct { val: "password".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].clone().message.unwrap(), "oops");
+}
+
+#[test]
+fn can_validate_option_fields_named_like_the_generated_code() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(password(min_length = 10))]
+        rules: Option<String>,
+        #[validate(password(min_length = 10, forbid_fields = [rules]))]
+        forbidden: Option<String>,
+    }
+
+    let s = TestStruct { rules: Some("short".to_string()), forbidden: None };
+    let err = s.validate().unwrap_err();
+    assert_eq!(err.field_errors()["rules"][0].code, "password_length");
+    assert!(TestStruct { rules: None, forbidden: None }.validate().is_ok());
+}
@@ -0,0 +1,111 @@
+use validator::{Validate, ValidationError, REDACTED};
+
+fn validate_not_reused(_: &str) -> Result<(), ValidationError> {
+    Err(ValidationError::new("reused"))
+}
+
+#[derive(Validate)]
+struct Card {
+    #[validate(length(equal = 16), sensitive)]
+    number: String,
+    #[validate(length(min = 1))]
+    holder: String,
+}
+
+#[test]
+fn sensitive_field_values_are_redacted() {
+    #[derive(Validate)]
+    struct Signup {
+        #[validate(length(min = 3))]
+        username: String,
+        #[validate(length(min = 8), custom(function = "validate_not_reused"), sensitive)]
+        password: String,
+        #[validate(nested, sensitive)]
+        card: Card,
+    }
+
+    let signup = Signup {
+        username: "ab".to_string(),
+        password: "hunter2".to_string(),
+        card: Card { number: "4111".to_string(), holder: String::new() },
+    };
+    let err = signup.validate().unwrap_err();
+
+    assert_eq!(err.errors_at("username")[0].params["value"], "ab");
+    let password = err.errors_at("password");
+    assert_eq!(password.len(), 2);
+    assert!(password.iter().all(|e| e.params["value"] == REDACTED));
+    assert_eq!(password[0].params["min"], 8);
+    assert_eq!(err.errors_at("card.number")[0].params["value"], REDACTED);
+    assert_eq!(err.errors_at("card.holder")[0].params["value"], REDACTED);
+    assert!(!serde_json::to_string(&err).unwrap().contains("hunter2"));
+
+    // Only the field marked as sensitive is redacted when validating the nested struct on its own
+    let err = signup.card.validate().unwrap_err();
+    assert_eq!(err.errors_at("number")[0].params["value"], REDACTED);
+    assert_eq!(err.errors_at("holder")[0].params["value"], "");
+}
+
+#[test]
+fn struct_level_redact_hides_must_match_values() {
+    #[derive(Validate)]
+    #[validate(redact = [password, password_confirmation])]
+    struct ChangePassword {
+        #[validate(length(min = 8))]
+        password: String,
+        #[validate(must_match(other = "password"))]
+        password_confirmation: String,
+    }
+
+    let change = ChangePassword {
+        password: "hunter2".to_string(),
+        password_confirmation: "hunter3".to_string(),
+    };
+    let err = change.validate().unwrap_err();
+
+    assert_eq!(err.errors_at("password")[0].params["value"], REDACTED);
+    let confirmation = &err.errors_at("password_confirmation")[0];
+    assert_eq!(confirmation.params["value"], REDACTED);
+    assert_eq!(confirmation.params["other"], REDACTED);
+}
+
+#[test]
+fn must_match_redacts_the_value_of_a_sensitive_other_field() {
+    #[derive(Validate)]
+    struct ChangePassword {
+        #[validate(length(min = 1), sensitive)]
+        password: String,
+        #[validate(must_match(other = "password"))]
+        password_confirmation: String,
+    }
+
+    let change = ChangePassword {
+        password: "hunter2".to_string(),
+        password_confirmation: "typo".to_string(),
+    };
+    let err = change.validate().unwrap_err();
+
+    let confirmation = &err.errors_at("password_confirmation")[0];
+    assert_eq!(confirmation.params["value"], "typo");
+    assert_eq!(confirmation.params["other"], REDACTED);
+    assert!(!serde_json::to_string(&err).unwrap().contains("hunter2"));
+}
+
+#[test]
+fn can_redact_all_values() {
+    #[derive(Validate)]
+    struct Order {
+        #[validate(length(min = 1))]
+        name: String,
+        #[validate(range(max = 10))]
+        quantity: u32,
+    }
+
+    let mut err = Order { name: String::new(), quantity: 11 }.validate().unwrap_err();
+    err.redact_values();
+
+    for (_, error) in err.iter_flat() {
+        assert_eq!(error.params["value"], REDACTED);
+    }
+    assert_eq!(err.errors_at("quantity")[0].params["max"], 10);
+}
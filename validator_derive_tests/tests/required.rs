@@ -120,3 +120,29 @@ fn can_validate_custom_impl_for_required() {
     assert!(something.validate().is_ok());
     assert!(nothing.validate().is_err());
 }
+
+#[test]
+fn can_treat_empty_as_none_for_required() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(required(empty_as_none))]
+        name: Option<String>,
+        #[validate(required(empty_as_none, code = "no_tags"))]
+        tags: Option<Vec<String>>,
+    }
+
+    let s = TestStruct { name: Some(" ".to_string()), tags: Some(vec!["a".to_string()]) };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { name: Some(String::new()), tags: Some(vec![]) };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["name"][0].code, "required");
+    assert_eq!(errs["tags"][0].code, "no_tags");
+
+    let s = TestStruct { name: None, tags: None };
+    let err = s.validate().unwrap_err();
+    assert_eq!(err.field_errors().len(), 2);
+}
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use validator::{Sanitize, Validate};
+
+#[test]
+fn can_sanitize_strings() {
+    #[derive(Debug, Sanitize)]
+    struct TestStruct {
+        #[sanitizer(trim, lowercase)]
+        email: String,
+        #[sanitizer(collapse_whitespace, trim)]
+        name: Option<String>,
+        #[sanitizer(strip_control, uppercase)]
+        country: String,
+        #[sanitizer(nfc)]
+        city: String,
+        untouched: String,
+    }
+
+    let mut s = TestStruct {
+        email: "  Bob@Example.COM ".to_string(),
+        name: Some(" Vincent \t  Prouillet\n".to_string()),
+        country: "f\u{0}r".to_string(),
+        city: "Montre\u{0301}al".to_string(),
+        untouched: " a ".to_string(),
+    };
+    s.sanitize();
+
+    assert_eq!(s.email, "bob@example.com");
+    assert_eq!(s.name.as_deref(), Some("Vincent Prouillet"));
+    assert_eq!(s.country, "FR");
+    assert_eq!(s.city, "Montr\u{00E9}al");
+    assert_eq!(s.untouched, " a ");
+}
+
+#[test]
+fn collapses_whitespace_before_stripping_control_characters() {
+    #[derive(Debug, Sanitize)]
+    struct TestStruct {
+        #[sanitizer(collapse_whitespace, strip_control)]
+        bio: String,
+        #[sanitizer(strip_control, trim)]
+        name: String,
+    }
+
+    let mut s = TestStruct {
+        bio: "first\nsecond\tthird\u{7}".to_string(),
+        name: "\tVincent\u{0}\n".to_string(),
+    };
+    s.sanitize();
+
+    assert_eq!(s.bio, "first second third");
+    assert_eq!(s.name, "Vincent");
+}
+
+#[test]
+fn can_sanitize_collections_of_strings() {
+    #[derive(Debug, Sanitize)]
+    struct TestStruct {
+        #[sanitizer(trim)]
+        tags: Vec<String>,
+        #[sanitizer(trim)]
+        labels: HashMap<String, String>,
+    }
+
+    let mut s = TestStruct {
+        tags: vec![" a".to_string(), "b ".to_string()],
+        labels: HashMap::from([("env".to_string(), " prod ".to_string())]),
+    };
+    s.sanitize();
+
+    assert_eq!(s.tags, vec!["a", "b"]);
+    assert_eq!(s.labels["env"], "prod");
+}
+
+fn remove_dashes(value: &mut String) {
+    value.retain(|c| c != '-');
+}
+
+#[test]
+fn can_sanitize_nested_and_custom() {
+    #[derive(Debug, Sanitize)]
+    struct Parent {
+        #[sanitizer(nested)]
+        child: Child,
+        #[sanitizer(nested)]
+        children: Vec<Child>,
+        #[sanitizer(nested)]
+        optional_child: Option<Child>,
+    }
+
+    #[derive(Debug, Sanitize)]
+    struct Child {
+        #[sanitizer(trim, custom(function = remove_dashes))]
+        phone: String,
+    }
+
+    let mut s = Parent {
+        child: Child { phone: " 555-1234 ".to_string() },
+        children: vec![Child { phone: "1-2".to_string() }],
+        optional_child: Some(Child { phone: "3 ".to_string() }),
+    };
+    s.sanitize();
+
+    assert_eq!(s.child.phone, "5551234");
+    assert_eq!(s.children[0].phone, "12");
+    assert_eq!(s.optional_child.unwrap().phone, "3");
+}
+
+#[test]
+fn can_sanitize_and_validate() {
+    #[derive(Debug, Sanitize, Validate)]
+    struct TestStruct {
+        #[sanitizer(trim, lowercase)]
+        #[validate(email)]
+        email: String,
+        #[sanitizer(trim)]
+        #[validate(length(min = 1))]
+        name: String,
+    }
+
+    let mut s = TestStruct { email: " Bob@Example.com ".to_string(), name: "Bob".to_string() };
+    assert!(s.validate().is_err());
+    assert!(s.sanitize_and_validate().is_ok());
+    assert_eq!(s.email, "bob@example.com");
+
+    let mut s = TestStruct { email: "bob@example.com".to_string(), name: "   ".to_string() };
+    let err = s.sanitize_and_validate().unwrap_err();
+    assert_eq!(err.field_errors()["name"][0].code, "length");
+}
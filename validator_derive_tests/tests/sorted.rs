@@ -0,0 +1,65 @@
+use validator::{Validate, ValidationErrorsKind};
+
+#[test]
+fn can_validate_sorted_ok() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(sorted)]
+        timestamps: Vec<u64>,
+        #[validate(sorted(desc, strict))]
+        scores: [f32; 3],
+    }
+
+    let s = TestStruct { timestamps: vec![1, 2, 2, 5], scores: [3.0, 2.5, 1.0] };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn items_out_of_order_fail_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(sorted(strict))]
+        timestamps: Option<Vec<u64>>,
+    }
+
+    assert!(TestStruct { timestamps: None }.validate().is_ok());
+
+    let s = TestStruct { timestamps: Some(vec![1, 3, 3, 2]) };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    if let ValidationErrorsKind::List(ref items) = err.errors()["timestamps"] {
+        assert_eq!(items.keys().copied().collect::<Vec<_>>(), vec![2, 3]);
+        let item_errs = items[&3].field_errors();
+        assert_eq!(item_errs["_item"][0].code, "sorted");
+        assert_eq!(item_errs["_item"][0].params["order"], "asc");
+        assert_eq!(item_errs["_item"][0].params["strict"], true);
+        assert_eq!(item_errs["_item"][0].params["previous_index"], 2);
+        assert_eq!(item_errs["_item"][0].params["value"], 2);
+    } else {
+        panic!("Expected list validation errors");
+    }
+}
+
+#[test]
+fn can_combine_unique_and_sorted() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(unique, sorted(desc, code = "desc"))]
+        val: Vec<String>,
+    }
+
+    let s = TestStruct { val: vec!["b".to_string(), "c".to_string(), "b".to_string()] };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    if let ValidationErrorsKind::List(ref items) = err.errors()["val"] {
+        assert_eq!(items[&1].field_errors()["_item"][0].code, "desc");
+        let item_errs = items[&2].field_errors();
+        assert_eq!(item_errs["_item"].len(), 1);
+        assert_eq!(item_errs["_item"][0].code, "unique");
+    } else {
+        panic!("Expected list validation errors");
+    }
+}
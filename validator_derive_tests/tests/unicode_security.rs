@@ -0,0 +1,170 @@
+use validator::Validate;
+
+#[test]
+fn can_validate_single_script_ok() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(single_script)]
+        val: String,
+    }
+
+    let s = TestStruct { val: "東京タワー_2".to_string() };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn mixed_scripts_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(single_script)]
+        val: String,
+    }
+
+    let s = TestStruct { val: "pаypal".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "single_script");
+    assert_eq!(errs["val"][0].params["positions"], serde_json::json!([1]));
+    assert_eq!(errs["val"][0].params["value"], "pаypal");
+}
+
+#[test]
+fn confusable_with_field_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        username: Option<String>,
+        #[validate(no_confusable_with(field = username, values = ["admin"]))]
+        display_name: String,
+    }
+
+    let s = TestStruct { username: Some("keats".to_string()), display_name: "Vincent".to_string() };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { username: Some("keats".to_string()), display_name: "kеats".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["display_name"][0].code, "no_confusable_with");
+    assert_eq!(errs["display_name"][0].params["positions"], serde_json::json!([1]));
+
+    let s = TestStruct { username: None, display_name: "аdmin".to_string() };
+    let err = s.validate().unwrap_err();
+    assert_eq!(err.field_errors()["display_name"][0].code, "no_confusable_with");
+}
+
+#[test]
+fn confusable_with_values_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(no_confusable_with(values = ["admin", "root"]))]
+        val: String,
+    }
+
+    assert!(TestStruct { val: "rooted".to_string() }.validate().is_ok());
+
+    let s = TestStruct { val: "𝐫𝐨𝐨𝐭".to_string() };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "no_confusable_with");
+    assert_eq!(errs["val"][0].params["positions"], serde_json::json!([0, 1, 2, 3]));
+}
+
+#[test]
+fn bidi_controls_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(no_bidi_controls)]
+        val: String,
+    }
+
+    assert!(TestStruct { val: "שלום hello".to_string() }.validate().is_ok());
+
+    let s = TestStruct { val: "access\u{202E}level".to_string() };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "no_bidi_controls");
+    assert_eq!(errs["val"][0].params["positions"], serde_json::json!([6]));
+}
+
+#[test]
+fn invisible_characters_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(no_invisible)]
+        val: Option<String>,
+    }
+
+    assert!(TestStruct { val: None }.validate().is_ok());
+    assert!(TestStruct { val: Some("hello world".to_string()) }.validate().is_ok());
+
+    let s = TestStruct { val: Some("hel\u{200B}lo".to_string()) };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "no_invisible");
+    assert_eq!(errs["val"][0].params["positions"], serde_json::json!([3]));
+}
+
+#[test]
+fn can_specify_code_for_unicode_security() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(single_script(code = "oops"), no_invisible(code = "oops2"))]
+        val: String,
+    }
+    let s = TestStruct { val: "pаy\u{200B}pal".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 2);
+    assert_eq!(errs["val"][0].code, "oops");
+    assert_eq!(errs["val"][1].code, "oops2");
+}
+
+#[test]
+fn can_specify_message_for_unicode_security() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(no_bidi_controls(message = "oops"))]
+        val: String,
+    }
+    let s = TestStruct { val: "\u{2066}".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].clone().message.unwrap(), "oops");
+}
+
+#[test]
+fn can_validate_option_fields_named_like_the_generated_code() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(single_script)]
+        positions: Option<String>,
+        #[validate(no_confusable_with(values = ["admin"]))]
+        others: Option<String>,
+        #[validate(no_confusable_with(field = others))]
+        other: Option<String>,
+    }
+
+    let s = TestStruct {
+        positions: Some("pаypal".to_string()),
+        others: Some("аdmin".to_string()),
+        other: Some("аdmіn".to_string()),
+    };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["positions"][0].params["value"], "pаypal");
+    assert_eq!(errs["positions"][0].params["positions"], serde_json::json!([1]));
+    assert_eq!(errs["others"][0].code, "no_confusable_with");
+    assert_eq!(errs["other"][0].code, "no_confusable_with");
+    assert!(TestStruct { positions: None, others: None, other: None }.validate().is_ok());
+}
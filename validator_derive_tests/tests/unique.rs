@@ -0,0 +1,134 @@
+use std::collections::VecDeque;
+
+use serde::Serialize;
+use validator::{Validate, ValidationErrorsKind};
+
+#[test]
+fn can_validate_unique_ok() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(unique)]
+        emails: Vec<String>,
+        #[validate(unique)]
+        ids: Option<VecDeque<u64>>,
+    }
+
+    let s = TestStruct {
+        emails: vec!["a@example.com".to_string(), "b@example.com".to_string()],
+        ids: Some(VecDeque::from([1, 2, 3])),
+    };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn duplicates_fail_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(unique)]
+        emails: Vec<String>,
+    }
+
+    let s = TestStruct {
+        emails: vec![
+            "a@example.com".to_string(),
+            "b@example.com".to_string(),
+            "a@example.com".to_string(),
+        ],
+    };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.errors();
+    assert_eq!(errs.len(), 1);
+    if let ValidationErrorsKind::List(ref items) = errs["emails"] {
+        assert_eq!(items.len(), 1);
+        let item_errs = items[&2].field_errors();
+        assert_eq!(item_errs["_item"].len(), 1);
+        assert_eq!(item_errs["_item"][0].code, "unique");
+        assert_eq!(item_errs["_item"][0].params["first_index"], 0);
+        assert_eq!(item_errs["_item"][0].params["value"], "a@example.com");
+    } else {
+        panic!("Expected list validation errors");
+    }
+}
+
+#[test]
+fn duplicate_keys_fail_validation_with_nested_errors() {
+    #[derive(Debug, Validate)]
+    struct Order {
+        #[validate(nested, unique_by = "sku")]
+        items: Vec<LineItem>,
+    }
+
+    #[derive(Debug, Validate, Serialize)]
+    struct LineItem {
+        sku: String,
+        #[validate(range(min = 1))]
+        quantity: u32,
+    }
+
+    let s = Order {
+        items: vec![
+            LineItem { sku: "A-1".to_string(), quantity: 1 },
+            LineItem { sku: "B-2".to_string(), quantity: 0 },
+            LineItem { sku: "A-1".to_string(), quantity: 0 },
+        ],
+    };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.errors();
+    if let ValidationErrorsKind::List(ref items) = errs["items"] {
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[&1].field_errors()["quantity"][0].code, "range");
+
+        let item_errs = items[&2].field_errors();
+        assert_eq!(item_errs.len(), 2);
+        assert_eq!(item_errs["quantity"][0].code, "range");
+        assert_eq!(item_errs["sku"][0].code, "unique_by");
+        assert_eq!(item_errs["sku"][0].params["first_index"], 0);
+        assert_eq!(item_errs["sku"][0].params["value"], "A-1");
+    } else {
+        panic!("Expected list validation errors");
+    }
+}
+
+#[test]
+fn duplicates_are_added_to_field_errors_of_the_collection() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(length(max = 2), unique)]
+        tags: Vec<String>,
+    }
+
+    let s = TestStruct { tags: vec!["a".to_string(), "b".to_string(), "a".to_string()] };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["tags"].len(), 2);
+    assert_eq!(errs["tags"][0].code, "length");
+    assert_eq!(errs["tags"][1].code, "unique");
+    assert_eq!(errs["tags"][1].params["index"], 2);
+}
+
+#[test]
+fn can_specify_code_and_message_for_unique() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(unique(code = "oops", message = "no duplicates"))]
+        val: Vec<u8>,
+    }
+    let s = TestStruct { val: vec![1, 1] };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    if let ValidationErrorsKind::List(ref items) = err.errors()["val"] {
+        let item_errs = items[&1].field_errors();
+        assert_eq!(item_errs["_item"][0].code, "oops");
+        assert_eq!(item_errs["_item"][0].clone().message.unwrap(), "no duplicates");
+    } else {
+        panic!("Expected list validation errors");
+    }
+}
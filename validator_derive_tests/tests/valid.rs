@@ -0,0 +1,67 @@
+use serde::Deserialize;
+use validator::{Valid, Validate, ValidationError};
+
+#[derive(Debug, Deserialize, Validate)]
+struct SignupData {
+    #[validate(email)]
+    mail: String,
+    #[validate(range(min = 18))]
+    age: u32,
+}
+
+fn register(data: Valid<SignupData>) -> String {
+    data.mail.clone()
+}
+
+#[test]
+fn can_build_valid_value() {
+    let data = Valid::new(SignupData { mail: "bob@bob.com".to_string(), age: 20 }).unwrap();
+    assert_eq!(register(data), "bob@bob.com");
+}
+
+#[test]
+fn invalid_value_is_rejected() {
+    let err = Valid::new(SignupData { mail: "bob".to_string(), age: 12 }).unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["mail"][0].code, "email");
+    assert_eq!(errs["age"][0].code, "range");
+}
+
+#[test]
+fn can_deserialize_valid_value() {
+    let data: Valid<SignupData> =
+        serde_json::from_str(r#"{"mail": "bob@bob.com", "age": 20}"#).unwrap();
+    assert_eq!(data.age, 20);
+    assert_eq!(data.into_inner().mail, "bob@bob.com");
+
+    let res = serde_json::from_str::<Valid<SignupData>>(r#"{"mail": "bob", "age": 20}"#);
+    assert!(res.unwrap_err().to_string().contains("mail: must be a valid email address"));
+}
+
+#[derive(Debug)]
+struct TestContext {
+    max_age: u32,
+}
+
+fn check_age(age: u32, context: &TestContext) -> Result<(), ValidationError> {
+    if age > context.max_age {
+        return Err(ValidationError::new("too_old"));
+    }
+    Ok(())
+}
+
+#[test]
+fn can_build_valid_value_with_args() {
+    #[derive(Debug, Validate)]
+    #[validate(context = TestContext)]
+    struct TestStruct {
+        #[validate(custom(function = check_age, use_context))]
+        age: u32,
+    }
+
+    let context = TestContext { max_age: 30 };
+    assert!(Valid::new_with_args(TestStruct { age: 20 }, &context).is_ok());
+
+    let err = Valid::new_with_args(TestStruct { age: 40 }, &context).unwrap_err();
+    assert_eq!(err.field_errors()["age"][0].code, "too_old");
+}